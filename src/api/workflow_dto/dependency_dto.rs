@@ -4,4 +4,8 @@ use serde::{Deserialize, Serialize};
 pub struct DependencyDto {
     pub data: Vec<String>,
     pub sync: Vec<String>,
+
+    /// Preferred, but not required synchronization with the listed tasks.
+    #[serde(default)]
+    pub soft_sync: Vec<String>,
 }
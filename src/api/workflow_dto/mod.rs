@@ -1,4 +1,5 @@
 pub mod workflow_dto;
 pub mod client_dto;
 pub mod reservation_dto;
-pub mod dependency_dto;
\ No newline at end of file
+pub mod dependency_dto;
+pub mod validation;
\ No newline at end of file
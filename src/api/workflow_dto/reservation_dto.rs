@@ -23,6 +23,9 @@ pub struct NodeReservationDto {
     pub duration: i64,
     pub cpus: i64,
     pub is_moldable: bool,
+    /// Explicit lower bound on the start of the task in s (VRM time), e.g. the time at which external input data is available.
+    #[serde(default)]
+    pub earliest_start: Option<i64>,
     pub dependencies: DependencyDto,
     pub data_out: Vec<DataOutDto>,
     pub data_in: Vec<DataInDto>,
@@ -70,6 +73,20 @@ impl ReservationStateDto {
             Self::Finished => ReservationState::Finished,
         }
     }
+
+    /// Maps the internal state onto the DTO state. The transient probe states are mapped onto `ProbeAnswer`,
+    /// external tasks of a local RMS onto `Committed`.
+    pub fn from_reservation_state(state: ReservationState) -> Self {
+        match state {
+            ReservationState::Rejected => Self::Rejected,
+            ReservationState::Deleted => Self::Deleted,
+            ReservationState::Open => Self::Open,
+            ReservationState::ProbeAnswer | ReservationState::ProbeReservation | ReservationState::ReserveProbeReservation => Self::ProbeAnswer,
+            ReservationState::ReserveAnswer => Self::ReserveAnswer,
+            ReservationState::Committed | ReservationState::External => Self::Committed,
+            ReservationState::Finished => Self::Finished,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
@@ -89,6 +106,17 @@ impl ReservationProceedingDto {
             Self::Delete => ReservationProceeding::Delete,
         }
     }
+
+    /// Maps the internal proceeding onto the DTO proceeding. `Ignore` is only utilized for external tasks of a
+    /// local RMS and is mapped onto `Probe`, because both do not allocate resources.
+    pub fn from_reservation_proceeding(proceeding: ReservationProceeding) -> Self {
+        match proceeding {
+            ReservationProceeding::Probe | ReservationProceeding::Ignore => Self::Probe,
+            ReservationProceeding::Reserve => Self::Reserve,
+            ReservationProceeding::Commit => Self::Commit,
+            ReservationProceeding::Delete => Self::Delete,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -97,6 +125,9 @@ pub struct DataOutDto {
     pub file: Option<String>,
     pub size: Option<i64>,
     pub bandwidth: Option<i64>,
+    /// Fixed propagation latency of the transfer in s, independent of its size.
+    #[serde(default)]
+    pub latency: Option<i64>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use thiserror::Error;
+
+use crate::api::workflow_dto::client_dto::ClientsDto;
+use crate::api::workflow_dto::workflow_dto::WorkflowDto;
+use crate::domain::vrm_system_model::utils::config::EXTERNAL_DATA_SOURCE;
+
+/// A semantic problem of a parsed workflow file, which would otherwise only surface during the model construction.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ValidationError {
+    #[error("Workflow {workflow} contains the task id {task} more than once")]
+    DuplicateTaskId { workflow: String, task: String },
+
+    #[error("DataIn of task {task} in workflow {workflow} references the unknown output {source_reservation}/{source_port}")]
+    DanglingDataIn { workflow: String, task: String, source_reservation: String, source_port: String },
+
+    #[error("Task {task} in workflow {workflow} has a negative {field}: {value}")]
+    NegativeValue { workflow: String, task: String, field: String, value: i64 },
+
+    #[error("Workflow {workflow} has an empty booking interval [{start}, {end}]")]
+    InvalidBookingInterval { workflow: String, start: i64, end: i64 },
+}
+
+impl ClientsDto {
+    /// Checks all workflows for semantic problems (duplicate task ids, dangling `data_in` references,
+    /// negative `duration`/`cpus`/`size` and empty booking intervals).
+    ///
+    /// # Returns
+    /// `Ok(())` if no problem was found, otherwise all found problems at once.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut validation_errors = Vec::new();
+
+        for client in &self.clients {
+            for workflow in &client.workflows {
+                Self::validate_workflow(workflow, &mut validation_errors);
+            }
+        }
+
+        if validation_errors.is_empty() {
+            return Ok(());
+        }
+        return Err(validation_errors);
+    }
+
+    fn validate_workflow(workflow: &WorkflowDto, validation_errors: &mut Vec<ValidationError>) {
+        if workflow.booking_interval_end <= workflow.booking_interval_start {
+            validation_errors.push(ValidationError::InvalidBookingInterval {
+                workflow: workflow.id.clone(),
+                start: workflow.booking_interval_start,
+                end: workflow.booking_interval_end,
+            });
+        }
+
+        let mut task_ids: HashSet<&str> = HashSet::new();
+        let mut output_ports: HashSet<(&str, &str)> = HashSet::new();
+
+        for task in &workflow.tasks {
+            if !task_ids.insert(task.id.as_str()) {
+                validation_errors.push(ValidationError::DuplicateTaskId { workflow: workflow.id.clone(), task: task.id.clone() });
+            }
+
+            let node_reservation = &task.node_reservation;
+            let mut values = vec![("duration", node_reservation.duration), ("cpus", node_reservation.cpus)];
+            values.extend(node_reservation.data_out.iter().filter_map(|data_out| data_out.size.map(|size| ("size", size))));
+
+            for (field, value) in values {
+                if value < 0 {
+                    validation_errors.push(ValidationError::NegativeValue {
+                        workflow: workflow.id.clone(),
+                        task: task.id.clone(),
+                        field: field.to_string(),
+                        value,
+                    });
+                }
+            }
+
+            for data_out in &node_reservation.data_out {
+                output_ports.insert((task.id.as_str(), data_out.name.as_str()));
+            }
+        }
+
+        // Outputs can be consumed by any task of the workflow, hence all outputs have to be known first
+        for task in &workflow.tasks {
+            for data_in in &task.node_reservation.data_in {
+                if data_in.source_reservation != EXTERNAL_DATA_SOURCE
+                    && !output_ports.contains(&(data_in.source_reservation.as_str(), data_in.source_port.as_str()))
+                {
+                    validation_errors.push(ValidationError::DanglingDataIn {
+                        workflow: workflow.id.clone(),
+                        task: task.id.clone(),
+                        source_reservation: data_in.source_reservation.clone(),
+                        source_port: data_in.source_port.clone(),
+                    });
+                }
+            }
+        }
+    }
+}
@@ -12,6 +12,9 @@ pub struct WorkflowDto {
     pub booking_interval_end: i64,
     pub state: ReservationStateDto,
     pub request_proceeding: ReservationProceedingDto,
+    /// Priority of all tasks of the workflow, which do not define an own priority (default 0).
+    #[serde(default)]
+    pub priority: Option<i32>,
 
     pub tasks: Vec<TaskDto>,
 }
@@ -22,6 +25,9 @@ pub struct TaskDto {
     pub id: String,
     pub reservation_state: ReservationStateDto,
     pub request_proceeding: ReservationProceedingDto,
+    /// Priority of the task, overrides the priority of the workflow.
+    #[serde(default)]
+    pub priority: Option<i32>,
 
     pub link_reservation: Vec<LinkReservationDto>,
     pub node_reservation: NodeReservationDto,
@@ -0,0 +1,96 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::sync::Mutex;
+
+use crate::domain::vrm_system_model::reservation::reservation_store::ReservationId;
+
+/// An event of the discrete-event simulation, which is processed by the driver loop at its timestamp.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SimEvent {
+    /// The assigned start of the reservation is reached.
+    ReservationStart(ReservationId),
+    /// The assigned end of the reservation is reached.
+    ReservationEnd(ReservationId),
+}
+
+/// A `SimEvent` together with its timestamp.
+/// The sequence number preserves the insertion order of events with the same timestamp.
+#[derive(Debug)]
+struct ScheduledEvent {
+    at: i64,
+    sequence: u64,
+    event: SimEvent,
+}
+
+impl PartialEq for ScheduledEvent {
+    fn eq(&self, other: &Self) -> bool {
+        return self.at == other.at && self.sequence == other.sequence;
+    }
+}
+
+impl Eq for ScheduledEvent {}
+
+impl PartialOrd for ScheduledEvent {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for ScheduledEvent {
+    // Reversed, so that the max-heap `BinaryHeap` returns the earliest event first
+    fn cmp(&self, other: &Self) -> Ordering {
+        return other.at.cmp(&self.at).then(other.sequence.cmp(&self.sequence));
+    }
+}
+
+#[derive(Debug, Default)]
+struct EventQueueState {
+    events: BinaryHeap<ScheduledEvent>,
+    next_sequence: u64,
+}
+
+/// Thread-safe priority queue of `SimEvent`s keyed by their timestamp (in s).
+/// Events with the same timestamp are returned in the order they were scheduled.
+#[derive(Debug, Default)]
+pub struct EventQueue {
+    state: Mutex<EventQueueState>,
+}
+
+impl EventQueue {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Adds the `event`, which is due at the timestamp `at`.
+    pub fn push(&self, at: i64, event: SimEvent) {
+        let mut state = self.state.lock().expect("Lock poisoned");
+        let sequence = state.next_sequence;
+        state.next_sequence += 1;
+        state.events.push(ScheduledEvent { at, sequence, event });
+    }
+
+    /// Returns the timestamp of the earliest queued event, or `None` if the queue is empty.
+    pub fn peek_time(&self) -> Option<i64> {
+        return self.state.lock().expect("Lock poisoned").events.peek().map(|scheduled_event| scheduled_event.at);
+    }
+
+    /// Removes and returns all events, which are due at or before `now`, ordered by their timestamp.
+    pub fn pop_due(&self, now: i64) -> Vec<(i64, SimEvent)> {
+        let mut state = self.state.lock().expect("Lock poisoned");
+        let mut due_events = Vec::new();
+
+        while state.events.peek().is_some_and(|scheduled_event| scheduled_event.at <= now) {
+            let scheduled_event = state.events.pop().unwrap();
+            due_events.push((scheduled_event.at, scheduled_event.event));
+        }
+        return due_events;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.state.lock().expect("Lock poisoned").events.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+}
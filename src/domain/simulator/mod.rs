@@ -1 +1,2 @@
+pub mod event_queue;
 pub mod simulator;
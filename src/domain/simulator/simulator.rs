@@ -3,6 +3,8 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use serde::Deserialize;
 
+use crate::domain::simulator::event_queue::{EventQueue, SimEvent};
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GlobalClockDto {
@@ -13,6 +15,8 @@ pub struct GlobalClockDto {
 pub struct GlobalClock {
     pub is_simulation: bool,
     pub reference_start_time: AtomicI64,
+    /// Pending events of the discrete-event simulation, which allow to skip idle periods.
+    pub event_queue: EventQueue,
 }
 
 impl GlobalClock {
@@ -21,7 +25,7 @@ impl GlobalClock {
         if is_simulation {
             reference_start_time = AtomicI64::new(0);
         }
-        Self { is_simulation: is_simulation, reference_start_time: reference_start_time }
+        Self { is_simulation: is_simulation, reference_start_time: reference_start_time, event_queue: EventQueue::new() }
     }
 
     pub fn get_system_time_s(&self) -> i64 {
@@ -39,4 +43,30 @@ impl GlobalClock {
             self.reference_start_time = AtomicI64::new(self.reference_start_time.load(Ordering::Relaxed) + 1);
         }
     }
+
+    /// Schedules the `event` at the timestamp `at` (in s).
+    pub fn schedule_event(&self, at: i64, event: SimEvent) {
+        self.event_queue.push(at, event);
+    }
+
+    /// Jumps the simulation clock to the timestamp of the earliest queued event, so that idle periods are skipped.
+    /// The clock is never moved backwards, i.e. an overdue event is due immediately. In real-time mode the clock is not changed.
+    ///
+    /// The event stays in the queue and is fetched with `pop_due_events`.
+    ///
+    /// # Returns
+    /// The timestamp of the earliest queued event, or `None` if no event is queued.
+    pub fn advance_to_next_event(&self) -> Option<i64> {
+        let next_event_time = self.event_queue.peek_time()?;
+
+        if self.is_simulation {
+            self.reference_start_time.fetch_max(next_event_time, Ordering::Relaxed);
+        }
+        return Some(next_event_time);
+    }
+
+    /// Removes and returns all queued events, which are due at the current time, ordered by their timestamp.
+    pub fn pop_due_events(&self) -> Vec<(i64, SimEvent)> {
+        return self.event_queue.pop_due(self.get_system_time_s());
+    }
 }
@@ -1,40 +1,181 @@
+use serde::Serialize;
+
 use crate::api::workflow_dto::client_dto::ClientsDto;
+use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::RejectionReason;
+use crate::domain::vrm_system_model::reservation::reservation::Reservation;
 use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
-use crate::domain::vrm_system_model::utils::id::ClientId;
+use crate::domain::vrm_system_model::utils::id::{ClientId, ReservationName};
 use crate::domain::vrm_system_model::workflow::workflow::Workflow;
 use crate::error::Result;
-use crate::loader::parser::parse_json_file;
+use crate::loader::diagnostic::Diagnostic;
+use crate::loader::parser::parse_workflow_file;
 
 #[derive(Debug)]
 pub struct Clients {
     pub unprocessed_reservations: Vec<ReservationId>,
 }
 
+/// Serializable snapshot of the SystemModel, e.g. to diff the constructed model between runs.
+#[derive(Debug, Serialize)]
+pub struct SystemModelExport {
+    /// Snapshots of all unprocessed workflows.
+    pub workflows: Vec<Reservation>,
+
+    /// Snapshots of all sub-reservations (nodes and dependencies), which are referenced by id inside the workflows.
+    pub reservations: Vec<ReservationExport>,
+}
+
+/// Snapshot of a single reservation together with its `ReservationId`.
+#[derive(Debug, Serialize)]
+pub struct ReservationExport {
+    pub reservation_id: ReservationId,
+    pub reservation: Reservation,
+}
+
 impl Clients {
     pub fn from_dto(dto: ClientsDto, reservation_store: ReservationStore) -> Result<Self> {
+        return Self::from_dto_explained(dto, reservation_store).map(|(clients, _)| clients);
+    }
+
+    /// Like `from_dto`, but additionally returns the `Diagnostic`s of all constructed workflows
+    /// (see `Workflow::create_form_dto_explained`).
+    pub fn from_dto_explained(dto: ClientsDto, reservation_store: ReservationStore) -> Result<(Self, Vec<Diagnostic>)> {
         let mut unprocessed = Vec::new();
+        let mut diagnostics = Vec::new();
 
         for client_dto in dto.clients {
             let client_id = ClientId::new(client_dto.id);
 
             for workflow_dto in client_dto.workflows {
-                let workflow_res_id = Workflow::create_form_dto(workflow_dto, client_id.clone(), reservation_store.clone())?;
+                let (workflow_res_id, workflow_diagnostics) =
+                    Workflow::create_form_dto_explained(workflow_dto, client_id.clone(), reservation_store.clone())?;
                 unprocessed.push(workflow_res_id);
+                diagnostics.extend(workflow_diagnostics);
             }
         }
 
-        Ok(Clients { unprocessed_reservations: unprocessed })
+        Ok((Clients { unprocessed_reservations: unprocessed }, diagnostics))
     }
 
     pub fn get_clients(file_path: &str, reservation_store: ReservationStore) -> Result<Clients> {
         log::info!("Starting ClientsDto construction.");
 
-        let root_dto: ClientsDto = parse_json_file::<ClientsDto>(file_path)?;
-        log::info!("JSON file parsed successfully.");
+        let root_dto: ClientsDto = parse_workflow_file::<ClientsDto>(file_path)?;
+        log::info!("Workflow file parsed successfully.");
 
         let system_model = Clients::from_dto(root_dto, reservation_store)?;
         log::info!("Internal SystemModel was constructed successfully.");
 
         Ok(system_model)
     }
+
+    /// Takes a snapshot of all unprocessed workflows and their sub-reservations from the `reservation_store`.
+    /// Reservations, which are not present in the store, are skipped.
+    pub fn export(&self, reservation_store: &ReservationStore) -> SystemModelExport {
+        let mut workflows = Vec::new();
+        let mut reservations = Vec::new();
+
+        for workflow_res_id in &self.unprocessed_reservations {
+            let Some(workflow_snapshot) = reservation_store.get_reservation_snapshot(*workflow_res_id) else {
+                log::warn!("SystemModelExportMissingReservation: Reservation {:?} is not part of the ReservationStore.", workflow_res_id);
+                continue;
+            };
+
+            if let Reservation::Workflow(ref workflow) = workflow_snapshot {
+                for reservation_id in workflow.get_all_reservation_ids() {
+                    if let Some(reservation) = reservation_store.get_reservation_snapshot(reservation_id) {
+                        reservations.push(ReservationExport { reservation_id, reservation });
+                    }
+                }
+            }
+            workflows.push(workflow_snapshot);
+        }
+
+        return SystemModelExport { workflows, reservations };
+    }
+
+    /// Checks before an experiment, whether every workflow of the SystemModel is schedulable in isolation, i.e. against
+    /// the full resource pool and ignoring the contention with other workflows.
+    ///
+    /// The following necessary conditions are checked for each workflow:
+    /// * The maximal demand of a `CoAllocation` (summed `reserved_capacity` of its members) does not exceed
+    ///   `total_node_capacity`, otherwise `RejectionReason::NoCapacity` is reported for its smallest member.
+    /// * The critical path (see `Workflow::lower_bound_makespan`) fits into the booking interval, otherwise
+    ///   `RejectionReason::DeadlineMiss` is reported for the last node of the critical path.
+    ///
+    /// # Arguments
+    /// * `reservation_store` - Store holding the workflows and their sub-reservations.
+    /// * `total_node_capacity` - Total node capacity of the resource pool (e.g. `VrmComponentManager::get_total_node_capacity`).
+    /// * `avg_net_speed` - Average network speed, used to compute the communication times of the critical path.
+    ///
+    /// # Returns
+    /// The names of all infeasible workflows together with the reason of the first failed check.
+    pub fn check_feasibility(
+        &self,
+        reservation_store: &ReservationStore,
+        total_node_capacity: i64,
+        avg_net_speed: i64,
+    ) -> Vec<(ReservationName, RejectionReason)> {
+        let mut infeasible_workflows = Vec::new();
+
+        for workflow_res_id in &self.unprocessed_reservations {
+            let Some(Reservation::Workflow(mut workflow)) = reservation_store.get_reservation_snapshot(*workflow_res_id) else {
+                log::warn!("SystemModelFeasibilityMissingWorkflow: Reservation {:?} is not a Workflow of the ReservationStore.", workflow_res_id);
+                continue;
+            };
+
+            if let Some(reason) = Self::check_workflow_feasibility(&mut workflow, reservation_store, total_node_capacity, avg_net_speed) {
+                log::info!("SystemModelInfeasibleWorkflow: Workflow {} is not schedulable in isolation: {:?}", workflow.base.name, reason);
+                infeasible_workflows.push((workflow.base.name.clone(), reason));
+            }
+        }
+
+        return infeasible_workflows;
+    }
+
+    /// Returns the reason, why the workflow is not schedulable in isolation, or `None` (see `check_feasibility`).
+    fn check_workflow_feasibility(
+        workflow: &mut Workflow,
+        reservation_store: &ReservationStore,
+        total_node_capacity: i64,
+        avg_net_speed: i64,
+    ) -> Option<RejectionReason> {
+        let peak_co_allocation = workflow
+            .co_allocations
+            .values()
+            .map(|co_allocation| {
+                let demand: i64 = co_allocation
+                    .members
+                    .iter()
+                    .filter_map(|member_id| workflow.nodes.get(member_id))
+                    .map(|member| reservation_store.get_reserved_capacity(member.reservation_id))
+                    .sum();
+                (demand, co_allocation.members.iter().min().cloned())
+            })
+            .max_by(|(a_demand, a_node), (b_demand, b_node)| a_demand.cmp(b_demand).then(b_node.cmp(a_node)));
+
+        if let Some((demand, Some(node))) = peak_co_allocation {
+            if demand > total_node_capacity {
+                return Some(RejectionReason::NoCapacity { node });
+            }
+        }
+
+        let Ok(makespan) = workflow.lower_bound_makespan(avg_net_speed, reservation_store) else {
+            return Some(RejectionReason::InvalidWorkflow);
+        };
+
+        let projected_finish = workflow.base.booking_interval_start + makespan;
+        if projected_finish > workflow.base.booking_interval_end {
+            let (_, critical_path) = workflow.critical_path(avg_net_speed, reservation_store);
+            let node = critical_path.last().cloned()?;
+            return Some(RejectionReason::DeadlineMiss { node, projected_finish, deadline: workflow.base.booking_interval_end });
+        }
+
+        return None;
+    }
+
+    /// Serializes the SystemModel (see `export`) into pretty printed JSON.
+    pub fn to_json_pretty(&self, reservation_store: &ReservationStore) -> Result<String> {
+        return Ok(serde_json::to_string_pretty(&self.export(reservation_store))?);
+    }
 }
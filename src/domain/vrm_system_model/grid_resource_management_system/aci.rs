@@ -7,7 +7,7 @@ use crate::domain::vrm_system_model::reservation::reservation::{Reservation, Res
 use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
 use crate::domain::vrm_system_model::rms::advance_reservation_trait::AdvanceReservationRms;
 use crate::domain::vrm_system_model::rms::rms::RmsLoadMetric;
-use crate::domain::vrm_system_model::utils::id::{AciId, AdcId, ClientId, ComponentId, ShadowScheduleId};
+use crate::domain::vrm_system_model::utils::id::{AciId, AdcId, ClientId, ComponentId, RouterId, ShadowScheduleId};
 use crate::domain::vrm_system_model::utils::state_logging::{AnalyticLogger, BaseLog, DetailLog, ProbeLog, VrmCommand};
 use crate::error::ConversionError;
 
@@ -155,6 +155,14 @@ impl VrmComponent for AcI {
         self.rms_system.get_link_resource_count()
     }
 
+    fn get_router_list(&self) -> Vec<RouterId> {
+        self.rms_system.get_router_list()
+    }
+
+    fn contains_router(&self, router_id: &RouterId) -> bool {
+        self.rms_system.contains_router(router_id)
+    }
+
     fn can_handel(&self, res: Reservation) -> bool {
         self.rms_system.can_handle_adc_request(res)
     }
@@ -287,7 +295,8 @@ impl VrmComponent for AcI {
         if !shadow_schedule_id.is_none() {
             container = self.shadow_schedule_reservations.delete_reservation_container(reservation_id, &shadow_schedule_id.clone().unwrap());
         } else {
-            container = self.not_committed_reservations.remove(&reservation_id);
+            // Committed reservations are also deleted, e.g. during the rollback of a failed workflow commit
+            container = self.not_committed_reservations.remove(&reservation_id).or_else(|| self.committed_reservations.remove(&reservation_id));
         }
 
         if container.is_none() {
@@ -504,13 +513,17 @@ impl VrmComponent for AcI {
                     self.simulator.get_system_time_s() + self.commit_timeout,
                 );
 
-                if !shadow_schedule_id.is_none() {
-                    let mut new_committed_reservations: HashMap<ReservationId, ReservationContainer> = HashMap::new();
-                    new_committed_reservations.insert(reservation_id_of_answer, reservation_container.clone());
-
-                    if !self.shadow_schedule_reservations.insert(shadow_schedule_id.clone().unwrap(), &new_committed_reservations, &self.id) {
-                        self.reservation_store.update_state(reservation_id, ReservationState::Rejected);
-                        return reservation_id;
+                // The reservations of the shadow schedule were created by create_shadow_schedule, the answer is added to them
+                if let Some(shadow_schedule_id) = shadow_schedule_id.clone() {
+                    match self.shadow_schedule_reservations.get_mut(&shadow_schedule_id) {
+                        Some(shadow_reservations) => {
+                            shadow_reservations.insert(reservation_id_of_answer, reservation_container.clone());
+                        }
+                        None => {
+                            log::error!("AcI {}: ShadowScheduleId {} does not exist, reserve was rejected.", self.id, shadow_schedule_id);
+                            self.reservation_store.update_state(reservation_id, ReservationState::Rejected);
+                            return reservation_id;
+                        }
                     }
                 }
 
@@ -1,6 +1,7 @@
 use std::{
     cmp::Ordering,
     collections::HashMap,
+    sync::Arc,
     time::{SystemTime, UNIX_EPOCH},
 };
 
@@ -9,12 +10,12 @@ use crate::domain::vrm_system_model::{
     reservation::{
         probe_reservations::{ProbeReservationComparator, ProbeReservations},
         reservation::ReservationState,
-        reservation_store::ReservationId,
+        reservation_store::{HealthIssue, ReservationId},
     },
     utils::{
         config::TRY_N_PROMOTIONS,
         id::{ComponentId, ShadowScheduleId},
-        statistics::ANALYTICS_TARGET,
+        statistics::{ANALYTICS_TARGET, AnalyticsRow, CsvAnalyticsSink},
     },
 };
 
@@ -58,7 +59,7 @@ impl ADC {
                     // Reset the state in the shadow store to 'Open' so they can be reserved again.
                     // Accessing the shadow store via the manager.
                     if let Some((_, store)) = self.manager.shadow_schedule_reservations.get_mut(&shadow_id) {
-                        store.update_state(*res_id, ReservationState::Open);
+                        store.reopen(*res_id);
                     }
                 }
 
@@ -97,7 +98,7 @@ impl ADC {
     /// Removes an VrmComponent from the registry based on its unique identifier.
     fn delete_vrm_component(&mut self, vrm_component: Box<dyn VrmComponent>) -> bool {
         log::debug!("ACD {} deletes VrmComponent {}", self.id, vrm_component.get_id());
-        return self.manager.delete_vrm_component(vrm_component.get_id());
+        return self.manager.delete_vrm_component(vrm_component.get_id()).is_some();
     }
 
     /// Adds a new `GridComponent` to the domain and initializes its local schedule view.
@@ -162,32 +163,159 @@ impl ADC {
                     grid_component_res_database.insert(reserve_res_id, component_id.clone());
 
                     // Update VrmComponent's local view (schedule) of the underlying VrmComponents
-                    self.manager.reserve_without_check(component_id.clone(), reserve_res_id);
-
-                    if !self.reservation_store.is_reservation_state_at_least(reserve_res_id, ReservationState::ReserveAnswer) {
-                        log::error!("Reserve of reservation {:?} in local schedule copy of Grid Component {} failed.", reserve_res_id, component_id);
+                    // The local view only mirrors the master schedule, a shadow reserve must not change it.
+                    if shadow_schedule_id.is_none() {
+                        self.manager.reserve_without_check(component_id.clone(), reserve_res_id);
+
+                        if !self.reservation_store.is_reservation_state_at_least(reserve_res_id, ReservationState::ReserveAnswer) {
+                            log::error!(
+                                "Reserve of reservation {:?} in local schedule copy of Grid Component {} failed.",
+                                reserve_res_id,
+                                component_id
+                            );
+                        }
                     }
 
                     return reserve_res_id;
                 }
+
+                // The rejection of this VrmComponent is undone, such that the unchanged reservation is offered to the next VrmComponent
+                self.reservation_store.restore_reservation_snapshot(reservation_id, res_snapshot.clone());
             }
         }
         self.reservation_store.update_state(reservation_id, ReservationState::Rejected);
         return reservation_id;
     }
 
+    /// Submits a group of reservations (e.g. the members of a co-allocation) in fixed time windows to the first
+    /// VrmComponent (based on the defined `VrmComponentOrder`), which can host all of them. Either all reservations
+    /// are reserved on the same VrmComponent or none.
+    ///
+    /// Before a VrmComponent is requested, it is checked on the local view (schedule) of the VrmComponent that the
+    /// whole group fits, which avoids to place single members only to roll them back afterwards.
+    ///
+    /// # Returns
+    /// `true` if all reservations were reserved, otherwise `false` and all reservations are in `ReservationState::Rejected`.
+    pub fn submit_co_allocation_at_first_grid_component(
+        &mut self,
+        reservations: &[(ReservationId, (i64, i64))],
+        shadow_schedule_id: Option<ShadowScheduleId>,
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+    ) -> bool {
+        let component_ids = self.manager.get_ordered_vrm_components(self.vrm_component_order);
+        return self.submit_co_allocation_at_components(component_ids, reservations, shadow_schedule_id, grid_component_res_database);
+    }
+
+    /// Like `submit_co_allocation_at_first_grid_component`, but the group of reservations is only submitted to the
+    /// given VrmComponent, e.g. to place it next to an already placed member of the co-allocation.
+    ///
+    /// # Returns
+    /// `true` if all reservations were reserved, otherwise `false` and all reservations are in `ReservationState::Rejected`.
+    pub fn submit_co_allocation_at_vrm_component(
+        &mut self,
+        component_id: ComponentId,
+        reservations: &[(ReservationId, (i64, i64))],
+        shadow_schedule_id: Option<ShadowScheduleId>,
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+    ) -> bool {
+        return self.submit_co_allocation_at_components(vec![component_id], reservations, shadow_schedule_id, grid_component_res_database);
+    }
+
+    /// Submits the group of reservations to the first of the `component_ids`, which can host all of them.
+    fn submit_co_allocation_at_components(
+        &mut self,
+        component_ids: Vec<ComponentId>,
+        reservations: &[(ReservationId, (i64, i64))],
+        shadow_schedule_id: Option<ShadowScheduleId>,
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+    ) -> bool {
+        let mut res_snapshots = Vec::with_capacity(reservations.len());
+        for (reservation_id, _) in reservations {
+            match self.reservation_store.get_reservation_snapshot(*reservation_id) {
+                Some(snapshot) => res_snapshots.push(snapshot),
+                None => {
+                    log::error!("Cannot submit co-allocation: snapshot for {:?} not found.", reservation_id);
+                    reservations.iter().for_each(|(res_id, _)| self.reservation_store.update_state(*res_id, ReservationState::Rejected));
+                    return false;
+                }
+            }
+        }
+
+        for component_id in component_ids {
+            if !res_snapshots.iter().all(|snapshot| self.manager.can_component_handel(component_id.clone(), snapshot.clone())) {
+                continue;
+            }
+
+            // The local view only mirrors the master schedule
+            if shadow_schedule_id.is_none() && !self.manager.can_reserve_all_in_local_schedule(component_id.clone(), reservations) {
+                continue;
+            }
+
+            let mut reserved_res_ids = Vec::with_capacity(reservations.len());
+            for (reservation_id, _) in reservations {
+                let reserve_res_id = self.manager.reserve(component_id.clone(), *reservation_id, shadow_schedule_id.clone());
+
+                if !self.reservation_store.is_reservation_state_at_least(reserve_res_id, ReservationState::ReserveAnswer) {
+                    break;
+                }
+                reserved_res_ids.push(reserve_res_id);
+            }
+
+            if reserved_res_ids.len() == reservations.len() {
+                // Update VrmComponent's local view (schedule) of the underlying VrmComponents
+                if shadow_schedule_id.is_none() && !self.manager.reserve_all_or_none_in_local_schedule(component_id.clone(), reservations) {
+                    log::error!(
+                        "Reserve of co-allocation with {} reservations in local schedule copy of Grid Component {} failed.",
+                        reservations.len(),
+                        component_id
+                    );
+                }
+
+                for reserve_res_id in reserved_res_ids {
+                    grid_component_res_database.insert(reserve_res_id, component_id.clone());
+                }
+                return true;
+            }
+
+            // Local view was outdated, release the partial placement on this VrmComponent
+            log::debug!(
+                "ADCCoAllocationPartialPlacement: Only {} of {} reservations of the co-allocation were reserved on VrmComponent {}. Release partial placement.",
+                reserved_res_ids.len(),
+                reservations.len(),
+                component_id
+            );
+            for reserved_res_id in reserved_res_ids {
+                self.manager.get_vrm_component_mut(component_id.clone()).delete(reserved_res_id, shadow_schedule_id.clone());
+                self.manager.not_committed_reservations.remove(&reserved_res_id);
+            }
+            // Undo the placement attempt, such that the unchanged reservations are offered to the next VrmComponent
+            for ((reservation_id, _), res_snapshot) in reservations.iter().zip(&res_snapshots) {
+                self.reservation_store.restore_reservation_snapshot(*reservation_id, res_snapshot.clone());
+            }
+        }
+
+        reservations.iter().for_each(|(res_id, _)| self.reservation_store.update_state(*res_id, ReservationState::Rejected));
+        return false;
+    }
+
     /// Probes all available VrmComponents and selects the best candidate based on the provided comparison function.
     ///
     /// This implements a "Best Fit" strategy, useful for optimizing resource utilization or
     /// meeting Earliest Finish Time (EFT) constraints.
+    /// The `placement_penalty` of a VrmComponent is added to the objective of the comparator, e.g. to prefer
+    /// the VrmComponents of soft synchronized tasks.
     /// TODO should be moved to VrmComponentManager
-    pub fn submit_task_at_best_vrm_component(
+    pub fn submit_task_at_best_vrm_component<F>(
         &mut self,
         reservation_id: ReservationId,
         shadow_schedule_id: Option<ShadowScheduleId>,
         grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
         probe_reservation_comparator: ProbeReservationComparator,
-    ) -> Option<ReservationId> {
+        placement_penalty: F,
+    ) -> Option<ReservationId>
+    where
+        F: Fn(&ComponentId) -> i64,
+    {
         let mut probe_reservations = ProbeReservations::new(reservation_id, self.reservation_store.clone());
 
         let res_snapshot = match self.reservation_store.get_reservation_snapshot(reservation_id) {
@@ -206,15 +334,79 @@ impl ADC {
             }
         }
 
+        return self.reserve_best_probe_reservation(
+            reservation_id,
+            probe_reservations,
+            grid_component_res_database,
+            probe_reservation_comparator,
+            placement_penalty,
+        );
+    }
+
+    /// Submits a task to the given VrmComponent only, e.g. to pin the task onto a preselected VrmComponent.
+    ///
+    /// The VrmComponent is probed and the best candidate according to the `probe_reservation_comparator` is reserved.
+    ///
+    /// # Returns
+    /// The `ReservationId` of the reserved task, or `None` if the VrmComponent cannot host the task.
+    pub fn submit_task_at_vrm_component(
+        &mut self,
+        component_id: ComponentId,
+        reservation_id: ReservationId,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+        probe_reservation_comparator: ProbeReservationComparator,
+    ) -> Option<ReservationId> {
+        let res_snapshot = match self.reservation_store.get_reservation_snapshot(reservation_id) {
+            Some(snapshot) => snapshot,
+            None => {
+                log::error!("Cannot submit task: snapshot for {:?} not found.", reservation_id);
+                return None;
+            }
+        };
+
+        if !self.manager.vrm_components.contains_key(&component_id) || !self.manager.can_component_handel(component_id.clone(), res_snapshot) {
+            return None;
+        }
+
+        let mut probe_reservations = ProbeReservations::new(reservation_id, self.reservation_store.clone());
+        probe_reservations.add_probe_reservations(self.manager.get_vrm_component_mut(component_id).probe(reservation_id, shadow_schedule_id));
+
+        return self.reserve_best_probe_reservation(
+            reservation_id,
+            probe_reservations,
+            grid_component_res_database,
+            probe_reservation_comparator,
+            |_| 0,
+        );
+    }
+
+    /// Promotes the best candidates of the `probe_reservations` one after another and reserves the task at the
+    /// VrmComponent of the first candidate, which is accepted.
+    fn reserve_best_probe_reservation<F>(
+        &mut self,
+        reservation_id: ReservationId,
+        mut probe_reservations: ProbeReservations,
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+        probe_reservation_comparator: ProbeReservationComparator,
+        placement_penalty: F,
+    ) -> Option<ReservationId>
+    where
+        F: Fn(&ComponentId) -> i64,
+    {
         for _ in 0..TRY_N_PROMOTIONS {
-            if let Some((component_id, shadow_schedule_id)) = probe_reservations.prompt_best(reservation_id, probe_reservation_comparator.clone()) {
-                self.manager.reserve(component_id.clone(), reservation_id, shadow_schedule_id);
+            if let Some((component_id, shadow_schedule_id)) =
+                probe_reservations.prompt_best_with_penalty(reservation_id, probe_reservation_comparator.clone(), &placement_penalty)
+            {
+                self.manager.reserve(component_id.clone(), reservation_id, shadow_schedule_id.clone());
 
                 if self.reservation_store.is_reservation_state_at_least(reservation_id, ReservationState::ReserveAnswer) {
                     log::info!("Reservation {:?} successful!", reservation_id);
 
-                    // Update local schedule
-                    self.manager.reserve_without_check(component_id.clone(), reservation_id);
+                    // Update local schedule (only the master schedule is mirrored)
+                    if shadow_schedule_id.is_none() {
+                        self.manager.reserve_without_check(component_id.clone(), reservation_id);
+                    }
 
                     // Register new schedule Sub-Task
                     // Update grid_component_res_database for rollback and for ADC to keep track
@@ -236,13 +428,91 @@ impl ADC {
     }
 
     /// Deletes a task from the underlying component and cleans up the associated local schedule.
+    /// Only the master schedule is mirrored in the local view, hence the local view is not changed for shadow schedules.
     pub fn delete_task_at_component(
         &mut self,
         component_id: ComponentId,
         reservation_id: ReservationId,
         shadow_schedule_id: Option<ShadowScheduleId>,
     ) {
-        todo!()
+        // No real task (e.g. dummy dependency between tasks on the same VrmComponent)
+        if component_id.is_dummy() {
+            self.reservation_store.update_state(reservation_id, ReservationState::Deleted);
+            return;
+        }
+
+        let container = match self.manager.try_get_vrm_component_container_mut(&component_id) {
+            Ok(container) => container,
+            Err(error) => {
+                log::error!("ADCDeleteTaskFailed: Deletion of reservation {:?} failed: {}", reservation_id, error);
+                return;
+            }
+        };
+
+        container.vrm_component.delete(reservation_id, shadow_schedule_id.clone());
+
+        if shadow_schedule_id.is_none() {
+            container.schedule.delete_reservation(reservation_id);
+            self.manager.not_committed_reservations.remove(&reservation_id);
+        }
+    }
+
+    /// Enforces the `commit_timeout`: All reserved but not committed reservations, whose reserve time plus the
+    /// `commit_timeout` is before `now`, are deleted at their VrmComponent and marked as `ReservationState::Rejected`,
+    /// such that they do not occupy slots indefinitely.
+    ///
+    /// Reservations without a recorded reserve time (e.g. restored from a shadow schedule) are not expired.
+    ///
+    /// # Returns
+    /// The `ReservationId`s of all expired reservations.
+    pub fn expire_stale_reservations(&mut self, now: i64) -> Vec<ReservationId> {
+        // Drop the reserve times of all reservations, which were committed or deleted in the meantime
+        let not_committed_reservations = &self.manager.not_committed_reservations;
+        self.manager.reserve_times.retain(|reservation_id, _| not_committed_reservations.contains_key(reservation_id));
+
+        let mut stale_reservations: Vec<(ReservationId, ComponentId)> = self
+            .manager
+            .not_committed_reservations
+            .iter()
+            .filter(|(reservation_id, _)| {
+                self.manager.reserve_times.get(*reservation_id).is_some_and(|reserve_time| reserve_time + self.commit_timeout < now)
+            })
+            .map(|(reservation_id, component_id)| (*reservation_id, component_id.clone()))
+            .collect();
+        stale_reservations.sort();
+
+        for (reservation_id, component_id) in &stale_reservations {
+            log::debug!(
+                "ADCCommitTimeoutExpired: Reservation {:?} on VrmComponent {} of ADC {} was not committed within {}s and is released.",
+                self.reservation_store.get_name_for_key(*reservation_id),
+                component_id,
+                self.id,
+                self.commit_timeout
+            );
+
+            // Rejected before the deletion, which releases the slots but keeps the terminal state
+            self.reservation_store.update_state(*reservation_id, ReservationState::Rejected);
+            self.delete_task_at_component(component_id.clone(), *reservation_id, None);
+            self.manager.remove_allocation(reservation_id);
+            self.manager.reserve_times.remove(reservation_id);
+        }
+
+        return stale_reservations.into_iter().map(|(reservation_id, _)| reservation_id).collect();
+    }
+
+    /// Checks, whether the ReservationStore and the SyncRegistry of the ADC are in a sane starting state for a
+    /// scheduling episode, i.e., no reservation is stuck in a transient state and no gate is orphaned.
+    ///
+    /// # Returns
+    /// Returns all found `HealthIssue`s, each issue is additionally logged.
+    pub fn health_check(&self) -> Vec<HealthIssue> {
+        let mut health_issues = self.reservation_store.health_check();
+        health_issues.extend(self.sync_registry.health_check(&self.reservation_store));
+
+        for health_issue in health_issues.iter() {
+            log::warn!("HealthCheckIssue: ADC {} found {:?} before scheduling.", self.id, health_issue);
+        }
+        return health_issues;
     }
 
     pub fn log_state_probe(&mut self, num_of_answers: i64, arrival_time_at_aci: i64) {
@@ -258,9 +528,15 @@ impl ADC {
         );
     }
 
+    /// Attaches a `CsvAnalyticsSink`, which receives one `AnalyticsRow` per finished operation (see `log_stat`).
+    pub fn attach_analytics_sink(&mut self, analytics_sink: Arc<CsvAnalyticsSink>) {
+        self.analytics_sink = Some(analytics_sink);
+    }
+
     pub fn log_stat(&mut self, command: String, reservation_id: ReservationId, arrival_time_at_aci: i64) {
         let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
         let processing_time = self.simulator.get_system_time_s() - arrival_time_at_aci;
+        self.manager.record_processing_time(&command, processing_time);
 
         if let Some(res_handle) = self.reservation_store.get(reservation_id) {
             let (start, end, res_name, capacity, workload, state, proceeding, num_tasks) = {
@@ -292,6 +568,29 @@ impl ADC {
 
             let network_possible_capacity = rms_load_metric.link_load_metric.as_ref().map(|n| Some(n.possible_capacity)).unwrap_or(None);
 
+            let component_fragmentation = self.manager.get_system_satisfaction(None);
+
+            if let Some(analytics_sink) = &self.analytics_sink {
+                analytics_sink.record(AnalyticsRow {
+                    time: now,
+                    log_description: "AcI Operation finished".to_string(),
+                    component_type: self.id.to_string(),
+                    node_component_utilization: node_utilization,
+                    node_component_capacity: node_possible_capacity,
+                    network_component_utilization: network_utilization,
+                    network_component_capacity: network_possible_capacity,
+                    component_fragmentation: Some(component_fragmentation),
+                    reservation_name: Some(res_name.to_string()),
+                    reservation_capacity: Some(capacity),
+                    reservation_workload: Some(workload),
+                    reservation_state: Some(format!("{:?}", state)),
+                    reservation_proceeding: Some(format!("{:?}", proceeding)),
+                    number_of_tasks: Some(num_tasks),
+                    command: command.clone(),
+                    processing_time,
+                });
+            }
+
             tracing::info!(
                 target: ANALYTICS_TARGET,
                 Time = now,
@@ -301,7 +600,7 @@ impl ADC {
                 NodeComponentCapacity = node_possible_capacity,
                 NetworkComponentUtilization = network_utilization,
                 NetworkComponentCapacity = network_possible_capacity,
-                ComponentFragmentation = self.manager.get_system_satisfaction(None),
+                ComponentFragmentation = component_fragmentation,
                 ReservationName = %res_name,
                 ReservationCapacity = capacity,
                 ReservationWorkload = workload,
@@ -313,6 +612,26 @@ impl ADC {
             );
         } else {
             // Handling in case reservation is missing (e.g. deleted/cleaned up)
+            if let Some(analytics_sink) = &self.analytics_sink {
+                analytics_sink.record(AnalyticsRow {
+                    time: now,
+                    log_description: "AcI Operation finished (Reservation Missing/Deleted)".to_string(),
+                    component_type: self.id.to_string(),
+                    node_component_utilization: None,
+                    node_component_capacity: None,
+                    network_component_utilization: None,
+                    network_component_capacity: None,
+                    component_fragmentation: None,
+                    reservation_name: None,
+                    reservation_capacity: None,
+                    reservation_workload: None,
+                    reservation_state: None,
+                    reservation_proceeding: None,
+                    number_of_tasks: None,
+                    command: command.clone(),
+                    processing_time,
+                });
+            }
 
             tracing::warn!(
                 target: ANALYTICS_TARGET,
@@ -12,7 +12,7 @@ use crate::domain::{
             vrm_component_registry::{registry_client::RegistryClient, vrm_component_proxy::VrmComponentProxy},
         },
         reservation::{reservation_store::ReservationStore, reservation_sync_gate::SyncRegistry},
-        utils::id::AdcId,
+        utils::{id::AdcId, statistics::CsvAnalyticsSink},
     },
 };
 
@@ -55,6 +55,9 @@ pub struct ADC {
     pub slot_width: i64,
 
     pub sync_registry: SyncRegistry,
+
+    /// If attached, `log_stat` additionally records one CSV row per finished operation.
+    pub analytics_sink: Option<Arc<CsvAnalyticsSink>>,
 }
 
 impl ADC {
@@ -73,6 +76,8 @@ impl ADC {
         let vrm_component_manager =
             VrmComponentManager::new(adc_id.clone(), vrm_components_list, simulator.clone(), reservation_store.clone(), num_of_slots, slot_width);
 
+        let sync_registry = vrm_component_manager.sync_registry.clone();
+
         ADC {
             id: adc_id,
             manager: vrm_component_manager,
@@ -84,7 +89,8 @@ impl ADC {
             simulator: simulator,
             num_of_slots: num_of_slots,
             slot_width: slot_width,
-            sync_registry: SyncRegistry::new(),
+            sync_registry: sync_registry,
+            analytics_sink: None,
         }
     }
 }
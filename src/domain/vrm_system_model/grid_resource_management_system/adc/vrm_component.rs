@@ -6,7 +6,10 @@ use crate::domain::vrm_system_model::{
         reservation_store::ReservationId,
     },
     rms::rms::RmsLoadMetric,
-    utils::id::{ComponentId, ShadowScheduleId},
+    utils::{
+        config::HEALTH_CHECK_BEFORE_SCHEDULING,
+        id::{ComponentId, RouterId, ShadowScheduleId},
+    },
 };
 
 impl VrmComponent for ADC {
@@ -30,6 +33,18 @@ impl VrmComponent for ADC {
         self.manager.get_link_resource_count()
     }
 
+    fn get_hierarchy_depth(&self) -> usize {
+        self.manager.get_hierarchy_depth()
+    }
+
+    fn get_router_list(&self) -> Vec<RouterId> {
+        self.manager.get_router_list()
+    }
+
+    fn contains_router(&self, router_id: &RouterId) -> bool {
+        self.manager.contains_router(router_id)
+    }
+
     fn can_handel(&self, res: Reservation) -> bool {
         for component_id in self.manager.get_random_ordered_vrm_components() {
             if self.manager.can_component_handel(component_id, res.clone()) {
@@ -280,10 +295,21 @@ impl VrmComponent for ADC {
 
         // Perform Reserve
         if self.reservation_store.is_workflow(reservation_id) {
+            if HEALTH_CHECK_BEFORE_SCHEDULING {
+                self.health_check();
+            }
+
             // "Option Dance" with WorkflowScheduler
             if let Some(mut workflow_scheduler) = self.workflow_scheduler.take() {
                 // Performs all reservation tracking like self.manager.not_committed_reservations
-                workflow_scheduler.reserve(reservation_id, self);
+                if let Err(rejection_reason) = workflow_scheduler.reserve(reservation_id, self) {
+                    log::debug!(
+                        "ADC {} rejected workflow {:?}: {:?}",
+                        self.id,
+                        self.reservation_store.get_name_for_key(reservation_id),
+                        rejection_reason
+                    );
+                }
 
                 self.workflow_scheduler = Some(workflow_scheduler);
             } else {
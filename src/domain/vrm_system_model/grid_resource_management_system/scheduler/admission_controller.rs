@@ -0,0 +1,113 @@
+use crate::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
+use crate::domain::vrm_system_model::grid_resource_management_system::vrm_component_trait::VrmComponent;
+use crate::domain::vrm_system_model::reservation::reservation::{ReservationState, ReservationTrait};
+use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+
+/// Policy which defines in which order pending workflows are submitted to the ADC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdmissionPolicy {
+    /// **Shortest Job First**: Workflows with the least total work (reserved capacity * task duration of all tasks) first.
+    ShortestJobFirst,
+
+    /// **Earliest Deadline First**: Workflows with the earliest `booking_interval_end` first.
+    EarliestDeadlineFirst,
+
+    /// **Arrival Order (FIFO)**: Workflows are submitted in the order of their arrival time.
+    ArrivalOrder,
+}
+
+/// Orders competing workflows according to an `AdmissionPolicy` and submits them to the ADC.
+///
+/// The `AdmissionController` sits above the per-workflow scheduler (e.g. `HEFTSyncWorkflowScheduler`):
+/// it only decides which workflow is reserved next, the placement of each workflow is still done by the
+/// `WorkflowScheduler` of the ADC. The admission stops as soon as the first workflow is rejected,
+/// because the domain is considered full. Ties are resolved by the order in which the workflows were added,
+/// such that the admission order is deterministic.
+#[derive(Debug)]
+pub struct AdmissionController {
+    pub admission_policy: AdmissionPolicy,
+    pub reservation_store: ReservationStore,
+
+    /// Workflows waiting for admission in the order they were added.
+    pending_workflows: Vec<ReservationId>,
+}
+
+impl AdmissionController {
+    pub fn new(admission_policy: AdmissionPolicy, reservation_store: ReservationStore) -> Self {
+        AdmissionController { admission_policy, reservation_store, pending_workflows: Vec::new() }
+    }
+
+    /// Adds a workflow to the pending workflows. Reservations which are no workflows are ignored.
+    pub fn add_workflow(&mut self, workflow_res_id: ReservationId) {
+        if !self.reservation_store.is_workflow(workflow_res_id) {
+            log::error!(
+                "ErrorAdmissionControllerReservationIsNoWorkflow: Reservation {:?} can not be added to the AdmissionController, because it is not of type Workflow.",
+                self.reservation_store.get_name_for_key(workflow_res_id)
+            );
+            return;
+        }
+        self.pending_workflows.push(workflow_res_id);
+    }
+
+    /// Returns the number of workflows which are waiting for admission.
+    pub fn get_num_of_pending_workflows(&self) -> usize {
+        self.pending_workflows.len()
+    }
+
+    /// Returns the pending workflows in the order they will be submitted according to the `AdmissionPolicy`.
+    pub fn get_admission_order(&self) -> Vec<ReservationId> {
+        let mut admission_order = self.pending_workflows.clone();
+
+        // Stable sort keeps the insertion order for workflows with equal keys
+        match self.admission_policy {
+            AdmissionPolicy::ShortestJobFirst => admission_order.sort_by_key(|res_id| self.get_total_work(*res_id)),
+            AdmissionPolicy::EarliestDeadlineFirst => admission_order.sort_by_key(|res_id| self.reservation_store.get_booking_interval_end(*res_id)),
+            AdmissionPolicy::ArrivalOrder => admission_order.sort_by_key(|res_id| self.get_arrival_time(*res_id)),
+        }
+        return admission_order;
+    }
+
+    /// Submits the pending workflows in the order of the `AdmissionPolicy` to the ADC.
+    /// The admission stops at the first rejected workflow, the rejected and all remaining workflows stay pending.
+    ///
+    /// # Returns
+    /// The admitted workflows in the order they were reserved.
+    pub fn admit(&mut self, adc: &mut ADC) -> Vec<ReservationId> {
+        let mut admitted_workflows = Vec::new();
+
+        for workflow_res_id in self.get_admission_order() {
+            adc.reserve(workflow_res_id, None);
+
+            if !self.reservation_store.is_reservation_state_at_least(workflow_res_id, ReservationState::ReserveAnswer) {
+                log::debug!(
+                    "AdmissionControllerDomainIsFull: Workflow {:?} was rejected by ADC {}. Stop admission with {} pending workflows.",
+                    self.reservation_store.get_name_for_key(workflow_res_id),
+                    adc.id,
+                    self.pending_workflows.len() - admitted_workflows.len()
+                );
+                break;
+            }
+            admitted_workflows.push(workflow_res_id);
+        }
+
+        self.pending_workflows.retain(|res_id| !admitted_workflows.contains(res_id));
+        return admitted_workflows;
+    }
+
+    /// Total work of the workflow: sum of reserved capacity * task duration over all tasks.
+    fn get_total_work(&self, workflow_res_id: ReservationId) -> i64 {
+        let task_res_ids = self
+            .reservation_store
+            .with_workflow_mut(workflow_res_id, |workflow| workflow.nodes.values().map(|node| node.reservation_id).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        return task_res_ids
+            .iter()
+            .map(|res_id| self.reservation_store.get_reserved_capacity(*res_id) * self.reservation_store.get_task_duration(*res_id))
+            .sum();
+    }
+
+    fn get_arrival_time(&self, workflow_res_id: ReservationId) -> i64 {
+        return self.reservation_store.get_reservation_snapshot(workflow_res_id).map(|res| res.get_arrival_time()).unwrap_or(i64::MAX);
+    }
+}
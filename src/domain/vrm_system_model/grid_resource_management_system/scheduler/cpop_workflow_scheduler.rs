@@ -0,0 +1,275 @@
+use crate::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
+use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::heft_sync_workflow_scheduler::HEFTSyncWorkflowScheduler;
+use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::{
+    RejectionReason, WorkflowScheduler, WorkflowSchedulerBase,
+};
+use crate::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState, ReservationTrait};
+use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use crate::domain::vrm_system_model::reservation::reservations::Reservations;
+use crate::domain::vrm_system_model::utils::id::{CoAllocationId, ComponentId};
+use crate::domain::vrm_system_model::workflow::workflow::Workflow;
+use crate::domain::vrm_system_model::workflow::workflow_node::WorkflowNode;
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+
+/// A workflow scheduler implementing the **CPOP** (Critical Path On a Processor) heuristic.
+///
+/// The priority of a CoAllocation is the sum of its upward and downward rank (minus its duration, which is
+/// contained in both ranks). All CoAllocations, whose priority equals the makespan of the workflow, form the
+/// critical path. The critical path is pinned onto the single VrmComponent, which minimizes the cumulative
+/// execution time of all critical CoAllocations. All other CoAllocations are placed like in HEFTSync on the
+/// VrmComponent with the earliest finish time.
+///
+/// The placement of the CoAllocations and their dependencies reuses the primitives of the
+/// [`HEFTSyncWorkflowScheduler`], which also provides the [`WorkflowSchedulerBase`].
+#[derive(Debug)]
+pub struct CpopWorkflowScheduler {
+    pub heft_sync: HEFTSyncWorkflowScheduler,
+}
+
+impl WorkflowScheduler for CpopWorkflowScheduler {
+    fn new(reservation_store: ReservationStore) -> Box<dyn WorkflowScheduler> {
+        Box::new(Self { heft_sync: HEFTSyncWorkflowScheduler { base: WorkflowSchedulerBase::new(reservation_store) } })
+    }
+
+    fn get_reservation_store(&self) -> &ReservationStore {
+        &self.heft_sync.base.reservation_store
+    }
+
+    fn name(&self) -> &str {
+        "CpopWorkflowScheduler"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn reserve(&mut self, workflow_res_id: ReservationId, adc: &mut ADC) -> Result<(), RejectionReason> {
+        if self.reserve_workflow(workflow_res_id, adc) {
+            return Ok(());
+        }
+        return Err(self.heft_sync.base.get_rejection_reason());
+    }
+
+    /// The probe does not depend on the placement order, hence it is delegated to the wrapped HEFTSync scheduler.
+    fn probe(&mut self, workflow_res_id: ReservationId, adc: &mut ADC) -> Reservations {
+        return self.heft_sync.probe(workflow_res_id, adc);
+    }
+}
+
+impl CpopWorkflowScheduler {
+    /// CPOP scheduling of the workflow on the master schedule.
+    ///
+    /// # Returns
+    /// `true` if all sub-reservations of the workflow were reserved (state becomes `ReservationState::ReserveAnswer`),
+    /// otherwise all done sub-reservations are rolled back and the workflow is rejected.
+    fn reserve_workflow(&mut self, workflow_res_id: ReservationId, adc: &mut ADC) -> bool {
+        self.heft_sync.base.last_rejection = None;
+
+        let Some(workflow_handle) = self.heft_sync.base.reservation_store.get(workflow_res_id) else {
+            return false;
+        };
+        let mut reservation = workflow_handle.write().unwrap();
+        let Reservation::Workflow(ref mut workflow) = *reservation else {
+            return false;
+        };
+
+        // Local reservation map will be later committed to global state if all reservations where successful
+        let mut grid_component_res_database: HashMap<ReservationId, ComponentId> = HashMap::new();
+
+        let average_link_speed = adc.manager.get_average_link_speed() as i64;
+        let zone_communication_cost = adc.manager.get_zone_communication_cost();
+        // The upward rank order guarantees, that all predecessors of a CoAllocation are placed before it
+        let ranked_node_reservations = match workflow
+            .calculate_downward_rank_with_zones(&zone_communication_cost, &self.heft_sync.base.reservation_store)
+            .and_then(|_| workflow.calculate_upward_rank_with_zones(&zone_communication_cost, &self.heft_sync.base.reservation_store))
+        {
+            Ok(ranked_node_reservations) => ranked_node_reservations,
+            Err(error) => {
+                log::error!("CpopRankCalculationFailed: Workflow {} is rejected: {}", workflow.base.get_name(), error);
+                workflow.set_state(ReservationState::Rejected);
+                return false;
+            }
+        };
+
+        let critical_co_allocations = self.get_critical_co_allocations(workflow);
+        let critical_path_component_id = self.select_critical_path_component(workflow, &critical_co_allocations, adc);
+
+        let workflow_booking_interval_end = workflow.get_booking_interval_end();
+
+        for mut workflow_node in ranked_node_reservations {
+            let co_allocation_key = workflow_node.co_allocation_key.clone().unwrap();
+            let start = self.heft_sync.get_earliest_start(workflow, &co_allocation_key, average_link_speed);
+            let task_duration = self.heft_sync.base.reservation_store.get_task_duration(workflow_node.reservation_id);
+
+            // Do not process workflow, where the deadline will be missed
+            if start + task_duration > workflow_booking_interval_end {
+                log::debug!("Deadline exceeded for node {:?} or workflow {}. Rolling back.", workflow_node.reservation_id, workflow.base.get_name());
+                let reason = RejectionReason::DeadlineMiss {
+                    node: workflow.node_id_for_reservation(workflow_node.reservation_id).unwrap(),
+                    projected_finish: start + task_duration,
+                    deadline: workflow_booking_interval_end,
+                };
+                return self.reject(workflow, &workflow_node, reason, (start, workflow_booking_interval_end), &mut grid_component_res_database, adc);
+            }
+
+            self.heft_sync.base.reservation_store.set_booking_interval_start(workflow_node.reservation_id, start);
+            self.heft_sync.base.reservation_store.set_booking_interval_end(workflow_node.reservation_id, workflow_booking_interval_end);
+
+            let pinned_component_id = critical_path_component_id.clone().filter(|_| critical_co_allocations.contains(&co_allocation_key));
+
+            // Schedule Co-Allocation nodes, a failed pinned placement is retried without pinning
+            if !self.heft_sync.schedule_co_allocation_node_reservations_at(
+                workflow,
+                &mut workflow_node,
+                &mut grid_component_res_database,
+                adc,
+                None,
+                pinned_component_id,
+            ) && !self.heft_sync.retry_co_allocation_placement(workflow, &mut workflow_node, &mut grid_component_res_database, adc, None)
+            {
+                return self.reject(
+                    workflow,
+                    &workflow_node,
+                    RejectionReason::NoCapacity { node: workflow.node_id_for_reservation(workflow_node.reservation_id).unwrap() },
+                    (start, workflow_booking_interval_end),
+                    &mut grid_component_res_database,
+                    adc,
+                );
+            }
+
+            // Try to get network connection form all predecessors (data dependencies)
+            if let Err(dependency) =
+                self.heft_sync.schedule_data_dependencies(workflow, &mut workflow_node, &mut grid_component_res_database, adc, None)
+            {
+                return self.reject(
+                    workflow,
+                    &workflow_node,
+                    RejectionReason::NetworkUnroutable { dependency },
+                    (start, workflow_booking_interval_end),
+                    &mut grid_component_res_database,
+                    adc,
+                );
+            }
+        }
+
+        // Success: Submit done reservations into global state ADC -> VrmComponentManager
+        adc.manager.register_workflow_subtasks(workflow_res_id, &grid_component_res_database);
+        workflow.set_state(ReservationState::ReserveAnswer);
+        return true;
+    }
+
+    /// Returns all CoAllocations on the critical path, i.e. all CoAllocations whose
+    /// `rank_upward + rank_downward - duration` equals the makespan of the workflow.
+    ///
+    /// The upward and downward ranks must be computed beforehand.
+    pub fn get_critical_co_allocations(&self, workflow: &Workflow) -> HashSet<CoAllocationId> {
+        let makespan = workflow
+            .entry_co_allocation
+            .iter()
+            .filter_map(|co_allocation_key| workflow.co_allocations.get(co_allocation_key))
+            .map(|co_allocation| co_allocation.rank_upward)
+            .max()
+            .unwrap_or(0);
+
+        return workflow
+            .co_allocations
+            .iter()
+            .filter(|(_, co_allocation)| {
+                let duration = co_allocation.get_co_allocation_duration(&workflow.nodes, &self.heft_sync.base.reservation_store);
+                co_allocation.rank_upward + co_allocation.rank_downward - duration == makespan
+            })
+            .map(|(co_allocation_key, _)| co_allocation_key.clone())
+            .collect();
+    }
+
+    /// Selects the VrmComponent, which minimizes the cumulative execution time of all critical CoAllocations.
+    ///
+    /// Every VrmComponent is probed with the representative task of each critical CoAllocation (in the booking
+    /// interval of the workflow). VrmComponents, which cannot host all critical CoAllocations, and blacklisted
+    /// VrmComponents are skipped. Ties are broken by the `VrmComponentOrder` of the ADC.
+    ///
+    /// # Returns
+    /// The selected `ComponentId`, or `None` if no VrmComponent can host the whole critical path.
+    pub fn select_critical_path_component(
+        &mut self,
+        workflow: &Workflow,
+        critical_co_allocations: &HashSet<CoAllocationId>,
+        adc: &mut ADC,
+    ) -> Option<ComponentId> {
+        let workflow_booking_interval_start = workflow.base.get_booking_interval_start();
+        let workflow_booking_interval_end = workflow.get_booking_interval_end();
+
+        let critical_res_ids: Vec<ReservationId> = critical_co_allocations
+            .iter()
+            .filter_map(|co_allocation_key| workflow.co_allocations.get(co_allocation_key)?.representative.as_ref())
+            .map(|representative| representative.reservation_id)
+            .collect();
+
+        if critical_res_ids.is_empty() {
+            return None;
+        }
+
+        for critical_res_id in &critical_res_ids {
+            self.heft_sync.base.reservation_store.set_booking_interval_start(*critical_res_id, workflow_booking_interval_start);
+            self.heft_sync.base.reservation_store.set_booking_interval_end(*critical_res_id, workflow_booking_interval_end);
+        }
+
+        let component_ids = adc.manager.get_ordered_vrm_components(adc.vrm_component_order);
+
+        let mut best: Option<(ComponentId, i64)> = None;
+        for component_id in component_ids {
+            let mut cumulative_execution_time: i64 = 0;
+            let mut can_host_critical_path = true;
+
+            for critical_res_id in &critical_res_ids {
+                let Some(res_snapshot) = self.heft_sync.base.reservation_store.get_reservation_snapshot(*critical_res_id) else {
+                    can_host_critical_path = false;
+                    break;
+                };
+
+                if !adc.manager.can_component_handel(component_id.clone(), res_snapshot) {
+                    can_host_critical_path = false;
+                    break;
+                }
+
+                let probe_reservations = adc.manager.get_vrm_component_mut(component_id.clone()).probe(*critical_res_id, None);
+                match probe_reservations.best_execution_time() {
+                    Some(execution_time) => cumulative_execution_time += execution_time,
+                    None => {
+                        can_host_critical_path = false;
+                        break;
+                    }
+                }
+            }
+
+            if can_host_critical_path && best.as_ref().is_none_or(|(_, best_execution_time)| cumulative_execution_time < *best_execution_time) {
+                best = Some((component_id, cumulative_execution_time));
+            }
+        }
+
+        log::debug!(
+            "CpopCriticalPathComponent: Critical path of workflow {} with {} CoAllocations is pinned onto {:?}.",
+            workflow.base.get_name(),
+            critical_res_ids.len(),
+            best
+        );
+        return best.map(|(component_id, _)| component_id);
+    }
+
+    /// Records the rejection diagnostics, rolls back all done sub-reservations and rejects the workflow.
+    fn reject(
+        &mut self,
+        workflow: &mut Workflow,
+        workflow_node: &WorkflowNode,
+        reason: RejectionReason,
+        window: (i64, i64),
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+        adc: &mut ADC,
+    ) -> bool {
+        self.heft_sync.record_rejection(workflow, workflow_node, reason, window, grid_component_res_database, adc, None);
+        self.heft_sync.cancel_all_reservations(adc, grid_component_res_database, None);
+        workflow.set_state(ReservationState::Rejected);
+        return false;
+    }
+}
@@ -1,14 +1,21 @@
 use crate::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
-use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::{WorkflowScheduler, WorkflowSchedulerBase};
+use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::{
+    PlacementFailurePolicy, RejectionDiagnostics, RejectionReason, WorkflowReservationOutcome, WorkflowScheduler, WorkflowSchedulerBase,
+};
+use crate::domain::vrm_system_model::grid_resource_management_system::scheduler_comparator::priority_reservation_compare::PriorityReservationCompare;
 use crate::domain::vrm_system_model::reservation::probe_reservations::ProbeReservationComparator;
 use crate::domain::vrm_system_model::reservation::reservations::Reservations;
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use tokio_util::sync::CancellationToken;
+use union_find::{QuickUnionUf, UnionBySize, UnionFind};
 
-use crate::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState, ReservationTrait};
+use crate::domain::vrm_system_model::reservation::reservation::{ASSIGNED_TIME_UNSET, Reservation, ReservationState, ReservationTrait};
 use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
-use crate::domain::vrm_system_model::utils::id::{ComponentId, RouterId, WorkflowNodeId};
+use crate::domain::vrm_system_model::utils::config::MOLDABLE_CAPACITY_FACTORS;
+use crate::domain::vrm_system_model::utils::id::{CoAllocationId, ComponentId, RouterId, ShadowScheduleId, WorkflowNodeId};
 
+use crate::domain::vrm_system_model::workflow::dependency::DataDependency;
 use crate::domain::vrm_system_model::workflow::workflow::Workflow;
 use crate::domain::vrm_system_model::workflow::workflow_node::WorkflowNode;
 
@@ -36,7 +43,7 @@ pub struct HEFTSyncWorkflowScheduler {
 
 impl WorkflowScheduler for HEFTSyncWorkflowScheduler {
     fn new(reservation_store: ReservationStore) -> Box<dyn WorkflowScheduler> {
-        Box::new(Self { base: WorkflowSchedulerBase { reservation_store } })
+        Box::new(Self { base: WorkflowSchedulerBase::new(reservation_store) })
     }
 
     fn get_reservation_store(&self) -> &ReservationStore {
@@ -51,7 +58,175 @@ impl WorkflowScheduler for HEFTSyncWorkflowScheduler {
         self
     }
 
-    fn reserve(&mut self, workflow_res_id: ReservationId, adc: &mut ADC) -> bool {
+    fn reserve(&mut self, workflow_res_id: ReservationId, adc: &mut ADC) -> Result<(), RejectionReason> {
+        return match self.reserve_workflow(workflow_res_id, adc, None, None, None) {
+            WorkflowReservationOutcome::Reserved => Ok(()),
+            _ => Err(self.base.get_rejection_reason()),
+        };
+    }
+
+    fn probe(&mut self, workflow_res_id: ReservationId, adc: &mut ADC) -> Reservations {
+        return self.probe_workflow(workflow_res_id, adc);
+    }
+}
+
+impl HEFTSyncWorkflowScheduler {
+    /// Schedules the workflow like `reserve`, but all sub-reservations are placed on the shadow schedule
+    /// `shadow_schedule_id` of the VrmComponents. The master schedule and the master tracking of the
+    /// `VrmComponentManager` are not modified, successful placements are registered in the shadow schedule.
+    ///
+    /// # Returns
+    /// `true` if all sub-reservations of the workflow were reserved on the shadow schedule.
+    pub fn reserve_on_shadow(&mut self, workflow_res_id: ReservationId, adc: &mut ADC, shadow_schedule_id: ShadowScheduleId) -> bool {
+        return self.reserve_workflow(workflow_res_id, adc, Some(shadow_schedule_id), None, None) == WorkflowReservationOutcome::Reserved;
+    }
+
+    /// Schedules the workflow like `reserve`, but the placement of the whole workflow must be finished before
+    /// the `deadline` (in s of the `GlobalClock` of the ADC, i.e. wall-clock or simulation time). The deadline is checked
+    /// before each CoAllocation is placed, if it is exceeded all done sub-reservations are rolled back and the workflow is rejected.
+    ///
+    /// # Returns
+    /// * `WorkflowReservationOutcome::Reserved` if all sub-reservations of the workflow were reserved.
+    /// * `WorkflowReservationOutcome::Rejected` if the workflow could not be placed.
+    /// * `WorkflowReservationOutcome::Timeout` if the deadline was exceeded during the placement.
+    pub fn reserve_with_deadline(&mut self, workflow_res_id: ReservationId, adc: &mut ADC, deadline: i64) -> WorkflowReservationOutcome {
+        return self.reserve_workflow(workflow_res_id, adc, None, Some(deadline), None);
+    }
+
+    /// Schedules the workflow like `reserve`, but the placement can be cancelled from another thread via the
+    /// `cancellation_token`. The token is checked before each CoAllocation is placed, if it is cancelled
+    /// all done sub-reservations are rolled back and the workflow is rejected.
+    ///
+    /// # Returns
+    /// * `WorkflowReservationOutcome::Reserved` if all sub-reservations of the workflow were reserved.
+    /// * `WorkflowReservationOutcome::Rejected` if the workflow could not be placed.
+    /// * `WorkflowReservationOutcome::Cancelled` if the token was cancelled during the placement.
+    pub fn reserve_with_cancellation(
+        &mut self,
+        workflow_res_id: ReservationId,
+        adc: &mut ADC,
+        cancellation_token: &CancellationToken,
+    ) -> WorkflowReservationOutcome {
+        return self.reserve_workflow(workflow_res_id, adc, None, None, Some(cancellation_token));
+    }
+
+    /// Dry-run of `reserve`: The workflow is scheduled with HEFTSync on a temporary shadow schedule, which is
+    /// deleted afterwards, and all modifications of the workflow and its sub-reservations in the reservation store
+    /// are undone. Therefore, nothing is reserved on the VrmComponents.
+    ///
+    /// The listeners of the reservation store are detached during the dry-run, hence neither the dry-run nor its
+    /// undo are notified. All reservations added during the dry-run (e.g. the probe reservations of the
+    /// VrmComponents) are removed from the store again.
+    ///
+    /// # Returns
+    /// A `Reservations` set with one `ReservationState::ProbeReservation` per workflow node, which carries the
+    /// tentative assigned_start/assigned_end of the node. The set is empty if the workflow could not be placed or
+    /// the projected finish of any node exceeds the booking interval end of the workflow.
+    fn probe_workflow(&mut self, workflow_res_id: ReservationId, adc: &mut ADC) -> Reservations {
+        let mut probe_reservations = Reservations::new_empty(self.base.reservation_store.clone());
+
+        let Some(workflow_snapshot) = self.base.reservation_store.get_reservation_snapshot(workflow_res_id) else {
+            log::error!("HEFTSyncProbeFailed: Reservation {:?} is not present in the reservation store.", workflow_res_id);
+            return probe_reservations;
+        };
+        let Reservation::Workflow(ref workflow) = workflow_snapshot else {
+            log::error!("HEFTSyncProbeFailed: Reservation {:?} is not a workflow.", workflow_snapshot.get_name());
+            return probe_reservations;
+        };
+
+        let workflow_booking_interval_end = workflow.get_booking_interval_end();
+        let node_res_ids: Vec<ReservationId> = workflow.nodes.values().map(|node| node.reservation_id).collect();
+        let sub_reservation_snapshots: Vec<(ReservationId, Reservation)> = workflow
+            .get_all_reservation_ids()
+            .into_iter()
+            .filter_map(|reservation_id| {
+                self.base.reservation_store.get_reservation_snapshot(reservation_id).map(|snapshot| (reservation_id, snapshot))
+            })
+            .collect();
+
+        let listeners = self.base.reservation_store.take_listeners();
+        let reservation_ids_before_dry_run: HashSet<ReservationId> = self.base.reservation_store.iter_ids().into_iter().collect();
+
+        let shadow_schedule_id = ShadowScheduleId::new(format!("HEFTSyncProbe-{}", workflow.base.get_name()));
+        if !adc.manager.create_shadow_schedule(shadow_schedule_id.clone()) {
+            log::error!("HEFTSyncProbeFailed: Creation of the shadow schedule {} for the probe failed.", shadow_schedule_id);
+            self.base.reservation_store.add_listeners(listeners);
+            return probe_reservations;
+        }
+
+        let outcome = self.reserve_workflow(workflow_res_id, adc, Some(shadow_schedule_id.clone()), None, None);
+
+        // Tentative placement of all nodes, the projected finish must not exceed the booking interval end
+        let mut tentative_nodes = Vec::new();
+        if outcome == WorkflowReservationOutcome::Reserved {
+            for node_res_id in &node_res_ids {
+                let Some(mut node_snapshot) = self.base.reservation_store.get_node_reservation_snapshot(*node_res_id) else {
+                    continue;
+                };
+
+                if node_snapshot.get_assigned_end() > workflow_booking_interval_end {
+                    log::debug!(
+                        "HEFTSyncProbeDeadlineExceeded: Projected finish of node {} exceeds the booking interval end of workflow {}.",
+                        node_snapshot.get_name(),
+                        workflow.base.get_name()
+                    );
+                    tentative_nodes.clear();
+                    break;
+                }
+
+                node_snapshot.set_state(ReservationState::ProbeReservation);
+                tentative_nodes.push(Reservation::Node(node_snapshot));
+            }
+        }
+
+        // Undo the dry-run on the VrmComponents and in the reservation store
+        adc.manager.delete_shadow_schedule(shadow_schedule_id);
+        for (reservation_id, snapshot) in sub_reservation_snapshots {
+            self.base.reservation_store.restore_reservation_snapshot(reservation_id, snapshot);
+        }
+        self.base.reservation_store.restore_reservation_snapshot(workflow_res_id, workflow_snapshot);
+        for reservation_id in self.base.reservation_store.iter_ids() {
+            if !reservation_ids_before_dry_run.contains(&reservation_id) {
+                self.base.reservation_store.remove(reservation_id);
+            }
+        }
+        self.base.reservation_store.add_listeners(listeners);
+
+        for node_snapshot in tentative_nodes {
+            probe_reservations.insert(self.base.reservation_store.add_probe_reservation(node_snapshot));
+        }
+        return probe_reservations;
+    }
+
+    /// Returns the canonical placement snapshot of the workflow (see `VrmComponentManager::workflow_placement_snapshot`),
+    /// e.g. to compare the result of `reserve` against a golden snapshot.
+    ///
+    /// # Returns
+    /// The snapshot, or `None` if `workflow_res_id` is not a workflow of the reservation store.
+    pub fn placement_snapshot(&self, workflow_res_id: ReservationId, adc: &ADC) -> Option<String> {
+        let workflow_handle = self.base.reservation_store.get(workflow_res_id)?;
+        let reservation = workflow_handle.read().unwrap();
+
+        if let Reservation::Workflow(ref workflow) = *reservation {
+            return Some(adc.manager.workflow_placement_snapshot(workflow));
+        }
+        return None;
+    }
+
+    /// HEFTSync scheduling of the workflow on the master schedule (`shadow_schedule_id == None`)
+    /// or on the provided shadow schedule. If a `deadline` is provided, the placement is aborted
+    /// as soon as the deadline is exceeded. If a `cancellation_token` is provided, the placement is
+    /// aborted at the next CoAllocation as soon as the token is cancelled.
+    fn reserve_workflow(
+        &mut self,
+        workflow_res_id: ReservationId,
+        adc: &mut ADC,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+        deadline: Option<i64>,
+        cancellation_token: Option<&CancellationToken>,
+    ) -> WorkflowReservationOutcome {
+        self.base.last_rejection = None;
+
         // 1. Get exclusive access via the store
         if let Some(workflow_handle) = self.base.reservation_store.get(workflow_res_id) {
             let mut reservation = workflow_handle.write().unwrap();
@@ -60,46 +235,74 @@ impl WorkflowScheduler for HEFTSyncWorkflowScheduler {
             let mut grid_component_res_database: HashMap<ReservationId, ComponentId> = HashMap::new();
 
             if let Reservation::Workflow(ref mut workflow) = *reservation {
+                if self.base.split_oversized_nodes {
+                    let max_capacity = adc.manager.get_max_component_node_capacity();
+                    if let Err(error) = workflow.split_oversized_nodes(max_capacity, self.base.reservation_store.clone()) {
+                        log::error!("HEFTSyncSplitNodesFailed: Workflow {} is rejected: {}", workflow.base.get_name(), error);
+                        workflow.set_state(ReservationState::Rejected);
+                        return WorkflowReservationOutcome::Rejected;
+                    }
+                }
+
                 let average_link_speed = adc.manager.get_average_link_speed() as i64;
-                let ranked_node_reservations = workflow.calculate_upward_rank(average_link_speed, &self.base.reservation_store);
+                let zone_communication_cost = adc.manager.get_zone_communication_cost();
+                let mut ranked_node_reservations =
+                    match workflow.calculate_upward_rank_with_zones(&zone_communication_cost, &self.base.reservation_store) {
+                        Ok(ranked_node_reservations) => ranked_node_reservations,
+                        Err(error) => {
+                            log::error!("HEFTSyncRankCalculationFailed: Workflow {} is rejected: {}", workflow.base.get_name(), error);
+                            workflow.set_state(ReservationState::Rejected);
+                            return WorkflowReservationOutcome::Rejected;
+                        }
+                    };
+                self.order_by_priority(workflow, &mut ranked_node_reservations);
 
+                let workflow_booking_interval_start = workflow.base.get_booking_interval_start();
                 let workflow_booking_interval_end = workflow.get_booking_interval_end();
 
                 for mut workflow_node in ranked_node_reservations {
-                    let mut start = workflow.get_booking_interval_start();
+                    if Self::is_deadline_exceeded(deadline, adc) {
+                        log::debug!(
+                            "HEFTSyncPlacementDeadlineExceeded: The placement of workflow {} exceeded its deadline. Rolling back.",
+                            workflow.base.get_name()
+                        );
+                        self.record_rejection(
+                            workflow,
+                            &workflow_node,
+                            RejectionReason::Timeout,
+                            (workflow_booking_interval_start, workflow_booking_interval_end),
+                            &grid_component_res_database,
+                            adc,
+                            shadow_schedule_id.clone(),
+                        );
+                        self.cancel_all_reservations(adc, &mut grid_component_res_database, shadow_schedule_id.clone());
+                        workflow.set_state(ReservationState::Rejected);
+                        return WorkflowReservationOutcome::Timeout;
+                    }
 
-                    let co_allocation_key = &workflow_node.co_allocation_key.clone().unwrap();
-                    let co_allocation_node = workflow.co_allocations.get(co_allocation_key).unwrap();
+                    if cancellation_token.is_some_and(|cancellation_token| cancellation_token.is_cancelled()) {
+                        log::debug!(
+                            "HEFTSyncPlacementCancelled: The placement of workflow {} was cancelled. Rolling back.",
+                            workflow.base.get_name()
+                        );
+                        self.record_rejection(
+                            workflow,
+                            &workflow_node,
+                            RejectionReason::Cancelled,
+                            (workflow_booking_interval_start, workflow_booking_interval_end),
+                            &grid_component_res_database,
+                            adc,
+                            shadow_schedule_id.clone(),
+                        );
+                        self.cancel_all_reservations(adc, &mut grid_component_res_database, shadow_schedule_id.clone());
+                        workflow.set_state(ReservationState::Rejected);
+                        return WorkflowReservationOutcome::Cancelled;
+                    }
 
                     // Calculate Earliest Start Time based on data dependencies
-                    for data_dependency in co_allocation_node.incoming_data_dependencies.clone() {
-                        let data_dep_source_res_id = data_dependency.source_node.unwrap();
-
-                        let data_dep_source_assigned_end =
-                            self.base.reservation_store.get_assigned_end(workflow.nodes.get(&data_dep_source_res_id).unwrap().reservation_id);
+                    let co_allocation_key = workflow_node.co_allocation_key.clone().unwrap();
+                    let start = self.get_earliest_start(workflow, &co_allocation_key, average_link_speed);
 
-                        let mut file_transfer_time = 0;
-
-                        if data_dependency.size > 0 {
-                            file_transfer_time = data_dependency.size / average_link_speed;
-                        }
-
-                        // If there is something to transfer it should be at least be one
-                        if data_dependency.size > 0 && file_transfer_time == 0 {
-                            log::debug!(
-                                "MissMatchDataDependencySizeTransferTime: The Data dependency {} has a size of {}, however the file transfer time is 0. Process dependency with transfer_time of 1.",
-                                self.base.reservation_store.get_name_for_key(data_dependency.reservation_id).unwrap(),
-                                data_dependency.size
-                            );
-                            file_transfer_time = 1;
-                        }
-
-                        let start_after_this_dep = data_dep_source_assigned_end + file_transfer_time;
-
-                        if start_after_this_dep > start {
-                            start = start_after_this_dep;
-                        }
-                    }
                     // Access duration from Store
                     let task_duration = self.base.reservation_store.get_task_duration(workflow_node.reservation_id);
 
@@ -110,9 +313,23 @@ impl WorkflowScheduler for HEFTSyncWorkflowScheduler {
                             workflow_node.reservation_id,
                             workflow.base.get_name()
                         );
-                        self.cancel_all_reservations(adc, &mut grid_component_res_database);
-                        self.base.reservation_store.update_state(workflow_res_id, ReservationState::Rejected);
-                        return false;
+                        let reason = RejectionReason::DeadlineMiss {
+                            node: workflow.node_id_for_reservation(workflow_node.reservation_id).unwrap(),
+                            projected_finish: start + task_duration,
+                            deadline: workflow_booking_interval_end,
+                        };
+                        self.record_rejection(
+                            workflow,
+                            &workflow_node,
+                            reason,
+                            (start, workflow_booking_interval_end),
+                            &grid_component_res_database,
+                            adc,
+                            shadow_schedule_id.clone(),
+                        );
+                        self.cancel_all_reservations(adc, &mut grid_component_res_database, shadow_schedule_id.clone());
+                        workflow.set_state(ReservationState::Rejected);
+                        return WorkflowReservationOutcome::Rejected;
                     }
 
                     self.base.reservation_store.set_booking_interval_start(workflow_node.reservation_id, start);
@@ -121,35 +338,600 @@ impl WorkflowScheduler for HEFTSyncWorkflowScheduler {
 
                     // Schedule all compute task (and all synced compute tasks and sync dependencies)
                     // Schedule Co-Allocation nodes
-                    if !self.schedule_co_allocation_node_reservations(workflow, &mut workflow_node, &mut grid_component_res_database, adc) {
-                        self.cancel_all_reservations(adc, &mut grid_component_res_database);
+                    if !self.schedule_co_allocation_node_reservations(
+                        workflow,
+                        &mut workflow_node,
+                        &mut grid_component_res_database,
+                        adc,
+                        shadow_schedule_id.clone(),
+                    ) && !self.retry_co_allocation_placement(
+                        workflow,
+                        &mut workflow_node,
+                        &mut grid_component_res_database,
+                        adc,
+                        shadow_schedule_id.clone(),
+                    ) {
+                        let reason = RejectionReason::NoCapacity { node: workflow.node_id_for_reservation(workflow_node.reservation_id).unwrap() };
+                        self.record_rejection(
+                            workflow,
+                            &workflow_node,
+                            reason,
+                            (start, workflow_booking_interval_end),
+                            &grid_component_res_database,
+                            adc,
+                            shadow_schedule_id.clone(),
+                        );
+                        self.cancel_all_reservations(adc, &mut grid_component_res_database, shadow_schedule_id.clone());
                         workflow.set_state(ReservationState::Rejected);
-                        return false;
+                        return WorkflowReservationOutcome::Rejected;
                     }
 
                     // Try to get network connection form all predecessors (data dependencies)
-                    if !self.schedule_data_dependencies(workflow, &mut workflow_node, &mut grid_component_res_database, adc) {
-                        self.cancel_all_reservations(adc, &mut grid_component_res_database);
+                    if let Err(dependency) = self.schedule_data_dependencies(
+                        workflow,
+                        &mut workflow_node,
+                        &mut grid_component_res_database,
+                        adc,
+                        shadow_schedule_id.clone(),
+                    ) {
+                        self.record_rejection(
+                            workflow,
+                            &workflow_node,
+                            RejectionReason::NetworkUnroutable { dependency },
+                            (start, workflow_booking_interval_end),
+                            &grid_component_res_database,
+                            adc,
+                            shadow_schedule_id.clone(),
+                        );
+                        self.cancel_all_reservations(adc, &mut grid_component_res_database, shadow_schedule_id.clone());
                         workflow.set_state(ReservationState::Rejected);
-                        return false;
+                        return WorkflowReservationOutcome::Rejected;
                     }
                 }
 
                 // Success: Submit done reservations into global state ADC -> VrmComponentManager
-                adc.manager.register_workflow_subtasks(workflow_res_id, &grid_component_res_database);
+                match shadow_schedule_id {
+                    Some(shadow_schedule_id) => {
+                        adc.manager.register_shadow_workflow_subtasks(shadow_schedule_id, &grid_component_res_database);
+                    }
+                    None => adc.manager.register_workflow_subtasks(workflow_res_id, &grid_component_res_database),
+                }
                 workflow.set_state(ReservationState::ReserveAnswer);
+                return WorkflowReservationOutcome::Reserved;
+            }
+        }
+        return WorkflowReservationOutcome::Rejected;
+    }
+
+    /// Returns the diagnostics of the last rejected workflow placement of this scheduler.
+    ///
+    /// # Returns
+    /// The `RejectionDiagnostics`, or `None` if the last placement succeeded or no placement was done yet.
+    pub fn last_rejection_diagnostics(&self) -> Option<&RejectionDiagnostics> {
+        return self.base.last_rejection.as_ref();
+    }
+
+    /// Assembles the `RejectionDiagnostics` of a failed placement and stores them as `last_rejection`.
+    /// Must be called before `cancel_all_reservations`, as the partial placement is read from
+    /// `grid_component_res_database`.
+    ///
+    /// # Arguments
+    /// * `workflow` - The rejected workflow.
+    /// * `workflow_node` - The node, whose placement failed.
+    /// * `reason` - The reason of the rejection.
+    /// * `window` - Time window (start, end) in s (VRM time), in which the free capacity of the ADC is queried.
+    /// * `grid_component_res_database` - The partial placement, which will be rolled back.
+    /// * `adc` - The ADC, on which the workflow was placed.
+    /// * `shadow_schedule_id` - Shadow schedule of the placement (If None utilize master schedule).
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn record_rejection(
+        &mut self,
+        workflow: &Workflow,
+        workflow_node: &WorkflowNode,
+        reason: RejectionReason,
+        window: (i64, i64),
+        grid_component_res_database: &HashMap<ReservationId, ComponentId>,
+        adc: &ADC,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+    ) {
+        let mut rolled_back: Vec<_> = grid_component_res_database
+            .iter()
+            .filter_map(|(reservation_id, component_id)| {
+                self.base.reservation_store.get_name_for_key(*reservation_id).map(|name| (name, component_id.clone()))
+            })
+            .collect();
+        rolled_back.sort();
+
+        let diagnostics = RejectionDiagnostics {
+            workflow: workflow.base.get_name(),
+            failing_node: self.base.reservation_store.get_name_for_key(workflow_node.reservation_id),
+            co_allocation: workflow_node.co_allocation_key.clone(),
+            reason,
+            window,
+            free_capacity: adc.manager.free_capacity(window.0, window.1, shadow_schedule_id),
+            rolled_back,
+        };
+
+        log::debug!("HEFTSyncPlacementRejected: {:?}", diagnostics);
+        self.base.last_rejection = Some(diagnostics);
+    }
+
+    fn is_deadline_exceeded(deadline: Option<i64>, adc: &ADC) -> bool {
+        return deadline.is_some_and(|deadline| adc.manager.simulator.get_system_time_s() >= deadline);
+    }
+
+    /// Returns the earliest start of the CoAllocation, i.e. the latest arrival of the data of all its
+    /// predecessors, but not before the booking interval start of the workflow and not before the
+    /// explicit earliest start of its members.
+    pub(super) fn get_earliest_start(&self, workflow: &Workflow, co_allocation_key: &CoAllocationId, average_link_speed: i64) -> i64 {
+        let mut start = workflow.get_booking_interval_start();
+        let co_allocation_node = workflow.co_allocations.get(co_allocation_key).unwrap();
+
+        if let Some(explicit_earliest_start) = co_allocation_node.get_explicit_earliest_start(&workflow.nodes) {
+            start = start.max(explicit_earliest_start);
+        }
+
+        for data_dependency in &co_allocation_node.incoming_data_dependencies {
+            let data_dep_source_res_id = data_dependency.source_node.clone().unwrap();
+
+            let data_dep_source_assigned_end =
+                self.base.reservation_store.get_assigned_end(workflow.nodes.get(&data_dep_source_res_id).unwrap().reservation_id);
+
+            let start_after_this_dep = data_dep_source_assigned_end + self.get_file_transfer_time(data_dependency, average_link_speed);
+
+            if start_after_this_dep > start {
+                start = start_after_this_dep;
+            }
+        }
+        return start;
+    }
+
+    /// Checks, that the start router of the LinkReservation is part of the source VrmComponent and its end router
+    /// part of the target VrmComponent. On a mismatch the LinkReservation is rejected.
+    ///
+    /// The routers are looked up in the network topology of the VrmComponents themselves (see
+    /// `VrmComponentManager::component_contains_router`) and not in the router lists, from which they were assigned.
+    ///
+    /// # Arguments
+    /// * `dependency_reservation_id` - LinkReservation with already assigned `start_point` and `end_point`.
+    /// * `source_component_id` - VrmComponent, on which the data transfer starts.
+    /// * `target_component_id` - VrmComponent, on which the data transfer ends.
+    /// * `adc` - ADC, which manages both VrmComponents.
+    ///
+    /// # Returns
+    /// `true` if both end points belong to their VrmComponent, otherwise `false`.
+    pub fn validate_link_end_points(
+        &self,
+        dependency_reservation_id: ReservationId,
+        source_component_id: &ComponentId,
+        target_component_id: &ComponentId,
+        adc: &ADC,
+    ) -> bool {
+        let (start_point, end_point) = self
+            .base
+            .reservation_store
+            .get_link_reservation_snapshot(dependency_reservation_id)
+            .map(|link_reservation| (link_reservation.start_point, link_reservation.end_point))
+            .unwrap_or_default();
+
+        let is_start_valid = start_point.as_ref().is_some_and(|router_id| adc.manager.component_contains_router(source_component_id, router_id));
+        let is_end_valid = end_point.as_ref().is_some_and(|router_id| adc.manager.component_contains_router(target_component_id, router_id));
+
+        if is_start_valid && is_end_valid {
+            return true;
+        }
+
+        log::error!(
+            "HEFTSyncInvalidLinkEndPoints: LinkReservation {:?} was assigned from {:?} to {:?}, but the start router is not part of the source VrmComponent {} or the end router not part of the target VrmComponent {}.",
+            self.base.reservation_store.get_name_for_key(dependency_reservation_id),
+            start_point,
+            end_point,
+            source_component_id,
+            target_component_id
+        );
+        self.base.reservation_store.update_state(dependency_reservation_id, ReservationState::Rejected);
+        return false;
+    }
+
+    /// Reorders the ranked nodes with `PriorityReservationCompare`, so that among CoAllocations with an equal upward rank
+    /// the one with the higher priority is placed first. Has no effect if `is_priority_ordering` is disabled.
+    /// The sort is stable, i.e. nodes with an equal rank and priority keep their order by CoAllocation id.
+    pub fn order_by_priority(&self, workflow: &Workflow, ranked_node_reservations: &mut [WorkflowNode]) {
+        if !self.base.is_priority_ordering {
+            return;
+        }
+
+        let comparator = PriorityReservationCompare::new(self.base.reservation_store.clone());
+        let get_rank = |workflow_node: &WorkflowNode| workflow.co_allocations[workflow_node.co_allocation_key.as_ref().unwrap()].rank_upward;
+
+        ranked_node_reservations.sort_by(|a, b| get_rank(b).cmp(&get_rank(a)).then_with(|| comparator.compare(a.reservation_id, b.reservation_id)));
+    }
+
+    /// Returns the transfer time of the data dependency, which is at least one if there is something to transfer.
+    fn get_file_transfer_time(&self, data_dependency: &DataDependency, average_link_speed: i64) -> i64 {
+        let file_transfer_time = data_dependency.get_communication_time(average_link_speed);
+
+        // If there is something to transfer it should be at least be one
+        if data_dependency.size > 0 && file_transfer_time == 0 {
+            log::debug!(
+                "MissMatchDataDependencySizeTransferTime: The Data dependency {} has a size of {}, however the file transfer time is 0. Process dependency with transfer_time of 1.",
+                self.base.reservation_store.get_name_for_key(data_dependency.reservation_id).unwrap(),
+                data_dependency.size
+            );
+            return 1;
+        }
+        return file_transfer_time;
+    }
+
+    /// Incrementally reschedules an already placed workflow on the master schedule.
+    ///
+    /// All CoAllocations contained in `fixed_windows` are marked as not moveable (e.g. because they are already committed)
+    /// and keep their placement. All moveable CoAllocations and their dependencies are released and placed again in
+    /// HEFTSync order, such that they start after their (fixed or moved) predecessors and end before the fixed window
+    /// of their fixed successors. Data dependencies from moved predecessors to fixed CoAllocations are placed again as well.
+    ///
+    /// # Arguments
+    /// * `workflow` - The workflow, whose sub-reservations are tracked by the `VrmComponentManager` of the `adc`.
+    /// * `fixed_windows` - Assigned window (start, end) of each CoAllocation, which must not be moved.
+    /// * `adc` - The ADC, on whose VrmComponents the workflow is placed.
+    ///
+    /// # Returns
+    /// `true` if all moveable CoAllocations were placed again. Otherwise all new placements are released,
+    /// the released data dependencies of the fixed CoAllocations are restored and the workflow is rejected.
+    pub(crate) fn reschedule_workflow(
+        &mut self,
+        workflow: &mut Workflow,
+        fixed_windows: &HashMap<CoAllocationId, (i64, i64)>,
+        adc: &mut ADC,
+    ) -> bool {
+        let workflow_res_id = self.base.reservation_store.get_key_for_name(workflow.base.get_name());
+
+        for (co_allocation_id, co_allocation) in workflow.co_allocations.iter_mut() {
+            let is_moveable = !fixed_windows.contains_key(co_allocation_id);
+            co_allocation.is_moveable = is_moveable;
+            co_allocation.is_moveable_interval_start = is_moveable;
+            co_allocation.is_moveable_interval_end = is_moveable;
+        }
+
+        // Current placement of all sub-reservations of the workflow
+        let mut grid_component_res_database: HashMap<ReservationId, ComponentId> = HashMap::new();
+        for reservation_id in workflow.get_all_reservation_ids() {
+            let component_id =
+                adc.manager.not_committed_reservations.get(&reservation_id).or_else(|| adc.manager.committed_reservations.get(&reservation_id));
+            if let Some(component_id) = component_id {
+                grid_component_res_database.insert(reservation_id, component_id.clone());
+            }
+        }
+
+        let fixed_dependencies = self.get_fixed_predecessor_dependencies(workflow, &grid_component_res_database);
+        let released_reservations = self.release_moveable_co_allocations(workflow, &mut grid_component_res_database, adc);
+        let fixed_placements = grid_component_res_database.clone();
+
+        workflow.base.set_assigned_start(ASSIGNED_TIME_UNSET);
+        workflow.base.set_assigned_end(ASSIGNED_TIME_UNSET);
+
+        let average_link_speed = adc.manager.get_average_link_speed() as i64;
+        let zone_communication_cost = adc.manager.get_zone_communication_cost();
+        let mut ranked_node_reservations = match workflow.calculate_upward_rank_with_zones(&zone_communication_cost, &self.base.reservation_store) {
+            Ok(ranked_node_reservations) => ranked_node_reservations,
+            Err(error) => {
+                log::error!("HEFTSyncRescheduleRankCalculationFailed: Workflow {} could not be rescheduled: {}", workflow.base.get_name(), error);
+                self.abort_reschedule(workflow, adc, &grid_component_res_database, &fixed_placements, fixed_dependencies);
+                return false;
+            }
+        };
+        self.order_by_priority(workflow, &mut ranked_node_reservations);
+
+        for mut workflow_node in ranked_node_reservations {
+            let co_allocation_key = workflow_node.co_allocation_key.clone().unwrap();
+            let co_allocation = workflow.co_allocations.get(&co_allocation_key).unwrap();
+
+            if !co_allocation.is_moveable {
+                let (fixed_start, fixed_end) = fixed_windows[&co_allocation_key];
+                for member_id in co_allocation.members.clone() {
+                    let member_res_id = workflow.nodes.get(&member_id).unwrap().reservation_id;
+
+                    if self.base.reservation_store.get_assigned_start(member_res_id) != fixed_start
+                        || self.base.reservation_store.get_assigned_end(member_res_id) != fixed_end
+                    {
+                        log::warn!(
+                            "HEFTSyncRescheduleFixedWindowMismatch: Node {} of fixed CoAllocation {} of workflow {} is not placed in the fixed window [{}, {}].",
+                            member_id,
+                            co_allocation_key,
+                            workflow.base.get_name(),
+                            fixed_start,
+                            fixed_end
+                        );
+                    }
+                    workflow.update_reservation(self.base.reservation_store.clone(), member_res_id);
+                }
+
+                // Connect the fixed CoAllocation again with its moved predecessors
+                if self
+                    .schedule_incoming_data_dependencies(workflow, &workflow_node, &mut grid_component_res_database, adc, None, |dep_res_id| {
+                        released_reservations.contains(&dep_res_id)
+                    })
+                    .is_err()
+                {
+                    self.abort_reschedule(workflow, adc, &grid_component_res_database, &fixed_placements, fixed_dependencies);
+                    return false;
+                }
+                continue;
+            }
+
+            let start = self.get_earliest_start(workflow, &co_allocation_key, average_link_speed);
+            let end = self.get_latest_end(workflow, &co_allocation_key, fixed_windows, average_link_speed);
+            let task_duration = self.base.reservation_store.get_task_duration(workflow_node.reservation_id);
+
+            if start + task_duration > end {
+                log::debug!(
+                    "HEFTSyncRescheduleWindowTooSmall: CoAllocation {} of workflow {} does not fit between {} and {}.",
+                    co_allocation_key,
+                    workflow.base.get_name(),
+                    start,
+                    end
+                );
+                self.abort_reschedule(workflow, adc, &grid_component_res_database, &fixed_placements, fixed_dependencies);
+                return false;
+            }
+
+            self.base.reservation_store.set_booking_interval_start(workflow_node.reservation_id, start);
+            self.base.reservation_store.set_booking_interval_end(workflow_node.reservation_id, end);
+
+            if !self.schedule_co_allocation_node_reservations(workflow, &mut workflow_node, &mut grid_component_res_database, adc, None)
+                || self.schedule_data_dependencies(workflow, &mut workflow_node, &mut grid_component_res_database, adc, None).is_err()
+            {
+                self.abort_reschedule(workflow, adc, &grid_component_res_database, &fixed_placements, fixed_dependencies);
+                return false;
+            }
+        }
+
+        adc.manager.replace_workflow_subtasks(workflow_res_id, &grid_component_res_database);
+        workflow.set_state(ReservationState::ReserveAnswer);
+        return true;
+    }
+
+    /// Returns the latest end of the CoAllocation, i.e. the earliest fixed window start of its fixed successors
+    /// minus the transfer time, but not after the booking interval end of the workflow.
+    fn get_latest_end(
+        &self,
+        workflow: &Workflow,
+        co_allocation_key: &CoAllocationId,
+        fixed_windows: &HashMap<CoAllocationId, (i64, i64)>,
+        average_link_speed: i64,
+    ) -> i64 {
+        let mut end = workflow.get_booking_interval_end();
+        let co_allocation_node = workflow.co_allocations.get(co_allocation_key).unwrap();
+
+        for data_dependency in &co_allocation_node.outgoing_data_dependencies {
+            let target_node = workflow.nodes.get(data_dependency.target_node.as_ref().unwrap()).unwrap();
+
+            if let Some((fixed_start, _)) = target_node.co_allocation_key.as_ref().and_then(|key| fixed_windows.get(key)) {
+                let end_before_this_dep = fixed_start - self.get_file_transfer_time(data_dependency, average_link_speed);
+
+                if end_before_this_dep < end {
+                    end = end_before_this_dep;
+                }
+            }
+        }
+        return end;
+    }
+
+    /// Deletes all moveable CoAllocations, their sync dependencies and their incoming and outgoing data
+    /// dependencies at the VrmComponents and resets them to `ReservationState::Open`.
+    ///
+    /// # Returns
+    /// The ids of all released reservations.
+    fn release_moveable_co_allocations(
+        &mut self,
+        workflow: &Workflow,
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+        adc: &mut ADC,
+    ) -> HashSet<ReservationId> {
+        let mut released_reservations = HashSet::new();
+
+        for co_allocation in workflow.co_allocations.values().filter(|co_allocation| co_allocation.is_moveable) {
+            released_reservations.extend(co_allocation.members.iter().map(|member_id| workflow.nodes.get(member_id).unwrap().reservation_id));
+            released_reservations.extend(co_allocation.sync_dependencies.iter().map(|sync_dep| sync_dep.reservation_id));
+            released_reservations.extend(co_allocation.incoming_data_dependencies.iter().map(|data_dep| data_dep.reservation_id));
+            released_reservations.extend(co_allocation.outgoing_data_dependencies.iter().map(|data_dep| data_dep.reservation_id));
+        }
+
+        for reservation_id in &released_reservations {
+            if let Some(component_id) = grid_component_res_database.remove(reservation_id) {
+                adc.delete_task_at_component(component_id, *reservation_id, None);
+            }
+            self.base.reservation_store.reopen(*reservation_id);
+            self.base.reservation_store.set_assigned_start(*reservation_id, ASSIGNED_TIME_UNSET);
+            self.base.reservation_store.set_assigned_end(*reservation_id, ASSIGNED_TIME_UNSET);
+        }
+        return released_reservations;
+    }
+
+    /// Returns a snapshot and the current VrmComponent of each data dependency from a fixed CoAllocation
+    /// to a moveable CoAllocation, i.e. of each dependency of a fixed predecessor, which is released by the reschedule.
+    fn get_fixed_predecessor_dependencies(
+        &self,
+        workflow: &Workflow,
+        grid_component_res_database: &HashMap<ReservationId, ComponentId>,
+    ) -> Vec<(ReservationId, Reservation, Option<ComponentId>)> {
+        let mut fixed_dependencies = Vec::new();
+
+        for co_allocation in workflow.co_allocations.values().filter(|co_allocation| co_allocation.is_moveable) {
+            for data_dep in &co_allocation.incoming_data_dependencies {
+                let is_source_fixed = data_dep
+                    .source_node
+                    .as_ref()
+                    .and_then(|source_node_id| workflow.nodes.get(source_node_id))
+                    .and_then(|source_node| source_node.co_allocation_key.as_ref())
+                    .and_then(|co_allocation_key| workflow.co_allocations.get(co_allocation_key))
+                    .is_some_and(|source_co_allocation| !source_co_allocation.is_moveable);
+
+                if !is_source_fixed {
+                    continue;
+                }
+                if let Some(snapshot) = self.base.reservation_store.get_reservation_snapshot(data_dep.reservation_id) {
+                    fixed_dependencies.push((data_dep.reservation_id, snapshot, grid_component_res_database.get(&data_dep.reservation_id).cloned()));
+                }
+            }
+        }
+        return fixed_dependencies;
+    }
+
+    /// Releases all placements done during a failed reschedule, the fixed placements are kept.
+    ///
+    /// The released data dependencies of the fixed CoAllocations (see `get_fixed_predecessor_dependencies`) are
+    /// restored: Dependencies without a VrmComponent (dummy dependencies) get their snapshot back, all others are
+    /// reserved again in their original window at their original VrmComponent.
+    fn abort_reschedule(
+        &mut self,
+        workflow: &mut Workflow,
+        adc: &mut ADC,
+        grid_component_res_database: &HashMap<ReservationId, ComponentId>,
+        fixed_placements: &HashMap<ReservationId, ComponentId>,
+        fixed_dependencies: Vec<(ReservationId, Reservation, Option<ComponentId>)>,
+    ) {
+        for (reservation_id, component_id) in grid_component_res_database {
+            if !fixed_placements.contains_key(reservation_id) {
+                adc.delete_task_at_component(component_id.clone(), *reservation_id, None);
+            }
+        }
+
+        let mut restored_placements: HashMap<ReservationId, ComponentId> = HashMap::new();
+        for (reservation_id, snapshot, component_id) in fixed_dependencies {
+            let Some(component_id) = component_id.filter(|component_id| !component_id.is_dummy()) else {
+                self.base.reservation_store.restore_reservation_snapshot(reservation_id, snapshot);
+                workflow.update_reservation(self.base.reservation_store.clone(), reservation_id);
+                continue;
+            };
+
+            // Pin the dependency onto its original window
+            let mut pinned_dependency = snapshot.clone();
+            pinned_dependency.set_state(ReservationState::Open);
+            pinned_dependency.set_booking_interval_start(snapshot.get_assigned_start());
+            pinned_dependency.set_booking_interval_end(snapshot.get_assigned_end());
+            self.base.reservation_store.restore_reservation_snapshot(reservation_id, pinned_dependency);
+
+            let is_restored = adc
+                .submit_task_at_vrm_component(
+                    component_id.clone(),
+                    reservation_id,
+                    None,
+                    &mut restored_placements,
+                    ProbeReservationComparator::EFTReservationCompare,
+                )
+                .is_some()
+                && (snapshot.get_state() != ReservationState::Committed || adc.manager.commit_at_component(reservation_id, component_id.clone()));
+
+            if is_restored {
+                self.base.reservation_store.set_booking_interval_start(reservation_id, snapshot.get_booking_interval_start());
+                self.base.reservation_store.set_booking_interval_end(reservation_id, snapshot.get_booking_interval_end());
+            } else {
+                log::warn!(
+                    "HEFTSyncRescheduleRestoreDependencyFailed: Dependency {:?} of workflow {} could not be restored at {} in [{}, {}].",
+                    self.base.reservation_store.get_name_for_key(reservation_id),
+                    workflow.base.get_name(),
+                    component_id,
+                    snapshot.get_assigned_start(),
+                    snapshot.get_assigned_end()
+                );
+            }
+            workflow.update_reservation(self.base.reservation_store.clone(), reservation_id);
+        }
+        workflow.set_state(ReservationState::Rejected);
+    }
+
+    /// Creates a new HEFTSync scheduler, which handles failed placements of intermediate CoAllocations
+    /// according to the provided `PlacementFailurePolicy`.
+    pub fn with_placement_failure_policy(
+        reservation_store: ReservationStore,
+        placement_failure_policy: PlacementFailurePolicy,
+    ) -> Box<dyn WorkflowScheduler> {
+        let mut base = WorkflowSchedulerBase::new(reservation_store);
+        base.placement_failure_policy = placement_failure_policy;
+        Box::new(Self { base })
+    }
+
+    /// Applies the `PlacementFailurePolicy` after the placement of the CoAllocation of `workflow_node` failed.
+    ///
+    /// A failed placement of an exit CoAllocation is always fatal. For intermediate CoAllocations
+    /// the partial placement is released and the placement is retried, if the policy allows it.
+    ///
+    /// # Returns
+    /// `true` if a retry placed the CoAllocation successfully, otherwise `false`.
+    pub(super) fn retry_co_allocation_placement(
+        &mut self,
+        workflow: &mut Workflow,
+        workflow_node: &mut WorkflowNode,
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+        adc: &mut ADC,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+    ) -> bool {
+        let co_allocation_key = workflow_node.co_allocation_key.clone().unwrap();
+
+        let is_exit_co_allocation = workflow.exit_co_allocation.contains(&co_allocation_key);
+        let max_retries = self.base.placement_failure_policy.get_max_retries(is_exit_co_allocation);
+
+        if is_exit_co_allocation {
+            log::debug!(
+                "HEFTSyncExitCoAllocationPlacementFailed: Placement of exit CoAllocation {} of workflow {} failed. Workflow is rejected.",
+                co_allocation_key,
+                workflow.base.get_name()
+            );
+        }
+
+        for attempt in 1..=max_retries {
+            log::debug!(
+                "HEFTSyncRetryCoAllocationPlacement: Retry {}/{} to place intermediate CoAllocation {} of workflow {}.",
+                attempt,
+                max_retries,
+                co_allocation_key,
+                workflow.base.get_name()
+            );
+
+            self.release_co_allocation_placement(workflow, &co_allocation_key, grid_component_res_database, adc, shadow_schedule_id.clone());
+
+            if self.schedule_co_allocation_node_reservations(workflow, workflow_node, grid_component_res_database, adc, shadow_schedule_id.clone()) {
                 return true;
             }
         }
         return false;
     }
 
-    fn probe(&mut self, _workflow_res_id: ReservationId, _adc: &mut ADC) -> Reservations {
-        todo!("Not implemented yet!")
+    /// Deletes all already placed member nodes and sync dependencies of the CoAllocation and
+    /// resets them to `ReservationState::Open`, such that the CoAllocation can be placed again.
+    ///
+    /// The booking intervals of the member nodes are reset to the earliest start of the CoAllocation and the end of
+    /// the workflow, because a failed reserve leaves the (narrowed) booking interval of the last candidate behind.
+    fn release_co_allocation_placement(
+        &mut self,
+        workflow: &Workflow,
+        co_allocation_key: &CoAllocationId,
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+        adc: &mut ADC,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+    ) {
+        let co_allocation = workflow.co_allocations.get(co_allocation_key).unwrap();
+
+        let mut reservation_ids: Vec<ReservationId> =
+            co_allocation.members.iter().map(|member_id| workflow.nodes.get(member_id).unwrap().reservation_id).collect();
+        reservation_ids.extend(co_allocation.sync_dependencies.iter().map(|sync_dep| sync_dep.reservation_id));
+
+        for reservation_id in reservation_ids {
+            if let Some(component_id) = grid_component_res_database.remove(&reservation_id) {
+                adc.delete_task_at_component(component_id, reservation_id, shadow_schedule_id.clone());
+            }
+            self.base.reservation_store.reopen(reservation_id);
+        }
+
+        let start = self.get_earliest_start(workflow, co_allocation_key, adc.manager.get_average_link_speed() as i64);
+        for member_id in &co_allocation.members {
+            let member_res_id = workflow.nodes.get(member_id).unwrap().reservation_id;
+            self.base.reservation_store.set_booking_interval_start(member_res_id, start);
+            self.base.reservation_store.set_booking_interval_end(member_res_id, workflow.get_booking_interval_end());
+        }
     }
-}
 
-impl HEFTSyncWorkflowScheduler {
     /**
      * Schedule and try to reserve all data dependencies (e.g. file transfers) to
      * all {@link NodeReservation}s co-allocated with the given reservation. All
@@ -160,21 +942,45 @@ impl HEFTSyncWorkflowScheduler {
      * @param aisPerReservation A container for all successful reservations for this workflow
      */
     /// Safely schedules data dependencies by handling missing mappings in the component database.
-
-    fn schedule_data_dependencies(
+    ///
+    /// # Returns
+    /// `Err` with the reservation of the first data dependency, which could not be reserved.
+    pub(super) fn schedule_data_dependencies(
         &mut self,
         workflow: &mut Workflow,
         workflow_node: &mut WorkflowNode,
         grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
         adc: &mut ADC,
-    ) -> bool {
+        shadow_schedule_id: Option<ShadowScheduleId>,
+    ) -> Result<(), ReservationId> {
+        return self.schedule_incoming_data_dependencies(workflow, workflow_node, grid_component_res_database, adc, shadow_schedule_id, |_| true);
+    }
+
+    /// Schedules all incoming data dependencies of the CoAllocation of `workflow_node`, which are accepted by `filter`.
+    fn schedule_incoming_data_dependencies<F>(
+        &mut self,
+        workflow: &mut Workflow,
+        workflow_node: &WorkflowNode,
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+        adc: &mut ADC,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+        filter: F,
+    ) -> Result<(), ReservationId>
+    where
+        F: Fn(ReservationId) -> bool,
+    {
         let incoming_data_dep = workflow
             .co_allocations
             .get(&workflow_node.co_allocation_key.clone().unwrap())
             .map(|co_allocation| co_allocation.incoming_data_dependencies.clone())
             .unwrap_or_default();
 
-        for data_dep in incoming_data_dep {
+        for data_dep in incoming_data_dep.into_iter().filter(|data_dep| filter(data_dep.reservation_id)) {
+            // All consumers of an output port share its reservation, which is only placed for the first scheduled consumer
+            if grid_component_res_database.contains_key(&data_dep.reservation_id) {
+                continue;
+            }
+
             let source_node_id = data_dep.source_node.clone().unwrap();
             let target_node_id = data_dep.target_node.clone().unwrap();
             let source_res_id = workflow.nodes.get(&source_node_id).unwrap().reservation_id;
@@ -195,8 +1001,9 @@ impl HEFTSyncWorkflowScheduler {
                         target_component_id.clone(),
                         grid_component_res_database,
                         adc,
+                        shadow_schedule_id.clone(),
                     ) {
-                        return false;
+                        return Err(data_dep.reservation_id);
                     }
                 } else {
                     log::error!(
@@ -213,28 +1020,68 @@ impl HEFTSyncWorkflowScheduler {
                 )
             }
         }
-        return true;
+        return Ok(());
     }
 
     /// Manages co-allocation groups while ensuring that failed sub-reservations do not leave
     /// the scheduler in an inconsistent state.
-    fn schedule_co_allocation_node_reservations(
+    pub(super) fn schedule_co_allocation_node_reservations(
         &mut self,
         workflow: &mut Workflow,
         node_to_schedule: &mut WorkflowNode,
         grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
         adc: &mut ADC,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+    ) -> bool {
+        return self.schedule_co_allocation_node_reservations_at(
+            workflow,
+            node_to_schedule,
+            grid_component_res_database,
+            adc,
+            shadow_schedule_id,
+            None,
+        );
+    }
+
+    /// Like `schedule_co_allocation_node_reservations`, but the first task of the CoAllocation is only placed on
+    /// `pinned_component_id`, if provided (instead of the VrmComponent with the earliest finish time).
+    /// The remaining members are placed as usual.
+    ///
+    /// # Returns
+    /// `true` if the CoAllocation and its sync dependencies were reserved, otherwise `false`.
+    pub(super) fn schedule_co_allocation_node_reservations_at(
+        &mut self,
+        workflow: &mut Workflow,
+        node_to_schedule: &mut WorkflowNode,
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+        adc: &mut ADC,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+        pinned_component_id: Option<ComponentId>,
     ) -> bool {
         let co_allocation_to_schedule = node_to_schedule.co_allocation_key.clone().unwrap();
         let co_allocation_nodes_to_schedule = workflow.co_allocations.get(&co_allocation_to_schedule).unwrap().members.clone();
 
         let reservation_id_to_schedule = node_to_schedule.reservation_id;
 
-        let mut first_task_candidate = self.schedule_node_reservation_eft(workflow, reservation_id_to_schedule, grid_component_res_database, adc);
+        let mut first_task_candidate = self.schedule_node_reservation_eft(
+            workflow,
+            reservation_id_to_schedule,
+            grid_component_res_database,
+            adc,
+            shadow_schedule_id.clone(),
+            pinned_component_id.clone(),
+        );
 
         if first_task_candidate.is_none() {
-            self.get_reservation_store().update_state(reservation_id_to_schedule, ReservationState::Open);
-            first_task_candidate = self.schedule_node_reservation_eft(workflow, reservation_id_to_schedule, grid_component_res_database, adc);
+            self.get_reservation_store().reopen(reservation_id_to_schedule);
+            first_task_candidate = self.schedule_node_reservation_eft(
+                workflow,
+                reservation_id_to_schedule,
+                grid_component_res_database,
+                adc,
+                shadow_schedule_id.clone(),
+                pinned_component_id,
+            );
         }
         // Failure
         if first_task_candidate.is_none()
@@ -254,42 +1101,177 @@ impl HEFTSyncWorkflowScheduler {
 
         // All nodes which are connected by Sync dependencies
         // Update all group members of Co-Allocation Node
-        for co_allocation_node_id in co_allocation_nodes_to_schedule.clone() {
-            let member_id = workflow.nodes.get(&co_allocation_node_id).unwrap().reservation_id;
-
-            if member_id == first_task_candidate {
+        let member_ids: Vec<ReservationId> = co_allocation_nodes_to_schedule
+            .iter()
+            .map(|co_allocation_node_id| workflow.nodes.get(co_allocation_node_id).unwrap().reservation_id)
+            .filter(|member_id| *member_id != first_task_candidate)
+            .collect();
+
+        let Some(co_allocation_members) = self.apply_co_allocation_window(&member_ids, start, end, duration, workflow.get_booking_interval_end())
+        else {
+            return false;
+        };
+
+        // Each member is placed separately, only members which must share a VrmComponent are reserved together
+        let first_task_component_id = grid_component_res_database.get(&first_task_candidate).cloned();
+        for group in self.get_shared_component_groups(workflow, &co_allocation_to_schedule, adc) {
+            let group_members: Vec<(ReservationId, (i64, i64))> =
+                co_allocation_members.iter().filter(|(member_id, _)| group.contains(member_id)).cloned().collect();
+            if group_members.is_empty() {
                 continue;
             }
 
-            self.base.reservation_store.set_booking_interval_start(member_id, start);
-            self.base.reservation_store.set_booking_interval_end(member_id, end);
-            self.base.reservation_store.adjust_capacity(member_id, duration);
-
-            // Try to reserve this task
-            let co_allocation_candidate_id = adc.submit_task_at_first_grid_component(member_id, None, grid_component_res_database);
-
-            if !self.base.reservation_store.is_reservation_state_at_least(co_allocation_candidate_id, ReservationState::ReserveAnswer) {
-                log::debug!(
-                    "WorkflowSchedulerScheduleCoAllocationNodeFailed: reservation: {:?}, booking_interval_start {:?}, booking_interval_end: {:?}, reserved_capacity {:?}",
-                    self.get_reservation_store().get_name_for_key(member_id),
-                    self.get_reservation_store().get_booking_interval_start(member_id),
-                    self.get_reservation_store().get_booking_interval_end(member_id),
-                    self.get_reservation_store().get_reserved_capacity(member_id)
-                );
+            let is_reserved = match &first_task_component_id {
+                Some(component_id) if group.contains(&first_task_candidate) => adc.submit_co_allocation_at_vrm_component(
+                    component_id.clone(),
+                    &group_members,
+                    shadow_schedule_id.clone(),
+                    grid_component_res_database,
+                ),
+                _ if group_members.len() == 1 => {
+                    let candidate_id =
+                        adc.submit_task_at_first_grid_component(group_members[0].0, shadow_schedule_id.clone(), grid_component_res_database);
+                    self.base.reservation_store.is_reservation_state_at_least(candidate_id, ReservationState::ReserveAnswer)
+                }
+                _ => adc.submit_co_allocation_at_first_grid_component(&group_members, shadow_schedule_id.clone(), grid_component_res_database),
+            };
+
+            if !is_reserved {
+                for (member_id, _) in &group_members {
+                    log::debug!(
+                        "WorkflowSchedulerScheduleCoAllocationNodeFailed: reservation: {:?}, booking_interval_start {:?}, booking_interval_end: {:?}, reserved_capacity {:?}",
+                        self.get_reservation_store().get_name_for_key(*member_id),
+                        self.get_reservation_store().get_booking_interval_start(*member_id),
+                        self.get_reservation_store().get_booking_interval_end(*member_id),
+                        self.get_reservation_store().get_reserved_capacity(*member_id)
+                    );
+                }
                 return false;
             }
-            workflow.update_reservation(self.base.reservation_store.clone(), co_allocation_candidate_id);
+
+            for (member_id, _) in group_members {
+                workflow.update_reservation(self.base.reservation_store.clone(), member_id);
+            }
         }
 
         // Reserve all Sync dependencies between the NodeReservations
         for co_allocation_node_id in co_allocation_nodes_to_schedule {
-            if !self.schedule_sync_dependencies(workflow, co_allocation_node_id, grid_component_res_database, adc) {
+            if !self.schedule_sync_dependencies(workflow, co_allocation_node_id, grid_component_res_database, adc, shadow_schedule_id.clone()) {
                 return false;
             }
         }
         return true;
     }
 
+    /// Partitions the members of a CoAllocation into groups, which must be placed on the same VrmComponent.
+    ///
+    /// Two members must share a VrmComponent, if they are connected by a sync dependency with a bandwidth, but the
+    /// ADC has no link resources to route this dependency between two VrmComponents. All other members form a group
+    /// on their own and are placed separately.
+    ///
+    /// # Returns
+    /// The reservations of the members grouped by the VrmComponent they must share.
+    fn get_shared_component_groups(&self, workflow: &Workflow, co_allocation_key: &CoAllocationId, adc: &ADC) -> Vec<Vec<ReservationId>> {
+        let co_allocation = workflow.co_allocations.get(co_allocation_key).unwrap();
+        let member_res_ids: Vec<ReservationId> =
+            co_allocation.members.iter().map(|member_id| workflow.nodes.get(member_id).unwrap().reservation_id).collect();
+
+        let mut dsu = QuickUnionUf::<UnionBySize>::new(member_res_ids.len());
+        if adc.manager.get_link_resource_count() == 0 {
+            for sync_dep in co_allocation.sync_dependencies.iter().filter(|sync_dep| sync_dep.bandwidth > 0) {
+                let member_index = |node_id: &Option<WorkflowNodeId>| {
+                    let reservation_id = workflow.nodes.get(node_id.as_ref()?)?.reservation_id;
+                    member_res_ids.iter().position(|member_res_id| *member_res_id == reservation_id)
+                };
+
+                if let (Some(source_index), Some(target_index)) = (member_index(&sync_dep.source_node), member_index(&sync_dep.target_node)) {
+                    dsu.union(source_index, target_index);
+                }
+            }
+        }
+
+        let mut groups: Vec<Vec<ReservationId>> = Vec::new();
+        let mut group_of_root: HashMap<usize, usize> = HashMap::new();
+        for (index, member_res_id) in member_res_ids.iter().enumerate() {
+            let group_index = *group_of_root.entry(dsu.find(index)).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group_index].push(*member_res_id);
+        }
+        return groups;
+    }
+
+    /// Applies the window `[start, end]` of the first task of a CoAllocation to all other members of the CoAllocation.
+    ///
+    /// A member, whose task duration exceeds `end - start` (e.g. a longer non-moldable task), cannot be placed in
+    /// this window. In this case the window of the whole CoAllocation is widened to the longest member duration.
+    ///
+    /// # Arguments
+    /// * `member_ids` - Reservations of the remaining members of the CoAllocation.
+    /// * `start` - Assigned start of the first task, all members start together.
+    /// * `end` - Assigned end of the first task.
+    /// * `capacity` - Capacity, which is applied to all (moldable) members.
+    /// * `booking_interval_end` - Latest end of the workflow, which the widened window must not exceed.
+    ///
+    /// # Returns
+    /// The members together with their placement windows, or `None` if a member was not found or the widened
+    /// window exceeds `booking_interval_end`.
+    pub fn apply_co_allocation_window(
+        &self,
+        member_ids: &[ReservationId],
+        start: i64,
+        end: i64,
+        capacity: i64,
+        booking_interval_end: i64,
+    ) -> Option<Vec<(ReservationId, (i64, i64))>> {
+        let mut member_durations: Vec<(ReservationId, i64)> = Vec::new();
+        for member_id in member_ids {
+            let Some(member_duration) = self.base.reservation_store.with_reservation_mut(*member_id, |member| {
+                member.adjust_capacity(capacity);
+                member.get_task_duration()
+            }) else {
+                log::error!("Get reservation (id: {:?}) was not possible.", member_id);
+                return None;
+            };
+            member_durations.push((*member_id, member_duration));
+        }
+
+        let max_member_duration = member_durations.iter().map(|(_, member_duration)| *member_duration).max().unwrap_or(0);
+        let window_end = end.max(start + max_member_duration);
+
+        if window_end > booking_interval_end {
+            log::debug!(
+                "WorkflowSchedulerCoAllocationWindowExceeded: The longest member ({} s) of the CoAllocation starting at {} ends at {} after the booking interval end {}.",
+                max_member_duration,
+                start,
+                window_end,
+                booking_interval_end
+            );
+            return None;
+        }
+
+        if window_end > end {
+            log::debug!(
+                "WorkflowSchedulerCoAllocationWindowWidened: Window of the CoAllocation [{}, {}] was widened to [{}, {}].",
+                start,
+                end,
+                start,
+                window_end
+            );
+        }
+
+        let mut co_allocation_members: Vec<(ReservationId, (i64, i64))> = Vec::new();
+        for (member_id, member_duration) in member_durations {
+            self.base.reservation_store.with_reservation_mut(member_id, |member| {
+                member.set_booking_interval_start(start);
+                member.set_booking_interval_end(window_end);
+            });
+            co_allocation_members.push((member_id, (start, start + member_duration)));
+        }
+        return Some(co_allocation_members);
+    }
+
     /**
      * Schedule and reserve a network link for the given dependency.
      *
@@ -313,6 +1295,7 @@ impl HEFTSyncWorkflowScheduler {
         target_component_id: ComponentId,
         grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
         adc: &mut ADC,
+        shadow_schedule_id: Option<ShadowScheduleId>,
     ) -> bool {
         if self.base.reservation_store.is_link(dependency_reservation_id) {
             let mut end = end;
@@ -341,6 +1324,7 @@ impl HEFTSyncWorkflowScheduler {
                 target_component_id,
                 grid_component_res_database,
                 adc,
+                shadow_schedule_id,
             );
         } else {
             log::error!(
@@ -358,6 +1342,7 @@ impl HEFTSyncWorkflowScheduler {
         target_node_id: WorkflowNodeId,
         grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
         adc: &mut ADC,
+        shadow_schedule_id: Option<ShadowScheduleId>,
     ) -> bool {
         let target_node = workflow.nodes.get(&target_node_id).unwrap();
         let target_res_id = target_node.reservation_id;
@@ -382,6 +1367,7 @@ impl HEFTSyncWorkflowScheduler {
                         target_component_id.clone(),
                         grid_component_res_database,
                         adc,
+                        shadow_schedule_id.clone(),
                     ) {
                         return false;
                     }
@@ -416,14 +1402,67 @@ impl HEFTSyncWorkflowScheduler {
         reservation_id: ReservationId,
         grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
         adc: &mut ADC,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+        pinned_component_id: Option<ComponentId>,
     ) -> Option<ReservationId> {
-        // Request all GirdComponents for reservation candidates and sort them according to EFT (earliest finishing time)
+        // A pinned task is only placed on its VrmComponent, hence no placement penalty is needed
+        if let Some(pinned_component_id) = pinned_component_id {
+            let candidate_id = adc.submit_task_at_vrm_component(
+                pinned_component_id,
+                reservation_id,
+                shadow_schedule_id,
+                grid_component_res_database,
+                ProbeReservationComparator::EFTReservationCompare,
+            );
+
+            if candidate_id
+                .is_some_and(|candidate_id| self.base.reservation_store.is_reservation_state_at_least(candidate_id, ReservationState::ReserveAnswer))
+            {
+                workflow.update_reservation(self.base.reservation_store.clone(), candidate_id.unwrap());
+                return candidate_id;
+            }
+            return None;
+        }
+
+        // Soft synchronized tasks, which are already placed, penalize all other GridComponents
+        let placed_soft_sync_partners = self.get_placed_soft_sync_partners(workflow, reservation_id, grid_component_res_database);
 
+        // If locality zones are configured, the transfer time of the incoming data from already placed predecessors
+        // is added, so that data dependent tasks are preferably kept within one zone
+        let zone_communication_cost = adc.manager.get_zone_communication_cost();
+        let placed_data_predecessors = if zone_communication_cost.has_zones() {
+            self.get_placed_data_predecessors(workflow, reservation_id, grid_component_res_database)
+        } else {
+            Vec::new()
+        };
+
+        let placement_penalty = |component_id: &ComponentId| -> i64 {
+            let soft_sync_penalty: i64 = placed_soft_sync_partners
+                .iter()
+                .filter(|(partner_component_id, _)| partner_component_id != component_id)
+                .map(|(_, penalty)| penalty)
+                .sum();
+            let communication_penalty: i64 = placed_data_predecessors
+                .iter()
+                .map(|(source_component_id, data_dependency)| {
+                    zone_communication_cost.get_communication_time(data_dependency, source_component_id, component_id)
+                })
+                .sum();
+            soft_sync_penalty + communication_penalty
+        };
+
+        // Moldable tasks may finish earlier, if they are widened
+        let original_capacity = self.base.reservation_store.get_reserved_capacity(reservation_id);
+        let original_duration = self.base.reservation_store.get_task_duration(reservation_id);
+        let moldable_shape = self.select_moldable_shape(reservation_id, adc, shadow_schedule_id.clone());
+
+        // Request all GirdComponents for reservation candidates and sort them according to EFT (earliest finishing time)
         let candidate_id = adc.submit_task_at_best_vrm_component(
             reservation_id,
-            None,
+            shadow_schedule_id,
             grid_component_res_database,
             ProbeReservationComparator::EFTReservationCompare,
+            placement_penalty,
         );
 
         if !candidate_id.is_none()
@@ -432,17 +1471,139 @@ impl HEFTSyncWorkflowScheduler {
             workflow.update_reservation(self.base.reservation_store.clone(), candidate_id.unwrap());
             return candidate_id;
         }
+
+        // The reshaped task could not be placed, hence the requested shape is restored for later retries
+        if moldable_shape.is_some() {
+            self.set_node_reservation_shape(reservation_id, original_capacity, original_duration);
+        }
         return None;
     }
 
+    /// Reshapes a moldable NodeReservation to the capacity configuration with the earliest finish time.
+    ///
+    /// The reserved capacity is widened by each factor of `MOLDABLE_CAPACITY_FACTORS`, while the task duration is
+    /// derived from the moldable work (`reserved_capacity * task_duration`). Each shape is probed on all VrmComponents
+    /// (without placement penalties) and the shape with the earliest finish time is kept in the store, on equal finish
+    /// times the smaller capacity is kept. Non-moldable reservations are not changed.
+    ///
+    /// # Returns
+    /// The selected shape (reserved capacity, task duration), or `None` if the reservation is not moldable or
+    /// no shape could be placed (the original shape is kept).
+    pub fn select_moldable_shape(
+        &self,
+        reservation_id: ReservationId,
+        adc: &mut ADC,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+    ) -> Option<(i64, i64)> {
+        if !self.base.reservation_store.is_moldable(reservation_id) || !self.base.reservation_store.is_node(reservation_id) {
+            return None;
+        }
+
+        let moldable_work = self.base.reservation_store.get_moldable_work(reservation_id);
+        let original_capacity = self.base.reservation_store.get_reserved_capacity(reservation_id).max(1);
+        let original_duration = self.base.reservation_store.get_task_duration(reservation_id);
+
+        // (finish time, reserved capacity, task duration)
+        let mut best: Option<(i64, i64, i64)> = None;
+        for factor in MOLDABLE_CAPACITY_FACTORS {
+            let capacity = original_capacity * factor;
+            // The task duration can not be shortened below one second
+            if factor > 1 && capacity > moldable_work {
+                break;
+            }
+            let duration = if factor == 1 { original_duration } else { (moldable_work + capacity - 1) / capacity };
+
+            self.set_node_reservation_shape(reservation_id, capacity, duration);
+            let Some(finish_time) = adc.manager.probe_all_vrm_components(reservation_id, shadow_schedule_id.clone()).best_finish_time() else {
+                continue;
+            };
+
+            if best.is_none_or(|(best_finish_time, _, _)| finish_time < best_finish_time) {
+                best = Some((finish_time, capacity, duration));
+            }
+        }
+
+        let (capacity, duration) = best.map(|(_, capacity, duration)| (capacity, duration)).unwrap_or((original_capacity, original_duration));
+        self.set_node_reservation_shape(reservation_id, capacity, duration);
+
+        if capacity != original_capacity {
+            log::debug!(
+                "HEFTSyncMoldableReshaped: Reservation {:?} was reshaped from {} x {} s to {} x {} s.",
+                self.base.reservation_store.get_name_for_key(reservation_id),
+                original_capacity,
+                original_duration,
+                capacity,
+                duration
+            );
+        }
+        return best.map(|(_, capacity, duration)| (capacity, duration));
+    }
+
+    fn set_node_reservation_shape(&self, reservation_id: ReservationId, capacity: i64, duration: i64) {
+        self.base.reservation_store.with_reservation_mut(reservation_id, |reservation| {
+            reservation.set_reserved_capacity(capacity);
+            reservation.set_task_duration(duration);
+        });
+    }
+
+    /// Returns the GridComponents and separation penalties of all already placed soft sync partners of the given task.
+    fn get_placed_soft_sync_partners(
+        &self,
+        workflow: &Workflow,
+        reservation_id: ReservationId,
+        grid_component_res_database: &HashMap<ReservationId, ComponentId>,
+    ) -> Vec<(ComponentId, i64)> {
+        let node_id = match workflow.nodes.iter().find(|(_, node)| node.reservation_id == reservation_id) {
+            Some((node_id, _)) => node_id.clone(),
+            None => return Vec::new(),
+        };
+
+        return workflow
+            .get_soft_sync_partners(&node_id)
+            .into_iter()
+            .filter_map(|(partner_id, penalty)| {
+                let partner_res_id = workflow.nodes.get(&partner_id)?.reservation_id;
+                grid_component_res_database.get(&partner_res_id).map(|component_id| (component_id.clone(), penalty))
+            })
+            .collect();
+    }
+
+    /// Returns the GridComponents and DataDependencies of all already placed predecessors of the given task.
+    fn get_placed_data_predecessors(
+        &self,
+        workflow: &Workflow,
+        reservation_id: ReservationId,
+        grid_component_res_database: &HashMap<ReservationId, ComponentId>,
+    ) -> Vec<(ComponentId, DataDependency)> {
+        let node = match workflow.nodes.values().find(|node| node.reservation_id == reservation_id) {
+            Some(node) => node,
+            None => return Vec::new(),
+        };
+
+        return node
+            .incoming_data
+            .iter()
+            .filter_map(|data_dependency_id| {
+                let data_dependency = workflow.data_dependencies.get(data_dependency_id)?;
+                let source_res_id = workflow.nodes.get(data_dependency.source_node.as_ref()?)?.reservation_id;
+                grid_component_res_database.get(&source_res_id).map(|component_id| (component_id.clone(), data_dependency.clone()))
+            })
+            .collect();
+    }
+
     /**
      * Cancels all reservations of a workflow already done.
      *
      * @param aisPerReservation a container with all reservations to cancel and the AIs where they are booked.
      */
-    pub fn cancel_all_reservations(&mut self, adc: &mut ADC, grid_component_res_database: &mut HashMap<ReservationId, ComponentId>) {
+    pub fn cancel_all_reservations(
+        &mut self,
+        adc: &mut ADC,
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+    ) {
         for (reservation_id, component_id) in grid_component_res_database.clone() {
-            adc.delete_task_at_component(component_id.clone(), reservation_id.clone(), None)
+            adc.delete_task_at_component(component_id.clone(), reservation_id.clone(), shadow_schedule_id.clone())
         }
         grid_component_res_database.clear();
     }
@@ -463,14 +1624,12 @@ impl HEFTSyncWorkflowScheduler {
         self.base.reservation_store.set_reserved_capacity(dependency_reservation_id, 0);
         self.base.reservation_store.set_task_duration(dependency_reservation_id, end - start);
 
-        if let Some(res_arc) = self.base.reservation_store.get(dependency_reservation_id) {
-            let mut guard = res_arc.write().expect("Lock poisoned");
-
-            if let Some(link) = guard.as_link_mut() {
+        self.base.reservation_store.with_reservation_mut(dependency_reservation_id, |reservation| {
+            if let Some(link) = reservation.as_link_mut() {
                 link.start_point = Some(RouterId::new("localhost"));
                 link.end_point = Some(RouterId::new("localhost"));
             }
-        }
+        });
 
         // aisPerReservation.put(dependency.assignedReservation,ADC.INTERNAL_JOB);
         workflow.update_reservation(self.base.reservation_store.clone(), dependency_reservation_id);
@@ -500,9 +1659,10 @@ impl HEFTSyncWorkflowScheduler {
         target_component_id: ComponentId,
         grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
         adc: &mut ADC,
+        shadow_schedule_id: Option<ShadowScheduleId>,
     ) -> bool {
         // Init dependency Reservation
-        self.base.reservation_store.update_state(dependency_reservation_id, ReservationState::Open);
+        self.base.reservation_store.reopen(dependency_reservation_id);
         self.base.reservation_store.set_booking_interval_start(dependency_reservation_id, start);
         self.base.reservation_store.set_booking_interval_end(dependency_reservation_id, end);
 
@@ -513,27 +1673,43 @@ impl HEFTSyncWorkflowScheduler {
             self.base.reservation_store.set_task_duration(dependency_reservation_id, end - start);
         }
 
+        // The transfer must fit into the window of the dependency
+        let transfer_duration = adc
+            .manager
+            .get_transfer_duration(
+                self.base.reservation_store.get_moldable_work(dependency_reservation_id),
+                adc.manager.get_average_link_speed() as i64,
+                &source_component_id,
+                &target_component_id,
+            )
+            .min((end - start).max(1));
+
         let source_component_router_id_list = adc.manager.get_component_router_list(source_component_id.clone());
         let target_component_router_id_list = adc.manager.get_component_router_list(target_component_id.clone());
 
         for source_router_id in &source_component_router_id_list {
             for target_router_id in &target_component_router_id_list {
-                if let Some(res_arc) = self.base.reservation_store.get(dependency_reservation_id) {
-                    let mut guard = res_arc.write().expect("Lock poisoned");
-
-                    if let Some(link) = guard.as_link_mut() {
+                self.base.reservation_store.with_reservation_mut(dependency_reservation_id, |reservation| {
+                    if let Some(link) = reservation.as_link_mut() {
                         link.start_point = Some(source_router_id.clone());
                         link.end_point = Some(target_router_id.clone());
                     }
+                });
+
+                if self.base.validate_links
+                    && !self.validate_link_end_points(dependency_reservation_id, &source_component_id, &target_component_id, adc)
+                {
+                    return false;
                 }
 
-                // If data transfer reset parameter and transfer all in a single time slot
+                // If data transfer reset parameter and transfer in whole slots of the coarser schedule of both VrmComponents
                 if is_filetransfer {
-                    self.base.reservation_store.adjust_task_duration(dependency_reservation_id, 1);
+                    self.base.reservation_store.adjust_task_duration(dependency_reservation_id, transfer_duration);
                 }
 
                 // Reserve transfer task, these tasks are moldable, because the GridComponent may change duration + bandwidth
-                let candidate_id = adc.submit_task_at_first_grid_component(dependency_reservation_id, None, grid_component_res_database);
+                let candidate_id =
+                    adc.submit_task_at_first_grid_component(dependency_reservation_id, shadow_schedule_id.clone(), grid_component_res_database);
 
                 if self.base.reservation_store.is_reservation_state_at_least(candidate_id, ReservationState::ReserveAnswer) {
                     workflow.update_reservation(self.base.reservation_store.clone(), candidate_id);
@@ -541,6 +1717,19 @@ impl HEFTSyncWorkflowScheduler {
                 }
             }
         }
+
+        let dependency = workflow.dependency_for_reservation(dependency_reservation_id);
+        log::debug!(
+            "HEFTSyncScheduleDependencyFailed: Dependency {:?} of workflow {} from node {:?} on {} to node {:?} on {} could not be reserved in [{}, {}].",
+            self.base.reservation_store.get_name_for_key(dependency_reservation_id),
+            workflow.base.get_name(),
+            dependency.and_then(|dependency| dependency.source_node()),
+            source_component_id,
+            dependency.and_then(|dependency| dependency.target_node()),
+            target_component_id,
+            start,
+            end
+        );
         return false;
     }
 }
@@ -0,0 +1,219 @@
+use crate::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
+use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::heft_sync_workflow_scheduler::HEFTSyncWorkflowScheduler;
+use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::{
+    RejectionReason, WorkflowScheduler, WorkflowSchedulerBase,
+};
+use crate::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState, ReservationTrait};
+use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use crate::domain::vrm_system_model::reservation::reservations::Reservations;
+use crate::domain::vrm_system_model::utils::id::{CoAllocationId, ComponentId};
+use crate::domain::vrm_system_model::workflow::workflow::Workflow;
+use crate::domain::vrm_system_model::workflow::workflow_node::WorkflowNode;
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+
+/// A throughput-oriented workflow scheduler implementing the **Min-Min** heuristic.
+///
+/// In contrast to HEFTSync, the CoAllocations are not placed in the order of their upward rank.
+/// At each step all ready CoAllocations (all data predecessors are placed) are probed at all VrmComponents,
+/// the minimum completion time of each CoAllocation is determined and the CoAllocation with the globally
+/// smallest minimum completion time is placed next.
+///
+/// The placement of the CoAllocations and their dependencies reuses the primitives of the
+/// [`HEFTSyncWorkflowScheduler`], which also provides the [`WorkflowSchedulerBase`].
+#[derive(Debug)]
+pub struct MinMinWorkflowScheduler {
+    pub heft_sync: HEFTSyncWorkflowScheduler,
+}
+
+impl WorkflowScheduler for MinMinWorkflowScheduler {
+    fn new(reservation_store: ReservationStore) -> Box<dyn WorkflowScheduler> {
+        Box::new(Self { heft_sync: HEFTSyncWorkflowScheduler { base: WorkflowSchedulerBase::new(reservation_store) } })
+    }
+
+    fn get_reservation_store(&self) -> &ReservationStore {
+        &self.heft_sync.base.reservation_store
+    }
+
+    fn name(&self) -> &str {
+        "MinMinWorkflowScheduler"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn reserve(&mut self, workflow_res_id: ReservationId, adc: &mut ADC) -> Result<(), RejectionReason> {
+        if self.reserve_workflow(workflow_res_id, adc) {
+            return Ok(());
+        }
+        return Err(self.heft_sync.base.get_rejection_reason());
+    }
+
+    /// The probe does not depend on the placement order, hence it is delegated to the wrapped HEFTSync scheduler.
+    fn probe(&mut self, workflow_res_id: ReservationId, adc: &mut ADC) -> Reservations {
+        return self.heft_sync.probe(workflow_res_id, adc);
+    }
+}
+
+impl MinMinWorkflowScheduler {
+    /// Min-Min scheduling of the workflow on the master schedule.
+    ///
+    /// # Returns
+    /// `true` if all sub-reservations of the workflow were reserved (state becomes `ReservationState::ReserveAnswer`),
+    /// otherwise all done sub-reservations are rolled back and the workflow is rejected.
+    fn reserve_workflow(&mut self, workflow_res_id: ReservationId, adc: &mut ADC) -> bool {
+        self.heft_sync.base.last_rejection = None;
+
+        let Some(workflow_handle) = self.heft_sync.base.reservation_store.get(workflow_res_id) else {
+            return false;
+        };
+        let mut reservation = workflow_handle.write().unwrap();
+        let Reservation::Workflow(ref mut workflow) = *reservation else {
+            return false;
+        };
+
+        // Local reservation map will be later committed to global state if all reservations where successful
+        let mut grid_component_res_database: HashMap<ReservationId, ComponentId> = HashMap::new();
+
+        let average_link_speed = adc.manager.get_average_link_speed() as i64;
+        let zone_communication_cost = adc.manager.get_zone_communication_cost();
+        // The upward rank order is only used to break ties between equal completion times
+        let mut unplaced_nodes = match workflow.calculate_upward_rank_with_zones(&zone_communication_cost, &self.heft_sync.base.reservation_store) {
+            Ok(ranked_node_reservations) => ranked_node_reservations,
+            Err(error) => {
+                log::error!("MinMinRankCalculationFailed: Workflow {} is rejected: {}", workflow.base.get_name(), error);
+                workflow.set_state(ReservationState::Rejected);
+                return false;
+            }
+        };
+        let mut placed_co_allocations: HashSet<CoAllocationId> = HashSet::new();
+
+        let workflow_booking_interval_end = workflow.get_booking_interval_end();
+
+        while !unplaced_nodes.is_empty() {
+            // Probe all ready CoAllocations and select the one with the minimum completion time
+            let mut best: Option<(usize, i64, i64)> = None;
+
+            for (index, workflow_node) in unplaced_nodes.iter().enumerate() {
+                let co_allocation_key = workflow_node.co_allocation_key.clone().unwrap();
+                if !Self::is_ready(workflow, &co_allocation_key, &placed_co_allocations) {
+                    continue;
+                }
+
+                let start = self.heft_sync.get_earliest_start(workflow, &co_allocation_key, average_link_speed);
+                let task_duration = self.heft_sync.base.reservation_store.get_task_duration(workflow_node.reservation_id);
+
+                // The start of a ready CoAllocation does not change anymore, so the deadline will be missed in any case
+                if start + task_duration > workflow_booking_interval_end {
+                    log::debug!(
+                        "Deadline exceeded for node {:?} or workflow {}. Rolling back.",
+                        workflow_node.reservation_id,
+                        workflow.base.get_name()
+                    );
+                    let reason = RejectionReason::DeadlineMiss {
+                        node: workflow.node_id_for_reservation(workflow_node.reservation_id).unwrap(),
+                        projected_finish: start + task_duration,
+                        deadline: workflow_booking_interval_end,
+                    };
+                    return self.reject(
+                        workflow,
+                        workflow_node,
+                        reason,
+                        (start, workflow_booking_interval_end),
+                        &mut grid_component_res_database,
+                        adc,
+                    );
+                }
+
+                self.heft_sync.base.reservation_store.set_booking_interval_start(workflow_node.reservation_id, start);
+                self.heft_sync.base.reservation_store.set_booking_interval_end(workflow_node.reservation_id, workflow_booking_interval_end);
+
+                let completion_time = adc.manager.probe_all_vrm_components(workflow_node.reservation_id, None).best_finish_time().unwrap_or(i64::MAX);
+
+                if best.is_none_or(|(_, best_completion_time, _)| completion_time < best_completion_time) {
+                    best = Some((index, completion_time, start));
+                }
+            }
+
+            let Some((index, _, start)) = best else {
+                log::error!(
+                    "ErrorMinMinWorkflowScheduler: Workflow {} contains unplaced CoAllocations, but none of them is ready.",
+                    workflow.base.get_name()
+                );
+                self.heft_sync.cancel_all_reservations(adc, &mut grid_component_res_database, None);
+                workflow.set_state(ReservationState::Rejected);
+                return false;
+            };
+
+            let mut workflow_node = unplaced_nodes.remove(index);
+            self.heft_sync.base.reservation_store.set_booking_interval_start(workflow_node.reservation_id, start);
+            self.heft_sync.base.reservation_store.set_booking_interval_end(workflow_node.reservation_id, workflow_booking_interval_end);
+
+            // Schedule Co-Allocation nodes
+            if !self.heft_sync.schedule_co_allocation_node_reservations(workflow, &mut workflow_node, &mut grid_component_res_database, adc, None)
+                && !self.heft_sync.retry_co_allocation_placement(workflow, &mut workflow_node, &mut grid_component_res_database, adc, None)
+            {
+                return self.reject(
+                    workflow,
+                    &workflow_node,
+                    RejectionReason::NoCapacity { node: workflow.node_id_for_reservation(workflow_node.reservation_id).unwrap() },
+                    (start, workflow_booking_interval_end),
+                    &mut grid_component_res_database,
+                    adc,
+                );
+            }
+
+            // Try to get network connection form all predecessors (data dependencies)
+            if let Err(dependency) =
+                self.heft_sync.schedule_data_dependencies(workflow, &mut workflow_node, &mut grid_component_res_database, adc, None)
+            {
+                return self.reject(
+                    workflow,
+                    &workflow_node,
+                    RejectionReason::NetworkUnroutable { dependency },
+                    (start, workflow_booking_interval_end),
+                    &mut grid_component_res_database,
+                    adc,
+                );
+            }
+
+            placed_co_allocations.insert(workflow_node.co_allocation_key.clone().unwrap());
+        }
+
+        // Success: Submit done reservations into global state ADC -> VrmComponentManager
+        adc.manager.register_workflow_subtasks(workflow_res_id, &grid_component_res_database);
+        workflow.set_state(ReservationState::ReserveAnswer);
+        return true;
+    }
+
+    /// Returns `true` if all data predecessors of the CoAllocation are already placed.
+    fn is_ready(workflow: &Workflow, co_allocation_key: &CoAllocationId, placed_co_allocations: &HashSet<CoAllocationId>) -> bool {
+        let co_allocation = workflow.co_allocations.get(co_allocation_key).unwrap();
+
+        return co_allocation.incoming_data_dependencies.iter().all(|data_dependency| {
+            data_dependency
+                .source_node
+                .as_ref()
+                .and_then(|source_node_id| workflow.nodes.get(source_node_id))
+                .and_then(|source_node| source_node.co_allocation_key.as_ref())
+                .is_some_and(|source_co_allocation_key| placed_co_allocations.contains(source_co_allocation_key))
+        });
+    }
+
+    /// Records the rejection diagnostics, rolls back all done sub-reservations and rejects the workflow.
+    fn reject(
+        &mut self,
+        workflow: &mut Workflow,
+        workflow_node: &WorkflowNode,
+        reason: RejectionReason,
+        window: (i64, i64),
+        grid_component_res_database: &mut HashMap<ReservationId, ComponentId>,
+        adc: &mut ADC,
+    ) -> bool {
+        self.heft_sync.record_rejection(workflow, workflow_node, reason, window, grid_component_res_database, adc, None);
+        self.heft_sync.cancel_all_reservations(adc, grid_component_res_database, None);
+        workflow.set_state(ReservationState::Rejected);
+        return false;
+    }
+}
@@ -1,3 +1,6 @@
+pub mod admission_controller;
+pub mod cpop_workflow_scheduler;
 pub mod heft_sync_workflow_scheduler;
+pub mod min_min_workflow_scheduler;
 pub mod workflow_scheduler;
 pub mod workflow_scheduler_type;
@@ -1,5 +1,9 @@
 use crate::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
 use crate::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState};
+use crate::domain::vrm_system_model::utils::config::{
+    INTERMEDIATE_PLACEMENT_FAILURE_POLICY, PRIORITY_AWARE_NODE_ORDERING, SPLIT_OVERSIZED_NODES, VALIDATE_LINK_END_POINTS,
+};
+use crate::domain::vrm_system_model::utils::id::{CoAllocationId, ComponentId, ReservationName, WorkflowNodeId};
 use crate::domain::vrm_system_model::{
     reservation::{
         reservation_store::{ReservationId, ReservationStore},
@@ -31,9 +35,9 @@ pub trait WorkflowScheduler: std::fmt::Debug + Any + Send {
     /// * `adc` - The ADC unit responsible for the grid resources.
     ///
     /// # Returns
-    /// * `true` if the reservation was successful (state becomes `ReservationState::ReservedAnswer`).
-    /// * `false` if the reservation was rejected (state becomes `ReservationState::Rejected`)..
-    fn reserve(&mut self, workflow_res_id: ReservationId, adc: &mut ADC) -> bool;
+    /// * `Ok(())` if the reservation was successful (state becomes `ReservationState::ReservedAnswer`).
+    /// * `Err(RejectionReason)` if the reservation was rejected (state becomes `ReservationState::Rejected`).
+    fn reserve(&mut self, workflow_res_id: ReservationId, adc: &mut ADC) -> Result<(), RejectionReason>;
 
     /// Probes the system for possible reservation configurations without committing resources.
     ///
@@ -77,8 +81,138 @@ pub trait WorkflowScheduler: std::fmt::Debug + Any + Send {
     }
 }
 
+/// Defines how a [`WorkflowScheduler`] reacts if the placement of an intermediate (non-exit) CoAllocation fails.
+///
+/// **Note:** A failed placement of an exit CoAllocation is always fatal, because without its exit nodes
+/// the workflow can not produce any result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementFailurePolicy {
+    /// Rejects the whole workflow on the first failed placement.
+    Abort,
+
+    /// Releases the partial placement of the CoAllocation and tries to place it again, up to the given number of times.
+    Retry(u32),
+}
+
+impl PlacementFailurePolicy {
+    /// Returns the number of placement retries allowed for a CoAllocation.
+    /// Exit CoAllocations are never retried.
+    pub fn get_max_retries(&self, is_exit_co_allocation: bool) -> u32 {
+        if is_exit_co_allocation {
+            return 0;
+        }
+
+        match self {
+            PlacementFailurePolicy::Abort => 0,
+            PlacementFailurePolicy::Retry(max_retries) => *max_retries,
+        }
+    }
+}
+
+/// Outcome of a workflow reservation, which distinguishes a rejected workflow from a workflow,
+/// whose placement exceeded its time budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkflowReservationOutcome {
+    /// All sub-reservations were reserved (state becomes `ReservationState::ReserveAnswer`).
+    Reserved,
+
+    /// The workflow could not be placed, all done sub-reservations were rolled back.
+    Rejected,
+
+    /// The deadline of the placement was exceeded, all done sub-reservations were rolled back.
+    Timeout,
+
+    /// The placement was cancelled via its `CancellationToken`, all done sub-reservations were rolled back.
+    Cancelled,
+}
+
+/// Reason why the placement of a workflow was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RejectionReason {
+    /// The projected finish (earliest start + duration) of `node` exceeds the booking interval end of the workflow (`deadline`).
+    DeadlineMiss { node: WorkflowNodeId, projected_finish: i64, deadline: i64 },
+
+    /// No VrmComponent could provide the compute resources for the CoAllocation of `node`.
+    NoCapacity { node: WorkflowNodeId },
+
+    /// No network connection could be reserved for the data dependency with the reservation `dependency`.
+    NetworkUnroutable { dependency: ReservationId },
+
+    /// The wall-clock deadline of the placement was exceeded.
+    Timeout,
+
+    /// The placement was cancelled via its `CancellationToken`.
+    Cancelled,
+
+    /// The workflow reservation does not exist or could not be ranked, so no node was placed.
+    InvalidWorkflow,
+}
+
+/// Diagnostics of a rejected workflow placement, assembled before the partial placement is rolled back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectionDiagnostics {
+    /// Name of the rejected workflow.
+    pub workflow: ReservationName,
+
+    /// Name of the node reservation, whose placement failed.
+    pub failing_node: Option<ReservationName>,
+
+    /// CoAllocation of the failing node.
+    pub co_allocation: Option<CoAllocationId>,
+
+    pub reason: RejectionReason,
+
+    /// Time window in s (VRM time) of the failing node, in which `free_capacity` was queried.
+    pub window: (i64, i64),
+
+    /// Aggregated free capacity of all VrmComponents of the ADC in `window` at the time of the rejection.
+    pub free_capacity: i64,
+
+    /// Sub-reservations, which were already placed and have been rolled back, sorted by reservation name.
+    pub rolled_back: Vec<(ReservationName, ComponentId)>,
+}
+
 /// A base structure providing shared storage for concrete [`WorkflowScheduler`] implementations.
 #[derive(Debug)]
 pub struct WorkflowSchedulerBase {
     pub reservation_store: ReservationStore,
+
+    /// Policy applied if an intermediate CoAllocation could not be placed.
+    pub placement_failure_policy: PlacementFailurePolicy,
+
+    /// If enabled, CoAllocations with an equal upward rank are placed by their priority (highest first).
+    pub is_priority_ordering: bool,
+
+    /// If enabled, the start and end router of each reserved LinkReservation are checked against the router lists
+    /// of the source and target VrmComponent.
+    pub validate_links: bool,
+
+    /// If enabled, moldable nodes exceeding the node capacity of the largest VrmComponent are split into co-allocated
+    /// sub-nodes before the ranking, otherwise such nodes can never be placed.
+    pub split_oversized_nodes: bool,
+
+    /// Diagnostics of the last rejected workflow placement, `None` if the last placement succeeded.
+    pub last_rejection: Option<RejectionDiagnostics>,
+}
+
+impl WorkflowSchedulerBase {
+    pub fn new(reservation_store: ReservationStore) -> Self {
+        WorkflowSchedulerBase {
+            reservation_store,
+            placement_failure_policy: INTERMEDIATE_PLACEMENT_FAILURE_POLICY,
+            is_priority_ordering: PRIORITY_AWARE_NODE_ORDERING,
+            validate_links: VALIDATE_LINK_END_POINTS,
+            split_oversized_nodes: SPLIT_OVERSIZED_NODES,
+            last_rejection: None,
+        }
+    }
+
+    /// Returns the reason of the last rejected workflow placement.
+    ///
+    /// # Returns
+    /// The reason of `last_rejection`, or `RejectionReason::InvalidWorkflow` if the workflow was rejected
+    /// before any node was placed (no diagnostics are recorded in this case).
+    pub fn get_rejection_reason(&self) -> RejectionReason {
+        return self.last_rejection.as_ref().map(|diagnostics| diagnostics.reason.clone()).unwrap_or(RejectionReason::InvalidWorkflow);
+    }
 }
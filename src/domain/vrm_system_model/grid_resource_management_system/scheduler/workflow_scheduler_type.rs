@@ -1,4 +1,6 @@
+use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::cpop_workflow_scheduler::CpopWorkflowScheduler;
 use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::heft_sync_workflow_scheduler::HEFTSyncWorkflowScheduler;
+use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::min_min_workflow_scheduler::MinMinWorkflowScheduler;
 use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowScheduler;
 use crate::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
 use crate::error::ConversionError;
@@ -13,6 +15,12 @@ pub enum WorkflowSchedulerType {
     /// for scheduling tasks on a set of heterogeneous processors.
     HEFTSync,
     HEFTFrag,
+    /// **Min-Min**: A throughput-oriented heuristic, which always places the ready task
+    /// with the smallest minimum completion time next.
+    MinMin,
+    /// **Critical Path On a Processor**: Pins all CoAllocations of the critical path onto a single
+    /// VrmComponent and places the remaining tasks like HEFTSync.
+    Cpop,
     FragWindow,
     FragWindowZHAO,
 }
@@ -31,6 +39,8 @@ impl WorkflowSchedulerType {
             WorkflowSchedulerType::HEFTFrag => {
                 todo!("Not implemented yet!")
             }
+            WorkflowSchedulerType::MinMin => MinMinWorkflowScheduler::new(reservation_store),
+            WorkflowSchedulerType::Cpop => CpopWorkflowScheduler::new(reservation_store),
             WorkflowSchedulerType::FragWindow => {
                 todo!("Not implemented yet!")
             }
@@ -50,6 +60,8 @@ impl FromStr for WorkflowSchedulerType {
             "Exhaustive-Frag" => Ok(WorkflowSchedulerType::ExhaustiveFrag),
             "HEFT-Sync" => Ok(WorkflowSchedulerType::HEFTSync),
             "HEFT-Frag" => Ok(WorkflowSchedulerType::HEFTFrag),
+            "Min-Min" => Ok(WorkflowSchedulerType::MinMin),
+            "CPOP" => Ok(WorkflowSchedulerType::Cpop),
             "Frag-Window" => Ok(WorkflowSchedulerType::FragWindow),
             "Frag-Window-Zhao" => Ok(WorkflowSchedulerType::FragWindowZHAO),
             _ => Err(ConversionError::UnknownRmsType(rms_type_dto.to_string())),
@@ -1 +1,2 @@
 pub mod eft_reservation_compare;
+pub mod priority_reservation_compare;
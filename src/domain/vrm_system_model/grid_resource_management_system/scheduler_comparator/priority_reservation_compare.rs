@@ -0,0 +1,25 @@
+use std::cmp::Ordering;
+
+use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+
+/// Orders reservations by their priority (highest first), equal priorities are ordered by EFT (earliest finishing time).
+pub struct PriorityReservationCompare {
+    reservation_store: ReservationStore,
+}
+
+impl PriorityReservationCompare {
+    pub fn new(reservation_store: ReservationStore) -> Self {
+        Self { reservation_store }
+    }
+
+    pub fn compare(&self, reservation_id0: ReservationId, reservation_id1: ReservationId) -> Ordering {
+        let priority0 = self.reservation_store.get_priority(reservation_id0);
+        let priority1 = self.reservation_store.get_priority(reservation_id1);
+
+        return priority1.cmp(&priority0).then_with(|| {
+            let assigned_end0 = self.reservation_store.get_assigned_end(reservation_id0);
+            let assigned_end1 = self.reservation_store.get_assigned_end(reservation_id1);
+            assigned_end0.cmp(&assigned_end1)
+        });
+    }
+}
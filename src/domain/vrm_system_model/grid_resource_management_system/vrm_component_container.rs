@@ -18,12 +18,21 @@ pub struct VrmComponentContainer {
     // Internal schedule of the VrmComponent (is e.g. a SlottedSchedule)
     pub schedule: Box<dyn Schedule>,
 
+    /// Width of a single slot of the schedule in s.
+    pub slot_width: i64,
+
     /// The sequence number assigned at registration time, used for stable sorting.
     pub registration_index: usize,
 
     /// A counter of how many times operations on this VrmComponent have failed.
     pub failures: u32,
 
+    /// The total capacity of the VrmComponent (see `VrmComponent::get_total_capacity`).
+    pub total_capacity: i64,
+
+    /// The total capacity of all nodes of the VrmComponent (does not mean free capacity).
+    pub total_node_capacity: i64,
+
     /// The total bandwidth available on all links of the VrmComponent (does not mean free capacity).
     pub total_link_capacity: i64,
 
@@ -45,6 +54,7 @@ impl VrmComponentContainer {
         let component_id = vrm_component.get_id();
         let scheduler_id = SlottedScheduleId::new(format!("Scheduler of VrmComponent: {:?}", component_id));
         let total_capacity = vrm_component.get_total_capacity();
+        let total_node_capacity = vrm_component.get_total_node_capacity();
         let node_strategy = NodeStrategy::default();
         let slotted_schedule_nodes = SlottedNodeSchedule::new(
             scheduler_id,
@@ -59,7 +69,17 @@ impl VrmComponentContainer {
 
         let schedule = Box::new(slotted_schedule_nodes);
 
-        Self { vrm_component, schedule, registration_index, total_link_capacity, link_resource_count, failures: 0 }
+        Self {
+            vrm_component,
+            schedule,
+            slot_width,
+            registration_index,
+            total_capacity,
+            total_node_capacity,
+            total_link_capacity,
+            link_resource_count,
+            failures: 0,
+        }
     }
 
     pub fn can_handel(&self, res: Reservation) -> bool {
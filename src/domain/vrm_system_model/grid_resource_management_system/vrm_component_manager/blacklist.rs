@@ -0,0 +1,64 @@
+use crate::domain::vrm_system_model::utils::id::ComponentId;
+
+use super::VrmComponentManager;
+
+/// Failure-count-based blacklisting of flaky VrmComponents.
+///
+/// Each failed commit at a VrmComponent and each reserve, which the VrmComponent did not answer within the
+/// `ReserveConfig::gate_timeout`, increments the `failures` of its container, while each successful commit resets them.
+/// A rejected reserve is no fault of the VrmComponent (e.g. it is full), hence it is not counted.
+/// Once a VrmComponent reached `max_failures` consecutive failures, it is skipped by `get_random_ordered_vrm_components`
+/// and `get_ordered_vrm_components` (and hence not probed anymore) until its failures are reset.
+impl VrmComponentManager {
+    /// Increments the failure counter of the VrmComponent, e.g. after a failed commit or an unanswered reserve.
+    pub fn increment_failure(&mut self, component_id: ComponentId) {
+        let Some(container) = self.vrm_components.get_mut(&component_id) else {
+            log::error!(
+                "VrmComponentManagerIncrementFailureUnknownComponent: ComponentManager of ADC {} has no component {}.",
+                self.adc_id,
+                component_id
+            );
+            return;
+        };
+
+        container.failures = container.failures.saturating_add(1);
+
+        if self.max_failures > 0 && container.failures == self.max_failures {
+            log::warn!(
+                "VrmComponentManagerComponentBlacklisted: Component {} of ADC {} failed {} times and is skipped from now on.",
+                component_id,
+                self.adc_id,
+                container.failures
+            );
+        }
+    }
+
+    /// Resets the failure counter of the VrmComponent, which also lifts its blacklisting.
+    pub fn reset_failures(&mut self, component_id: ComponentId) {
+        if let Some(container) = self.vrm_components.get_mut(&component_id) {
+            container.failures = 0;
+        }
+    }
+
+    /// Returns true, if the VrmComponent reached `max_failures` and is therefore skipped.
+    pub fn is_blacklisted(&self, component_id: &ComponentId) -> bool {
+        return self.vrm_components.get(component_id).is_some_and(|container| self.is_failure_count_exceeded(container.failures));
+    }
+
+    /// Returns the ids of all blacklisted VrmComponents in ascending order.
+    pub fn blacklisted_components(&self) -> Vec<ComponentId> {
+        let mut component_ids: Vec<ComponentId> = self
+            .vrm_components
+            .iter()
+            .filter(|(_, container)| self.is_failure_count_exceeded(container.failures))
+            .map(|(component_id, _)| component_id.clone())
+            .collect();
+        component_ids.sort();
+        return component_ids;
+    }
+
+    /// Returns true, if `failures` reaches `max_failures` (a `max_failures` of 0 disables the blacklisting).
+    pub fn is_failure_count_exceeded(&self, failures: u32) -> bool {
+        return self.max_failures > 0 && failures >= self.max_failures;
+    }
+}
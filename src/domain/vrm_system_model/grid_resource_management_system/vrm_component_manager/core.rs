@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 use crate::domain::simulator::simulator::GlobalClock;
@@ -5,48 +6,51 @@ use crate::domain::vrm_system_model::grid_resource_management_system::vrm_compon
 use crate::domain::vrm_system_model::grid_resource_management_system::vrm_component_order::VrmComponentOrder;
 use crate::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::vrm_component_proxy::VrmComponentProxy;
 use crate::domain::vrm_system_model::grid_resource_management_system::vrm_component_trait::VrmComponent;
-use crate::domain::vrm_system_model::reservation::reservation::Reservation;
+use crate::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState};
 use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
 use crate::domain::vrm_system_model::utils::config::DELETE_ALL_VRM_MANAGED_RESERVATIONS_IF_VRM_COMPONENT_IS_DELETED;
 use crate::domain::vrm_system_model::utils::id::{ComponentId, RouterId};
+use crate::domain::vrm_system_model::workflow::workflow::Workflow;
+use crate::error::Error;
 
-use rand::rng;
 use rand::seq::SliceRandom;
 
 use super::VrmComponentManager;
 
 impl VrmComponentManager {
+    /// Returns the container of the VrmComponent, or `Error::ComponentNotFound` if the VrmComponent is not registered
+    /// (e.g. a stale id of a rolled back shadow schedule).
+    pub fn try_get_vrm_component_container(&self, component_id: &ComponentId) -> Result<&VrmComponentContainer, Error> {
+        return self
+            .vrm_components
+            .get(component_id)
+            .ok_or_else(|| Error::ComponentNotFound { adc: self.adc_id.clone(), component: component_id.clone() });
+    }
+
+    /// Mutable variant of `try_get_vrm_component_container`.
+    pub fn try_get_vrm_component_container_mut(&mut self, component_id: &ComponentId) -> Result<&mut VrmComponentContainer, Error> {
+        let adc_id = self.adc_id.clone();
+        return self.vrm_components.get_mut(component_id).ok_or_else(|| Error::ComponentNotFound { adc: adc_id, component: component_id.clone() });
+    }
+
+    /// Panicking variant of `try_get_vrm_component_container_mut`.
     pub fn get_vrm_component_container_mut(&mut self, component_id: ComponentId) -> &mut VrmComponentContainer {
-        match self.vrm_components.get_mut(&component_id) {
-            Some(container) => container,
-            None => panic!(
-                "ErrorFailedToGetVrmComponentContainer: In the VrmComponentManager of ADC {}, was the ComponentId {} not found.",
-                self.adc_id,
-                component_id.clone()
-            ),
+        match self.try_get_vrm_component_container_mut(&component_id) {
+            Ok(container) => container,
+            Err(error) => panic!("ErrorFailedToGetVrmComponentContainer: {}", error),
         }
     }
 
+    /// Panicking variant of `try_get_vrm_component_container`.
     pub fn get_vrm_component_container(&mut self, component_id: ComponentId) -> &VrmComponentContainer {
-        match self.vrm_components.get(&component_id) {
-            Some(container) => container,
-            None => panic!(
-                "ErrorFailedToGetVrmComponentContainer: In the VrmComponentManager of ADC {}, was the ComponentId {} not found.",
-                self.adc_id,
-                component_id.clone()
-            ),
+        match self.try_get_vrm_component_container(&component_id) {
+            Ok(container) => container,
+            Err(error) => panic!("ErrorFailedToGetVrmComponentContainer: {}", error),
         }
     }
 
     pub fn get_vrm_component_mut(&mut self, component_id: ComponentId) -> &mut Box<dyn VrmComponent + Send + 'static> {
-        match self.vrm_components.get_mut(&component_id) {
-            Some(container) => &mut container.vrm_component,
-            None => panic!(
-                "ErrorFailedToGetVrmComponentContainer: In the VrmComponentManager of ADC {}, was the ComponentId {} not found.",
-                self.adc_id,
-                component_id.clone()
-            ),
-        }
+        return &mut self.get_vrm_component_container_mut(component_id).vrm_component;
     }
 
     pub fn is_reservation_reserved(&self, reservation_id: ReservationId) -> bool {
@@ -57,10 +61,39 @@ impl VrmComponentManager {
         self.not_committed_reservations.get(&reservation_id).cloned()
     }
 
-    // Should aggregate hte router list of all components
+    /// Returns the router list of the network topology of the VrmComponent with the provided id.
+    /// An unknown VrmComponent has no routers.
     pub fn get_component_router_list(&self, component_id: ComponentId) -> Vec<RouterId> {
-        self.vrm_components.get(&component_id).unwrap();
-        todo!()
+        match self.vrm_components.get(&component_id) {
+            Some(container) => container.vrm_component.get_router_list(),
+            None => {
+                log::error!(
+                    "ComponentManagerHasNotFoundGridComponent: ComponentManager of ADC {}, requested component {} for its router list.",
+                    self.adc_id,
+                    component_id
+                );
+                return Vec::new();
+            }
+        }
+    }
+
+    /// Returns true, if the network topology of the VrmComponent with the provided id contains the router.
+    /// In contrast to `get_component_router_list`, the router is looked up in the VrmComponent itself.
+    pub fn component_contains_router(&self, component_id: &ComponentId, router_id: &RouterId) -> bool {
+        return self.vrm_components.get(component_id).is_some_and(|container| container.vrm_component.contains_router(router_id));
+    }
+
+    /// Aggregates the router lists of all connected VrmComponents (sorted, without duplicates).
+    pub fn get_router_list(&self) -> Vec<RouterId> {
+        let mut router_list: Vec<RouterId> = self.vrm_components.values().flat_map(|container| container.vrm_component.get_router_list()).collect();
+        router_list.sort();
+        router_list.dedup();
+        return router_list;
+    }
+
+    /// Returns true, if any connected VrmComponent contains the provided router.
+    pub fn contains_router(&self, router_id: &RouterId) -> bool {
+        return self.vrm_components.values().any(|container| container.vrm_component.contains_router(router_id));
     }
 
     pub fn can_component_handel(&self, component_id: ComponentId, res: Reservation) -> bool {
@@ -119,48 +152,52 @@ impl VrmComponentManager {
         return true;
     }
 
-    /// Get the total capacity of all connected VrmComponents
+    /// Get the total capacity of all connected VrmComponents, i.e. their node and link capacities combined
+    /// (see `capacity_breakdown`). The value is cached and updated on `add_vrm_component`/`delete_vrm_component`.
     pub fn get_total_capacity(&self) -> i64 {
-        let mut total_capacity = 0;
-
-        for (_, container) in &self.vrm_components {
-            total_capacity += container.vrm_component.get_total_capacity()
-        }
-
-        total_capacity
+        self.total_capacity
     }
 
-    /// Get the total link capacity of all connected VrmComponents
+    /// Get the total link capacity of all connected VrmComponents (cached, see `get_total_capacity`).
     pub fn get_total_link_capacity(&self) -> i64 {
-        let mut total_link_capacity = 0;
-
-        for (_, container) in &self.vrm_components {
-            total_link_capacity += container.vrm_component.get_total_link_capacity()
-        }
-
-        total_link_capacity
+        self.total_link_capacity
     }
 
-    /// Get the total node capacity of all connected VrmComponents
+    /// Get the total node capacity of all connected VrmComponents (cached, see `get_total_capacity`).
     pub fn get_total_node_capacity(&self) -> i64 {
-        let mut total_node_capacity = 0;
-
-        for (_, container) in &self.vrm_components {
-            total_node_capacity += container.vrm_component.get_total_node_capacity()
-        }
+        self.total_node_capacity
+    }
 
-        total_node_capacity
+    /// Get the largest node capacity of a single connected VrmComponent (cached, see `get_total_capacity`).
+    pub fn get_max_component_node_capacity(&self) -> i64 {
+        return self.vrm_components.values().map(|container| container.total_node_capacity).max().unwrap_or(0);
     }
 
-    /// Get the link resource_count of all connected VrmComponents
+    /// Get the link resource_count of all connected VrmComponents (cached, see `get_total_capacity`).
     pub fn get_link_resource_count(&self) -> usize {
-        let mut link_resource_count = 0;
+        self.link_resource_count
+    }
 
-        for (_, container) in &self.vrm_components {
-            link_resource_count += container.vrm_component.get_link_resource_count()
+    /// Recomputes the cached capacities of all VrmComponent containers and their aggregated sums of the manager,
+    /// by requesting them again from the VrmComponents.
+    /// Must be called after a bulk mutation of `vrm_components`, which bypasses `add_vrm_component`/`delete_vrm_component`.
+    pub fn recompute_totals(&mut self) {
+        self.total_capacity = 0;
+        self.total_node_capacity = 0;
+        self.total_link_capacity = 0;
+        self.link_resource_count = 0;
+
+        for container in self.vrm_components.values_mut() {
+            container.total_capacity = container.vrm_component.get_total_capacity();
+            container.total_node_capacity = container.vrm_component.get_total_node_capacity();
+            container.total_link_capacity = container.vrm_component.get_total_link_capacity();
+            container.link_resource_count = container.vrm_component.get_link_resource_count();
+
+            self.total_capacity += container.total_capacity;
+            self.total_node_capacity += container.total_node_capacity;
+            self.total_link_capacity += container.total_link_capacity;
+            self.link_resource_count += container.link_resource_count;
         }
-
-        link_resource_count
     }
 
     /// Increments and returns the next available registration counter.
@@ -179,6 +216,106 @@ impl VrmComponentManager {
         return self.total_link_capacity as f64 / self.link_resource_count as f64;
     }
 
+    /// Returns the slot width (in s) of the schedule of the VrmComponent, or `None` if the VrmComponent is not registered.
+    pub fn get_component_slot_width(&self, component_id: &ComponentId) -> Option<i64> {
+        return self.vrm_components.get(component_id).map(|container| container.slot_width);
+    }
+
+    /// Calculates the duration (in s) of a data transfer between two VrmComponents, which is a whole number of slots.
+    ///
+    /// The transfer time `transfer_size / link_speed` (rounded up, at least 1 s, or 1 s if the link speed is unknown)
+    /// is converted into slots of the coarser schedule of both VrmComponents, because the transfer must occupy whole
+    /// slots on both ends. VrmComponents, which are not registered (e.g. the dummy component), do not contribute a
+    /// slot width. If no slot width is known, the transfer time is returned unchanged.
+    ///
+    /// # Arguments
+    /// * `transfer_size` - Amount of data to transfer.
+    /// * `link_speed` - Bandwidth of the transfer in data per s.
+    /// * `source_component_id` - VrmComponent, where the transfer starts.
+    /// * `target_component_id` - VrmComponent, where the transfer ends.
+    ///
+    /// # Returns
+    /// The transfer duration in s, which is a multiple of the relevant slot width.
+    pub fn get_transfer_duration(
+        &self,
+        transfer_size: i64,
+        link_speed: i64,
+        source_component_id: &ComponentId,
+        target_component_id: &ComponentId,
+    ) -> i64 {
+        let transfer_time = if link_speed > 0 { (transfer_size + link_speed - 1) / link_speed } else { 1 };
+        let transfer_time = transfer_time.max(1);
+
+        let slot_width = [source_component_id, target_component_id]
+            .into_iter()
+            .filter_map(|component_id| self.get_component_slot_width(component_id))
+            .filter(|slot_width| *slot_width > 0)
+            .max();
+
+        return match slot_width {
+            Some(slot_width) => ((transfer_time + slot_width - 1) / slot_width) * slot_width,
+            None => transfer_time,
+        };
+    }
+
+    /// Cheap feasibility gate, which detects workflows that can never fit into the connected VrmComponents,
+    /// before the expensive workflow scheduling (reserve and rollback) is started.
+    ///
+    /// The following necessary conditions are checked:
+    /// * The peak concurrent node demand, i.e. the maximal summed `reserved_capacity` of the members of a `CoAllocation`,
+    ///   does not exceed `get_total_node_capacity`.
+    /// * The largest `DataDependency` can be transferred within the booking interval of the workflow, if all links are used
+    ///   (`get_total_link_capacity` * length of the booking interval). The check is skipped if no link capacity exists,
+    ///   because the transfers may stay local within a VrmComponent.
+    ///
+    /// # Returns
+    /// `false` if the workflow is trivially infeasible, `true` otherwise (which does not guarantee a successful reservation).
+    pub fn can_fit_workflow(&self, workflow: &Workflow) -> bool {
+        let total_node_capacity = self.get_total_node_capacity();
+        let peak_node_demand = workflow
+            .co_allocations
+            .values()
+            .map(|co_allocation| {
+                co_allocation
+                    .members
+                    .iter()
+                    .filter_map(|member_id| workflow.nodes.get(member_id))
+                    .map(|member| self.reservation_store.get_reserved_capacity(member.reservation_id))
+                    .sum::<i64>()
+            })
+            .max()
+            .unwrap_or(0);
+
+        if peak_node_demand > total_node_capacity {
+            log::debug!(
+                "WorkflowCanNotFit: ADC {} can not fit workflow {:?}, because the peak node demand {} exceeds the total node capacity {}.",
+                self.adc_id,
+                workflow.base.name,
+                peak_node_demand,
+                total_node_capacity
+            );
+            return false;
+        }
+
+        let total_link_capacity = self.get_total_link_capacity();
+        let max_transfer_size = workflow.data_dependencies.values().map(|data_dependency| data_dependency.size).max().unwrap_or(0);
+        let booking_interval_length = (workflow.base.booking_interval_end - workflow.base.booking_interval_start).max(0);
+
+        if total_link_capacity > 0 && max_transfer_size > total_link_capacity.saturating_mul(booking_interval_length) {
+            log::debug!(
+                "WorkflowCanNotFit: ADC {} can not fit workflow {:?}, because the transfer of size {} exceeds the link capacity {} within the booking interval of {}s.",
+                self.adc_id,
+                workflow.base.name,
+                max_transfer_size,
+                total_link_capacity,
+                booking_interval_length
+            );
+            return false;
+        }
+
+        return true;
+    }
+
     /// Registers a new **VrmComponent** with the manager.
     ///
     /// # Arguments
@@ -219,7 +356,13 @@ impl VrmComponentManager {
             link_resource_count,
         );
 
+        let (total_capacity, total_node_capacity) = (container.total_capacity, container.total_node_capacity);
+
         if self.vrm_components.insert(vrm_component_id.clone(), container).is_none() {
+            self.total_capacity += total_capacity;
+            self.total_node_capacity += total_node_capacity;
+            self.total_link_capacity += total_link_capacity;
+            self.link_resource_count += link_resource_count;
             return true;
         } else {
             log::error!(
@@ -233,37 +376,55 @@ impl VrmComponentManager {
 
     /// Removes an **VrmComponent** from the manager by its ID.
     ///
-    /// Updates the total link capacity and link resource counts upon successful removal.
+    /// Updates the cached total capacities and link resource counts upon successful removal.
+    /// If `DELETE_ALL_VRM_MANAGED_RESERVATIONS_IF_VRM_COMPONENT_IS_DELETED` is set, all reservations handled by the
+    /// VrmComponent are released: They are set to `ReservationState::Rejected` and removed from `res_to_vrm_component`,
+    /// `committed_reservations` and `not_committed_reservations`, such that no later operation targets the removed VrmComponent.
     ///
     /// # Arguments
     /// * `VrmComponentId` - The identifier of the VrmComponent to remove.
     ///
     /// # Returns
-    /// * `true` - If the VrmComponent was found and removed.
-    /// * `false` - If the VrmComponent ID was not found.
-    pub fn delete_vrm_component(&mut self, del_component_id: ComponentId) -> bool {
+    /// * `Some(Vec<ReservationId>)` - If the VrmComponent was found and removed, containing all released reservations
+    ///   (e.g. to notify the clients).
+    /// * `None` - If the VrmComponent ID was not found.
+    pub fn delete_vrm_component(&mut self, del_component_id: ComponentId) -> Option<Vec<ReservationId>> {
         let container = self.vrm_components.remove(&del_component_id);
 
         match container {
             Some(container) => {
+                self.total_capacity -= container.total_capacity;
+                self.total_node_capacity -= container.total_node_capacity;
                 self.total_link_capacity -= container.total_link_capacity;
                 self.link_resource_count -= container.link_resource_count;
+                self.component_zones.remove(&del_component_id);
 
-                // Delete all managed Reservation by VRM form the VrmComponent
+                let mut released_reservations: Vec<ReservationId> = Vec::new();
+
+                // Release all managed Reservation by VRM form the VrmComponent
                 if DELETE_ALL_VRM_MANAGED_RESERVATIONS_IF_VRM_COMPONENT_IS_DELETED {
-                    for (res_id, component_id) in self.res_to_vrm_component.clone() {
-                        if del_component_id.eq(&component_id) {
-                            if !self.delete_task_at_component(res_id, None) {
-                                log::debug!(
-                                    "In the process of deleting the VrmComponent {:?}, was it not possible to delete the managed reservation: {:?}.",
-                                    del_component_id,
-                                    res_id
-                                );
+                    for tracking_map in [&self.res_to_vrm_component, &self.committed_reservations, &self.not_committed_reservations] {
+                        for (res_id, component_id) in tracking_map {
+                            if del_component_id.eq(component_id) && !released_reservations.contains(res_id) {
+                                released_reservations.push(*res_id);
                             }
                         }
                     }
+
+                    for res_id in &released_reservations {
+                        log::debug!(
+                            "VrmComponentManagerReleaseReservation: Reservation {:?} is released, because its VrmComponent {} was deleted form ADC {}.",
+                            self.reservation_store.get_name_for_key(*res_id),
+                            del_component_id,
+                            self.adc_id
+                        );
+                        self.reservation_store.update_state(*res_id, ReservationState::Rejected);
+                        self.remove_allocation(res_id);
+                        self.committed_reservations.remove(res_id);
+                        self.not_committed_reservations.remove(res_id);
+                    }
                 }
-                return true;
+                return Some(released_reservations);
             }
             None => {
                 log::error!(
@@ -271,31 +432,57 @@ impl VrmComponentManager {
                     del_component_id,
                     self.adc_id
                 );
-                return false;
+                return None;
             }
         }
     }
 
     /// Returns a list of all registered VrmComponent IDs in **random order**.
     ///
+    /// The order is drawn from the seeded random number generator of the manager. The ids are sorted before shuffling,
+    /// because the iteration order of the HashMap differs between runs. Blacklisted VrmComponents are skipped.
+    ///
     /// # Returns
     /// A `Vec<VrmComponentId>` where the VrmComponentIds are in random order.
     pub fn get_random_ordered_vrm_components(&self) -> Vec<ComponentId> {
-        let mut keys: Vec<ComponentId> = self.vrm_components.keys().cloned().into_iter().collect();
-        keys.shuffle(&mut rng());
+        let mut keys: Vec<ComponentId> = self
+            .vrm_components
+            .iter()
+            .filter(|(_, container)| !self.is_failure_count_exceeded(container.failures))
+            .map(|(component_id, _)| component_id.clone())
+            .collect();
+        keys.sort();
+        keys.shuffle(&mut *self.rng.lock().expect("Lock poisoned"));
         return keys;
     }
 
     /// Returns a list of registered VrmComponent IDs sorted according to the specified strategy.
     /// If strict ordering is not required, `get_random_ordered_vrm_components` is preferred for performance.
+    /// Blacklisted VrmComponents are skipped.
     ///
     /// # Returns
     /// A `Vec<VrmComponentId>` sorted based on the comparator provided by `VrmComponentOrder`.
     pub fn get_ordered_vrm_components(&self, request_order: VrmComponentOrder) -> Vec<ComponentId> {
         let comparator = request_order.get_comparator();
-        let mut components_vec: Vec<&VrmComponentContainer> = self.vrm_components.values().collect();
+        return self.get_components_ordered_by(|a, b| comparator(a, b));
+    }
 
-        components_vec.sort_unstable_by(|a, b| comparator(a, b));
+    /// Returns a list of registered VrmComponent IDs sorted by a custom comparator, e.g. to order the
+    /// VrmComponents by a metric (utilization, earliest finish time, ...), which is not covered by `VrmComponentOrder`.
+    ///
+    /// # Arguments
+    /// * `cmp` - Comparator of two `VrmComponentContainer`s, the VrmComponents are returned in ascending order.
+    ///
+    /// # Returns
+    /// A `Vec<VrmComponentId>` sorted based on `cmp`, without the blacklisted VrmComponents.
+    pub fn get_components_ordered_by<F>(&self, cmp: F) -> Vec<ComponentId>
+    where
+        F: Fn(&VrmComponentContainer, &VrmComponentContainer) -> Ordering,
+    {
+        let mut components_vec: Vec<&VrmComponentContainer> =
+            self.vrm_components.values().filter(|container| !self.is_failure_count_exceeded(container.failures)).collect();
+
+        components_vec.sort_unstable_by(|a, b| cmp(a, b));
 
         let sorted_keys: Vec<ComponentId> = components_vec.into_iter().map(|container| container.vrm_component.get_id()).collect();
         return sorted_keys;
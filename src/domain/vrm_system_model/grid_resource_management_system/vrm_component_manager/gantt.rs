@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::domain::vrm_system_model::utils::id::ComponentId;
+use crate::error::Error;
+
+use super::VrmComponentManager;
+
+/// A single bar of the Gantt chart, i.e. one reservation on one VrmComponent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GanttBar {
+    pub reservation_name: String,
+    pub client: String,
+    pub start: i64,
+    pub end: i64,
+    pub capacity: i64,
+}
+
+/// All bars of one VrmComponent, ordered by their start time.
+#[derive(Debug, Clone, Serialize)]
+pub struct GanttComponent {
+    pub component_id: String,
+    pub bars: Vec<GanttBar>,
+}
+
+/// Component-centric timeline of the master schedule of an ADC in the time window [`start`, `end`).
+#[derive(Debug, Clone, Serialize)]
+pub struct GanttData {
+    pub start: i64,
+    pub end: i64,
+
+    /// VrmComponents ordered by their id.
+    pub components: Vec<GanttComponent>,
+}
+
+impl GanttData {
+    /// Serializes the Gantt data into JSON, which can directly be utilized for a timeline visualization.
+    pub fn to_json(&self) -> Result<String, Error> {
+        return Ok(serde_json::to_string_pretty(self)?);
+    }
+
+    /// Returns the bars of the given VrmComponent, or `None` if the VrmComponent is not part of the Gantt data.
+    pub fn get_bars(&self, component_id: &ComponentId) -> Option<&Vec<GanttBar>> {
+        return self.components.iter().find(|component| component.component_id == component_id.id).map(|component| &component.bars);
+    }
+}
+
+impl VrmComponentManager {
+    /// Collects the **Gantt Data** of all reservations (committed and not committed) on the master schedule,
+    /// which overlap with the time window [`start`, `end`).
+    ///
+    /// # Arguments
+    /// * `start` - Start of the time window in seconds (VRM Time).
+    /// * `end` - End of the time window in seconds (VRM Time).
+    ///
+    /// # Returns
+    /// A `GanttData` with one entry per registered VrmComponent (also without bars) and one bar per reservation.
+    pub fn gantt_data(&self, start: i64, end: i64) -> GanttData {
+        let mut bars_per_component: BTreeMap<String, Vec<GanttBar>> =
+            self.vrm_components.keys().map(|component_id| (component_id.id.clone(), Vec::new())).collect();
+
+        for (reservation_id, component_id) in self.committed_reservations.iter().chain(self.not_committed_reservations.iter()) {
+            // No real reservation
+            if component_id.is_dummy() {
+                continue;
+            }
+
+            let assigned_start = self.reservation_store.get_assigned_start(*reservation_id);
+            let assigned_end = self.reservation_store.get_assigned_end(*reservation_id);
+
+            if assigned_start >= end || assigned_end <= start {
+                continue;
+            }
+
+            let bar = GanttBar {
+                reservation_name: self.reservation_store.get_name_for_key(*reservation_id).map(|name| name.id).unwrap_or_default(),
+                client: self.reservation_store.get_client_id(*reservation_id).id,
+                start: assigned_start,
+                end: assigned_end,
+                capacity: self.reservation_store.get_reserved_capacity(*reservation_id),
+            };
+            bars_per_component.entry(component_id.id.clone()).or_default().push(bar);
+        }
+
+        let components = bars_per_component
+            .into_iter()
+            .map(|(component_id, mut bars)| {
+                bars.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.reservation_name.cmp(&b.reservation_name)));
+                GanttComponent { component_id, bars }
+            })
+            .collect();
+
+        return GanttData { start, end, components };
+    }
+}
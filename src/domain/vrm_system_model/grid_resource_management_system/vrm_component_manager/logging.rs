@@ -2,6 +2,7 @@ impl VrmComponentManager {
     pub fn log_stat(&mut self, command: String, reservation_id: ReservationId, arrival_time_at_aci: i64) {
         let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
         let processing_time = self.simulator.get_system_time_s() - arrival_time_at_aci;
+        self.record_processing_time(&command, processing_time);
 
         if let Some(res_handle) = self.reservation_store.get(reservation_id) {
             let (start, end, res_name, capacity, workload, state, proceeding, num_tasks) = {
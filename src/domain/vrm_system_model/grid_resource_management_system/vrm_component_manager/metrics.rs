@@ -1,12 +1,101 @@
+use std::collections::HashMap;
+
 use crate::domain::vrm_system_model::rms::rms::RmsLoadMetric;
-use crate::domain::vrm_system_model::utils::id::{ComponentId, ShadowScheduleId};
+use crate::domain::vrm_system_model::utils::id::{ClientId, ComponentId, ShadowScheduleId};
 use crate::domain::vrm_system_model::utils::load_buffer::LoadMetric;
 
 use super::VrmComponentManager;
 
+/// Distribution of the committed work of the ADC across its clients.
+#[derive(Debug, Clone)]
+pub struct FairnessReport {
+    /// Committed work (reserved capacity * task duration of node reservations) per known client in capacity-seconds.
+    pub per_client_work: HashMap<ClientId, i64>,
+
+    /// Jain's fairness index over `per_client_work` in the range (0.0, 1.0].
+    /// 1.0 means all clients received the same amount of work, 1/n means a single client received all the work.
+    pub jains_index: f64,
+}
+
+/// Capacity of all VrmComponents of the ADC, separated by resource type.
+///
+/// Node capacity is measured in CPUs, link capacity in bandwidth units. `total` is the plain sum of both, which
+/// matches `VrmComponentManager::get_total_capacity` and must not be interpreted as a capacity of a single unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityBreakdown {
+    /// Aggregated capacity of all NodeResources.
+    pub node: i64,
+
+    /// Aggregated capacity of all LinkResources.
+    pub link: i64,
+
+    /// Combined capacity of nodes and links (`node + link`).
+    pub total: i64,
+}
+
+/// Weighting of the satisfaction of a single VrmComponent in the aggregated satisfaction of the ADC.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SatisfactionWeight {
+    /// The satisfaction is weighted by the total node capacity of the VrmComponent (default, former behavior).
+    #[default]
+    Capacity,
+
+    /// The node capacity is additionally multiplied by `factor^depth`, where `depth` is the number of ADC levels
+    /// below the VrmComponent (0 for an AcI). With a `factor` below 1.0 the satisfaction of deep child ADCs,
+    /// which report the aggregated capacity of many AcIs, is damped.
+    CapacityWithDepthDecay { factor: f64 },
+}
+
+impl SatisfactionWeight {
+    /// Returns the weight of a VrmComponent with the provided node capacity and hierarchy depth.
+    pub fn get_weight(&self, node_capacity: i64, depth: usize) -> f64 {
+        return match self {
+            SatisfactionWeight::Capacity => node_capacity as f64,
+            SatisfactionWeight::CapacityWithDepthDecay { factor } => node_capacity as f64 * factor.powi(depth as i32),
+        };
+    }
+}
+
 impl VrmComponentManager {
+    /// Returns the node, link and combined capacity of all connected VrmComponents.
+    ///
+    /// If the total capacity reported by a VrmComponent differs from the sum of its node and link capacity,
+    /// the mismatch is logged and the sum of node and link capacity is utilized.
+    pub fn capacity_breakdown(&self) -> CapacityBreakdown {
+        let mut node = 0;
+        let mut link = 0;
+
+        for (component_id, container) in &self.vrm_components {
+            let node_capacity = container.vrm_component.get_total_node_capacity();
+            let link_capacity = container.vrm_component.get_total_link_capacity();
+            let total_capacity = container.vrm_component.get_total_capacity();
+
+            if total_capacity != node_capacity + link_capacity {
+                log::debug!(
+                    "CapacityBreakdownMismatch: VrmComponent {} of ADC {} reports a total capacity of {}, but a node capacity of {} and a link capacity of {}.",
+                    component_id,
+                    self.adc_id,
+                    total_capacity,
+                    node_capacity,
+                    link_capacity
+                );
+            }
+
+            node += node_capacity;
+            link += link_capacity;
+        }
+
+        return CapacityBreakdown { node, link, total: node + link };
+    }
+
+    /// Returns the number of ADC levels below the ADC of this manager, i.e. the depth of the deepest VrmComponent plus one.
+    pub fn get_hierarchy_depth(&self) -> usize {
+        return 1 + self.vrm_components.values().map(|container| container.vrm_component.get_hierarchy_depth()).max().unwrap_or(0);
+    }
+
     /// Calculates the average **Satisfaction Score** (0.0 to 1.0) for the current schedule within a specific time window.
-    /// This method queries all directly and indirectly connected AcIs and calculates the capacity-weighted average satisfaction.
+    /// This method queries all directly and indirectly connected AcIs and calculates the capacity-weighted average satisfaction
+    /// (see `satisfaction_weight_mode`).
     ///
     /// # Arguments
     /// * `start` - The start of the time window.
@@ -26,6 +115,7 @@ impl VrmComponentManager {
 
         let mut satisfaction_sum = 0.0;
         let mut total_capacity = 0.0;
+        let satisfaction_weight_mode = self.satisfaction_weight_mode;
 
         for (id, container) in self.vrm_components.iter_mut() {
             let satisfaction = container.vrm_component.get_satisfaction(start, end, shadow_schedule_id.clone());
@@ -38,7 +128,8 @@ impl VrmComponentManager {
                     shadow_schedule_id
                 );
             } else {
-                let cap = container.vrm_component.get_total_node_capacity() as f64;
+                let cap = satisfaction_weight_mode
+                    .get_weight(container.vrm_component.get_total_node_capacity(), container.vrm_component.get_hierarchy_depth());
                 satisfaction_sum += satisfaction * cap;
                 total_capacity += cap;
             }
@@ -48,7 +139,8 @@ impl VrmComponentManager {
     }
 
     /// Calculates the system-wide **Satisfaction Score** (0.0 to 1.0) across the full range of every schedule.
-    /// This method queries all directly and indirectly connected AcIs and calculates the capacity-weighted average.
+    /// This method queries all directly and indirectly connected AcIs and calculates the capacity-weighted average
+    /// (see `satisfaction_weight_mode`).
     ///
     /// # Behavioral Note
     /// **Network AcIs:** This calculation generally excludes network AIs if their satisfaction/fragmentation
@@ -65,6 +157,7 @@ impl VrmComponentManager {
 
         let mut satisfaction_sum = 0.0;
         let mut total_capacity = 0.0;
+        let satisfaction_weight_mode = self.satisfaction_weight_mode;
 
         for (id, container) in self.vrm_components.iter_mut() {
             let satisfaction = container.vrm_component.get_system_satisfaction(shadow_schedule_id.clone());
@@ -76,7 +169,8 @@ impl VrmComponentManager {
                     shadow_schedule_id
                 );
             } else {
-                let cap = container.vrm_component.get_total_node_capacity() as f64;
+                let cap = satisfaction_weight_mode
+                    .get_weight(container.vrm_component.get_total_node_capacity(), container.vrm_component.get_hierarchy_depth());
                 satisfaction_sum += satisfaction * cap;
                 total_capacity += cap;
             }
@@ -167,6 +261,91 @@ impl VrmComponentManager {
         };
     }
 
+    /// Computes the **Load Delta**, which the shadow schedule introduces compared to the master schedule, i.e. the element-wise
+    /// difference of the node `LoadMetric` of the shadow schedule and the master schedule in the time window [`start`, `end`].
+    /// A missing node load metric (e.g. no VrmComponent is registered) is treated as an empty load.
+    ///
+    /// # Arguments
+    /// * `start` - Start of the analysis window in seconds (VRM Time).
+    /// * `end` - End of the analysis window in seconds (VRM Time).
+    /// * `shadow_schedule_id` - The shadow schedule, which is compared with the master schedule.
+    ///
+    /// # Returns
+    /// A `LoadMetric` with the additional average reserved capacity, possible capacity and utilization of the shadow schedule.
+    pub fn load_delta(&self, start: i64, end: i64, shadow_schedule_id: ShadowScheduleId) -> LoadMetric {
+        let empty_load_metric = LoadMetric::new(start, end, 0.0, 0.0, 0.0);
+
+        let shadow_load_metric = self.get_load_metric(start, end, Some(shadow_schedule_id)).node_load_metric.unwrap_or(empty_load_metric.clone());
+        let master_load_metric = self.get_load_metric(start, end, None).node_load_metric.unwrap_or(empty_load_metric);
+
+        return shadow_load_metric - master_load_metric;
+    }
+
+    /// Computes the aggregated **Free Capacity** of all VrmComponents in the time window [`start`, `end`].
+    /// The free capacity of a single VrmComponent is derived from the node load metric of its schedule, i.e. its possible
+    /// capacity minus its average reserved capacity (rounded down). VrmComponents without a valid node load metric are skipped.
+    ///
+    /// # Arguments
+    /// * `start` - Start of the time window in seconds (VRM Time).
+    /// * `end` - End of the time window in seconds (VRM Time).
+    /// * `shadow_schedule_id` - Optional ID for shadow schedule analysis (If None utilize master schedule).
+    ///
+    /// # Returns
+    /// The sum of the free capacity of all VrmComponents, or 0 if no VrmComponent is registered.
+    pub fn free_capacity(&self, start: i64, end: i64, shadow_schedule_id: Option<ShadowScheduleId>) -> i64 {
+        let mut free_capacity = 0;
+
+        for (id, container) in self.vrm_components.iter() {
+            match container.vrm_component.get_load_metric(start, end, shadow_schedule_id.clone()).node_load_metric {
+                Some(load_metric) if load_metric.start_time >= 0 => {
+                    free_capacity += (load_metric.possible_capacity - load_metric.avg_reserved_capacity).floor().max(0.0) as i64;
+                }
+                _ => {
+                    log::debug!(
+                        "VrmComponentManagerFreeCapacity: ADC: {}, child VrmComponent: {} provides no valid node load metric for ShadowScheduleId: {:?}",
+                        self.adc_id,
+                        id,
+                        shadow_schedule_id
+                    );
+                }
+            }
+        }
+        return free_capacity;
+    }
+
+    /// Calculates the **Fairness Report** of all committed node reservations of this ADC.
+    /// The committed work of a node reservation is its reserved capacity multiplied by its task duration (capacity-seconds).
+    /// Link reservations are not counted, because their capacity is measured in bandwidth units instead of CPUs.
+    ///
+    /// Jain's fairness index is defined as `(sum x_i)^2 / (n * sum x_i^2)`, where `x_i` is the committed work of client `i`
+    /// and `n` the number of all clients known to this manager (i.e. owning at least one tracked reservation).
+    /// Known clients without committed work are included with a work of 0, hence a starving client lowers the index.
+    ///
+    /// # Returns
+    /// A `FairnessReport` containing the committed work per known client and Jain's fairness index.
+    /// If no work was committed the index is 1.0 (no client is starving another).
+    pub fn fairness_report(&self) -> FairnessReport {
+        let mut per_client_work: HashMap<ClientId, i64> = HashMap::new();
+
+        for reservation_id in self.get_tracked_reservation_ids() {
+            if self.reservation_store.contains(reservation_id) {
+                per_client_work.entry(self.reservation_store.get_client_id(reservation_id)).or_insert(0);
+            }
+        }
+
+        for reservation_id in self.committed_reservations.keys().filter(|reservation_id| self.reservation_store.is_node(**reservation_id)) {
+            let work = self.reservation_store.get_reserved_capacity(*reservation_id) * self.reservation_store.get_task_duration(*reservation_id);
+            *per_client_work.entry(self.reservation_store.get_client_id(*reservation_id)).or_insert(0) += work;
+        }
+
+        let work_sum: f64 = per_client_work.values().map(|work| *work as f64).sum();
+        let work_square_sum: f64 = per_client_work.values().map(|work| (*work as f64).powi(2)).sum();
+
+        let jains_index = if work_square_sum > 0.0 { work_sum.powi(2) / (per_client_work.len() as f64 * work_square_sum) } else { 1.0 };
+
+        return FairnessReport { per_client_work, jains_index };
+    }
+
     /// Computes the **Load Metric** for the entire simulation timeline.
     /// Aggregates metrics from all valid AcIs to provide a high-level view of system utilization.
     ///
@@ -1,18 +1,35 @@
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 
 use super::vrm_component_container::VrmComponentContainer;
 use super::vrm_component_registry::vrm_component_proxy::VrmComponentProxy;
 use super::vrm_component_trait::VrmComponent;
 use crate::domain::simulator::simulator::GlobalClock;
+use crate::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::metrics::SatisfactionWeight;
+use crate::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::reserve_config::ReserveConfig;
+use crate::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::shadow::ShadowPlacement;
 use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
-use crate::domain::vrm_system_model::utils::id::{AdcId, ComponentId, ShadowScheduleId};
-
+use crate::domain::vrm_system_model::reservation::reservation_sync_gate::SyncRegistry;
+use crate::domain::vrm_system_model::utils::config::{
+    DEFAULT_VRM_COMPONENT_ORDER_SEED, VRM_COMPONENT_MAX_FAILURES, VRM_COMPONENT_MAX_PROBE_THREADS, VRM_COMPONENT_ORDER_SEED_ENV_VAR,
+};
+use crate::domain::vrm_system_model::utils::id::{AdcId, ComponentId, ShadowScheduleId, ZoneId};
+use crate::domain::vrm_system_model::utils::statistics::AnalyticsAggregator;
+
+pub mod blacklist;
 pub mod core;
+pub mod gantt;
 pub mod metrics;
+pub mod reconcile;
+pub mod reserve_config;
 pub mod scheduling;
 pub mod shadow;
+pub mod snapshot;
 pub mod tracking;
+pub mod zones;
 
 /// Manages a collection of **VrmComponents (ADCs and/or AcIs)** for a specific **ADC**.
 ///
@@ -44,20 +61,36 @@ pub struct VrmComponentManager {
 
     pub not_committed_reservations: HashMap<ReservationId, ComponentId>,
 
+    /// Maps a not committed `ReservationId` to the time (in s) of its reserve, used to enforce the commit timeout.
+    pub reserve_times: HashMap<ReservationId, i64>,
+
     pub shadow_schedule_reservations: HashMap<ShadowScheduleId, (HashMap<ReservationId, ComponentId>, ReservationStore)>,
 
+    /// Maps an open shadow schedule to the placements, which other shadow schedules committed after its creation
+    /// (see `commit_shadow_schedule`). These placements are not contained in the snapshot of the shadow schedule.
+    pub shadow_schedule_intervening_commits: HashMap<ShadowScheduleId, Vec<ShadowPlacement>>,
+
     /// Maps a `WorkflowId` (Parent) to a list of its sub-reservations (Nodes and Links).
     pub workflow_subtasks: HashMap<ReservationId, Vec<ReservationId>>,
 
     /// Maps a Subtask `ReservationId` back to its Parent `WorkflowId`.
     pub reverse_workflow_subtasks: HashMap<ReservationId, ReservationId>,
 
+    /// The aggregated sum of the total capacities of all registered VrmComponents (see `get_total_capacity`).
+    pub total_capacity: i64,
+
+    /// The aggregated sum of node capacities of all registered VrmComponents (does not mean free capacity).
+    pub total_node_capacity: i64,
+
     /// The aggregated sum of link capacities of all registered AcIs (does not mean free capacity).
     pub total_link_capacity: i64,
 
     /// The aggregated sum distinct link resources of all registered AcIs.
     pub link_resource_count: usize,
 
+    /// Maps a `VrmComponentId` to its locality zone (e.g. rack, datacenter). VrmComponents without zone are not contained.
+    pub component_zones: HashMap<ComponentId, ZoneId>,
+
     /// Monotonic counter used to assign `registration_index` to new VrmComponentContainer's.
     registration_counter: usize,
 
@@ -65,9 +98,32 @@ pub struct VrmComponentManager {
     pub reservation_store: ReservationStore,
 
     pub simulator: Arc<GlobalClock>,
+
+    /// Retry and backoff behavior of failed reserve requests.
+    pub reserve_config: ReserveConfig,
+
+    /// Gates, at which the reserve path waits for the answers of the VrmComponents (shared with the ADC).
+    pub sync_registry: SyncRegistry,
+
+    /// Weighting of the satisfaction of the VrmComponents in `get_satisfaction` and `get_system_satisfaction`.
+    pub satisfaction_weight_mode: SatisfactionWeight,
+
+    /// Number of failures, after which a VrmComponent is excluded from the VrmComponent orders (see `blacklist`).
+    pub max_failures: u32,
+
+    /// Maximal number of VrmComponents, which are probed concurrently (see `probe_all_vrm_components`).
+    pub max_probe_threads: usize,
+
+    /// Seeded random number generator used to randomly order the VrmComponents, such that runs are reproducible.
+    rng: Mutex<StdRng>,
+
+    /// If attached, receives the processing time of each finished operation (see `record_processing_time`).
+    pub analytics_aggregator: Option<Arc<Mutex<AnalyticsAggregator>>>,
 }
 
 impl VrmComponentManager {
+    /// Creates a VrmComponentManager, whose random VrmComponent order is seeded with the value of the
+    /// `VRM_COMPONENT_ORDER_SEED_ENV_VAR` environment variable or, if not set, with `DEFAULT_VRM_COMPONENT_ORDER_SEED`.
     pub fn new(
         adc_id: AdcId,
         vrm_components_list: Vec<VrmComponentProxy>,
@@ -75,20 +131,58 @@ impl VrmComponentManager {
         reservation_store: ReservationStore,
         number_of_real_slots: i64,
         slot_width: i64,
+    ) -> Self {
+        let seed = match std::env::var(VRM_COMPONENT_ORDER_SEED_ENV_VAR) {
+            Ok(value) => value.parse::<u64>().unwrap_or_else(|_| {
+                log::warn!(
+                    "VrmComponentManagerInvalidSeed: {}={} is not a valid u64, the default seed {} is used.",
+                    VRM_COMPONENT_ORDER_SEED_ENV_VAR,
+                    value,
+                    DEFAULT_VRM_COMPONENT_ORDER_SEED
+                );
+                DEFAULT_VRM_COMPONENT_ORDER_SEED
+            }),
+            Err(_) => DEFAULT_VRM_COMPONENT_ORDER_SEED,
+        };
+
+        return Self::new_seeded(adc_id, vrm_components_list, simulator, reservation_store, number_of_real_slots, slot_width, seed);
+    }
+
+    /// Like `new`, but the retries of failed reserves and the gate timeout are configured by `reserve_config`
+    /// instead of the defaults (see `ReserveConfig::default`).
+    pub fn new_with_config(
+        adc_id: AdcId,
+        vrm_components_list: Vec<VrmComponentProxy>,
+        simulator: Arc<GlobalClock>,
+        reservation_store: ReservationStore,
+        number_of_real_slots: i64,
+        slot_width: i64,
+        reserve_config: ReserveConfig,
+    ) -> Self {
+        let mut manager = Self::new(adc_id, vrm_components_list, simulator, reservation_store, number_of_real_slots, slot_width);
+        manager.reserve_config = reserve_config;
+        return manager;
+    }
+
+    /// Creates a VrmComponentManager, whose random VrmComponent order is seeded with `seed`.
+    /// With the same seed and the same input, the VrmComponents are ordered identically across runs.
+    pub fn new_seeded(
+        adc_id: AdcId,
+        vrm_components_list: Vec<VrmComponentProxy>,
+        simulator: Arc<GlobalClock>,
+        reservation_store: ReservationStore,
+        number_of_real_slots: i64,
+        slot_width: i64,
+        seed: u64,
     ) -> Self {
         let mut vrm_components = HashMap::with_capacity(vrm_components_list.len());
         let mut registration_counter = 0;
-        let mut manager_total_link_capacity = 0;
-        let mut manager_link_resource_count = 0;
 
         for vrm_component in vrm_components_list {
             let component_id = vrm_component.get_id().clone();
             let total_link_capacity = vrm_component.get_total_link_capacity();
             let link_resource_count = vrm_component.get_link_resource_count();
 
-            manager_total_link_capacity += total_link_capacity;
-            manager_link_resource_count += link_resource_count;
-
             let container = VrmComponentContainer::new(
                 Box::new(vrm_component),
                 simulator.clone(),
@@ -104,20 +198,52 @@ impl VrmComponentManager {
             vrm_components.insert(component_id, container);
         }
 
-        VrmComponentManager {
+        let mut manager = VrmComponentManager {
             adc_id,
             vrm_components,
             res_to_vrm_component: HashMap::new(),
             committed_reservations: HashMap::new(),
             not_committed_reservations: HashMap::new(),
+            reserve_times: HashMap::new(),
             shadow_schedule_reservations: HashMap::new(),
+            shadow_schedule_intervening_commits: HashMap::new(),
             workflow_subtasks: HashMap::new(),
             reverse_workflow_subtasks: HashMap::new(),
-            total_link_capacity: manager_total_link_capacity,
-            link_resource_count: manager_link_resource_count,
+            total_capacity: 0,
+            total_node_capacity: 0,
+            total_link_capacity: 0,
+            link_resource_count: 0,
+            component_zones: HashMap::new(),
             registration_counter,
             reservation_store: reservation_store.clone(),
             simulator: simulator.clone(),
+            reserve_config: ReserveConfig::default(),
+            sync_registry: SyncRegistry::new(),
+            satisfaction_weight_mode: SatisfactionWeight::default(),
+            max_failures: VRM_COMPONENT_MAX_FAILURES,
+            max_probe_threads: VRM_COMPONENT_MAX_PROBE_THREADS,
+            rng: Mutex::new(StdRng::seed_from_u64(seed)),
+            analytics_aggregator: None,
+        };
+
+        manager.recompute_totals();
+        return manager;
+    }
+
+    /// Attaches an `AnalyticsAggregator`, which accumulates the processing times per command (see `log_stat`).
+    pub fn attach_analytics_aggregator(&mut self, analytics_aggregator: Arc<Mutex<AnalyticsAggregator>>) {
+        self.analytics_aggregator = Some(analytics_aggregator);
+    }
+
+    /// Feeds the processing time of a finished `command` into the attached `AnalyticsAggregator`, if any.
+    pub fn record_processing_time(&self, command: &str, processing_time: i64) {
+        let Some(analytics_aggregator) = &self.analytics_aggregator else {
+            return;
+        };
+
+        match analytics_aggregator.lock() {
+            Ok(mut aggregator) => aggregator.record(command, processing_time),
+            Err(_) => log::error!("AnalyticsAggregatorLockPoisoned: The processing time of command {} is dropped.", command),
         }
     }
 }
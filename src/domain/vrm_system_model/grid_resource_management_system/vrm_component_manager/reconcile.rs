@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+use crate::domain::vrm_system_model::reservation::reservation_store::ReservationId;
+use crate::domain::vrm_system_model::utils::id::ComponentId;
+
+use super::VrmComponentManager;
+
+/// Outcome of `VrmComponentManager::reconcile`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReconcileReport {
+    /// Orphaned workflow subtasks, which were deleted at their VrmComponent, sorted by `ReservationId`.
+    pub removed: Vec<(ReservationId, ComponentId)>,
+
+    /// Orphaned workflow subtasks, whose deletion at their VrmComponent failed, sorted by `ReservationId`.
+    /// They stay tracked as not committed, hence a later reconcile retries the deletion.
+    pub failed: Vec<(ReservationId, ComponentId)>,
+}
+
+impl ReconcileReport {
+    /// Returns true, if no orphaned workflow subtask was found.
+    pub fn is_empty(&self) -> bool {
+        return self.removed.is_empty() && self.failed.is_empty();
+    }
+}
+
+impl VrmComponentManager {
+    /// Recovers from an interrupted workflow reservation (e.g. a restart during the reserve operation).
+    ///
+    /// A not committed reservation is an orphan, if it is a subtask of a workflow in the ReservationStore, but its
+    /// parent workflow was never registered (no entry in `reverse_workflow_subtasks`). All orphans are deleted at their
+    /// VrmComponent and removed from the tracking of the manager.
+    ///
+    /// # Returns
+    /// A `ReconcileReport` with all removed orphans and all orphans, whose deletion failed.
+    pub fn reconcile(&mut self) -> ReconcileReport {
+        let workflow_subtasks: HashSet<ReservationId> = self
+            .reservation_store
+            .iter_ids()
+            .into_iter()
+            .filter(|reservation_id| self.reservation_store.is_workflow(*reservation_id))
+            .filter_map(|workflow_id| self.reservation_store.get_workflow_res_ids(workflow_id))
+            .flatten()
+            .collect();
+
+        let mut orphans: Vec<(ReservationId, ComponentId)> = self
+            .not_committed_reservations
+            .iter()
+            .filter(|(reservation_id, _)| {
+                workflow_subtasks.contains(*reservation_id) && !self.reverse_workflow_subtasks.contains_key(*reservation_id)
+            })
+            .map(|(reservation_id, component_id)| (*reservation_id, component_id.clone()))
+            .collect();
+        orphans.sort();
+
+        let mut report = ReconcileReport::default();
+        for (reservation_id, component_id) in orphans {
+            // The allocation may be lost as well, the deletion is routed via res_to_vrm_component
+            self.res_to_vrm_component.entry(reservation_id).or_insert_with(|| component_id.clone());
+
+            if self.delete_task_at_component(reservation_id, None) {
+                log::info!(
+                    "VrmComponentManagerReconcileOrphan: ADC {} deleted orphaned workflow subtask {:?} on VrmComponent {}.",
+                    self.adc_id,
+                    self.reservation_store.get_name_for_key(reservation_id),
+                    component_id
+                );
+
+                self.not_committed_reservations.remove(&reservation_id);
+                self.reserve_times.remove(&reservation_id);
+                self.res_to_vrm_component.remove(&reservation_id);
+                report.removed.push((reservation_id, component_id));
+            } else {
+                log::error!(
+                    "VrmComponentManagerReconcileOrphanFailed: ADC {} failed to delete orphaned workflow subtask {:?} on VrmComponent {}.",
+                    self.adc_id,
+                    self.reservation_store.get_name_for_key(reservation_id),
+                    component_id
+                );
+                report.failed.push((reservation_id, component_id));
+            }
+        }
+
+        return report;
+    }
+}
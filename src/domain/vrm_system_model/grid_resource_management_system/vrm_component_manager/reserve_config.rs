@@ -0,0 +1,80 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::domain::vrm_system_model::reservation::reservation_sync_gate::{ReservationResult, ReservationSyncGate};
+use crate::domain::vrm_system_model::utils::config::{
+    RESERVE_BACKOFF_MULTIPLIER, RESERVE_GATE_TIMEOUT_MS, RESERVE_INITIAL_BACKOFF_MS, RESERVE_MAX_BACKOFF_MS, RESERVE_PROBE_RETRIES,
+};
+
+/// Clock, which is utilized to wait between two reserve retries.
+/// Allows to replace the wall clock, e.g. by a controllable clock in tests.
+pub trait BackoffClock: std::fmt::Debug + Send + Sync {
+    fn sleep(&self, duration: Duration);
+}
+
+/// Waits on the wall clock by blocking the current thread.
+#[derive(Debug, Default)]
+pub struct ThreadSleepClock;
+
+impl BackoffClock for ThreadSleepClock {
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// Configures how often and with which backoff the `VrmComponentManager` retries a failed reserve.
+///
+/// Between two retries the manager waits `initial_backoff * backoff_multiplier^retry`, limited by `max_backoff`.
+/// Hence, early failures are re-probed quickly, while persistent failures are retried less frequently.
+/// Fast unit tests can disable the retries (`probe_retries = 0`) and shorten the `gate_timeout`.
+#[derive(Debug, Clone)]
+pub struct ReserveConfig {
+    /// Number of probe rounds after the first failed reserve, 0 gives up after the first probe round.
+    pub probe_retries: usize,
+    pub initial_backoff: Duration,
+    pub backoff_multiplier: u32,
+    pub max_backoff: Duration,
+
+    /// Maximal time to wait at a `ReservationSyncGate` for the reserve answer of an AcI (see `wait_at_gate`).
+    pub gate_timeout: Duration,
+    pub clock: Arc<dyn BackoffClock>,
+}
+
+impl Default for ReserveConfig {
+    fn default() -> Self {
+        ReserveConfig {
+            probe_retries: RESERVE_PROBE_RETRIES,
+            initial_backoff: Duration::from_millis(RESERVE_INITIAL_BACKOFF_MS),
+            backoff_multiplier: RESERVE_BACKOFF_MULTIPLIER,
+            max_backoff: Duration::from_millis(RESERVE_MAX_BACKOFF_MS),
+            gate_timeout: Duration::from_millis(RESERVE_GATE_TIMEOUT_MS),
+            clock: Arc::new(ThreadSleepClock),
+        }
+    }
+}
+
+impl ReserveConfig {
+    /// Returns the backoff before the retry with the given index (starting at 0).
+    pub fn get_backoff(&self, retry: usize) -> Duration {
+        let factor = u32::try_from(retry).ok().and_then(|retry| self.backoff_multiplier.checked_pow(retry)).unwrap_or(u32::MAX);
+        return self.initial_backoff.checked_mul(factor).unwrap_or(self.max_backoff).min(self.max_backoff);
+    }
+
+    /// Returns the backoff before each of the `probe_retries` retries.
+    pub fn get_backoff_schedule(&self) -> Vec<Duration> {
+        return (0..self.probe_retries).map(|retry| self.get_backoff(retry)).collect();
+    }
+
+    /// Waits the backoff of the given retry on the configured clock.
+    pub fn wait_before_retry(&self, retry: usize) {
+        self.clock.sleep(self.get_backoff(retry));
+    }
+
+    /// Waits at most `gate_timeout` for the reserve answer of an AcI.
+    ///
+    /// # Returns
+    /// The answer of the AcI or a `ReservationState::Rejected` result, if the `gate_timeout` elapsed.
+    pub fn wait_at_gate(&self, gate: &ReservationSyncGate) -> ReservationResult {
+        return gate.wait_with_timeout(self.gate_timeout);
+    }
+}
@@ -1,22 +1,16 @@
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
-use lazy_static::lazy_static;
-
+use crate::domain::vrm_system_model::grid_resource_management_system::vrm_component_container::VrmComponentContainer;
 use crate::domain::vrm_system_model::grid_resource_management_system::vrm_component_order::VrmComponentOrder;
 use crate::domain::vrm_system_model::reservation::probe_reservations::{ProbeReservationComparator, ProbeReservations};
 use crate::domain::vrm_system_model::reservation::reservation::ReservationState;
 use crate::domain::vrm_system_model::reservation::reservation_store::ReservationId;
 use crate::domain::vrm_system_model::utils::id::{ComponentId, ShadowScheduleId};
+use crate::error::Error;
 
 use super::VrmComponentManager;
 
-// In the case where dummy dependencies where scheduled, a dummy VrmComponentId is utilized.
-// This happens in the cases, where network transfers can be skipped, as both endpoints are on the same node.
-lazy_static! {
-    pub static ref DUMMY_COMPONENT_ID: ComponentId = ComponentId::new("ADC INTERNAL JOB");
-}
-
 impl VrmComponentManager {
     pub fn probe(
         &mut self,
@@ -52,6 +46,54 @@ impl VrmComponentManager {
                 probe_results.add_probe_reservations(probe_reservations);
             }
         }
+        probe_results.validate_against_booking(&self.reservation_store);
+
+        if probe_results.is_empty() {
+            self.reservation_store.update_state(reservation_id, ReservationState::Rejected);
+        }
+
+        return probe_results;
+    }
+
+    /// Early-exit variant of `probe_all_components`, which trades the quality of the selection for latency.
+    ///
+    /// The VrmComponents are probed in the configured `vrm_component_order` (see `get_ordered_vrm_components`) and the
+    /// probing stops, as soon as `n` VrmComponents, which can handle the reservation, answered with at least one candidate
+    /// within the booking interval. VrmComponents without a valid candidate are not counted.
+    ///
+    /// # Arguments
+    /// * `reservation_id` - The reservation to probe.
+    /// * `n` - Maximal number of VrmComponents, whose candidates are collected.
+    /// * `vrm_component_order` - Order, in which the VrmComponents are probed (e.g. `ADC::vrm_component_order`).
+    ///
+    /// # Returns
+    /// The merged candidates of at most `n` VrmComponents. If no candidate was found, the reservation is rejected.
+    pub fn probe_first_n_components(&mut self, reservation_id: ReservationId, n: usize, vrm_component_order: VrmComponentOrder) -> ProbeReservations {
+        let mut probe_results = ProbeReservations::new(reservation_id, self.reservation_store.clone());
+        let mut number_of_probed_components = 0;
+
+        for component_id in self.get_ordered_vrm_components(vrm_component_order) {
+            if number_of_probed_components >= n {
+                break;
+            }
+
+            let Some(res_snapshot) = self.reservation_store.get_reservation_snapshot(reservation_id) else {
+                break;
+            };
+            let Some(container) = self.vrm_components.get_mut(&component_id) else {
+                continue;
+            };
+
+            if container.can_handel(res_snapshot) {
+                let mut probe_reservations = container.vrm_component.probe(reservation_id, None);
+                probe_reservations.validate_against_booking(&self.reservation_store);
+
+                if !probe_reservations.is_empty() {
+                    number_of_probed_components += 1;
+                    probe_results.add_probe_reservations(probe_reservations);
+                }
+            }
+        }
 
         if probe_results.is_empty() {
             self.reservation_store.update_state(reservation_id, ReservationState::Rejected);
@@ -68,10 +110,13 @@ impl VrmComponentManager {
     ) -> ReservationId {
         match self.vrm_components.get_mut(&component_id) {
             Some(container) => {
-                container.vrm_component.reserve(reservation_id, shadow_schedule_id);
+                container.vrm_component.reserve(reservation_id, shadow_schedule_id.clone());
 
-                if self.reservation_store.is_reservation_state_at_least(reservation_id, ReservationState::ReserveAnswer) {
+                if shadow_schedule_id.is_none()
+                    && self.reservation_store.is_reservation_state_at_least(reservation_id, ReservationState::ReserveAnswer)
+                {
                     self.not_committed_reservations.insert(reservation_id, component_id);
+                    self.reserve_times.insert(reservation_id, self.simulator.get_system_time_s());
                 }
 
                 return reservation_id;
@@ -104,12 +149,44 @@ impl VrmComponentManager {
         }
     }
 
+    /// Checks on the local view (schedule) of the VrmComponent, if all reservations fit together in their time windows.
+    pub fn can_reserve_all_in_local_schedule(&mut self, component_id: ComponentId, reservations: &[(ReservationId, (i64, i64))]) -> bool {
+        match self.vrm_components.get_mut(&component_id) {
+            Some(container) => container.schedule.can_reserve_all(reservations),
+            None => {
+                log::error!(
+                    "ComponentManagerHasNotFoundGridComponent: ComponentManager of ADC {}, requested component {} for can_reserve_all request of {} reservations on schedule",
+                    self.adc_id,
+                    component_id,
+                    reservations.len(),
+                );
+                return false;
+            }
+        }
+    }
+
+    /// Reserves all reservations in the local view (schedule) of the VrmComponent or none of them.
+    pub fn reserve_all_or_none_in_local_schedule(&mut self, component_id: ComponentId, reservations: &[(ReservationId, (i64, i64))]) -> bool {
+        match self.vrm_components.get_mut(&component_id) {
+            Some(container) => container.schedule.reserve_all_or_none(reservations),
+            None => {
+                log::error!(
+                    "ComponentManagerHasNotFoundGridComponent: ComponentManager of ADC {}, requested component {} for reserve_all_or_none request of {} reservations on schedule",
+                    self.adc_id,
+                    component_id,
+                    reservations.len(),
+                );
+                return false;
+            }
+        }
+    }
+
     // Handles only single reservation and no child reservations (deletes also workflow reservation but not the related children)
     pub fn delete_reservation(&mut self, reservation_id: &ReservationId, shadow_schedule_id: Option<ShadowScheduleId>) -> ReservationId {
         match self.res_to_vrm_component.get(reservation_id) {
             Some(component_id) => {
                 // No real reservation
-                if component_id.compare(&DUMMY_COMPONENT_ID) {
+                if component_id.is_dummy() {
                     self.reservation_store.update_state(*reservation_id, ReservationState::Deleted);
                     return *reservation_id;
                 }
@@ -154,7 +231,7 @@ impl VrmComponentManager {
         match target_component {
             Some(component_id) => {
                 // No Real Task
-                if component_id == *DUMMY_COMPONENT_ID {
+                if component_id.is_dummy() {
                     if let Some(sid) = &shadow_schedule_id {
                         if let Some((_, store)) = self.shadow_schedule_reservations.get(&sid) {
                             store.update_state(reservation_id, ReservationState::Deleted);
@@ -165,7 +242,13 @@ impl VrmComponentManager {
                     return true;
                 }
 
-                let container = self.get_vrm_component_container_mut(component_id.clone());
+                let container = match self.try_get_vrm_component_container_mut(&component_id) {
+                    Ok(container) => container,
+                    Err(error) => {
+                        log::error!("VrmComponentManagerDeleteFailed: Deletion of reservation {:?} failed: {}", reservation_id, error);
+                        return false;
+                    }
+                };
 
                 container.vrm_component.delete(reservation_id, shadow_schedule_id.clone());
 
@@ -184,8 +267,9 @@ impl VrmComponentManager {
 
                 if is_deleted {
                     // Update Local view
-                    let container = self.get_vrm_component_container_mut(component_id);
-                    container.schedule.delete_reservation(reservation_id);
+                    if let Ok(container) = self.try_get_vrm_component_container_mut(&component_id) {
+                        container.schedule.delete_reservation(reservation_id);
+                    }
 
                     // Cleanup Mapping
                     if let Some(sid) = &shadow_schedule_id {
@@ -221,20 +305,30 @@ impl VrmComponentManager {
     /// Returns `true` if the component successfully committed the reservation.
     pub fn commit_at_component(&mut self, reservation_id: ReservationId, component_id: ComponentId) -> bool {
         // Is dummy task/ "Internal task"
-        if component_id == *DUMMY_COMPONENT_ID {
+        if component_id.is_dummy() {
             self.reservation_store.update_state(reservation_id, ReservationState::Committed);
             return true;
         }
 
-        let container = self.get_vrm_component_container_mut(component_id.clone());
+        let container = match self.try_get_vrm_component_container_mut(&component_id) {
+            Ok(container) => container,
+            Err(error) => {
+                log::error!("VrmComponentManagerCommitFailed: Commit of reservation {:?} failed: {}", reservation_id, error);
+                self.reservation_store.update_state(reservation_id, ReservationState::Rejected);
+                return false;
+            }
+        };
+
         if container.vrm_component.commit(reservation_id) {
-            self.update_commit_tracking(reservation_id, component_id);
+            self.update_commit_tracking(reservation_id, component_id.clone());
+            self.reset_failures(component_id);
             return true;
         }
 
         // If commit fails, clean up local schedule and global mapping
         container.schedule.delete_reservation(reservation_id);
         self.reservation_store.update_state(reservation_id, ReservationState::Rejected);
+        self.increment_failure(component_id);
         return false;
     }
 
@@ -248,10 +342,95 @@ impl VrmComponentManager {
         }
     }
 
+    /// Rolls back the subtasks of a workflow, whose commit failed. Each subtask is deleted at its VrmComponent, which
+    /// also removes already committed subtasks from the local RMS, and transitions into `ReservationState::Rejected`.
+    ///
+    /// # Returns
+    /// The subtasks, whose deletion at the VrmComponent failed.
+    fn rollback_workflow_subtasks(&mut self, subtask_ids: &[ReservationId]) -> Vec<ReservationId> {
+        let mut not_rolled_back = Vec::new();
+
+        for subtask_id in subtask_ids {
+            if self.get_handler_id(*subtask_id).is_some() && !self.delete_task_at_component(*subtask_id, None) {
+                not_rolled_back.push(*subtask_id);
+            }
+            self.not_committed_reservations.remove(subtask_id);
+            self.committed_reservations.remove(subtask_id);
+            self.reservation_store.update_state(*subtask_id, ReservationState::Rejected);
+        }
+        return not_rolled_back;
+    }
+
+    /// Commits all subtasks of the workflow (see `workflow_subtasks`) as one transaction.
+    ///
+    /// The subtasks are committed one by one at their VrmComponents. If a commit fails, all other subtasks
+    /// (the already committed ones and the not yet committed ones) are rolled back with `rollback_workflow_subtasks`
+    /// and the workflow is rejected, so that the workflow is never left half-committed.
+    ///
+    /// # Returns
+    /// `Ok(())` only if all subtasks reached `ReservationState::Committed`, `Error::WorkflowCommitFailed` with the
+    /// first failing subtask otherwise, `Error::WorkflowRollbackFailed` if additionally the rollback of a subtask
+    /// failed, or `Error::WorkflowNotTracked` if no subtasks are registered for the workflow.
+    pub fn commit_workflow(&mut self, workflow_id: ReservationId) -> Result<(), Error> {
+        let Some(subtask_ids) = self.workflow_subtasks.get(&workflow_id).cloned() else {
+            return Err(Error::WorkflowNotTracked { workflow: workflow_id });
+        };
+
+        for (index, subtask_id) in subtask_ids.iter().enumerate() {
+            // E.g. dummy dependencies, which were already committed during the reserve
+            if self.reservation_store.get_state(*subtask_id) == ReservationState::Committed {
+                continue;
+            }
+
+            let is_committed = match self.get_handler_id(*subtask_id) {
+                Some(component_id) => self.commit_at_component(*subtask_id, component_id),
+                None => {
+                    self.reservation_store.update_state(*subtask_id, ReservationState::Rejected);
+                    false
+                }
+            };
+
+            if is_committed && self.reservation_store.get_state(*subtask_id) == ReservationState::Committed {
+                continue;
+            }
+
+            log::error!(
+                "VrmComponentManagerWorkflowCommitFailed: ADC {} failed to commit subtask {:?} of workflow {:?}, all subtasks are rolled back.",
+                self.adc_id,
+                self.reservation_store.get_name_for_key(*subtask_id),
+                self.reservation_store.get_name_for_key(workflow_id)
+            );
+
+            // The failed subtask was already cleaned up by commit_at_component
+            let mut rollback_ids = subtask_ids[..index].to_vec();
+            rollback_ids.extend_from_slice(&subtask_ids[index + 1..]);
+            let not_rolled_back = self.rollback_workflow_subtasks(&rollback_ids);
+            self.reservation_store.update_state(workflow_id, ReservationState::Rejected);
+
+            if !not_rolled_back.is_empty() {
+                log::error!(
+                    "VrmComponentManagerWorkflowRollbackFailed: ADC {} failed to roll back the subtasks {:?} of workflow {:?}.",
+                    self.adc_id,
+                    not_rolled_back.iter().map(|id| self.reservation_store.get_name_for_key(*id)).collect::<Vec<_>>(),
+                    self.reservation_store.get_name_for_key(workflow_id)
+                );
+                return Err(Error::WorkflowRollbackFailed { workflow: workflow_id, subtask: *subtask_id, not_rolled_back });
+            }
+            return Err(Error::WorkflowCommitFailed { workflow: workflow_id, subtask: *subtask_id });
+        }
+        return Ok(());
+    }
+
     /// Probes all available VrmComponents and selects the best candidate based on the provided comparison function.
     ///
     /// This implements a "Best Fit" strategy, useful for optimizing resource utilization or
     /// meeting Earliest Finish Time (EFT) constraints.
+    /// If the reserve of the best candidate fails, all VrmComponents are probed again after the backoff
+    /// of the `ReserveConfig`, up to `ReserveConfig::probe_retries` times. If a probe round finds no candidate at all,
+    /// a retry can not succeed either, hence the reservation is given up without backoff.
+    ///
+    /// The answer of the selected VrmComponent is awaited at a `ReservationSyncGate` of the `sync_registry` for at most
+    /// `ReserveConfig::gate_timeout`. A VrmComponent, which does not answer in time, gets a failure (see `increment_failure`).
     pub fn reserve_task_at_best_vrm_component<F>(
         &mut self,
         reservation_id: ReservationId,
@@ -263,71 +442,180 @@ impl VrmComponentManager {
     where
         F: Fn(ReservationId, ReservationId) -> Ordering + 'static,
     {
-        let try_n_probe_reservations = 5;
-        let mut probe_reservations = ProbeReservations::new(reservation_id, self.reservation_store.clone());
+        for retry in 0..=self.reserve_config.probe_retries {
+            if retry > 0 {
+                log::debug!(
+                    "VrmComponentManagerReserveRetry: ADC {} retries reserve of reservation {:?} ({}/{}) after a backoff of {:?}.",
+                    self.adc_id,
+                    self.reservation_store.get_name_for_key(reservation_id),
+                    retry,
+                    self.reserve_config.probe_retries,
+                    self.reserve_config.get_backoff(retry - 1)
+                );
+                self.reserve_config.wait_before_retry(retry - 1);
+            }
 
-        for component_id in self.get_random_ordered_vrm_components() {
-            // Get Reservation Clone of the ShadowScheduleId or MasterSchedule
-            let res_snapshot = if let Some(sid) = &shadow_schedule_id {
-                if let Some((_, store)) = self.shadow_schedule_reservations.get(sid) {
-                    store.get_reservation_snapshot(reservation_id)
-                } else {
-                    self.reservation_store.get_reservation_snapshot(reservation_id)
-                }
-            } else {
-                self.reservation_store.get_reservation_snapshot(reservation_id)
-            };
+            let mut probe_reservations = self.probe_all_vrm_components(reservation_id, shadow_schedule_id.clone());
+            probe_reservations.validate_against_booking(&self.reservation_store);
+            let (num_of_candidates, total_candidate_capacity, best_finish_time) =
+                (probe_reservations.len(), probe_reservations.total_candidate_capacity(), probe_reservations.best_finish_time());
 
-            if let Some(res) = res_snapshot {
-                if self.can_component_handel(component_id.clone(), res) {
-                    probe_reservations
-                        .add_probe_reservations(self.get_vrm_component_mut(component_id.clone()).probe(reservation_id, shadow_schedule_id.clone()));
-                }
+            if num_of_candidates == 0 {
+                log::debug!(
+                    "VrmComponentManagerNoCandidates: ADC {} found no candidate for reservation {:?}, the reserve is not retried.",
+                    self.adc_id,
+                    self.reservation_store.get_name_for_key(reservation_id)
+                );
+                return None;
             }
-        }
 
-        for _ in 0..=try_n_probe_reservations {
             if let Some((component_id, shadow_schedule_id)) = probe_reservations.prompt_best(reservation_id, probe_reservation_comparator.clone()) {
-                self.reserve(component_id, reservation_id, shadow_schedule_id);
-
-                // TODO
-                todo!();
-                // TODO
                 // 1. Prepare the gate
-                // let gate = self.sync_registry.create_gate(reservation_id);
+                let gate = self.sync_registry.create_gate(reservation_id);
 
-                // 2. Trigger the AcI by updating the store
-                self.reservation_store.update_state(reservation_id, ReservationState::ReserveProbeReservation);
+                // 2. Trigger the VrmComponent, an answered reserve (not ReserveProbeReservation anymore) opens the gate
+                let reserve_res_id = self.reserve(component_id.clone(), reservation_id, shadow_schedule_id.clone());
+                let answer_state = self.get_state(reserve_res_id, &shadow_schedule_id);
+                if answer_state != ReservationState::ReserveProbeReservation {
+                    gate.notify(answer_state, component_id.clone());
+                }
 
-                // TODO Add parameter to a config
-                // 3. BLOCK here. This thread sleeps until AcI calls notify().
-                // let result = gate.wait_with_timeout(std::time::Duration::from_secs(15));
+                // 3. BLOCK here, until the VrmComponent answered or the gate_timeout of the ReserveConfig elapsed
+                let result = self.reserve_config.wait_at_gate(&gate);
 
                 // 4. Clean up the registry
-                // self.sync_registry.remove_gate(reservation_id);
-
-                // Check if update of local schedule was successful
-                // if result.state == ReservationState::ReserveAnswer {
-                //     log::info!("Reservation {:?} successful!", reservation_id);
-
-                //     // Register new schedule Sub-Task
-                //     // Update grid_component_res_database for rollback and for ADC to keep track
-                //     // Update Transaction Log
-                //     if grid_component_res_database.contains_key(&reservation_id) {
-                //         log::error!(
-                //             "ErrorReservationWasReservedInMultipleGridComponents: The reservation {:?} was multiple times to the GirdComponent {} submitted.",
-                //             self.reservation_store.get_name_for_key(reservation_id),
-                //             result.aci_id.clone().unwrap(),
-                //         );
-                //     }
-                //     grid_component_res_database.insert(reservation_id, result.aci_id.unwrap());
-                //     return Some(reservation_id);
-                // }
+                self.sync_registry.remove_gate(reservation_id);
+
+                // A VrmComponent, which did not answer within the gate_timeout, is faulty, in contrast to a VrmComponent,
+                // which rejected the reserve (e.g. because it is full). Hence, only the missing answer counts as failure.
+                if result.aci_id.is_none() {
+                    log::debug!(
+                        "VrmComponentManagerReserveNotAnswered: Component {} of ADC {} did not answer the reserve of reservation {:?}.",
+                        component_id,
+                        self.adc_id,
+                        self.reservation_store.get_name_for_key(reservation_id)
+                    );
+                    self.increment_failure(component_id.clone());
+                }
+
+                if result.aci_id.is_some() && self.is_reserved(reserve_res_id, &shadow_schedule_id) {
+                    log::info!("Reservation {:?} successful!", reservation_id);
+
+                    // Register new schedule Sub-Task
+                    // Update grid_component_res_database for rollback and for ADC to keep track
+                    if grid_component_res_database.contains_key(&reservation_id) {
+                        log::error!(
+                            "ErrorReservationWasReservedInMultipleGridComponents: The reservation {:?} was multiple times to the GirdComponent {} submitted.",
+                            self.reservation_store.get_name_for_key(reservation_id),
+                            component_id,
+                        );
+                    }
+                    grid_component_res_database.insert(reservation_id, component_id.clone());
+
+                    // Update local schedule (only the master schedule is mirrored)
+                    if shadow_schedule_id.is_none() {
+                        self.reserve_without_check(component_id, reserve_res_id);
+                    }
+                    return Some(reserve_res_id);
+                }
             }
+
+            log::debug!(
+                "VrmComponentManagerProbeRoundFailed: ADC {} could not reserve reservation {:?} with {} candidates (total capacity: {}, best finish time: {:?}).",
+                self.adc_id,
+                self.reservation_store.get_name_for_key(reservation_id),
+                num_of_candidates,
+                total_candidate_capacity,
+                best_finish_time
+            );
         }
         return None;
     }
 
+    /// Probes all VrmComponents, which can handle the reservation, on the master schedule or the provided shadow schedule.
+    ///
+    /// The VrmComponents are probed concurrently in chunks of `max_probe_threads` (one scoped thread per VrmComponent
+    /// of a chunk), so that the round-trips to remote AcIs overlap without spawning a thread per VrmComponent at once.
+    /// With `max_probe_threads` <= 1 the VrmComponents are probed inline. The results are merged in the order of
+    /// `get_random_ordered_vrm_components` afterwards, hence the aggregated `ProbeReservations` do not depend on the
+    /// order, in which the probes are answered.
+    pub fn probe_all_vrm_components(&mut self, reservation_id: ReservationId, shadow_schedule_id: Option<ShadowScheduleId>) -> ProbeReservations {
+        let mut probe_reservations = ProbeReservations::new(reservation_id, self.reservation_store.clone());
+
+        // Get Reservation Clone of the ShadowScheduleId or MasterSchedule
+        let res_snapshot = if let Some(sid) = &shadow_schedule_id {
+            if let Some((_, store)) = self.shadow_schedule_reservations.get(sid) {
+                store.get_reservation_snapshot(reservation_id)
+            } else {
+                self.reservation_store.get_reservation_snapshot(reservation_id)
+            }
+        } else {
+            self.reservation_store.get_reservation_snapshot(reservation_id)
+        };
+
+        let Some(res_snapshot) = res_snapshot else {
+            return probe_reservations;
+        };
+
+        let component_order = self.get_random_ordered_vrm_components();
+        let max_probe_threads = self.max_probe_threads;
+
+        let mut containers: Vec<_> = self.vrm_components.iter_mut().filter(|(component_id, _)| component_order.contains(component_id)).collect();
+
+        let probe = |component_id: &ComponentId, container: &mut VrmComponentContainer| {
+            if container.can_handel(res_snapshot.clone()) {
+                return Some((component_id.clone(), container.vrm_component.probe(reservation_id, shadow_schedule_id.clone())));
+            }
+            return None;
+        };
+
+        let mut probe_results: HashMap<ComponentId, ProbeReservations> = HashMap::new();
+        if max_probe_threads <= 1 {
+            probe_results.extend(containers.into_iter().filter_map(|(component_id, container)| probe(component_id, container)));
+        } else {
+            for chunk in containers.chunks_mut(max_probe_threads) {
+                // Each thread borrows a different container mutably, hence no synchronization is required
+                std::thread::scope(|scope| {
+                    let probe = &probe;
+                    let probe_handles: Vec<_> =
+                        chunk.iter_mut().map(|(component_id, container)| scope.spawn(move || probe(component_id, container))).collect();
+
+                    probe_results.extend(
+                        probe_handles.into_iter().filter_map(|probe_handle| probe_handle.join().expect("Probe thread of VrmComponent panicked.")),
+                    );
+                });
+            }
+        }
+
+        for component_id in component_order {
+            if let Some(component_probe_reservations) = probe_results.remove(&component_id) {
+                probe_reservations.add_probe_reservations(component_probe_reservations);
+            }
+        }
+        return probe_reservations;
+    }
+
+    /// Returns the state of the reservation in the store of the master schedule or the provided shadow schedule.
+    fn get_state(&self, reservation_id: ReservationId, shadow_schedule_id: &Option<ShadowScheduleId>) -> ReservationState {
+        if let Some(sid) = shadow_schedule_id {
+            if let Some((_, store)) = self.shadow_schedule_reservations.get(sid) {
+                return store.get_state(reservation_id);
+            }
+        }
+        return self.reservation_store.get_state(reservation_id);
+    }
+
+    /// Checks in the store of the master schedule or the provided shadow schedule, if the reservation was reserved.
+    fn is_reserved(&self, reservation_id: ReservationId, shadow_schedule_id: &Option<ShadowScheduleId>) -> bool {
+        if let Some(sid) = shadow_schedule_id {
+            if let Some((_, store)) = self.shadow_schedule_reservations.get(sid) {
+                return store.is_reservation_state_at_least(reservation_id, ReservationState::ReserveAnswer);
+            }
+            return false;
+        }
+        return self.reservation_store.is_reservation_state_at_least(reservation_id, ReservationState::ReserveAnswer);
+    }
+
     /// Submits a task to the first VrmComponent that accepts the reservation based on the defined `VrmComponentOrder`.
     pub fn reserve_task_at_first_grid_component(
         &mut self,
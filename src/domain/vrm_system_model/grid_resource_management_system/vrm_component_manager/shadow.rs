@@ -1,8 +1,21 @@
+use std::collections::HashMap;
+
 use crate::domain::vrm_system_model::reservation::reservation::ReservationState;
-use crate::domain::vrm_system_model::utils::id::ShadowScheduleId;
+use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use crate::domain::vrm_system_model::utils::id::{ComponentId, ShadowScheduleId};
 
 use super::VrmComponentManager;
 
+/// A reservation, which a shadow schedule placed on a VrmComponent in the assigned window [start, end).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShadowPlacement {
+    pub reservation_id: ReservationId,
+    pub component_id: ComponentId,
+    pub start: i64,
+    pub end: i64,
+    pub capacity: i64,
+}
+
 impl VrmComponentManager {
     /// Creates a new Shadow Schedule environment.
     ///
@@ -33,7 +46,8 @@ impl VrmComponentManager {
         }
 
         // 3. Store the shadow context
-        self.shadow_schedule_reservations.insert(shadow_schedule_id, (shadow_map, shadow_store));
+        self.shadow_schedule_reservations.insert(shadow_schedule_id.clone(), (shadow_map, shadow_store));
+        self.shadow_schedule_intervening_commits.insert(shadow_schedule_id, Vec::new());
 
         return true;
     }
@@ -51,19 +65,37 @@ impl VrmComponentManager {
 
         // 2. Remove local shadow context
         self.shadow_schedule_reservations.remove(&shadow_schedule_id);
+        self.shadow_schedule_intervening_commits.remove(&shadow_schedule_id);
 
         return true;
     }
 
     /// Commits the Shadow Schedule to be the new Master Schedule.
     ///
-    /// This replaces the live state with the shadow state.
+    /// This replaces the live state with the shadow state. The commit is refused, if the shadow schedule together
+    /// with the shadow schedules committed after its creation would overcommit a VrmComponent (see
+    /// `shadow_schedule_conflicts`). On success, the placements of the shadow schedule are recorded for all other open
+    /// shadow schedules, so that their later commits are checked against them.
     pub fn commit_shadow_schedule(&mut self, shadow_schedule_id: ShadowScheduleId) -> bool {
         if !self.shadow_schedule_reservations.contains_key(&shadow_schedule_id) {
             log::error!("Cannot commit shadow schedule {:?} as it does not exist.", shadow_schedule_id);
             return false;
         }
 
+        let conflicts = self.shadow_schedule_conflicts(shadow_schedule_id.clone());
+        if !conflicts.is_empty() {
+            log::error!(
+                "VrmComponentManagerShadowScheduleConflict: The commit of shadow schedule {:?} of ADC {} was refused, because the reservations {:?} overcommit a VrmComponent together with the shadow schedules committed in between.",
+                shadow_schedule_id,
+                self.adc_id,
+                conflicts
+            );
+            return false;
+        }
+
+        let (shadow_map, shadow_store) = &self.shadow_schedule_reservations[&shadow_schedule_id];
+        let committed_placements = self.get_shadow_only_placements(shadow_map, shadow_store);
+
         // 1. Propagate commit to all children first
         for container in self.vrm_components.values_mut() {
             if !container.vrm_component.commit_shadow_schedule(shadow_schedule_id.clone()) {
@@ -74,11 +106,17 @@ impl VrmComponentManager {
 
         // 2. Atomic Switch: Replace Master State with Shadow State
         let (shadow_map, shadow_store) = self.shadow_schedule_reservations.remove(&shadow_schedule_id).unwrap();
+        self.shadow_schedule_intervening_commits.remove(&shadow_schedule_id);
+        for intervening_commits in self.shadow_schedule_intervening_commits.values_mut() {
+            intervening_commits.extend(committed_placements.iter().cloned());
+        }
 
         // Update the component mapping (Who handles what)
         self.res_to_vrm_component = shadow_map;
 
         // Update the reservation store (The source of truth for reservation states)
+        // The shadow store was created without listeners, so move them over from the master store
+        shadow_store.add_listeners(self.reservation_store.take_listeners());
         self.reservation_store = shadow_store;
 
         // Rebuild derived mappings (committed/not_committed) based on the new store state
@@ -101,4 +139,78 @@ impl VrmComponentManager {
 
         return true;
     }
+
+    /// Detects reservations of the shadow schedule, which would overcommit a VrmComponent, because other shadow
+    /// schedules were committed after its creation (see `shadow_schedule_intervening_commits`).
+    ///
+    /// Only reservations, which were placed in the shadow schedule (i.e. are not tracked on the same VrmComponent by
+    /// the master schedule), are considered. For each VrmComponent the capacities of these reservations and of the
+    /// placements committed in between are summed up per time slot, a slot whose sum exceeds the total capacity of
+    /// the VrmComponent is a conflict.
+    ///
+    /// # Returns
+    /// The sorted ids of all reservations, which are active in a conflicting slot. It is empty if there is no
+    /// conflict or if the shadow schedule does not exist.
+    pub fn shadow_schedule_conflicts(&self, shadow_schedule_id: ShadowScheduleId) -> Vec<ReservationId> {
+        let (Some((shadow_map, shadow_store)), Some(intervening_commits)) =
+            (self.shadow_schedule_reservations.get(&shadow_schedule_id), self.shadow_schedule_intervening_commits.get(&shadow_schedule_id))
+        else {
+            log::warn!("VrmComponentManagerShadowScheduleNotFound: ADC {} has no shadow schedule {:?}.", self.adc_id, shadow_schedule_id);
+            return Vec::new();
+        };
+
+        if intervening_commits.is_empty() {
+            return Vec::new();
+        }
+
+        let mut placements = self.get_shadow_only_placements(shadow_map, shadow_store);
+        placements.extend(intervening_commits.iter().cloned());
+        let mut conflicts: Vec<ReservationId> = Vec::new();
+
+        for (component_id, container) in &self.vrm_components {
+            let component_placements: Vec<&ShadowPlacement> = placements.iter().filter(|placement| placement.component_id == *component_id).collect();
+            let capacity = container.vrm_component.get_total_capacity();
+
+            // The load of a VrmComponent only increases at the start of a placement, hence only these slots are checked
+            for slot_start in component_placements.iter().map(|placement| placement.start) {
+                let active: Vec<&&ShadowPlacement> =
+                    component_placements.iter().filter(|placement| placement.start <= slot_start && slot_start < placement.end).collect();
+
+                if active.iter().map(|placement| placement.capacity).sum::<i64>() > capacity {
+                    conflicts.extend(active.iter().map(|placement| placement.reservation_id));
+                }
+            }
+        }
+
+        conflicts.sort();
+        conflicts.dedup();
+        return conflicts;
+    }
+
+    /// Returns the reservations, which are placed by the shadow schedule on a real VrmComponent other than in the
+    /// master schedule. Terminal reservations (e.g. deleted) are skipped. Reservations, which were created after the
+    /// snapshot of the shadow store, are read from the master store.
+    fn get_shadow_only_placements(&self, shadow_map: &HashMap<ReservationId, ComponentId>, shadow_store: &ReservationStore) -> Vec<ShadowPlacement> {
+        let mut placements = Vec::new();
+
+        for (reservation_id, component_id) in shadow_map {
+            if component_id.is_dummy() || self.res_to_vrm_component.get(reservation_id) == Some(component_id) {
+                continue;
+            }
+
+            let store = if shadow_store.contains(*reservation_id) { shadow_store } else { &self.reservation_store };
+            if store.get_state(*reservation_id).is_terminal() {
+                continue;
+            }
+
+            placements.push(ShadowPlacement {
+                reservation_id: *reservation_id,
+                component_id: component_id.clone(),
+                start: store.get_assigned_start(*reservation_id),
+                end: store.get_assigned_end(*reservation_id),
+                capacity: store.get_reserved_capacity(*reservation_id),
+            });
+        }
+        return placements;
+    }
 }
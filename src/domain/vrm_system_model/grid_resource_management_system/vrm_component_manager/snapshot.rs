@@ -0,0 +1,107 @@
+use crate::domain::vrm_system_model::reservation::reservation::ReservationTrait;
+use crate::domain::vrm_system_model::reservation::reservation_store::ReservationId;
+use crate::domain::vrm_system_model::utils::id::{DataDependencyId, SyncDependencyId, WorkflowNodeId};
+use crate::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use super::VrmComponentManager;
+
+/// Placeholder of the snapshot for a sub-reservation, which is not tracked by the `VrmComponentManager`.
+const UNPLACED: &str = "unplaced";
+
+impl VrmComponentManager {
+    /// Creates a canonical textual snapshot of the placement of the workflow on the master schedule.
+    ///
+    /// The snapshot contains one line for the workflow itself, one line per WorkflowNode (component, window, capacity)
+    /// and one line per DataDependency and SyncDependency (component, window, route). All entries are sorted by id
+    /// and only names are used, hence the snapshot of equal placements is equal and can be compared against a golden file.
+    ///
+    /// # Arguments
+    /// * `workflow` - The workflow, whose sub-reservations are tracked by this `VrmComponentManager`.
+    ///
+    /// # Returns
+    /// The snapshot, each entry terminated by a newline.
+    pub fn workflow_placement_snapshot(&self, workflow: &Workflow) -> String {
+        let mut snapshot = format!(
+            "workflow {} state={:?} window=[{}, {}]\n",
+            workflow.get_name(),
+            workflow.get_state(),
+            workflow.get_assigned_start(),
+            workflow.get_assigned_end()
+        );
+
+        let mut node_ids: Vec<&WorkflowNodeId> = workflow.nodes.keys().collect();
+        node_ids.sort();
+
+        for node_id in node_ids {
+            let reservation_id = workflow.nodes[node_id].reservation_id;
+            snapshot.push_str(&format!(
+                "node {} component={} window={} capacity={}\n",
+                node_id,
+                self.get_snapshot_component(reservation_id),
+                self.get_snapshot_window(reservation_id),
+                self.reservation_store.get_reserved_capacity(reservation_id)
+            ));
+        }
+
+        let mut data_dep_ids: Vec<&DataDependencyId> = workflow.data_dependencies.keys().collect();
+        data_dep_ids.sort();
+
+        for data_dep_id in data_dep_ids {
+            let data_dep = &workflow.data_dependencies[data_dep_id];
+            if let (Some(source), Some(target)) = (&data_dep.source_node, &data_dep.target_node) {
+                snapshot.push_str(&format!(
+                    "data {} -> {} component={} window={} route={}\n",
+                    source,
+                    target,
+                    self.get_snapshot_component(data_dep.reservation_id),
+                    self.get_snapshot_window(data_dep.reservation_id),
+                    self.get_snapshot_route(data_dep.reservation_id)
+                ));
+            }
+        }
+
+        let mut sync_dep_ids: Vec<&SyncDependencyId> = workflow.sync_dependencies.keys().collect();
+        sync_dep_ids.sort();
+
+        for sync_dep_id in sync_dep_ids {
+            let sync_dep = &workflow.sync_dependencies[sync_dep_id];
+            if let (Some(source), Some(target)) = (&sync_dep.source_node, &sync_dep.target_node) {
+                snapshot.push_str(&format!(
+                    "sync {} -> {} component={} window={} route={}\n",
+                    source,
+                    target,
+                    self.get_snapshot_component(sync_dep.reservation_id),
+                    self.get_snapshot_window(sync_dep.reservation_id),
+                    self.get_snapshot_route(sync_dep.reservation_id)
+                ));
+            }
+        }
+
+        return snapshot;
+    }
+
+    fn get_snapshot_component(&self, reservation_id: ReservationId) -> String {
+        return self
+            .res_to_vrm_component
+            .get(&reservation_id)
+            .or_else(|| self.not_committed_reservations.get(&reservation_id))
+            .or_else(|| self.committed_reservations.get(&reservation_id))
+            .map(|component_id| component_id.to_string())
+            .unwrap_or_else(|| UNPLACED.to_string());
+    }
+
+    fn get_snapshot_window(&self, reservation_id: ReservationId) -> String {
+        return format!(
+            "[{}, {}]",
+            self.reservation_store.get_assigned_start(reservation_id),
+            self.reservation_store.get_assigned_end(reservation_id)
+        );
+    }
+
+    fn get_snapshot_route(&self, reservation_id: ReservationId) -> String {
+        let start_point = self.reservation_store.get_start_point(reservation_id).map(|router_id| router_id.to_string());
+        let end_point = self.reservation_store.get_end_point(reservation_id).map(|router_id| router_id.to_string());
+
+        return format!("{}->{}", start_point.unwrap_or_else(|| "-".to_string()), end_point.unwrap_or_else(|| "-".to_string()));
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::domain::vrm_system_model::reservation::reservation::ReservationState;
 use crate::domain::vrm_system_model::reservation::reservation_store::ReservationId;
@@ -50,6 +50,41 @@ impl VrmComponentManager {
         }
     }
 
+    /// Replaces the tracking of a rescheduled workflow with the provided allocations.
+    /// In contrast to `register_workflow_subtasks`, the allocations may contain already committed subtasks.
+    pub fn replace_workflow_subtasks(&mut self, workflow_id: ReservationId, allocations: &HashMap<ReservationId, ComponentId>) {
+        self.remove_workflow_tracking(&workflow_id);
+
+        self.res_to_vrm_component.extend(allocations.clone());
+        self.workflow_subtasks.insert(workflow_id, allocations.keys().cloned().collect());
+        for subtask_id in allocations.keys() {
+            self.reverse_workflow_subtasks.insert(*subtask_id, workflow_id);
+        }
+    }
+
+    /// Merges a "transaction map" (from a Workflow Scheduler) into the allocation map of a shadow schedule.
+    /// The master tracking (`res_to_vrm_component`, `workflow_subtasks`, ...) stays untouched.
+    pub fn register_shadow_workflow_subtasks(
+        &mut self,
+        shadow_schedule_id: ShadowScheduleId,
+        allocations: &HashMap<ReservationId, ComponentId>,
+    ) -> bool {
+        match self.shadow_schedule_reservations.get_mut(&shadow_schedule_id) {
+            Some((shadow_map, _)) => {
+                shadow_map.extend(allocations.clone());
+                return true;
+            }
+            None => {
+                log::error!(
+                    "ErrorVrmComponentManagerShadowScheduleWasNotFound: The registration of workflow subtasks for ADC {} failed, because the ShadowSchedule {:?} does not exist.",
+                    self.adc_id,
+                    shadow_schedule_id
+                );
+                return false;
+            }
+        }
+    }
+
     /// Retrieves the ComponentId responsible for a specific reservation.
     pub fn get_handler_id(&self, reservation_id: ReservationId) -> Option<ComponentId> {
         self.res_to_vrm_component.get(&reservation_id).cloned()
@@ -60,6 +95,29 @@ impl VrmComponentManager {
         self.reverse_workflow_subtasks.get(&subtask_id).cloned()
     }
 
+    /// Returns the ids of all reservations tracked by this manager (allocations, commit states and workflow subtasks).
+    pub fn get_tracked_reservation_ids(&self) -> HashSet<ReservationId> {
+        let mut tracked_reservation_ids: HashSet<ReservationId> = HashSet::new();
+        tracked_reservation_ids.extend(self.res_to_vrm_component.keys());
+        tracked_reservation_ids.extend(self.committed_reservations.keys());
+        tracked_reservation_ids.extend(self.not_committed_reservations.keys());
+        tracked_reservation_ids.extend(self.reverse_workflow_subtasks.keys());
+        for (workflow_id, subtask_ids) in &self.workflow_subtasks {
+            tracked_reservation_ids.insert(*workflow_id);
+            tracked_reservation_ids.extend(subtask_ids);
+        }
+        return tracked_reservation_ids;
+    }
+
+    /// Garbage collects the terminal reservations of the reservation store, which are neither tracked by this manager
+    /// nor subtasks of a live workflow (see `ReservationStore::gc_terminal_except`).
+    ///
+    /// # Returns
+    /// The number of removed reservations.
+    pub fn gc_terminal_reservations(&self) -> usize {
+        return self.reservation_store.gc_terminal_except(&self.get_tracked_reservation_ids());
+    }
+
     /// Removes tracking for a reservation.
     /// If it's a workflow, this might need to clean up children, or children cleanup calls this.
     /// Currently, this removes the specific ID from the allocation map.
@@ -114,6 +172,7 @@ impl VrmComponentManager {
     ) {
         if shadow_schedule_id.is_none() {
             let old_value = self.not_committed_reservations.insert(reservation_id, component_id.clone());
+            self.reserve_times.insert(reservation_id, self.simulator.get_system_time_s());
 
             if !old_value.is_none() {
                 panic!(
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use crate::domain::vrm_system_model::utils::config::INTRA_ZONE_LINK_SPEED_FACTOR;
+use crate::domain::vrm_system_model::utils::id::{ComponentId, ZoneId};
+use crate::domain::vrm_system_model::workflow::dependency::DataDependency;
+
+use super::VrmComponentManager;
+
+/// Zone-aware estimation of the communication time of DataDependencies.
+///
+/// Data transfers within one locality zone (e.g. rack, datacenter) utilize a link speed, which is
+/// `intra_zone_link_speed_factor` times faster than the average link speed. Transfers across zones,
+/// or with a VrmComponent without zone, utilize the average link speed.
+///
+/// `component_ids` are all VrmComponents, on which a task may be placed. They are utilized to estimate the
+/// communication time before the placement is known (see `get_average_communication_time`).
+#[derive(Debug, Clone)]
+pub struct ZoneCommunicationCost {
+    pub component_zones: HashMap<ComponentId, ZoneId>,
+    pub component_ids: Vec<ComponentId>,
+    pub average_link_speed: i64,
+    pub intra_zone_link_speed_factor: i64,
+}
+
+impl ZoneCommunicationCost {
+    /// Creates the communication cost for the VrmComponents of `component_zones`.
+    pub fn new(component_zones: HashMap<ComponentId, ZoneId>, average_link_speed: i64) -> Self {
+        let mut component_ids: Vec<ComponentId> = component_zones.keys().cloned().collect();
+        component_ids.sort();
+
+        ZoneCommunicationCost { component_zones, component_ids, average_link_speed, intra_zone_link_speed_factor: INTRA_ZONE_LINK_SPEED_FACTOR }
+    }
+
+    /// Returns true, if at least one VrmComponent is assigned to a zone.
+    pub fn has_zones(&self) -> bool {
+        return !self.component_zones.is_empty();
+    }
+
+    /// Returns true, if both VrmComponents are assigned to the same zone.
+    pub fn is_same_zone(&self, component_a: &ComponentId, component_b: &ComponentId) -> bool {
+        match (self.component_zones.get(component_a), self.component_zones.get(component_b)) {
+            (Some(zone_a), Some(zone_b)) => zone_a == zone_b,
+            _ => false,
+        }
+    }
+
+    /// Estimates the communication time (in s) of the DataDependency, if the source task is placed on
+    /// `source_component_id` and the target task on `target_component_id`.
+    ///
+    /// # Returns
+    /// * `0` - If both tasks are placed on the same VrmComponent, no data has to be transferred via the network.
+    /// * The intra-zone communication time - If both VrmComponents are part of the same zone.
+    /// * The communication time based on the average link speed - Otherwise.
+    pub fn get_communication_time(
+        &self,
+        data_dependency: &DataDependency,
+        source_component_id: &ComponentId,
+        target_component_id: &ComponentId,
+    ) -> i64 {
+        if source_component_id == target_component_id {
+            return 0;
+        }
+
+        if self.is_same_zone(source_component_id, target_component_id) {
+            return data_dependency.get_communication_time(self.average_link_speed * self.intra_zone_link_speed_factor);
+        }
+
+        return data_dependency.get_communication_time(self.average_link_speed);
+    }
+
+    /// Estimates the communication time (in s) of the DataDependency, if the placement of its tasks is not known yet
+    /// (e.g. for the rank calculation of a scheduler).
+    ///
+    /// The estimate is the mean communication time over all pairs of different VrmComponents of `component_ids`,
+    /// i.e. the more pairs share a zone, the cheaper the estimate. Without zones, or with less than two VrmComponents,
+    /// the communication time based on the average link speed is returned.
+    pub fn get_average_communication_time(&self, data_dependency: &DataDependency) -> i64 {
+        let cross_zone_communication_time = data_dependency.get_communication_time(self.average_link_speed);
+        let number_of_components = self.component_ids.len();
+
+        if !self.has_zones() || number_of_components < 2 {
+            return cross_zone_communication_time;
+        }
+
+        let number_of_pairs = (number_of_components * (number_of_components - 1) / 2) as i64;
+        let number_of_intra_zone_pairs = self
+            .component_ids
+            .iter()
+            .enumerate()
+            .map(|(index, component_a)| {
+                self.component_ids[index + 1..].iter().filter(|component_b| self.is_same_zone(component_a, component_b)).count()
+            })
+            .sum::<usize>() as i64;
+
+        let intra_zone_communication_time = data_dependency.get_communication_time(self.average_link_speed * self.intra_zone_link_speed_factor);
+
+        return (number_of_intra_zone_pairs * intra_zone_communication_time
+            + (number_of_pairs - number_of_intra_zone_pairs) * cross_zone_communication_time)
+            / number_of_pairs;
+    }
+}
+
+impl VrmComponentManager {
+    /// Assigns the VrmComponent to the given locality zone. A previously assigned zone is replaced.
+    pub fn set_component_zone(&mut self, component_id: ComponentId, zone_id: ZoneId) {
+        if !self.vrm_components.contains_key(&component_id) {
+            log::warn!(
+                "ZoneUnknownComponent: VrmComponent {} is not registered at Adc {}, but is assigned to zone {}.",
+                component_id,
+                self.adc_id,
+                zone_id
+            );
+        }
+        self.component_zones.insert(component_id, zone_id);
+    }
+
+    /// Returns the locality zone of the VrmComponent, or `None` if the VrmComponent is not assigned to a zone.
+    pub fn get_component_zone(&self, component_id: &ComponentId) -> Option<&ZoneId> {
+        return self.component_zones.get(component_id);
+    }
+
+    /// Returns a snapshot of the zone-aware communication cost, based on the current zones, VrmComponents and average link speed.
+    pub fn get_zone_communication_cost(&self) -> ZoneCommunicationCost {
+        let mut zone_communication_cost = ZoneCommunicationCost::new(self.component_zones.clone(), self.get_average_link_speed() as i64);
+
+        zone_communication_cost.component_ids = self.vrm_components.keys().cloned().collect();
+        zone_communication_cost.component_ids.sort();
+        return zone_communication_cost;
+    }
+}
@@ -59,6 +59,15 @@ impl RegistryClient {
                 VrmMessage::GetLinkResourceCount(reply) => {
                     let _ = reply.send(component.get_link_resource_count());
                 }
+                VrmMessage::GetHierarchyDepth(reply) => {
+                    let _ = reply.send(component.get_hierarchy_depth());
+                }
+                VrmMessage::GetRouterList(reply) => {
+                    let _ = reply.send(component.get_router_list());
+                }
+                VrmMessage::ContainsRouter { router_id, reply_to } => {
+                    let _ = reply_to.send(component.contains_router(&router_id));
+                }
                 VrmMessage::CanHandel { reservation, reply_to } => {
                     let _ = reply_to.send(component.can_handel(reservation));
                 }
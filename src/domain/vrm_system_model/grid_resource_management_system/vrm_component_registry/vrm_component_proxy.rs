@@ -6,7 +6,7 @@ use crate::domain::vrm_system_model::reservation::probe_reservations::{ProbeRese
 use crate::domain::vrm_system_model::reservation::reservation::Reservation;
 use crate::domain::vrm_system_model::reservation::reservation_store::ReservationId;
 use crate::domain::vrm_system_model::rms::rms::RmsLoadMetric;
-use crate::domain::vrm_system_model::utils::id::{ComponentId, ShadowScheduleId};
+use crate::domain::vrm_system_model::utils::id::{ComponentId, RouterId, ShadowScheduleId};
 
 /// Proxy forwards everything to the thread owning the real component.
 #[derive(Debug, Clone)]
@@ -51,6 +51,18 @@ impl VrmComponent for VrmComponentProxy {
         self.call(VrmMessage::GetLinkResourceCount)
     }
 
+    fn get_hierarchy_depth(&self) -> usize {
+        self.call(VrmMessage::GetHierarchyDepth)
+    }
+
+    fn get_router_list(&self) -> Vec<RouterId> {
+        self.call(VrmMessage::GetRouterList)
+    }
+
+    fn contains_router(&self, router_id: &RouterId) -> bool {
+        self.call(|tx| VrmMessage::ContainsRouter { router_id: router_id.clone(), reply_to: tx })
+    }
+
     fn can_handel(&self, res: Reservation) -> bool {
         self.call(|tx| VrmMessage::CanHandel { reservation: res, reply_to: tx })
     }
@@ -2,7 +2,7 @@ use crate::domain::vrm_system_model::reservation::probe_reservations::{ProbeRese
 use crate::domain::vrm_system_model::reservation::reservation::Reservation;
 use crate::domain::vrm_system_model::reservation::reservation_store::ReservationId;
 use crate::domain::vrm_system_model::rms::rms::RmsLoadMetric;
-use crate::domain::vrm_system_model::utils::id::{ComponentId, ShadowScheduleId};
+use crate::domain::vrm_system_model::utils::id::{ComponentId, RouterId, ShadowScheduleId};
 
 use std::sync::mpsc;
 
@@ -14,6 +14,13 @@ pub enum VrmMessage {
     GetTotalLinkCapacity(mpsc::Sender<i64>),
     GetTotalNodeCapacity(mpsc::Sender<i64>),
     GetLinkResourceCount(mpsc::Sender<usize>),
+    GetHierarchyDepth(mpsc::Sender<usize>),
+    GetRouterList(mpsc::Sender<Vec<RouterId>>),
+
+    ContainsRouter {
+        router_id: RouterId,
+        reply_to: mpsc::Sender<bool>,
+    },
 
     CanHandel {
         reservation: Reservation,
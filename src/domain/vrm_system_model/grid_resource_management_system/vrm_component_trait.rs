@@ -2,7 +2,7 @@ use crate::domain::vrm_system_model::reservation::probe_reservations::{ProbeRese
 use crate::domain::vrm_system_model::reservation::reservation::{Reservation};
 use crate::domain::vrm_system_model::reservation::reservation_store::ReservationId;
 use crate::domain::vrm_system_model::rms::rms::RmsLoadMetric;
-use crate::domain::vrm_system_model::utils::id::{ComponentId, ShadowScheduleId};
+use crate::domain::vrm_system_model::utils::id::{ComponentId, RouterId, ShadowScheduleId};
 
 
 /// A specialized interface for a fully-featured **Distributed Resource Management System**.
@@ -31,7 +31,7 @@ pub trait VrmComponent: std::fmt::Debug {
     /// Returns the unique identifier of the component (AcI or ADC).
     fn get_id(&self) -> ComponentId;
 
-    /// Returns the total capacity of the component.
+    /// Returns the total capacity of the component, i.e. the node and link capacity combined.
     fn get_total_capacity(&self) -> i64;
 
     /// Returns the total link capacity of the component.
@@ -43,6 +43,23 @@ pub trait VrmComponent: std::fmt::Debug {
     /// Returns the total node capacity (often same as total capacity depending on model).
     fn get_total_node_capacity(&self) -> i64;
 
+    /// Returns the number of ADC levels below this VrmComponent.
+    /// An AcI is a leaf with a depth of 0, an ADC has the depth of its deepest child plus one.
+    fn get_hierarchy_depth(&self) -> usize {
+        return 0;
+    }
+
+    /// Returns the routers of the network topology of the component, on which data transfers can start or end.
+    /// A component without network resources has no routers.
+    fn get_router_list(&self) -> Vec<RouterId> {
+        return Vec::new();
+    }
+
+    /// Returns true, if the provided router is part of the network topology of the component.
+    fn contains_router(&self, router_id: &RouterId) -> bool {
+        return self.get_router_list().contains(router_id);
+    }
+
     // Return true, if the provided reservation can be scheduled on teh GridComponent
     fn can_handel(&self, res: Reservation) -> bool;
 
@@ -3,7 +3,7 @@ use std::any::Any;
 use serde::{Deserialize, Serialize};
 
 use crate::domain::vrm_system_model::{
-    reservation::reservation::{ReservationBase, ReservationProceeding, ReservationState, ReservationTrait, ReservationTyp},
+    reservation::reservation::{ASSIGNED_TIME_UNSET, ReservationBase, ReservationProceeding, ReservationState, ReservationTrait, ReservationTyp},
     resource::resource_store::NodeResourceId,
     rms::slurm_rms::api_client::response::tasks::{SlurmOptionExt, SlurmTask},
     utils::id::{ClientId, ComponentId, ReservationName, ResourceName},
@@ -66,13 +66,15 @@ impl NodeReservation {
             arrival_time,
             booking_interval_start,
             booking_interval_end,
-            assigned_start: 0, // Default to 0 until formally scheduled
-            assigned_end: 0,   // Default to 0 until formally scheduled
+            assigned_start: ASSIGNED_TIME_UNSET, // Unset until formally scheduled
+            assigned_end: ASSIGNED_TIME_UNSET,   // Unset until formally scheduled
             task_duration,
             reserved_capacity,
             is_moldable,
             moldable_work,
             frag_delta,
+            is_moveable: false,
+            priority: 0,
         };
 
         NodeReservation { base, task_path, output_path, error_path, current_working_directory, environment }
@@ -129,6 +131,8 @@ impl NodeReservation {
                 is_moldable: false,
                 moldable_work: capacity * duration,
                 frag_delta: 0.0,
+                is_moveable: false,
+                priority: 0,
             },
             current_working_directory: None,
             environment: None,
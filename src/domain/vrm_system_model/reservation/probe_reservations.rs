@@ -15,6 +15,16 @@ pub enum ProbeReservationComparator {
 }
 
 impl ProbeReservationComparator {
+    /// Returns the value, which is minimized by this comparator (assigned end for EFT, assigned start for EST).
+    pub fn get_objective(&self, res: &Reservation) -> i64 {
+        let base = res.get_base_reservation();
+
+        match self {
+            ProbeReservationComparator::EFTReservationCompare => base.get_assigned_end(),
+            ProbeReservationComparator::ESTReservationCompare => base.get_assigned_start(),
+        }
+    }
+
     pub fn compare(&self, a: &Reservation, b: &Reservation) -> Ordering {
         let base_a = a.get_base_reservation();
         let base_b = b.get_base_reservation();
@@ -81,7 +91,7 @@ impl ProbeReservations {
 
                 //Generates a new ID for ProbeReservation
                 let new_id = ProbeReservationId::new(format!("{}-{}", res.get_name(), self.reservation_idx));
-                self.local_reservation_store.insert(old_id, res);
+                self.local_reservation_store.insert(new_id.clone(), res);
 
                 if let Some(m) = meta {
                     self.probe_meta_data.insert(new_id, m);
@@ -111,7 +121,24 @@ impl ProbeReservations {
         original_res_id: ReservationId,
         comparator: ProbeReservationComparator,
     ) -> Option<(ComponentId, Option<ShadowScheduleId>)> {
-        let best_probe_res_id = self.get_best_probe_reservation_id(original_res_id, comparator)?;
+        return self.prompt_best_with_penalty(original_res_id, comparator, |_| 0);
+    }
+
+    /// Like `prompt_best`, however the objective of the comparator (e.g. the assigned end for EFT) of each
+    /// ProbeReservation is increased by the `placement_penalty` of the VrmComponent, which created the ProbeReservation.
+    ///
+    /// Return:
+    /// If promotion was successful the component_id, is returned, where the Reservation must be reserved.
+    pub fn prompt_best_with_penalty<F>(
+        &mut self,
+        original_res_id: ReservationId,
+        comparator: ProbeReservationComparator,
+        placement_penalty: F,
+    ) -> Option<(ComponentId, Option<ShadowScheduleId>)>
+    where
+        F: Fn(&ComponentId) -> i64,
+    {
+        let best_probe_res_id = self.get_best_probe_reservation_id_with_penalty(original_res_id, comparator, placement_penalty)?;
 
         let best_probe_reservation = self.local_reservation_store.remove(&best_probe_res_id);
         let meta_data = self.probe_meta_data.remove(&best_probe_res_id);
@@ -188,10 +215,59 @@ impl ProbeReservations {
         best_id
     }
 
+    /// Finds the ProbeReservation with the smallest penalized objective `comparator.get_objective(res) + placement_penalty(component_id)`.
+    /// ProbeReservations without meta data (unknown VrmComponent) are not penalized.
+    /// If multiple ProbeReservations have the same penalized objective, the unpenalized comparator decides.
+    pub fn get_best_probe_reservation_id_with_penalty<F>(
+        &self,
+        original_res_id: ReservationId,
+        comparator: ProbeReservationComparator,
+        placement_penalty: F,
+    ) -> Option<ProbeReservationId>
+    where
+        F: Fn(&ComponentId) -> i64,
+    {
+        if !self.is_request_valid(original_res_id) || self.local_reservation_store.is_empty() {
+            return None;
+        }
+
+        let penalized_objective = |probe_id: &ProbeReservationId, res: &Reservation| -> i64 {
+            let penalty = self.probe_meta_data.get(probe_id).map(|(component_id, _)| placement_penalty(component_id)).unwrap_or(0);
+            return comparator.get_objective(res) + penalty;
+        };
+
+        let mut best: Option<(&ProbeReservationId, &Reservation, i64)> = None;
+
+        for (candidate_id, res_candidate) in &self.local_reservation_store {
+            let candidate_objective = penalized_objective(candidate_id, res_candidate);
+
+            let is_better = match best {
+                None => true,
+                Some((_, current_best, best_objective)) => {
+                    candidate_objective < best_objective
+                        || (candidate_objective == best_objective && comparator.compare(current_best, res_candidate) == Ordering::Greater)
+                }
+            };
+
+            if is_better {
+                best = Some((candidate_id, res_candidate, candidate_objective));
+            }
+        }
+        best.map(|(probe_id, _, _)| probe_id.clone())
+    }
+
     pub fn get_ids(&self) -> Vec<ProbeReservationId> {
         self.local_reservation_store.keys().cloned().collect()
     }
 
+    /// Returns the distinct VrmComponents, which created the current candidates (ProbeReservations), in ascending order.
+    pub fn get_component_ids(&self) -> Vec<ComponentId> {
+        let mut component_ids: Vec<ComponentId> = self.probe_meta_data.values().map(|(component_id, _)| component_id.clone()).collect();
+        component_ids.sort();
+        component_ids.dedup();
+        return component_ids;
+    }
+
     pub fn len(&self) -> usize {
         self.local_reservation_store.len()
     }
@@ -200,6 +276,57 @@ impl ProbeReservations {
         self.local_reservation_store.is_empty()
     }
 
+    /// Returns the sum of the reserved capacities of all current candidates (ProbeReservations).
+    pub fn total_candidate_capacity(&self) -> i64 {
+        return self.local_reservation_store.values().map(|res| res.get_reserved_capacity()).sum();
+    }
+
+    /// Returns the earliest assigned end of all current candidates (ProbeReservations),
+    /// or `None` if there is no candidate.
+    pub fn best_finish_time(&self) -> Option<i64> {
+        return self.local_reservation_store.values().map(|res| res.get_assigned_end()).min();
+    }
+
+    /// Returns the shortest execution time (assigned end - assigned start) of all current candidates (ProbeReservations),
+    /// or `None` if there is no candidate.
+    pub fn best_execution_time(&self) -> Option<i64> {
+        return self.local_reservation_store.values().map(|res| res.get_assigned_end() - res.get_assigned_start()).min();
+    }
+
+    /// Deletes all ProbeReservations, whose assigned window is not within the booking interval of the original
+    /// reservation in the `reservation_store`, e.g. the invalid answers of a misbehaving VrmComponent.
+    ///
+    /// # Returns
+    /// The number of deleted ProbeReservations.
+    pub fn validate_against_booking(&mut self, reservation_store: &ReservationStore) -> usize {
+        let booking_interval_start = reservation_store.get_booking_interval_start(self.original_reservation_id);
+        let booking_interval_end = reservation_store.get_booking_interval_end(self.original_reservation_id);
+
+        let invalid_probe_ids: Vec<ProbeReservationId> = self
+            .local_reservation_store
+            .iter()
+            .filter(|(_, res)| res.get_assigned_start() < booking_interval_start || res.get_assigned_end() > booking_interval_end)
+            .map(|(probe_id, _)| probe_id.clone())
+            .collect();
+
+        for probe_id in &invalid_probe_ids {
+            let res = self.local_reservation_store.remove(probe_id).unwrap();
+            let component_id = self.probe_meta_data.remove(probe_id).map(|(component_id, _)| component_id);
+
+            log::debug!(
+                "ProbeReservationOutsideBookingInterval: ProbeReservation {} of VrmComponent {:?} with window [{}, {}] is outside of the booking interval [{}, {}] and was dropped.",
+                probe_id,
+                component_id,
+                res.get_assigned_start(),
+                res.get_assigned_end(),
+                booking_interval_start,
+                booking_interval_end
+            );
+        }
+
+        return invalid_probe_ids.len();
+    }
+
     pub fn get_mut_reservations(&mut self) -> Vec<&mut Reservation> {
         self.local_reservation_store.values_mut().collect()
     }
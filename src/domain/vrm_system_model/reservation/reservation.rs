@@ -7,6 +7,9 @@ use crate::domain::vrm_system_model::{
     workflow::workflow::Workflow,
 };
 
+/// Sentinel of `assigned_start` and `assigned_end` for reservations, which are not scheduled yet.
+pub const ASSIGNED_TIME_UNSET: i64 = i64::MIN;
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum Reservation {
     Workflow(Workflow),
@@ -195,6 +198,14 @@ pub trait ReservationTrait: std::fmt::Debug + Any + Send + Sync {
         self.get_base().is_moldable
     }
 
+    fn is_moveable(&self) -> bool {
+        self.get_base().is_moveable
+    }
+
+    fn get_priority(&self) -> i32 {
+        self.get_base().priority
+    }
+
     fn get_reserved_capacity(&self) -> i64 {
         self.get_base().reserved_capacity
     }
@@ -296,6 +307,10 @@ pub trait ReservationTrait: std::fmt::Debug + Any + Send + Sync {
         self.get_base_mut().is_moldable = is_moldable;
     }
 
+    fn set_is_moveable(&mut self, is_moveable: bool) {
+        self.get_base_mut().is_moveable = is_moveable;
+    }
+
     fn adjust_capacity(&mut self, capacity: i64) {
         if capacity != self.get_base().reserved_capacity {
             if self.is_moldable().not() {
@@ -399,6 +414,36 @@ impl ReservationState {
     pub fn is_reserve_request_valid(&self) -> bool {
         matches!(self, Self::Open | Self::ReserveProbeReservation | Self::ProbeReservation)
     }
+
+    /// Returns `true` for the terminal states `Rejected`, `Deleted` and `Finished`, which are never left.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Rejected | Self::Deleted | Self::Finished)
+    }
+
+    /// Returns `true` if the lifecycle of a reservation allows the transition from this state to `new_state`.
+    /// Staying in the same state is always allowed, the terminal states `Rejected`, `Deleted` and `Finished` (see
+    /// `is_terminal`) are never left.
+    pub fn can_transition_to(&self, new_state: ReservationState) -> bool {
+        if *self == new_state {
+            return true;
+        }
+
+        return match self {
+            Self::Open => matches!(
+                new_state,
+                Self::ProbeAnswer | Self::ProbeReservation | Self::ReserveProbeReservation | Self::ReserveAnswer | Self::Rejected | Self::Deleted
+            ),
+            Self::ProbeAnswer => {
+                matches!(new_state, Self::Open | Self::ReserveProbeReservation | Self::ReserveAnswer | Self::Rejected | Self::Deleted)
+            }
+            Self::ProbeReservation => matches!(new_state, Self::ReserveProbeReservation | Self::Rejected | Self::Deleted),
+            Self::ReserveProbeReservation => matches!(new_state, Self::ReserveAnswer | Self::Rejected | Self::Deleted),
+            Self::ReserveAnswer => matches!(new_state, Self::Committed | Self::Rejected | Self::Deleted),
+            Self::Committed => matches!(new_state, Self::Finished | Self::Deleted),
+            Self::External => matches!(new_state, Self::Finished | Self::Deleted),
+            Self::Rejected | Self::Deleted | Self::Finished => false,
+        };
+    }
 }
 
 /// Defines the set of primary actions (proceedings) that can be requested for a reservation.
@@ -465,10 +510,10 @@ pub struct ReservationBase {
     /// The latest time the resource is **requested to end** the booking interval.
     pub booking_interval_end: i64,
 
-    /// The precise time the resource was **formally assigned to start** the task. (0 if not set).
+    /// The precise time the resource was **formally assigned to start** the task. (`ASSIGNED_TIME_UNSET` if not set).
     pub assigned_start: i64,
 
-    /// The precise time the resource was **formally assigned to end** the task. (0 if not set).
+    /// The precise time the resource was **formally assigned to end** the task. (`ASSIGNED_TIME_UNSET` if not set).
     pub assigned_end: i64,
 
     // Resource properties
@@ -497,6 +542,15 @@ pub struct ReservationBase {
     /// fragmentation has worsened. This value is intended for use by a `WorkflowScheduler`
     /// or other schedule optimization criteria.
     pub frag_delta: f64,
+
+    /// Flag indicating if the reservation may be **shifted in time** after it was reserved (e.g. by `SlottedSchedule::compact`),
+    /// as long as it stays within its booking interval.
+    #[serde(default)]
+    pub is_moveable: bool,
+
+    /// The **priority** of the reservation, higher values are placed first among otherwise equal reservations (default 0).
+    #[serde(default)]
+    pub priority: i32,
 }
 
 impl ReservationBase {
@@ -516,6 +570,11 @@ impl ReservationBase {
         self.assigned_end
     }
 
+    /// Returns true, if both `assigned_start` and `assigned_end` were set by a schedule.
+    pub fn has_assigned_window(&self) -> bool {
+        self.assigned_start != ASSIGNED_TIME_UNSET && self.assigned_end != ASSIGNED_TIME_UNSET
+    }
+
     pub fn get_name(&self) -> ReservationName {
         self.name.clone()
     }
@@ -5,12 +5,14 @@ use std::fmt::Debug;
 use std::sync::{Arc, RwLock};
 
 use crate::domain::vrm_system_model::reservation::link_reservation::LinkReservation;
+use crate::domain::vrm_system_model::reservation::node_reservation::NodeReservation;
 use crate::domain::vrm_system_model::reservation::reservation::{
     Reservation, ReservationProceeding, ReservationState, ReservationTrait, ReservationTyp,
 };
 use crate::domain::vrm_system_model::utils::id::{ClientId, ComponentId, ReservationName, RouterId};
 use crate::domain::vrm_system_model::workflow::workflow::Workflow;
 use crate::domain::vrm_system_model::workflow::workflow_node::WorkflowNode;
+use crate::error::Error;
 
 use super::reservation_notification_listener::ReservationNotificationListener;
 
@@ -34,6 +36,18 @@ pub struct ReservationStore {
     inner: Arc<RwLock<StoreInner>>,
 }
 
+/// An inconsistency of the ReservationStore (or its surrounding bookkeeping), which indicates
+/// leftovers of a prior aborted scheduling run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthIssue {
+    /// The reservation is stuck in a transient intermediate state (e.g. `ReserveProbeReservation`),
+    /// which is only valid while a request is in flight.
+    StuckTransientState { reservation_id: ReservationId, name: ReservationName, state: ReservationState },
+
+    /// A `ReservationSyncGate` exists, but its reservation is no longer in the store or no longer waits for an AcI.
+    OrphanedGate { reservation_id: ReservationId },
+}
+
 /// The internal data structure for `ReservationStore`.
 ///
 /// This structure holds the primary data storage and secondary indices required
@@ -56,6 +70,52 @@ struct StoreInner {
     listeners: Vec<Arc<RwLock<dyn ReservationNotificationListener>>>,
 }
 
+impl StoreInner {
+    /// Inserts the reservation into the slot map and updates all indices.
+    fn insert(&mut self, reservation: Reservation) -> ReservationId {
+        let name = reservation.get_name().clone();
+        let client = reservation.get_client_id().clone();
+        let handler = reservation.get_handler_id().clone();
+
+        let key = self.slots.insert(Arc::new(RwLock::new(reservation)));
+
+        self.name_index.insert(name, key);
+        self.client_index.entry(client).or_default().insert(key);
+        if let Some(h) = handler {
+            self.handler_index.entry(h).or_default().insert(key);
+        }
+
+        return key;
+    }
+
+    /// Removes the reservation from the slot map and from all indices.
+    /// The entry of the name index is only removed, if it still refers to this reservation.
+    fn remove(&mut self, reservation_id: ReservationId) -> Option<Arc<RwLock<Reservation>>> {
+        let handle = self.slots.remove(reservation_id)?;
+
+        let (name, client, handler) = {
+            let reservation = handle.read().expect("Lock poisoned");
+            (reservation.get_name(), reservation.get_client_id(), reservation.get_handler_id())
+        };
+
+        if self.name_index.get(&name) == Some(&reservation_id) {
+            self.name_index.remove(&name);
+        } else {
+            // e.g. ProbeReservations are indexed with a different name
+            self.name_index.retain(|_, key| *key != reservation_id);
+        }
+
+        if let Some(client_reservations) = self.client_index.get_mut(&client) {
+            client_reservations.remove(&reservation_id);
+        }
+        if let Some(handler_reservations) = handler.and_then(|handler| self.handler_index.get_mut(&handler)) {
+            handler_reservations.remove(&reservation_id);
+        }
+
+        return Some(handle);
+    }
+}
+
 impl ReservationStore {
     pub fn new() -> Self {
         Self {
@@ -76,41 +136,142 @@ impl ReservationStore {
         guard.listeners.push(listener);
     }
 
+    /// Detaches all listeners from the store, e.g. to perform a dry run, which is not notified.
+    ///
+    /// # Returns
+    /// The detached listeners, which can be attached again via `add_listeners`.
+    pub fn take_listeners(&self) -> Vec<Arc<RwLock<dyn ReservationNotificationListener>>> {
+        let mut guard = self.inner.write().expect("RwLock poisoned");
+        return std::mem::take(&mut guard.listeners);
+    }
+
+    /// Subscribes all given listeners to state change notifications (see `add_listener`).
+    pub fn add_listeners(&self, listeners: Vec<Arc<RwLock<dyn ReservationNotificationListener>>>) {
+        let mut guard = self.inner.write().expect("RwLock poisoned");
+        guard.listeners.extend(listeners);
+    }
+
     /// Adds Reservation to ReservationStore.
     ///
     /// # Returns
     /// Returns the ReservationId (internal Key for ReservationStore).
     pub fn add(&self, reservation: Reservation) -> ReservationId {
         let mut guard = self.inner.write().unwrap();
+        return guard.insert(reservation);
+    }
 
-        let name = reservation.get_name().clone();
-        let client = reservation.get_client_id().clone();
-        let handler = reservation.get_handler_id().clone();
-
-        let key = guard.slots.insert(Arc::new(RwLock::new(reservation)));
+    /// Returns the ReservationId of the reservation with the provided name or, if no such reservation exists,
+    /// adds the reservation created by `factory` to the ReservationStore.
+    ///
+    /// Lookup and insertion are performed under the same lock, hence concurrent callers with the same name
+    /// always receive the same ReservationId. This allows an idempotent creation of derived reservations,
+    /// e.g. of dependencies which are referenced multiple times (fan-out).
+    ///
+    /// # Arguments
+    /// * `name` - Name of the requested reservation.
+    /// * `factory` - Creates the reservation, only called if no reservation with `name` exists.
+    ///               The created reservation must carry the provided `name`.
+    ///
+    /// # Returns
+    /// Returns the ReservationId of the existing or of the newly added reservation.
+    pub fn get_or_insert_by_name<F>(&self, name: ReservationName, factory: F) -> ReservationId
+    where
+        F: FnOnce() -> Reservation,
+    {
+        let mut guard = self.inner.write().unwrap();
 
-        guard.name_index.insert(name, key);
-        guard.client_index.entry(client).or_default().insert(key);
-        if let Some(h) = handler {
-            guard.handler_index.entry(h).or_default().insert(key);
+        if let Some(key) = guard.name_index.get(&name) {
+            if guard.slots.contains_key(*key) {
+                return *key;
+            }
         }
 
-        return key;
+        let reservation = factory();
+        if reservation.get_name() != name {
+            log::warn!(
+                "ReservationStoreGetOrInsertNameMismatch: The created reservation {} does not carry the requested name {}.",
+                reservation.get_name(),
+                name
+            );
+        }
+        return guard.insert(reservation);
     }
 
-    /// Removes a reservation and its associated name index from the store.
-    /// Note: This operation removes the reservation from the name index and the slot map,
+    /// Removes a reservation from the store and from all of its indices (name, client and handler),
     /// effectively ending its lifecycle in the store.
-    pub fn remove(&self, reservation_id: ReservationId) {
-        let res_name = self.get_name_for_key(reservation_id);
+    ///
+    /// Only the store releases its handle, components still holding an `Arc` to the reservation (see `get`)
+    /// can continue to use it.
+    ///
+    /// # Returns
+    /// Returns the removed reservation (a clone, if the reservation is still shared) or `None`, if the
+    /// reservation id was not present in the store.
+    pub fn remove(&self, reservation_id: ReservationId) -> Option<Reservation> {
+        let handle = self.inner.write().expect("RwLock poisoned").remove(reservation_id);
 
-        if let Some(name) = res_name {
-            let mut guard = self.inner.write().unwrap();
-            guard.name_index.remove(&name);
-            guard.slots.remove(reservation_id);
-        } else {
-            log::error!("ReservationStoreRemoveError: Failed to remove reservation, because res_name was None.")
+        let Some(handle) = handle else {
+            log::error!("ReservationStoreRemoveError: Failed to remove reservation {:?}, because it is not in the store.", reservation_id);
+            return None;
+        };
+
+        return match Arc::try_unwrap(handle) {
+            Ok(lock) => Some(lock.into_inner().expect("Lock poisoned")),
+            Err(handle) => Some(handle.read().expect("Lock poisoned").clone()),
+        };
+    }
+
+    /// Garbage collects all reservations in a terminal state (`Rejected`, `Deleted` or `Finished`), such that a long
+    /// simulation does not accumulate them forever. The store is locked only once.
+    ///
+    /// As for `remove`, components still holding an `Arc` to a removed reservation can continue to use it. Use
+    /// `gc_terminal_except` to keep reservations, which are still referenced by a live workflow or a `VrmComponentManager`.
+    ///
+    /// # Returns
+    /// The number of removed reservations.
+    pub fn gc_terminal(&self) -> usize {
+        let mut guard = self.inner.write().expect("RwLock poisoned");
+        return Self::remove_terminal_reservations(&mut guard, &HashSet::new());
+    }
+
+    /// Garbage collects the reservations in a terminal state like `gc_terminal`, but spares the reservations, which are
+    /// still referenced. The subtasks of a workflow are only collected, if the whole workflow is terminal, as a live
+    /// workflow still reads the states of its (e.g. already finished) subtasks. Reservations in `spared_reservation_ids`
+    /// (e.g. tracked by a `VrmComponentManager`, see `gc_terminal_reservations`) are never collected.
+    ///
+    /// # Returns
+    /// The number of removed reservations.
+    pub fn gc_terminal_except(&self, spared_reservation_ids: &HashSet<ReservationId>) -> usize {
+        let mut guard = self.inner.write().expect("RwLock poisoned");
+
+        let mut spared_reservation_ids = spared_reservation_ids.clone();
+        for (_, reservation) in guard.slots.iter() {
+            let reservation = reservation.read().expect("Lock poisoned");
+            if let Reservation::Workflow(workflow) = &*reservation {
+                if !reservation.get_state().is_terminal() {
+                    spared_reservation_ids.extend(workflow.get_all_reservation_ids());
+                }
+            }
+        }
+
+        return Self::remove_terminal_reservations(&mut guard, &spared_reservation_ids);
+    }
+
+    /// Removes all reservations in a terminal state, which are not contained in `spared_reservation_ids`.
+    fn remove_terminal_reservations(guard: &mut StoreInner, spared_reservation_ids: &HashSet<ReservationId>) -> usize {
+        let terminal_reservation_ids: Vec<ReservationId> = guard
+            .slots
+            .iter()
+            .filter(|(reservation_id, _)| !spared_reservation_ids.contains(reservation_id))
+            .filter(|(_, reservation)| reservation.read().expect("Lock poisoned").get_state().is_terminal())
+            .map(|(reservation_id, _)| reservation_id)
+            .collect();
+
+        for reservation_id in &terminal_reservation_ids {
+            guard.remove(*reservation_id);
         }
+
+        log::debug!("ReservationStoreGarbageCollection: Removed {} reservations in a terminal state.", terminal_reservation_ids.len());
+        return terminal_reservation_ids.len();
     }
 
     /// Adds a temporary "Probe" reservation to the store (only allowed by the SlottedScheduleContext logic).
@@ -165,6 +326,26 @@ impl ReservationStore {
         }
     }
 
+    /// Checks, whether the ReservationStore is in a sane starting state for a scheduling episode, i.e.,
+    /// no reservation is stuck in a transient intermediate state from a prior aborted run.
+    ///
+    /// # Returns
+    /// Returns all found `HealthIssue`s, an empty list signals a healthy ReservationStore.
+    pub fn health_check(&self) -> Vec<HealthIssue> {
+        let guard = self.inner.read().expect("RwLock poisoned");
+
+        let mut health_issues = Vec::new();
+        for (reservation_id, reservation) in guard.slots.iter() {
+            let reservation = reservation.read().expect("Lock poisoned");
+            let state = reservation.get_state();
+
+            if state == ReservationState::ProbeReservation || state == ReservationState::ReserveProbeReservation {
+                health_issues.push(HealthIssue::StuckTransientState { reservation_id, name: reservation.get_name().clone(), state });
+            }
+        }
+        return health_issues;
+    }
+
     /// Checks if the provided reservation ids are in the ReservationStore
     ///
     /// # Returns
@@ -207,6 +388,38 @@ impl ReservationStore {
         })
     }
 
+    /// Takes a static snapshot (clone) of a specific NodeReservation.
+    ///
+    /// # Returns
+    /// `None` if the reservation is not in the store or is not a NodeReservation.
+    pub fn get_node_reservation_snapshot(&self, reservation_id: ReservationId) -> Option<NodeReservation> {
+        return match self.get_reservation_snapshot(reservation_id)? {
+            Reservation::Node(node_reservation) => Some(node_reservation),
+            _ => None,
+        };
+    }
+
+    /// Takes a static snapshot (clone) of a specific LinkReservation.
+    ///
+    /// # Returns
+    /// `None` if the reservation is not in the store or is not a LinkReservation.
+    pub fn get_link_reservation_snapshot(&self, reservation_id: ReservationId) -> Option<LinkReservation> {
+        return match self.get_reservation_snapshot(reservation_id)? {
+            Reservation::Link(link_reservation) => Some(link_reservation),
+            _ => None,
+        };
+    }
+
+    /// Replaces the reservation of `reservation_id` with the provided (previously taken) snapshot,
+    /// e.g. to undo the modifications of a dry-run placement. Listeners are not notified.
+    pub fn restore_reservation_snapshot(&self, reservation_id: ReservationId, reservation: Reservation) {
+        if let Some(handle) = self.get(reservation_id) {
+            *handle.write().expect("Individual reservation lock poisoned") = reservation;
+        } else {
+            log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
+        }
+    }
+
     /// Get Reservation with User reservation name (ReservationName).
     ///  
     /// # Returns
@@ -247,6 +460,30 @@ impl ReservationStore {
         guard.handler_index.get(component_id).map(|set| set.iter().cloned().collect()).unwrap_or_default()
     }
 
+    /// Retrieve all keys of the ReservationStore, the store is locked only once.
+    pub fn iter_ids(&self) -> Vec<ReservationId> {
+        let guard = self.inner.read().expect("RwLock poisoned");
+        return guard.slots.keys().collect();
+    }
+
+    /// Returns the number of reservations in the ReservationStore.
+    pub fn count(&self) -> usize {
+        let guard = self.inner.read().expect("RwLock poisoned");
+        return guard.slots.len();
+    }
+
+    /// Retrieve all keys of reservations, which are currently in the provided `state`.
+    /// The store is locked only once, e.g. to assert that no reservation remained in `ReservationState::ReserveAnswer`.
+    pub fn find_by_state(&self, state: ReservationState) -> Vec<ReservationId> {
+        let guard = self.inner.read().expect("RwLock poisoned");
+        return guard
+            .slots
+            .iter()
+            .filter(|(_, reservation)| reservation.read().expect("Lock poisoned").get_state() == state)
+            .map(|(reservation_id, _)| reservation_id)
+            .collect();
+    }
+
     /// Retrieves form the provided reservation id the reserved_capacity
     pub fn get_reserved_capacity(&self, reservation_id: ReservationId) -> i64 {
         if let Some(handle) = self.get(reservation_id) {
@@ -344,6 +581,16 @@ impl ReservationStore {
         }
     }
 
+    /// Returns the moldable_work (reserved_capacity * task_duration) of the provided reservation_id. Panics if no reservation was found.
+    pub fn get_moldable_work(&self, reservation_id: ReservationId) -> i64 {
+        if let Some(handle) = self.get(reservation_id) {
+            let res = handle.read().unwrap();
+            return res.get_moldable_work();
+        } else {
+            panic!("Reservation (id: {:?}) was not found.", reservation_id);
+        }
+    }
+
     /// Returns the ReservationProceeding state of the provided reservation_id. Panics if no state was found.
     pub fn get_reservation_proceeding(&self, reservation_id: ReservationId) -> ReservationProceeding {
         if let Some(handle) = self.get(reservation_id) {
@@ -377,81 +624,86 @@ impl ReservationStore {
 
     // Updates the frag_delta value of the corresponding reservation of the provided reservation_id.
     pub fn set_frag_delta(&mut self, reservation_id: ReservationId, frag_delta: f64) {
-        if let Some(handle) = self.get(reservation_id) {
-            let mut res = handle.write().unwrap();
-            res.set_frag_delta(frag_delta);
-        } else {
+        if self.with_reservation_mut(reservation_id, |res| res.set_frag_delta(frag_delta)).is_none() {
             log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
         }
     }
 
     // Updates the booking_interval_start value of the corresponding reservation of the provided reservation_id.
     pub fn set_booking_interval_start(&mut self, reservation_id: ReservationId, booking_interval_start: i64) {
-        if let Some(handle) = self.get(reservation_id) {
-            let mut res = handle.write().unwrap();
-            res.set_booking_interval_start(booking_interval_start);
-        } else {
+        if self.with_reservation_mut(reservation_id, |res| res.set_booking_interval_start(booking_interval_start)).is_none() {
             log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
         }
     }
 
     // Updates the booking_interval_end value of the corresponding reservation of the provided reservation_id.
     pub fn set_booking_interval_end(&mut self, reservation_id: ReservationId, booking_interval_end: i64) {
-        if let Some(handle) = self.get(reservation_id) {
-            let mut res = handle.write().unwrap();
-            res.set_booking_interval_end(booking_interval_end);
-        } else {
+        if self.with_reservation_mut(reservation_id, |res| res.set_booking_interval_end(booking_interval_end)).is_none() {
             log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
         }
     }
 
     // Updates the assigned_start value of the corresponding reservation of the provided reservation_id.
     pub fn set_assigned_start(&mut self, reservation_id: ReservationId, assigned_start: i64) {
-        if let Some(handle) = self.get(reservation_id) {
-            let mut res = handle.write().unwrap();
-            res.set_assigned_start(assigned_start);
-        } else {
+        if self.with_reservation_mut(reservation_id, |res| res.set_assigned_start(assigned_start)).is_none() {
             log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
         }
     }
 
     // Updates the assigned_end value of the corresponding reservation of the provided reservation_id.
     pub fn set_assigned_end(&mut self, reservation_id: ReservationId, assigned_end: i64) {
-        if let Some(handle) = self.get(reservation_id) {
-            let mut res = handle.write().unwrap();
-            res.set_assigned_end(assigned_end);
-        } else {
+        if self.with_reservation_mut(reservation_id, |res| res.set_assigned_end(assigned_end)).is_none() {
             log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
         }
     }
 
     // Updates the reserved_capacity value of the corresponding reservation of the provided reservation_id.
     pub fn set_reserved_capacity(&mut self, reservation_id: ReservationId, reserved_capacity: i64) {
-        if let Some(handle) = self.get(reservation_id) {
-            let mut res = handle.write().unwrap();
-            res.set_reserved_capacity(reserved_capacity);
-        } else {
+        if self.with_reservation_mut(reservation_id, |res| res.set_reserved_capacity(reserved_capacity)).is_none() {
             log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
         }
     }
 
     // Updates the task_duration value of the corresponding reservation of the provided reservation_id.
     pub fn set_task_duration(&mut self, reservation_id: ReservationId, task_duration: i64) {
-        if let Some(handle) = self.get(reservation_id) {
-            let mut res = handle.write().unwrap();
-            res.set_task_duration(task_duration);
-        } else {
+        if self.with_reservation_mut(reservation_id, |res| res.set_task_duration(task_duration)).is_none() {
             log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
         }
     }
 
     // Updates the is_moldable value of the corresponding reservation of the provided reservation_id.
     pub fn set_is_moldable(&mut self, reservation_id: ReservationId, is_moldable: bool) {
+        if self.with_reservation_mut(reservation_id, |res| res.set_is_moldable(is_moldable)).is_none() {
+            log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
+        }
+    }
+
+    // Updates the is_moveable value of the corresponding reservation of the provided reservation_id.
+    pub fn set_is_moveable(&mut self, reservation_id: ReservationId, is_moveable: bool) {
+        if self.with_reservation_mut(reservation_id, |res| res.set_is_moveable(is_moveable)).is_none() {
+            log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
+        }
+    }
+
+    /// Retrieves form the provided reservation id the is_moveable.
+    pub fn is_moveable(&self, reservation_id: ReservationId) -> bool {
         if let Some(handle) = self.get(reservation_id) {
-            let mut res = handle.write().unwrap();
-            res.set_is_moldable(is_moldable);
+            let res = handle.read().unwrap();
+            return res.is_moveable();
         } else {
-            log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
+            log::error!("Get reservation (id: {:?}) was not possible.", reservation_id);
+            return false;
+        }
+    }
+
+    /// Retrieves form the provided reservation id the priority.
+    pub fn get_priority(&self, reservation_id: ReservationId) -> i32 {
+        if let Some(handle) = self.get(reservation_id) {
+            let res = handle.read().unwrap();
+            return res.get_priority();
+        } else {
+            log::error!("Get reservation (id: {:?}) was not possible.", reservation_id);
+            return 0;
         }
     }
 
@@ -539,7 +791,7 @@ impl ReservationStore {
             let res = handle.write().unwrap();
 
             if let Some(workflow) = res.as_any().downcast_ref::<Workflow>() {
-                return Some(workflow.clone().calculate_upward_rank(average_link_speed, self));
+                return workflow.clone().calculate_upward_rank(average_link_speed, self).ok();
             } else {
                 log::error!(
                     "Upward Rank can only be calculated for a Reservation of type Workflow. Reservation {:?} has type {:?}",
@@ -591,51 +843,113 @@ impl ReservationStore {
 
     /// Adjusts the reserved capacity of a reservation by the provided amount.
     pub fn adjust_capacity(&self, reservation_id: ReservationId, capacity: i64) {
-        if let Some(handle) = self.get(reservation_id) {
-            let mut res = handle.write().unwrap();
-            res.adjust_capacity(capacity);
-        } else {
+        if self.with_reservation_mut(reservation_id, |res| res.adjust_capacity(capacity)).is_none() {
             log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
         }
     }
 
     /// Adjusts the task duration of a reservation by the provided amount.
     pub fn adjust_task_duration(&self, reservation_id: ReservationId, duration: i64) {
-        if let Some(handle) = self.get(reservation_id) {
-            let mut res = handle.write().unwrap();
-            res.adjust_task_duration(duration);
-        } else {
+        if self.with_reservation_mut(reservation_id, |res| res.adjust_task_duration(duration)).is_none() {
             log::error!("Get reservation (id: {:?}) was not possible.", reservation_id)
         }
     }
 
+    /// Validated variant of `update_state`, which only performs transitions allowed by `ReservationState::can_transition_to`.
+    /// The transition is checked and performed under the same write lock, hence concurrent callers can not both pass the check.
+    ///
+    /// # Returns
+    /// `Err(Error::IllegalStateTransition)` if the transition is not allowed, the state is not changed in this case.
+    pub fn try_update_state(&self, id: ReservationId, new_state: ReservationState) -> Result<(), Error> {
+        let transition = self.with_reservation_mut(id, |res| {
+            let old_state = res.get_state();
+            if !old_state.can_transition_to(new_state) {
+                return Err(Error::IllegalStateTransition { from: old_state, to: new_state });
+            }
+
+            res.set_state(new_state);
+            return Ok(old_state);
+        });
+
+        match transition {
+            Some(Ok(old_state)) => {
+                self.notify_listeners(id, old_state, new_state);
+                return Ok(());
+            }
+            Some(Err(error)) => return Err(error),
+            None => panic!("Reservation (id: {:?}) does not exist.", id),
+        }
+    }
+
     /// Atomically updates the state of a reservation and notifies all listeners.
+    /// The new state is always set, illegal transitions (see `try_update_state`) are only logged as warning.
     pub fn update_state(&self, id: ReservationId, new_state: ReservationState) {
-        let old_state = self.get_state(id);
+        let old_state = self.with_reservation_mut(id, |res| {
+            let old_state = res.get_state();
+            res.set_state(new_state);
+            return old_state;
+        });
+
+        let Some(old_state) = old_state else {
+            panic!("Reservation (id: {:?}) does not exist.", id);
+        };
+
+        if !old_state.can_transition_to(new_state) {
+            log::warn!(
+                "ReservationStoreIllegalStateTransition: Reservation {:?} is forced from {:?} to {:?}.",
+                self.get_name_for_key(id),
+                old_state,
+                new_state
+            );
+        }
+
+        self.notify_listeners(id, old_state, new_state);
+    }
+
+    /// Resets the reservation to `ReservationState::Open`, such that its owner can place it again, e.g. a
+    /// WorkflowScheduler, which retries or reschedules a CoAllocation of a live workflow. In contrast to `update_state`,
+    /// leaving a terminal state is intended here and is not logged as illegal transition. The subtasks of a live
+    /// workflow are never collected by `gc_terminal_except` (see `VrmComponentManager::gc_terminal_reservations`).
+    pub fn reopen(&self, id: ReservationId) {
+        let old_state = self.with_reservation_mut(id, |res| {
+            let old_state = res.get_state();
+            res.set_state(ReservationState::Open);
+            return old_state;
+        });
+
+        match old_state {
+            Some(old_state) => self.notify_listeners(id, old_state, ReservationState::Open),
+            None => log::error!("Get reservation (id: {:?}) was not possible.", id),
+        }
+    }
 
-        let should_notify = {
+    /// Notifies all listeners about the state change of the reservation `id`.
+    fn notify_listeners(&self, id: ReservationId, old_state: ReservationState, new_state: ReservationState) {
+        let listeners = {
             let guard = self.inner.read().unwrap();
-            if let Some(res_lock) = guard.slots.get(id) {
-                let mut res = res_lock.write().unwrap();
-                res.set_state(new_state);
-                true
-            } else {
-                false
-            }
+            guard.listeners.clone()
         };
 
-        if should_notify {
-            let listeners = {
-                let guard = self.inner.read().unwrap();
-                guard.listeners.clone()
-            };
-
-            for listener in listeners {
-                listener.write().expect("Lock poisoned").on_reservation_change(id, self.get_name_for_key(id).unwrap(), old_state, new_state);
-            }
+        for listener in listeners {
+            listener.write().expect("Lock poisoned").on_reservation_change(id, self.get_name_for_key(id).unwrap(), old_state, new_state);
         }
     }
 
+    /// Provides mutable access to a reservation, such that several fields can be modified under a single write lock.
+    ///
+    /// # Returns
+    /// Returns `Some(R)` with the result of `f` if the ReservationId was present in the store, otherwise `None`.
+    pub fn with_reservation_mut<F, R>(&self, reservation_id: ReservationId, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut Reservation) -> R,
+    {
+        let guard = self.inner.read().expect("RwLock poisoned");
+        let handle = guard.slots.get(reservation_id)?;
+        let mut res = handle.write().expect("Lock poisoned");
+
+        return Some(f(&mut res));
+    }
+
     /// Provides mutable access to a workflow for scheduling purposes.
     pub fn with_workflow_mut<F, R>(&self, reservation_id: ReservationId, f: F) -> Option<R>
     where
@@ -665,7 +979,9 @@ impl ReservationStore {
     /// This creates a deep copy of all reservations to allow isolated modification.
     /// This means a Scheduler can work on the Shadow Store using the same Keys
     /// as the Master Store, but changes will not affect the Master.
-    /// Note: ReservationStore snapshot has no active Listeners.
+    /// Note: The snapshot starts without listeners, so state changes in the Shadow Store are not
+    /// reported to the listeners of the Master Store. If the snapshot replaces the Master Store, the
+    /// listeners have to be moved over (see `VrmComponentManager::commit_shadow_schedule`).
     pub fn snapshot(&self) -> ReservationStore {
         let guard = self.inner.read().unwrap();
         let mut new_slots = guard.slots.clone();
@@ -680,7 +996,7 @@ impl ReservationStore {
             name_index: guard.name_index.clone(),
             client_index: guard.client_index.clone(),
             handler_index: guard.handler_index.clone(),
-            listeners: guard.listeners.clone(),
+            listeners: Vec::new(),
         };
 
         ReservationStore { inner: Arc::new(RwLock::new(new_inner)) }
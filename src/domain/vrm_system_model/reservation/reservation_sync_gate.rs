@@ -4,7 +4,10 @@ use std::{
 };
 
 use crate::domain::vrm_system_model::{
-    reservation::{reservation::ReservationState, reservation_store::ReservationId},
+    reservation::{
+        reservation::{ReservationState, ReservationTrait},
+        reservation_store::{HealthIssue, ReservationId, ReservationStore},
+    },
     utils::id::ComponentId,
 };
 
@@ -84,4 +87,20 @@ impl SyncRegistry {
     pub fn remove_gate(&self, id: ReservationId) {
         self.gates.write().unwrap().remove(&id);
     }
+
+    /// Returns all gates, whose reservation is no longer in the ReservationStore or no longer
+    /// waits in state `ReserveProbeReservation` for the answer of an AcI.
+    pub fn health_check(&self, reservation_store: &ReservationStore) -> Vec<HealthIssue> {
+        return self
+            .gates
+            .read()
+            .unwrap()
+            .keys()
+            .filter(|reservation_id| match reservation_store.get(**reservation_id) {
+                Some(reservation) => reservation.read().unwrap().get_state() != ReservationState::ReserveProbeReservation,
+                None => true,
+            })
+            .map(|reservation_id| HealthIssue::OrphanedGate { reservation_id: *reservation_id })
+            .collect();
+    }
 }
@@ -42,10 +42,13 @@ impl Reservations {
     /// Deletes a reservation from the local set and updates the global state to `Deleted`.
     /// This effectively cancels the reservation and notifies the distributed store
     /// that the resources associated with this ID are no longer reserved.
+    /// A reservation in a terminal state (e.g. `Rejected`) keeps its state, only its resources are released.
     pub fn delete_reservation(&mut self, id: &ReservationId) -> bool {
         if self.reservations.remove(id) {
-            log::debug!("Reservation was updated to ReservationState::Deleted, by the schedule.");
-            self.reservation_store.update_state(*id, ReservationState::Deleted);
+            if !self.reservation_store.get_state(*id).is_terminal() {
+                log::debug!("Reservation was updated to ReservationState::Deleted, by the schedule.");
+                self.reservation_store.update_state(*id, ReservationState::Deleted);
+            }
             return true;
         }
         return false;
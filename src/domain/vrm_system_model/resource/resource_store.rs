@@ -1,7 +1,7 @@
 use colored::Colorize;
 use slotmap::{SlotMap, new_key_type};
 use std::{
-    collections::HashMap,
+    collections::{BTreeSet, HashMap},
     sync::{Arc, RwLock},
 };
 
@@ -40,6 +40,9 @@ struct StoreInner {
 
     /// Index lookup InternalKey (NodeResourceId) using input reservation name (ResourceName).
     node_index: HashMap<ResourceName, NodeResourceId>,
+
+    /// Routers of the network topology, i.e. the routers the nodes are connected to and the end points of all links.
+    routers: BTreeSet<RouterId>,
 }
 
 impl ResourceStore {
@@ -143,11 +146,34 @@ impl ResourceStore {
         return false;
     }
 
+    //-----------------------
+    // --- Router Methods ---
+    //-----------------------
+    /// Adds routers (e.g. the routers a node is connected to) to the network topology.
+    pub fn add_routers(&self, router_ids: &[RouterId]) {
+        let mut guard = self.inner.write().unwrap();
+        guard.routers.extend(router_ids.iter().cloned());
+    }
+
+    /// Returns the routers of the network topology (sorted).
+    pub fn get_router_list(&self) -> Vec<RouterId> {
+        let guard = self.inner.read().unwrap();
+        guard.routers.iter().cloned().collect()
+    }
+
+    /// Returns true, if the provided router is part of the network topology.
+    pub fn contains_router(&self, router_id: &RouterId) -> bool {
+        let guard = self.inner.read().unwrap();
+        guard.routers.contains(router_id)
+    }
+
     //---------------------
     // --- Link Methods ---
     //---------------------
     pub fn add_link(&self, link: LinkResource) -> LinkResourceId {
         let mut guard = self.inner.write().unwrap();
+        guard.routers.insert(link.source.clone());
+        guard.routers.insert(link.target.clone());
         guard.links.insert(Arc::new(RwLock::new(link)))
     }
 
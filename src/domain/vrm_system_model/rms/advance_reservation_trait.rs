@@ -2,7 +2,7 @@ use crate::domain::vrm_system_model::reservation::probe_reservations::{ProbeRese
 use crate::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState};
 use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
 use crate::domain::vrm_system_model::rms::rms::{Rms, RmsLoadMetric};
-use crate::domain::vrm_system_model::utils::id::ShadowScheduleId;
+use crate::domain::vrm_system_model::utils::id::{RouterId, ShadowScheduleId};
 
 /// Direct interface to a local Resource Management System (RMS) capable of making advance reservations.
 ///
@@ -219,4 +219,12 @@ pub trait AdvanceReservationRms: Rms + Send + Sync {
     fn get_link_resource_count(&self) -> usize {
         self.get_base().resource_store.get_num_of_links()
     }
+
+    fn get_router_list(&self) -> Vec<RouterId> {
+        self.get_base().resource_store.get_router_list()
+    }
+
+    fn contains_router(&self, router_id: &RouterId) -> bool {
+        self.get_base().resource_store.contains_router(router_id)
+    }
 }
@@ -54,6 +54,7 @@ impl TryFrom<(DummyRmsDto, Arc<GlobalClock>, AciId, ReservationStore)> for RmsNo
         // Add nodes to ResourceStore
         for node in nodes.iter() {
             resource_store.add_node(NodeResource::new(node.name.clone(), node.cpus));
+            resource_store.add_routers(&node.connected_to_router);
         }
 
         let name = format!("AcI: {}, RmsType: {}", aci_id, dto.typ);
@@ -84,6 +84,7 @@ impl TryFrom<(DummyRmsDto, Arc<GlobalClock>, AciId, ReservationStore)> for RmsSi
         for node in nodes.iter() {
             schedule_capacity += node.cpus;
             resource_store.add_node(NodeResource::new(node.name.clone(), node.cpus));
+            resource_store.add_routers(&node.connected_to_router);
         }
 
         let name = format!("AcI: {}, RmsType: {}", aci_id, dto.typ);
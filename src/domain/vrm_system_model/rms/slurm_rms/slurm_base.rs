@@ -71,6 +71,7 @@ impl SlurmRms {
         for node in nodes.iter() {
             schedule_capacity += node.cpus;
             resource_store.add_node(NodeResource::new(node.name.clone(), node.cpus));
+            resource_store.add_routers(&node.connected_to_router);
         }
 
         let name = format!("AcI: {}, RmsType: {}, RmsName: {}", aci_id, "Slurm".to_string(), dto.id);
@@ -1,2 +1,3 @@
+pub mod null_network_schedule;
 pub mod schedule_trait;
 pub mod slotted_schedule;
@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::domain::simulator::simulator::GlobalClock;
+use crate::domain::vrm_system_model::reservation::probe_reservations::{ProbeReservationComparator, ProbeReservations};
+use crate::domain::vrm_system_model::reservation::reservation::{ReservationState, ReservationTrait};
+use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use crate::domain::vrm_system_model::schedule::schedule_trait::Schedule;
+use crate::domain::vrm_system_model::utils::id::SlottedScheduleId;
+use crate::domain::vrm_system_model::utils::load_buffer::LoadMetric;
+
+/// A network schedule (NullBroker), which treats the network as infinite-bandwidth and zero-latency.
+///
+/// Every LinkReservation succeeds instantly: it is placed at the start of its booking interval with zero duration
+/// and zero capacity, hence a real dependency is effectively scheduled like a dummy dependency. This serves as a
+/// control condition, which isolates the compute scheduling from network effects.
+#[derive(Debug, Clone)]
+pub struct NullNetworkSchedule {
+    pub id: SlottedScheduleId,
+    pub reservation_store: ReservationStore,
+    pub simulator: Arc<GlobalClock>,
+    active_reservations: HashSet<ReservationId>,
+}
+
+impl NullNetworkSchedule {
+    pub fn new(id: SlottedScheduleId, reservation_store: ReservationStore, simulator: Arc<GlobalClock>) -> Self {
+        return Self { id, reservation_store, simulator, active_reservations: HashSet::new() };
+    }
+
+    /// Assigns the instant transfer (zero duration and capacity at the start of the booking interval) to `reservation_id`.
+    fn assign_instant_transfer(&mut self, reservation_id: ReservationId, start: i64) {
+        self.reservation_store.set_assigned_start(reservation_id, start);
+        self.reservation_store.set_assigned_end(reservation_id, start);
+        self.reservation_store.set_reserved_capacity(reservation_id, 0);
+        self.reservation_store.set_task_duration(reservation_id, 0);
+    }
+
+    /// The network does not limit anything, hence neither a reserved capacity nor a possible capacity is reported.
+    fn get_empty_load_metric(start_time: i64, end_time: i64) -> LoadMetric {
+        return LoadMetric::new(start_time, end_time, 0.0, 0.0, 0.0);
+    }
+}
+
+impl Schedule for NullNetworkSchedule {
+    fn get_fragmentation(&mut self, _frag_start_time: i64, _frag_end_time: i64) -> f64 {
+        return 0.0;
+    }
+
+    fn get_system_fragmentation(&mut self) -> f64 {
+        return 0.0;
+    }
+
+    fn get_load_metric_up_to_date(&mut self, start_time: i64, end_time: i64) -> LoadMetric {
+        self.update();
+        return Self::get_empty_load_metric(start_time, end_time);
+    }
+
+    fn get_load_metric(&self, start_time: i64, end_time: i64) -> LoadMetric {
+        return Self::get_empty_load_metric(start_time, end_time);
+    }
+
+    fn get_simulation_load_metric(&mut self) -> LoadMetric {
+        let now = self.simulator.get_system_time_s();
+        return Self::get_empty_load_metric(now, now);
+    }
+
+    fn probe(&mut self, reservation_id: ReservationId) -> ProbeReservations {
+        let mut candidates = ProbeReservations::new(reservation_id, self.reservation_store.clone());
+
+        if let Some(mut candidate) = self.reservation_store.get_reservation_snapshot(reservation_id) {
+            let start = candidate.get_booking_interval_start();
+            candidate.set_booking_interval_start(start);
+            candidate.set_booking_interval_end(start);
+            candidate.set_assigned_start(start);
+            candidate.set_assigned_end(start);
+            candidate.set_reserved_capacity(0);
+            candidate.set_task_duration(0);
+            candidate.set_state(ReservationState::ProbeReservation);
+
+            if candidates.add_reservation(candidate).is_err() {
+                log::error!("NullNetworkScheduleProbeFailed: Reservation {:?} on Schedule {}.", reservation_id, self.id);
+            }
+        }
+
+        self.reservation_store.update_state(reservation_id, ReservationState::ProbeAnswer);
+        return candidates;
+    }
+
+    fn probe_best(&mut self, reservation_id: ReservationId, _probe_reservation_comparator: ProbeReservationComparator) -> ProbeReservations {
+        // The probe contains only a single candidate
+        return self.probe(reservation_id);
+    }
+
+    fn reserve(&mut self, reservation_id: ReservationId) -> Option<ReservationId> {
+        let start = self.reservation_store.get_booking_interval_start(reservation_id);
+        self.assign_instant_transfer(reservation_id, start);
+        self.reserve_without_check(reservation_id);
+        return Some(reservation_id);
+    }
+
+    fn reserve_without_check(&mut self, reservation_id: ReservationId) {
+        self.active_reservations.insert(reservation_id);
+        self.reservation_store.update_state(reservation_id, ReservationState::ReserveAnswer);
+    }
+
+    fn can_reserve_all(&mut self, _reservations: &[(ReservationId, (i64, i64))]) -> bool {
+        return true;
+    }
+
+    fn reserve_all_or_none(&mut self, reservations: &[(ReservationId, (i64, i64))]) -> bool {
+        for (reservation_id, (start, _)) in reservations {
+            self.reservation_store.set_booking_interval_start(*reservation_id, *start);
+            self.reservation_store.set_booking_interval_end(*reservation_id, *start);
+            self.assign_instant_transfer(*reservation_id, *start);
+            self.reserve_without_check(*reservation_id);
+        }
+        return true;
+    }
+
+    fn delete_reservation(&mut self, id: ReservationId) {
+        if !self.active_reservations.remove(&id) {
+            log::debug!("NullNetworkScheduleDeleteUnknownReservation: Reservation {:?} is not active on Schedule {}.", id, self.id);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.active_reservations.clear();
+    }
+
+    fn update(&mut self) {
+        let now = self.simulator.get_system_time_s();
+        let reservation_store = self.reservation_store.clone();
+        self.active_reservations.retain(|reservation_id| reservation_store.get_assigned_end(*reservation_id) >= now);
+    }
+
+    fn update_capacity(&mut self, _capacity: usize) {
+        // The capacity of the NullNetworkSchedule is unlimited
+    }
+
+    fn clone_box(&self) -> Box<dyn Schedule> {
+        Box::new(self.clone())
+    }
+}
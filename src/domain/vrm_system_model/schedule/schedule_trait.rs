@@ -108,6 +108,32 @@ pub trait Schedule: Debug + Send + Sync {
     /// * `id` - The `ReservationId` to be inserted directly into the schedule slots.
     fn reserve_without_check(&mut self, id: ReservationId);
 
+    /// Checks if a group of reservations (e.g. the members of a co-allocation) **fits together** into the schedule
+    /// in the provided fixed time windows. The schedule is not modified.
+    ///
+    /// # Arguments
+    ///
+    /// * `reservations` - The `ReservationId`s with their time window `(assigned_start, assigned_end)`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if all reservations can be reserved at the same time.
+    fn can_reserve_all(&mut self, reservations: &[(ReservationId, (i64, i64))]) -> bool;
+
+    /// **Atomically reserves a group of reservations** (e.g. the members of a co-allocation) in fixed time windows.
+    ///
+    /// First it is checked if all reservations fit together into the schedule, only afterwards are the
+    /// reservations committed. If a single reservation does not fit, no reservation is inserted and no slot is modified.
+    ///
+    /// # Arguments
+    ///
+    /// * `reservations` - The `ReservationId`s with their time window `(assigned_start, assigned_end)`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if all reservations were reserved, `false` if none was reserved.
+    fn reserve_all_or_none(&mut self, reservations: &[(ReservationId, (i64, i64))]) -> bool;
+
     /// Removes an **active reservation** from the schedule and frees up the occupied capacity
     /// in all relevant time slots.
     ///
@@ -0,0 +1,92 @@
+use crate::domain::vrm_system_model::{
+    reservation::reservation_store::ReservationId,
+    schedule::{
+        schedule_trait::Schedule,
+        slotted_schedule::{slotted_schedule_context::SlottedScheduleContext, strategy::strategy_trait::SlottedScheduleStrategy},
+    },
+};
+
+impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
+    /// Searches for the earliest window of `task_duration` in which every covered slot has enough free capacity
+    /// for the reservation (**conservative backfilling**).
+    ///
+    /// The `latest` time acts as reservation floor: the found window ends at the latest at this point in time.
+    /// If `latest` is the planned start of a blocked (pivot) reservation, a backfilled reservation only uses idle capacity
+    /// ahead of the pivot and never delays it. The schedule is not modified and the reservation is not moldable in this search.
+    ///
+    /// # Arguments
+    /// * `reservation_id` - The reservation, which should be backfilled (provides `task_duration` and `reserved_capacity`).
+    /// * `earliest` - The earliest possible start time in s.
+    /// * `latest` - The reservation floor in s, the window must end at or before this time.
+    ///
+    /// # Returns
+    /// The earliest feasible start time or `None` if no window between `earliest` and `latest` has enough free capacity.
+    pub fn find_backfill_slot(&self, reservation_id: ReservationId, earliest: i64, latest: i64) -> Option<i64> {
+        let duration = self.reservation_store.get_task_duration(reservation_id);
+        let required_capacity = self.reservation_store.get_reserved_capacity(reservation_id);
+
+        if duration <= 0 || required_capacity < 0 || earliest < 0 || earliest + duration > latest {
+            return None;
+        }
+
+        let first_slot_index = self.get_effective_slot_index(self.time_to_slot_index(earliest));
+        let last_slot_index = self.get_effective_slot_index(self.time_to_slot_index(latest - duration));
+
+        for slot_start_index in first_slot_index..=last_slot_index {
+            let start_time = self.slot_index_to_time(slot_start_index).max(earliest);
+            let end_time = start_time + duration;
+
+            if end_time > latest || !self.is_time_in_scheduling_window(start_time) || !self.is_time_in_scheduling_window(end_time - 1) {
+                continue;
+            }
+
+            let fits = (self.time_to_slot_index(start_time)..=self.time_to_slot_index(end_time - 1))
+                .all(|slot_index| S::adjust_requirement_to_slot_capacity(self, slot_index, required_capacity, reservation_id) == required_capacity);
+
+            if fits {
+                return Some(start_time);
+            }
+        }
+        return None;
+    }
+
+    /// Reserves the reservation in the earliest idle window ahead of the reservation floor (see `find_backfill_slot`).
+    ///
+    /// The search is limited by the booking interval of the reservation and by the `reservation_floor`.
+    /// If no window is found, the schedule and the reservation state are not modified, so the reservation can still be
+    /// reserved regularly (e.g. after the pivot reservation).
+    ///
+    /// # Arguments
+    /// * `reservation_id` - The reservation, which should be backfilled.
+    /// * `reservation_floor` - Time in s (e.g. the planned start of the blocked reservation), at which the reservation must be finished.
+    ///
+    /// # Returns
+    /// `Some(reservation_id)` if the reservation was backfilled, otherwise `None`.
+    pub fn reserve_with_backfill(&mut self, reservation_id: ReservationId, reservation_floor: i64) -> Option<ReservationId> {
+        SlottedScheduleContext::update(self);
+
+        let earliest = self.reservation_store.get_booking_interval_start(reservation_id).max(self.scheduling_window_start_time);
+        let latest = self.reservation_store.get_booking_interval_end(reservation_id).min(reservation_floor);
+
+        let Some(start_time) = self.find_backfill_slot(reservation_id, earliest, latest) else {
+            log::debug!(
+                "SlottedScheduleContextNoBackfillSlot: Reservation {:?} does not fit in Schedule {} between {} and {}.",
+                self.reservation_store.get_name_for_key(reservation_id),
+                self.id,
+                earliest,
+                latest
+            );
+            return None;
+        };
+
+        let end_time = start_time + self.reservation_store.get_task_duration(reservation_id);
+        self.reservation_store.set_booking_interval_start(reservation_id, start_time);
+        self.reservation_store.set_booking_interval_end(reservation_id, end_time);
+        self.reservation_store.set_assigned_start(reservation_id, start_time);
+        self.reservation_store.set_assigned_end(reservation_id, end_time);
+
+        self.is_frag_cache_up_to_date = false;
+        self.reserve_without_check(reservation_id);
+        return Some(reservation_id);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::domain::vrm_system_model::{
+    reservation::reservation_store::ReservationId,
+    schedule::{
+        schedule_trait::Schedule,
+        slotted_schedule::{slotted_schedule_context::SlottedScheduleContext, strategy::strategy_trait::SlottedScheduleStrategy},
+    },
+};
+
+impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
+    /// **Compacts the schedule** by shifting moveable reservations earlier to coalesce the free capacity.
+    ///
+    /// The moveable reservations (`is_moveable`), which have not started yet, are processed in the order of their
+    /// assigned start. Each reservation is removed from its slots and placed again at the earliest start between its
+    /// `booking_interval_start` and its old start, at which all covered slots have enough free capacity (see `find_backfill_slot`).
+    /// Hence a reservation is never moved later, never before its booking interval and never exceeds a slot capacity.
+    /// The booking interval and the state of the moved reservations are not modified.
+    ///
+    /// # Returns
+    /// A `Vec` of `(reservation_id, old_start, new_start)` of all reservations, which were moved.
+    pub fn compact(&mut self) -> Vec<(ReservationId, i64, i64)> {
+        SlottedScheduleContext::update(self);
+
+        let current_time = self.simulator.get_system_time_s();
+        let mut moveable_reservations: Vec<(i64, ReservationId)> = self
+            .active_reservations
+            .iter()
+            .filter(|id| self.reservation_store.is_moveable(**id))
+            .map(|id| (self.reservation_store.get_assigned_start(*id), *id))
+            .filter(|(assigned_start, _)| *assigned_start > current_time)
+            .collect();
+        moveable_reservations.sort();
+
+        let mut moved_reservations: Vec<(ReservationId, i64, i64)> = Vec::new();
+
+        for (old_start, reservation_id) in moveable_reservations {
+            let old_end = self.reservation_store.get_assigned_end(reservation_id);
+            let earliest = self.reservation_store.get_booking_interval_start(reservation_id).max(self.scheduling_window_start_time).max(current_time);
+
+            if earliest >= old_start {
+                continue;
+            }
+
+            // Release the slots of the reservation, so that its old position is a feasible candidate again
+            let state = self.reservation_store.get_state(reservation_id);
+            SlottedScheduleContext::delete_reservation(self, reservation_id);
+
+            let new_start = self.find_backfill_slot(reservation_id, earliest, old_end).unwrap_or(old_start);
+            let duration = old_end - old_start;
+
+            self.reservation_store.set_assigned_start(reservation_id, new_start);
+            self.reservation_store.set_assigned_end(reservation_id, new_start + duration);
+            self.reserve_without_check(reservation_id);
+            self.reservation_store.update_state(reservation_id, state);
+
+            if new_start < old_start {
+                moved_reservations.push((reservation_id, old_start, new_start));
+            }
+        }
+
+        if !moved_reservations.is_empty() {
+            self.is_frag_cache_up_to_date = false;
+            log::debug!("SlottedSchedule {}: Compaction moved {} reservations.", self.id, moved_reservations.len());
+        }
+
+        return moved_reservations;
+    }
+}
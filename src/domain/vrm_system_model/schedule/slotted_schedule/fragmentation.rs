@@ -39,6 +39,61 @@ impl<S: SlottedScheduleStrategy + Clone + 'static> SlottedScheduleContext<S> {
         return self.calculate_avg_fragmentation(&quad_sum_per_free_block, &sum_per_free_block);
     }
 
+    /// Computes the fragmentation contribution of every slot in the current scheduling window, e.g. to locate
+    /// fragmentation hotspots over time.
+    ///
+    /// The quadratic mean formula is applied per slot instead of aggregated: for every capacity level, which is free
+    /// in the slot, the slot gets the normalized term `(block_len / sum_block_len)^(FRAGMENTATION_POWER - 1)` of the free
+    /// block it belongs to (summed over the slots of a block, this is the term of the aggregated formula).
+    /// The fragmentation of the slot is `1.0` minus the average of these terms. A fully occupied slot has a fragmentation of **0.0**.
+    ///
+    /// The slots are only read, hence the `fragmentation_cache` stays valid.
+    ///
+    /// # Returns
+    /// A `Vec` of `(virtual slot index, fragmentation)` for all slots from `start_slot_index` to `end_slot_index`.
+    pub fn fragmentation_by_slot(&self) -> Vec<(i64, f64)> {
+        let capacity = S::get_capacity(self);
+        let free_capacities: Vec<i64> =
+            (self.start_slot_index..=self.end_slot_index).map(|slot_index| capacity - self.get_slot_load(slot_index)).collect();
+
+        let mut sum_of_terms: Vec<f64> = vec![0.0; free_capacities.len()];
+        let mut number_of_terms: Vec<i64> = vec![0; free_capacities.len()];
+
+        for level in 1..=capacity {
+            let sum_block_len = free_capacities.iter().filter(|free_capacity| **free_capacity >= level).count() as f64;
+            if sum_block_len == 0.0 {
+                continue;
+            }
+
+            let mut block_start = 0;
+            while block_start < free_capacities.len() {
+                if free_capacities[block_start] < level {
+                    block_start += 1;
+                    continue;
+                }
+
+                let block_end = (block_start..free_capacities.len()).find(|index| free_capacities[*index] < level).unwrap_or(free_capacities.len());
+                let term = f64::powf((block_end - block_start) as f64 / sum_block_len, FRAGMENTATION_POWER - 1.0);
+
+                for index in block_start..block_end {
+                    sum_of_terms[index] += term;
+                    number_of_terms[index] += 1;
+                }
+                block_start = block_end;
+            }
+        }
+
+        return (self.start_slot_index..=self.end_slot_index)
+            .enumerate()
+            .map(|(index, slot_index)| {
+                if number_of_terms[index] == 0 {
+                    return (slot_index, 0.0);
+                }
+                return (slot_index, 1.0 - sum_of_terms[index] / number_of_terms[index] as f64);
+            })
+            .collect();
+    }
+
     fn add_block_which_end_in_range(
         &self,
         start_slot_index: i64,
@@ -154,7 +209,7 @@ impl<S: SlottedScheduleStrategy + Clone + 'static> SlottedScheduleContext<S> {
                 let id = self.active_reservations.get_random_id().expect("No random ReservationId was found in test SlottedSchedule.");
 
                 let is_non_overlapping = self.reservation_store.get_assigned_start(id.clone()) > self.get_slot_end_time(end_slot_index)
-                    || self.reservation_store.get_assigned_end(id.clone()) < self.get_slot_start_time(start_slot_index);
+                    || self.reservation_store.get_assigned_end(id.clone()) < self.slot_index_to_time(start_slot_index);
 
                 if !is_non_overlapping {
                     break id;
@@ -3,8 +3,11 @@ use crate::domain::vrm_system_model::schedule::slotted_schedule::{
     strategy::{link::link_strategy::LinkStrategy, node::node_strategy::NodeStrategy},
 };
 
+pub mod backfill;
+pub mod compaction;
 pub mod fragmentation;
 pub mod schedule_base;
+pub mod schedule_snapshot;
 pub mod slot;
 pub mod slotted_schedule_context;
 pub mod strategy;
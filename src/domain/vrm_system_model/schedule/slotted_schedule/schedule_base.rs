@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use crate::domain::vrm_system_model::{
     reservation::{
         probe_reservations::{ProbeReservationComparator, ProbeReservations},
@@ -118,6 +120,11 @@ impl<S: SlottedScheduleStrategy> Schedule for SlottedScheduleContext<S> {
             SlottedScheduleContext::update(self);
             // Delete Reservation from SlottedSchedule
             SlottedScheduleContext::delete_reservation(self, reservation_id);
+
+            // Release the strategy specific bookings (e.g. the booked network path), if the reservation was deleted
+            if !self.active_reservations.contains_key(&reservation_id) {
+                S::on_delete_reservation(self, reservation_id);
+            }
         }
     }
 
@@ -159,8 +166,8 @@ impl<S: SlottedScheduleStrategy> Schedule for SlottedScheduleContext<S> {
             self.reservation_store.update_state(reservation_id, ReservationState::Rejected);
         }
 
-        let start_slot = self.get_slot_index(self.reservation_store.get_assigned_start(reservation_id.clone()));
-        let end_slot = self.get_slot_index(self.reservation_store.get_assigned_end(reservation_id.clone()) - 1);
+        let start_slot = self.time_to_slot_index(self.reservation_store.get_assigned_start(reservation_id.clone()));
+        let end_slot = self.time_to_slot_index(self.reservation_store.get_assigned_end(reservation_id.clone()) - 1);
 
         for slot_index in start_slot..=end_slot {
             S::insert_reservation_into_slot(self, self.reservation_store.get_reserved_capacity(reservation_id), slot_index, reservation_id);
@@ -170,6 +177,71 @@ impl<S: SlottedScheduleStrategy> Schedule for SlottedScheduleContext<S> {
         self.reservation_store.update_state(reservation_id, ReservationState::ReserveAnswer);
     }
 
+    fn can_reserve_all(&mut self, reservations: &[(ReservationId, (i64, i64))]) -> bool {
+        SlottedScheduleContext::update(self);
+
+        // Accumulate the requested capacity of all reservations per slot
+        let mut slot_requirements: HashMap<i64, i64> = HashMap::new();
+
+        for (reservation_id, (start, end)) in reservations {
+            let reserved_capacity = self.reservation_store.get_reserved_capacity(*reservation_id);
+
+            if reserved_capacity < 0 || start >= end || !self.is_time_in_scheduling_window(*start) || !self.is_time_in_scheduling_window(end - 1) {
+                log::debug!(
+                    "SlottedScheduleContextCanReserveAllInvalidRequest: Reservation {:?} with window ({}, {}) and capacity {} can not be placed on Schedule {}.",
+                    self.reservation_store.get_name_for_key(*reservation_id),
+                    start,
+                    end,
+                    reserved_capacity,
+                    self.id
+                );
+                return false;
+            }
+
+            for slot_index in self.time_to_slot_index(*start)..=self.time_to_slot_index(end - 1) {
+                *slot_requirements.entry(slot_index).or_insert(0) += reserved_capacity;
+            }
+        }
+
+        for (slot_index, requirement) in &slot_requirements {
+            let fits = match self.get_slot(*slot_index) {
+                Some(slot) => slot.get_adjust_requirement(*requirement) == *requirement,
+                None => false,
+            };
+
+            if !fits {
+                log::debug!(
+                    "SlottedScheduleContextCanReserveAllDoesNotFit: The group of {} reservations does not fit into slot {} of Schedule {} (requirement: {}).",
+                    reservations.len(),
+                    slot_index,
+                    self.id,
+                    requirement
+                );
+                return false;
+            }
+        }
+        return true;
+    }
+
+    fn reserve_all_or_none(&mut self, reservations: &[(ReservationId, (i64, i64))]) -> bool {
+        // Check phase: all reservations must fit together, before any slot is modified
+        if !self.can_reserve_all(reservations) {
+            return false;
+        }
+
+        // Commit phase
+        for (reservation_id, (start, end)) in reservations {
+            self.reservation_store.set_booking_interval_start(*reservation_id, *start);
+            self.reservation_store.set_booking_interval_end(*reservation_id, *end);
+            self.reservation_store.set_assigned_start(*reservation_id, *start);
+            self.reservation_store.set_assigned_end(*reservation_id, *end);
+            self.reserve_without_check(*reservation_id);
+        }
+
+        self.is_frag_cache_up_to_date = false;
+        return true;
+    }
+
     fn update(&mut self) {
         SlottedScheduleContext::update(self);
     }
@@ -0,0 +1,51 @@
+use crate::domain::vrm_system_model::{
+    reservation::reservations::Reservations,
+    schedule::slotted_schedule::{slot::Slot, slotted_schedule_context::SlottedScheduleContext, strategy::strategy_trait::SlottedScheduleStrategy},
+};
+
+/// Copy of the slot allocations of a single `SlottedScheduleContext`.
+///
+/// In contrast to a shadow schedule, only the state of the schedule itself is copied and not the `ReservationStore`.
+/// Hence a snapshot is cheap and can be used to try a placement and roll it back afterwards.
+#[derive(Debug, Clone)]
+pub struct ScheduleSnapshot {
+    pub slots: Vec<Slot>,
+    pub start_slot_index: i64,
+    pub end_slot_index: i64,
+    pub scheduling_window_start_time: i64,
+    pub scheduling_window_end_time: i64,
+    pub active_reservations: Reservations,
+    pub is_frag_cache_up_to_date: bool,
+    pub fragmentation_cache: f64,
+}
+
+impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
+    /// Creates a snapshot of the slots, the scheduling window and the active reservations of this schedule.
+    pub fn snapshot(&self) -> ScheduleSnapshot {
+        return ScheduleSnapshot {
+            slots: self.slots.clone(),
+            start_slot_index: self.start_slot_index,
+            end_slot_index: self.end_slot_index,
+            scheduling_window_start_time: self.scheduling_window_start_time,
+            scheduling_window_end_time: self.scheduling_window_end_time,
+            active_reservations: self.active_reservations.clone(),
+            is_frag_cache_up_to_date: self.is_frag_cache_up_to_date,
+            fragmentation_cache: self.fragmentation_cache,
+        };
+    }
+
+    /// Restores the slots, the scheduling window and the active reservations from the provided snapshot.
+    ///
+    /// **Note:** The `ReservationStore` is not restored, i.e. reservations placed after the snapshot keep
+    /// their state and assigned times and have to be reset by the caller if needed.
+    pub fn restore(&mut self, snapshot: ScheduleSnapshot) {
+        self.slots = snapshot.slots;
+        self.start_slot_index = snapshot.start_slot_index;
+        self.end_slot_index = snapshot.end_slot_index;
+        self.scheduling_window_start_time = snapshot.scheduling_window_start_time;
+        self.scheduling_window_end_time = snapshot.scheduling_window_end_time;
+        self.active_reservations = snapshot.active_reservations;
+        self.is_frag_cache_up_to_date = snapshot.is_frag_cache_up_to_date;
+        self.fragmentation_cache = snapshot.fragmentation_cache;
+    }
+}
@@ -93,15 +93,19 @@ impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
             slots.push(Slot::new(capacity));
         }
 
+        // The scheduling window starts at the beginning of the slot containing the current time
+        let start_slot_index = simulator.get_system_time_s().div_euclid(slot_width);
+        let end_slot_index = start_slot_index + number_of_real_slots - 1;
+
         let mut slotted_context = SlottedScheduleContext {
             strategy,
             id: SlottedScheduleId::new(id),
             slots: slots,
             slot_width: slot_width,
-            start_slot_index: simulator.get_system_time_s(),
-            end_slot_index: number_of_real_slots,
-            scheduling_window_start_time: simulator.get_system_time_s(),
-            scheduling_window_end_time: (number_of_real_slots * slot_width) - 1, // End of the valid scheduling window
+            start_slot_index: start_slot_index,
+            end_slot_index: end_slot_index,
+            scheduling_window_start_time: start_slot_index * slot_width,
+            scheduling_window_end_time: (end_slot_index + 1) * slot_width - 1, // End of the valid scheduling window
             load_buffer: LoadBuffer::new(Arc::new(GlobalLoadContext::new())),
             active_reservations: Reservations::new_empty(reservation_store.clone()),
             is_frag_cache_up_to_date: true,
@@ -165,32 +169,26 @@ impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
         return self.slots.get_mut(real_index as usize);
     }
 
-    /// Calculates the **virtual index** of the time slot that contains the given point in time.
-    ///
-    /// This index is an abstract representation based on the `slot_width` and may exceed the actual number
-    /// of allocated slots (real slots) (`self.slots.len()`).
-    ///
-    /// **Note:** A negative input time will always yield an index of $0$.
-    pub fn get_slot_index(&self, time: i64) -> i64 {
-        let index: i64 = (time as f64 / self.slot_width as f64).floor() as i64;
-
-        if index < 0 {
-            log::error!("The requested slot index is negative ({}), because the requested time was negative: {}", index, time,);
-
-            return 0;
-        }
-
-        return index;
+    /// Computes the **absolute end time** in seconds of a virtual slot (inclusive, i.e. `slot_index_to_time(index + 1) - 1`).
+    pub fn get_slot_end_time(&self, index: i64) -> i64 {
+        return self.slot_index_to_time(index + 1) - 1;
     }
 
-    /// Computes the **absolute start time** in seconds of a virtual slot.
-    pub fn get_slot_start_time(&self, index: i64) -> i64 {
-        return index * self.slot_width;
+    /// Converts an absolute time in s to the **virtual index** of the slot containing it, relative to
+    /// `scheduling_window_start_time` (the slot `start_slot_index` starts at this time).
+    ///
+    /// The time is rounded down (floor) to the start of its slot, hence a start time maps to the first slot it occupies.
+    /// An exclusive end time has to be rounded up (ceil) to the next slot boundary instead, i.e. the last occupied slot is
+    /// `time_to_slot_index(end - 1)`. Times before the window start result in indices below `start_slot_index`
+    /// (without clamping, see `get_effective_slot_index`).
+    pub fn time_to_slot_index(&self, time: i64) -> i64 {
+        return self.start_slot_index + (time - self.scheduling_window_start_time).div_euclid(self.slot_width);
     }
 
-    /// Computes the **absolute end time** in seconds of a virtual slot.
-    pub fn get_slot_end_time(&self, index: i64) -> i64 {
-        return index * self.slot_width + self.slot_width - 1;
+    /// Converts a **virtual slot index** to the absolute start time in s of the slot (inverse of `time_to_slot_index`).
+    /// The exclusive end time of the slot is `slot_index_to_time(index + 1)`.
+    pub fn slot_index_to_time(&self, index: i64) -> i64 {
+        return self.scheduling_window_start_time + (index - self.start_slot_index) * self.slot_width;
     }
 
     /// Limits a given **virtual slot index** to ensure it is bounded by the current schedule window.
@@ -217,7 +215,7 @@ impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
     /// Optimization: This functions is prior to every probe and reserve request called
     pub fn update(&mut self) {
         let current_time = self.simulator.get_system_time_s();
-        let new_start_slot_index = self.get_slot_index(current_time);
+        let new_start_slot_index = self.time_to_slot_index(current_time);
         let effective_cleanup_end = new_start_slot_index.min(self.end_slot_index + 1);
 
         if self.start_slot_index < new_start_slot_index {
@@ -230,7 +228,7 @@ impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
         for clean_index in self.start_slot_index..effective_cleanup_end {
             if let Some(slot) = self.get_slot(clean_index) {
                 for id in &slot.reservation_ids {
-                    let last_slot_of_reservation = self.get_slot_index(self.reservation_store.get_assigned_end(id.clone()) - 1);
+                    let last_slot_of_reservation = self.time_to_slot_index(self.reservation_store.get_assigned_end(id.clone()) - 1);
                     if last_slot_of_reservation == clean_index {
                         ids_to_remove.insert(id.clone());
                     }
@@ -257,13 +255,51 @@ impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
             }
         }
 
+        // set corresponding start time of the new scheduling window (relative to the old window, hence before moving the pointers)
+        self.scheduling_window_start_time = self.slot_index_to_time(new_start_slot_index);
+
         // set new Pointer to start and end of the new scheduling window
         self.start_slot_index = new_start_slot_index;
         self.end_slot_index = new_start_slot_index + (self.slots.len() as i64) - 1;
+        self.scheduling_window_end_time = self.get_slot_end_time(self.end_slot_index);
+    }
+
+    /// **Extends the scheduling window** by appending `additional_slots` new slots with the capacity of the existing slots.
+    ///
+    /// Because the real index of a slot depends on the number of slots (see `get_real_slot_index`), all slots of the
+    /// current window are moved to their new real index, hence the existing reservations are preserved.
+    /// Afterwards `end_slot_index` and `scheduling_window_end_time` point to the end of the extended window.
+    pub fn extend_window(&mut self, additional_slots: i64) {
+        if additional_slots <= 0 {
+            log::warn!("SlottedSchedule {}: The scheduling window can not be extended by {} slots.", self.id, additional_slots);
+            return;
+        }
+
+        let capacity = self.slots.first().map(|slot| slot.capacity).unwrap_or_else(|| S::get_capacity(self));
+        let new_number_of_slots = self.slots.len() + additional_slots as usize;
+        let mut new_slots: Vec<Slot> = vec![Slot::new(capacity); new_number_of_slots];
+
+        // An end_slot_index, which wraps around onto the real slot of the start_slot_index, is not copied,
+        // otherwise the load of the start slot would be duplicated into the extended window
+        let last_slot_index = self.end_slot_index.min(self.start_slot_index + self.slots.len() as i64 - 1);
 
-        // set corresponding time borders for the scheduling window
-        self.scheduling_window_start_time = self.get_slot_start_time(self.start_slot_index);
+        for slot_index in self.start_slot_index..=last_slot_index {
+            if let Some(slot) = self.get_slot(slot_index) {
+                new_slots[(slot_index % new_number_of_slots as i64) as usize] = slot.clone();
+            }
+        }
+
+        self.slots = new_slots;
+        self.end_slot_index = self.start_slot_index + (self.slots.len() as i64) - 1;
         self.scheduling_window_end_time = self.get_slot_end_time(self.end_slot_index);
+        self.is_frag_cache_up_to_date = false;
+
+        log::debug!(
+            "SlottedSchedule {}: Extended scheduling window by {} slots, new window end: {}.",
+            self.id,
+            additional_slots,
+            self.scheduling_window_end_time
+        );
     }
 
     /// Validates, if deletion of reservation is possible, sets reservation in state `ReservationState::Rejected` if
@@ -307,8 +343,8 @@ impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
         }
 
         // Delete reservation from all occupied slots
-        let mut reservation_start_slot_index: i64 = self.get_slot_index(del_res_assigned_start);
-        let reservation_end_slot_index: i64 = self.get_effective_slot_index(self.get_slot_index(del_res_assigned_end - 1));
+        let mut reservation_start_slot_index: i64 = self.time_to_slot_index(del_res_assigned_start);
+        let reservation_end_slot_index: i64 = self.get_effective_slot_index(self.time_to_slot_index(del_res_assigned_end - 1));
 
         // Delete only parts that are in the scheduling window
         if reservation_start_slot_index < self.start_slot_index {
@@ -352,6 +388,29 @@ impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
             }
         }
     }
+
+    /// Returns the free capacity (capacity - load) of each slot in the time window [`start`, `end`] in s, e.g. to
+    /// chart the utilization of the schedule. A window, which partially overlaps the scheduling window, is clamped to it.
+    ///
+    /// # Returns
+    /// The `(virtual slot index, free capacity)` pairs in ascending slot order, or an empty `Vec` if the window lies
+    /// completely outside of [`start_slot_index`, `end_slot_index`].
+    pub fn free_capacity_profile(&self, start: i64, end: i64) -> Vec<(i64, i64)> {
+        if end < start {
+            return Vec::new();
+        }
+
+        let start_index = self.time_to_slot_index(start);
+        let end_index = self.time_to_slot_index(end);
+
+        if end_index < self.start_slot_index || start_index > self.end_slot_index {
+            return Vec::new();
+        }
+
+        return (self.get_effective_slot_index(start_index)..=self.get_effective_slot_index(end_index))
+            .filter_map(|slot_index| self.get_slot(slot_index).map(|slot| (slot_index, slot.capacity - slot.load)))
+            .collect();
+    }
 }
 
 impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
@@ -396,10 +455,10 @@ impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
             return search_results;
         }
 
-        let mut earliest_start_index: i64 = self.get_slot_index(request_start_boundary);
+        let mut earliest_start_index: i64 = self.time_to_slot_index(request_start_boundary);
         earliest_start_index = self.get_effective_slot_index(earliest_start_index);
 
-        let mut latest_start_index: i64 = self.get_slot_index(request_end_boundary - initial_duration);
+        let mut latest_start_index: i64 = self.time_to_slot_index(request_end_boundary - initial_duration);
         latest_start_index = self.get_effective_slot_index(latest_start_index);
 
         for slot_start_index in earliest_start_index..=latest_start_index {
@@ -416,7 +475,7 @@ impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
 
         let mut current_required_capacity = self.reservation_store.get_reserved_capacity(candidate_id.clone());
         let mut current_duration: i64 = self.reservation_store.get_task_duration(candidate_id.clone());
-        let mut start_time = self.get_slot_start_time(slot_start_index);
+        let mut start_time = self.slot_index_to_time(slot_start_index);
 
         let booking_interval_start = candidate.get_booking_interval_start();
 
@@ -426,7 +485,7 @@ impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
 
         let mut is_feasible: bool = true;
         let mut end_time = start_time + current_duration;
-        let mut current_end_slot_index = self.get_slot_index(end_time - 1);
+        let mut current_end_slot_index = self.time_to_slot_index(end_time - 1);
         let mut current_slot_index: i64 = slot_start_index;
 
         while current_slot_index <= current_end_slot_index {
@@ -454,7 +513,7 @@ impl<S: SlottedScheduleStrategy> SlottedScheduleContext<S> {
                     break;
                 }
 
-                current_end_slot_index = self.get_slot_index(end_time - 1);
+                current_end_slot_index = self.time_to_slot_index(end_time - 1);
             }
 
             current_slot_index += 1;
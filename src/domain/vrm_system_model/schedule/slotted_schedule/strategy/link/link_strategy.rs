@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use crate::domain::vrm_system_model::{
-    reservation::{reservation::ReservationState, reservation_store::ReservationId},
+    reservation::reservation_store::ReservationId,
     resource::resource_store::ResourceStore,
     schedule::slotted_schedule::{
         slotted_schedule_context::SlottedScheduleContext,
@@ -32,6 +32,49 @@ impl LinkStrategy {
     }
 }
 
+impl SlottedScheduleContext<LinkStrategy> {
+    /// Removes the booked path of the reservation from `reserved_paths` and deletes the reserved capacity of the
+    /// reservation in every booked slot of all traversed Links, so that the bandwidth is available again.
+    /// Slots, which already left the scheduling window of a Link, were reset by the Link schedule itself and are skipped.
+    ///
+    /// # Returns
+    /// `true` if the booked path was released from all Links, otherwise `false`.
+    pub fn release_path(&mut self, reservation_id: ReservationId) -> bool {
+        let Some(path_per_slot) = self.strategy.reserved_paths.remove(&reservation_id) else {
+            log::error!(
+                "NetworkScheduleReleasePathFailed: Reservation {:?} has no booked path in NetworkSlottedSchedule {}.",
+                self.reservation_store.get_name_for_key(reservation_id),
+                self.id
+            );
+            return false;
+        };
+
+        let reserved_capacity = self.reservation_store.get_reserved_capacity(reservation_id);
+        let mut is_released = true;
+
+        // For each time slot resolve the booked path
+        for (slot_index, path) in path_per_slot {
+            for link_id in &path.network_links {
+                let is_deleted = self.strategy.resource_store.with_mut_slotted_schedule_strategy(*link_id, |schedule| {
+                    schedule.get_slot(slot_index).is_none() || schedule.delete_reservation_in_slot(reservation_id, reserved_capacity, slot_index)
+                });
+
+                if !is_deleted {
+                    log::error!(
+                        "NetworkScheduleReleasePathFailed: The bandwidth of Reservation {:?} could not be released on link {:?} in slot {}.",
+                        self.reservation_store.get_name_for_key(reservation_id),
+                        link_id,
+                        slot_index
+                    );
+                    is_released = false;
+                }
+            }
+        }
+
+        return is_released;
+    }
+}
+
 impl SlottedScheduleStrategy for LinkStrategy {
     fn get_capacity(ctx: &SlottedScheduleContext<Self>) -> i64 {
         ctx.strategy.max_bandwidth_all_paths
@@ -106,6 +149,9 @@ impl SlottedScheduleStrategy for LinkStrategy {
         return available_capacity;
     }
 
+    /// Books the reservation in the slot on the first path between its source and target, which can carry the reserved capacity.
+    /// The cached k shortest paths (see `NetworkTopology::k_shortest_paths`) are tried in ascending cost order, i.e. if the
+    /// least congested path is saturated the next path is tried before giving up. The chosen path is recorded in `reserved_paths`.
     fn insert_reservation_into_slot(ctx: &mut SlottedScheduleContext<Self>, _requirement: i64, slot_index: i64, reservation_id: ReservationId) {
         let start = ctx.reservation_store.get_start_point(reservation_id);
         let end = ctx.reservation_store.get_end_point(reservation_id);
@@ -191,40 +237,10 @@ impl SlottedScheduleStrategy for LinkStrategy {
         ctx.strategy.reserved_paths.clear();
     }
 
-    /// Deletes the reserved capacity of the booked path form all affected Links.
+    /// Releases the booked path of the reservation from all affected Links (see `release_path`).
     /// Returns true, if the deletion clean up process was a success otherwise return false.
     fn on_delete_reservation(ctx: &mut SlottedScheduleContext<Self>, reservation_id: ReservationId) -> bool {
-        let path_per_slot = if let Some(value) = ctx.strategy.reserved_paths.remove(&reservation_id) {
-            value
-        } else {
-            log::error!(
-                "NetworkScheduleDeleteReservationFailed: Deletion of booked path of Reservation {:?} failed.",
-                ctx.reservation_store.get_name_for_key(reservation_id)
-            );
-
-            ctx.reservation_store.update_state(reservation_id, ReservationState::Rejected);
-            return false;
-        };
-
-        // For each time slot resolve the booked path
-        for (slot_index, path) in path_per_slot {
-            for link_id in &path.network_links {
-                let reserved_capacity = ctx.reservation_store.get_reserved_capacity(reservation_id);
-                if let Some(slot) = ctx.get_mut_slot(slot_index) {
-                    slot.delete_reservation(reservation_id, reserved_capacity);
-                } else {
-                    log::error!(
-                        "NetworkPolicyDeletionOfReservationFailed: The network path deletion of Reservation {:?} failed. slot_index: {}, path: {:?} the link_id which failed of the processed path {:?}. This link should be empty but part of it is still occupied.",
-                        ctx.reservation_store.get_name_for_key(reservation_id),
-                        slot_index,
-                        path,
-                        link_id
-                    );
-                    return false;
-                }
-            }
-        }
-        return true;
+        return ctx.release_path(reservation_id);
     }
 
     /// Unimplemented:
@@ -6,7 +6,8 @@ use crate::domain::vrm_system_model::schedule::slotted_schedule::SlottedNodeSche
 use crate::domain::vrm_system_model::schedule::slotted_schedule::strategy::node::node_strategy::NodeStrategy;
 use crate::domain::vrm_system_model::utils::id::{AciId, ResourceName, RouterId, SlottedScheduleId};
 
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::sync::Arc;
 
 /// The number of k shortest paths to calculate and cache between any two grid access points.
@@ -53,6 +54,34 @@ impl Path {
     }
 }
 
+/// Accumulated cost of a (partial) path during the shortest path search.
+/// Paths are ordered by their cost, paths with equal cost by their number of hops.
+#[derive(Debug, Clone, Copy)]
+struct PathCost {
+    cost: f64,
+    hops: usize,
+}
+
+impl PartialEq for PathCost {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PathCost {}
+
+impl PartialOrd for PathCost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PathCost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost.total_cmp(&other.cost).then(self.hops.cmp(&other.hops))
+    }
+}
+
 /// Represents the calculated virtual resource capability between two endpoints.
 ///
 /// Unlike a physical link, a `VirtualLinkResource` represents the aggregated potential
@@ -150,62 +179,199 @@ impl NetworkTopology {
         return topology;
     }
 
-    /// Calculates the K-shortest paths between the source and target router using a Breadth-First Search (BFS) approach.
+    /// Calculates the `k` shortest loop-free paths between the routers `from` and `to` (Yen's algorithm), where the cost
+    /// of a network link is its inverse bandwidth. Trying the paths in order therefore prefers the least congested routes.
+    ///
     /// # Returns
+    /// Up to `k` paths sorted by their total cost (paths with equal cost by their number of hops),
+    /// or an empty vector if `to` is not reachable from `from`.
+    pub fn k_shortest_paths(&self, from: RouterId, to: RouterId, k: usize) -> Vec<Path> {
+        return self.k_shortest_paths_by(from, to, k, |link_id| self.get_inverse_bandwidth(link_id));
+    }
+
+    /// Calculates the `k` shortest loop-free paths between the routers `from` and `to` (Yen's algorithm)
+    /// with a custom cost per network link (see `shortest_path_by`).
     ///
-    /// Returns `Some(VirtualLinkResource)` if at least one path is found, otherwise `None`.
-    fn calc_k_shortest_paths(&mut self, source_router: Router, target_router: Router) -> Option<VirtualLinkResource> {
-        let mut found_solutions = Vec::new();
-        let mut queue: VecDeque<Path> = VecDeque::new();
+    /// # Returns
+    /// Up to `k` paths sorted by their total cost (paths with equal cost by their number of hops),
+    /// or an empty vector if `to` is not reachable from `from`.
+    pub fn k_shortest_paths_by<F>(&self, from: RouterId, to: RouterId, k: usize, link_cost: F) -> Vec<Path>
+    where
+        F: Fn(LinkResourceId) -> f64,
+    {
+        let mut shortest_paths: Vec<Path> = Vec::new();
+        if k == 0 {
+            return shortest_paths;
+        }
 
-        // Initialize queue with all outgoing network links from source
-        if let Some(outgoing_links) = self.adjacency.get(&source_router.id) {
-            for link_id in outgoing_links {
-                if self.link_ids.contains(link_id) {
-                    let mut p = Path::new();
-                    p.network_links.push(link_id.clone());
-                    queue.push_back(p);
+        match self.shortest_path_by(from.clone(), to.clone(), &link_cost) {
+            Some(path) => shortest_paths.push(path),
+            None => return shortest_paths,
+        }
+        let mut candidates: Vec<(PathCost, Path)> = Vec::new();
+
+        while shortest_paths.len() < k {
+            let previous_path = shortest_paths.last().unwrap().clone();
+
+            for spur_index in 0..previous_path.network_links.len() {
+                let root_links = &previous_path.network_links[..spur_index];
+                let spur_router_id = if spur_index == 0 { from.clone() } else { self.resource_store.get_target(root_links[spur_index - 1]) };
+
+                // Remove the next link of all found paths sharing the same root path
+                let removed_links: HashSet<LinkResourceId> = shortest_paths
+                    .iter()
+                    .filter(|path| path.network_links.len() > spur_index && path.network_links[..spur_index] == *root_links)
+                    .map(|path| path.network_links[spur_index])
+                    .collect();
+
+                // Remove all routers of the root path (except the spur router) to keep the paths loop-free
+                let removed_routers: HashSet<RouterId> = root_links.iter().map(|link_id| self.resource_store.get_source(*link_id)).collect();
+
+                let spur_path = self.shortest_path_by(spur_router_id, to.clone(), |link_id| {
+                    if removed_links.contains(&link_id) || removed_routers.contains(&self.resource_store.get_target(link_id)) {
+                        return f64::INFINITY;
+                    }
+                    link_cost(link_id)
+                });
+
+                if let Some(spur_path) = spur_path {
+                    let mut candidate = Path::new();
+                    candidate.network_links.extend_from_slice(root_links);
+                    candidate.network_links.extend(spur_path.network_links);
+
+                    let is_known = shortest_paths
+                        .iter()
+                        .chain(candidates.iter().map(|(_, path)| path))
+                        .any(|path| path.network_links == candidate.network_links);
+                    if !is_known {
+                        let candidate_cost = PathCost {
+                            cost: candidate.network_links.iter().map(|link_id| link_cost(*link_id)).sum(),
+                            hops: candidate.network_links.len(),
+                        };
+                        candidates.push((candidate_cost, candidate));
+                    }
                 }
             }
+
+            // Take the cheapest candidate, ties are broken deterministically by the network links
+            let best_candidate_index = candidates
+                .iter()
+                .enumerate()
+                .min_by(|(_, (a_cost, a_path)), (_, (b_cost, b_path))| {
+                    a_cost.cmp(b_cost).then_with(|| a_path.network_links.cmp(&b_path.network_links))
+                })
+                .map(|(index, _)| index);
+
+            match best_candidate_index {
+                Some(index) => shortest_paths.push(candidates.swap_remove(index).1),
+                None => break,
+            }
+        }
+
+        return shortest_paths;
+    }
+
+    /// Returns the inverse bandwidth of the network link, which is infinite for network links without capacity.
+    fn get_inverse_bandwidth(&self, link_id: LinkResourceId) -> f64 {
+        let capacity = self.resource_store.get_capacity(link_id);
+        if capacity > 0 { 1.0 / capacity as f64 } else { f64::INFINITY }
+    }
+
+    /// Calculates the shortest path between the routers `from` and `to` (Dijkstra), where the cost of a network link is
+    /// its inverse bandwidth. Of all paths with equal cost, the path with the fewest hops is preferred.
+    ///
+    /// # Returns
+    /// The `Path` from `from` to `to` (empty if both are equal), or `None` if `to` is not reachable from `from`.
+    pub fn shortest_path(&self, from: RouterId, to: RouterId) -> Option<Path> {
+        return self.shortest_path_by(from, to, |link_id| self.get_inverse_bandwidth(link_id));
+    }
+
+    /// Calculates the shortest path between the routers `from` and `to` (Dijkstra) with a custom cost per network link.
+    /// Of all paths with equal cost, the path with the fewest hops is preferred.
+    ///
+    /// # Arguments
+    /// * `from` - The router, where the path starts.
+    /// * `to` - The router, where the path ends.
+    /// * `link_cost` - Cost of traversing a network link. Network links with a negative or infinite cost are not traversed.
+    ///
+    /// # Returns
+    /// The `Path` from `from` to `to` (empty if both are equal), or `None` if `to` is not reachable from `from`.
+    pub fn shortest_path_by<F>(&self, from: RouterId, to: RouterId, link_cost: F) -> Option<Path>
+    where
+        F: Fn(LinkResourceId) -> f64,
+    {
+        if !self.routers.contains_key(&from) || !self.routers.contains_key(&to) {
+            log::debug!("NoPathFound: Unknown router {} or {}", from, to);
+            return None;
         }
 
-        while let Some(current_path) = queue.pop_front() {
-            let current_last_network_link = current_path.network_links.last().expect("Path should not be empty");
+        let mut best_costs: HashMap<RouterId, PathCost> = HashMap::new();
+        let mut predecessor_links: HashMap<RouterId, LinkResourceId> = HashMap::new();
+        let mut queue: BinaryHeap<Reverse<(PathCost, RouterId)>> = BinaryHeap::new();
 
-            let last_link_id = self.link_ids.get(current_last_network_link).expect("Network Link should exist.");
+        best_costs.insert(from.clone(), PathCost { cost: 0.0, hops: 0 });
+        queue.push(Reverse((PathCost { cost: 0.0, hops: 0 }, from.clone())));
 
-            let current_target_router_id = self.resource_store.get_target(*last_link_id);
+        while let Some(Reverse((path_cost, router_id))) = queue.pop() {
+            // Skip outdated queue entries
+            if best_costs.get(&router_id).is_some_and(|best_cost| path_cost > *best_cost) {
+                continue;
+            }
 
-            if current_target_router_id.eq(&target_router.id) {
-                found_solutions.push(current_path);
+            if router_id == to {
+                break;
+            }
 
-                if found_solutions.len() >= K_NUMBER_OF_PATHS {
-                    break;
-                }
-            } else if self.adjacency.contains_key(&current_target_router_id) {
-                for outgoing_link_id in self.adjacency.get(&current_target_router_id).unwrap().clone().iter() {
-                    let outgoing_link_target_id = self.resource_store.get_target(*outgoing_link_id);
+            let Some(outgoing_links) = self.adjacency.get(&router_id) else {
+                continue;
+            };
 
-                    let mut is_loop: bool = false;
+            // Sorted, such that the search is deterministic
+            let mut outgoing_links: Vec<LinkResourceId> = outgoing_links.iter().filter(|link_id| self.link_ids.contains(*link_id)).cloned().collect();
+            outgoing_links.sort();
 
-                    for old_part_id in &current_path.network_links {
-                        let old_part_source_id = self.resource_store.get_source(*old_part_id);
+            for link_id in outgoing_links {
+                let cost = link_cost(link_id);
+                if !cost.is_finite() || cost < 0.0 {
+                    continue;
+                }
 
-                        if old_part_source_id == outgoing_link_target_id {
-                            is_loop = true;
-                            break;
-                        }
-                    }
+                let next_router_id = self.resource_store.get_target(link_id);
+                let next_path_cost = PathCost { cost: path_cost.cost + cost, hops: path_cost.hops + 1 };
 
-                    if !is_loop {
-                        let mut new_path = current_path.clone();
-                        new_path.network_links.push(outgoing_link_id.clone());
-                        queue.push_back(new_path);
-                    }
+                if best_costs.get(&next_router_id).is_none_or(|best_cost| next_path_cost < *best_cost) {
+                    best_costs.insert(next_router_id.clone(), next_path_cost);
+                    predecessor_links.insert(next_router_id.clone(), link_id);
+                    queue.push(Reverse((next_path_cost, next_router_id)));
                 }
             }
         }
 
+        if !best_costs.contains_key(&to) {
+            log::debug!("NoPathFound: {} => {}", from, to);
+            return None;
+        }
+
+        // Walk back from the target to the source
+        let mut path = Path::new();
+        let mut router_id = to;
+        while router_id != from {
+            let link_id = predecessor_links[&router_id];
+            path.network_links.push(link_id);
+            router_id = self.resource_store.get_source(link_id);
+        }
+        path.network_links.reverse();
+
+        return Some(path);
+    }
+
+    /// Calculates the K-shortest paths between the source and target router (see `k_shortest_paths`).
+    /// # Returns
+    ///
+    /// Returns `Some(VirtualLinkResource)` if at least one path is found, otherwise `None`.
+    fn calc_k_shortest_paths(&mut self, source_router: Router, target_router: Router) -> Option<VirtualLinkResource> {
+        let found_solutions = self.k_shortest_paths(source_router.id.clone(), target_router.id.clone(), K_NUMBER_OF_PATHS);
+
         if found_solutions.is_empty() {
             log::debug!("NoPathFound: {} => {}", source_router.id, target_router.id);
             return None;
@@ -53,10 +53,10 @@ impl SlottedScheduleStrategy for NodeStrategy {
             )
         }
 
-        let mut start_slot_index = ctx.get_slot_index(frag_start_time);
+        let mut start_slot_index = ctx.time_to_slot_index(frag_start_time);
         start_slot_index = ctx.get_effective_slot_index(start_slot_index);
 
-        let mut end_slot_index = ctx.get_slot_index(frag_end_time);
+        let mut end_slot_index = ctx.time_to_slot_index(frag_end_time);
         end_slot_index = ctx.get_effective_slot_index(end_slot_index);
 
         if ctx.use_quadratic_mean_fragmentation {
@@ -86,10 +86,10 @@ impl SlottedScheduleStrategy for NodeStrategy {
             log::error!("Start time must be before end time: SlottedSchedule id: {} is end_time: {} < start_time: {}", ctx.id, end_time, start_time)
         }
 
-        let mut start_slot_nr = ctx.get_slot_index(start_time);
+        let mut start_slot_nr = ctx.time_to_slot_index(start_time);
         start_slot_nr = ctx.get_effective_slot_index(start_slot_nr);
 
-        let mut end_slot_nr = ctx.get_slot_index(end_time);
+        let mut end_slot_nr = ctx.time_to_slot_index(end_time);
         end_slot_nr = ctx.get_effective_slot_index(end_slot_nr);
 
         let mut reserved_capacity_sum: i64 = 0;
@@ -122,10 +122,10 @@ impl SlottedScheduleStrategy for NodeStrategy {
 
     fn get_simulation_load_metric(ctx: &mut SlottedScheduleContext<Self>) -> LoadMetric {
         let index_of_first_slot: i64 = ctx.load_buffer.context.get_first_load() + SLOTS_TO_DROP_ON_START;
-        let start_time_of_first_slot: i64 = ctx.get_slot_start_time(index_of_first_slot);
+        let start_time_of_first_slot: i64 = ctx.slot_index_to_time(index_of_first_slot);
 
         let index_of_last_slot: i64 = ctx.load_buffer.context.get_last_load() - SLOTS_TO_DROP_ON_END;
-        let start_time_of_last_slot: i64 = ctx.get_slot_start_time(index_of_last_slot);
+        let start_time_of_last_slot: i64 = ctx.slot_index_to_time(index_of_last_slot);
 
         return ctx.load_buffer.get_effective_overall_load(NodeStrategy::get_capacity(ctx) as f64, start_time_of_first_slot, start_time_of_last_slot);
     }
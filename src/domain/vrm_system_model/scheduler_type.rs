@@ -1,6 +1,7 @@
 use crate::domain::simulator::simulator::GlobalClock;
 use crate::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
 use crate::domain::vrm_system_model::resource::resource_store::ResourceStore;
+use crate::domain::vrm_system_model::schedule::null_network_schedule::NullNetworkSchedule;
 use crate::domain::vrm_system_model::schedule::schedule_trait::Schedule;
 use crate::domain::vrm_system_model::schedule::slotted_schedule::strategy::link::link_strategy::LinkStrategy;
 use crate::domain::vrm_system_model::schedule::slotted_schedule::strategy::link::topology::NetworkTopology;
@@ -21,9 +22,12 @@ pub enum SchedulerType {
     SlottedSchedule12,
     SlottedSchedule12000,
     UnlimitedSchedule,
+    /// Nodes are scheduled like `SlottedSchedule`, the network like `NullNetworkSchedule`.
+    SlottedScheduleNullNetwork,
 
     // Link Scheduler
     SlottedScheduleLinks { topology: NetworkTopology, resource_store: ResourceStore },
+    NullNetworkSchedule,
 }
 #[derive(Debug, Clone)]
 pub struct ScheduleContext {
@@ -45,6 +49,7 @@ impl FromStr for SchedulerType {
             "SlottedSchedule12" => Ok(SchedulerType::SlottedSchedule12),
             "SlottedSchedule12000" => Ok(SchedulerType::SlottedSchedule12000),
             "UnlimitedSchedule" => Ok(SchedulerType::UnlimitedSchedule),
+            "SlottedScheduleNullNetwork" => Ok(SchedulerType::SlottedScheduleNullNetwork),
             _ => Err(ConversionError::UnknownSchedulerType(s.to_string())),
         }
     }
@@ -57,7 +62,7 @@ impl SchedulerType {
             Self::FreeListSchedule => {
                 todo!()
             }
-            Self::SlottedSchedule => {
+            Self::SlottedSchedule | Self::SlottedScheduleNullNetwork => {
                 let node_strategy = NodeStrategy::default();
                 let node_schedule = SlottedNodeSchedule::new(
                     ctx.id,
@@ -87,6 +92,7 @@ impl SchedulerType {
 
                 Box::new(link_schedule)
             }
+            Self::NullNetworkSchedule => Box::new(NullNetworkSchedule::new(ctx.id, ctx.reservation_store.clone(), ctx.simulator.clone())),
             Self::SlottedSchedule12 => {
                 let number_of_real_slots = (ctx.number_of_slots * (ctx.slot_width + 11)) / 12;
                 let node_strategy = NodeStrategy::default();
@@ -128,6 +134,7 @@ impl SchedulerType {
     pub fn get_network_scheduler_variant(&self, topology: NetworkTopology, resource_store: ResourceStore) -> SchedulerType {
         match self {
             Self::SlottedSchedule => SchedulerType::SlottedScheduleLinks { topology, resource_store },
+            Self::SlottedScheduleNullNetwork => SchedulerType::NullNetworkSchedule,
             _ => {
                 log::error!("The specified Scheduler {:?} is not implemented as NetworkScheduler. Default to SlottedSchedule", self);
                 SchedulerType::SlottedScheduleLinks { topology, resource_store }
@@ -1,12 +1,78 @@
 //////////////////////////////////////////////////////////
 /// File contains all constants of the VRM-Rust system ///
 //////////////////////////////////////////////////////////
+use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::PlacementFailurePolicy;
 
 /// Defines the number of times a ProbeReservation is prompted, start the reserve process for this specific reservation
 /// Optimization high number of tries leads to suboptimal solutions ...
 /// But small number of tries leads to potential rejection ...
 pub const TRY_N_PROMOTIONS: u64 = 50;
 
+/// Defines the number of times the VrmComponentManager re-probes and retries a failed reserve of a task.
+pub const RESERVE_PROBE_RETRIES: usize = 5;
+
+/// Defines the backoff before the first reserve retry, each further retry multiplies the backoff
+/// by `RESERVE_BACKOFF_MULTIPLIER` up to `RESERVE_MAX_BACKOFF_MS`.
+pub const RESERVE_INITIAL_BACKOFF_MS: u64 = 100;
+pub const RESERVE_BACKOFF_MULTIPLIER: u32 = 2;
+pub const RESERVE_MAX_BACKOFF_MS: u64 = 15000;
+
+/// Defines how long the ADC waits at a `ReservationSyncGate` for the reserve answer of an AcI, before the reservation
+/// is treated as rejected.
+pub const RESERVE_GATE_TIMEOUT_MS: u64 = 15000;
+
+/// Defines the number of consecutive failed commit or unanswered reserve requests, after which the VrmComponentManager
+/// excludes a VrmComponent from the VrmComponent orders (blacklisting). A value of 0 disables the blacklisting.
+pub const VRM_COMPONENT_MAX_FAILURES: u32 = 3;
+
+/// Defines the maximal number of VrmComponents, which the VrmComponentManager probes concurrently. The VrmComponents
+/// are probed in chunks of this size, each VrmComponent of a chunk on its own scoped thread. A value of 0 or 1 probes
+/// all VrmComponents sequentially on the calling thread.
+pub const VRM_COMPONENT_MAX_PROBE_THREADS: usize = 8;
+
+/// Defines, how the WorkflowScheduler reacts if the placement of an intermediate (non-exit) CoAllocation fails.
+/// A failed placement of an exit CoAllocation always rejects the whole workflow.
+pub const INTERMEDIATE_PLACEMENT_FAILURE_POLICY: PlacementFailurePolicy = PlacementFailurePolicy::Abort;
+
+/// Defines, if the WorkflowScheduler places CoAllocations with an equal upward rank by their priority (highest first).
+/// If disabled, equal ranks are ordered by the CoAllocation id only.
+pub const PRIORITY_AWARE_NODE_ORDERING: bool = false;
+
+/// Defines, if the WorkflowScheduler verifies that the routers assigned to a LinkReservation belong to the
+/// source and target VrmComponent of the dependency. Enabled in debug builds only, like a debug assertion.
+pub const VALIDATE_LINK_END_POINTS: bool = cfg!(debug_assertions);
+
+/// Defines, if the WorkflowScheduler splits moldable nodes, which exceed the node capacity of the largest VrmComponent,
+/// into co-allocated sub-nodes before the ranking (see `Workflow::split_oversized_nodes`).
+pub const SPLIT_OVERSIZED_NODES: bool = false;
+
+/// Penalty in s, which is added to the placement objective (e.g. finish time for EFT) of a task,
+/// if the task is placed on a different VrmComponent than one of its soft synchronized tasks.
+pub const SOFT_SYNC_SEPARATION_PENALTY: i64 = 60;
+
+/// Factor by which a data transfer between two VrmComponents of the same locality zone (e.g. rack, datacenter)
+/// is faster than a data transfer across zones, which utilizes the average link speed.
+pub const INTRA_ZONE_LINK_SPEED_FACTOR: i64 = 10;
+
+/// Factors by which the reserved capacity of a moldable NodeReservation is widened during the EFT placement,
+/// the task duration is shortened accordingly (the moldable work is kept). The shape with the earliest finish time is reserved.
+pub const MOLDABLE_CAPACITY_FACTORS: [i64; 3] = [1, 2, 4];
+
+/// If true, the ADC checks the ReservationStore for leftovers of prior aborted runs (stuck transient states,
+/// orphaned gates) before a workflow is scheduled. Found issues are only logged.
+pub const HEALTH_CHECK_BEFORE_SCHEDULING: bool = false;
+
+/// Value of `sourceReservation` of a DataIn, which marks input data provided from outside of the workflow
+/// (e.g. detector data), such DataIns do not reference an output of a task.
+pub const EXTERNAL_DATA_SOURCE: &str = "EXTERNAL";
+
+/// Seed of the random number generator, which is used by the VrmComponentManager to randomly order its VrmComponents.
+/// A fixed seed makes simulation runs with the same input reproducible.
+pub const DEFAULT_VRM_COMPONENT_ORDER_SEED: u64 = 42;
+
+/// Environment variable, which overrides `DEFAULT_VRM_COMPONENT_ORDER_SEED` (e.g. `VRM_SEED=7`).
+pub const VRM_COMPONENT_ORDER_SEED_ENV_VAR: &str = "VRM_SEED";
+
 /// Defines, what happen with scheduled/running reservations on a managed VrmComponent, 
 /// if this component is is deleted form the VRM system. 
 pub const DELETE_ALL_VRM_MANAGED_RESERVATIONS_IF_VRM_COMPONENT_IS_DELETED: bool = true;
@@ -1,8 +1,11 @@
+use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::marker::PhantomData;
 
+// Transparent, such that an Id is serialized as plain string and can be utilized as key of a JSON object
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct Id<T> {
     pub id: String,
     _marker: PhantomData<T>,
@@ -67,6 +70,8 @@ pub struct ClientTag;
 pub struct ComponentTag;
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Copy)]
 pub struct ProbeReservationTag;
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Copy)]
+pub struct ZoneTag;
 
 // Workflow Domain Tags
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Copy, Serialize, Deserialize)]
@@ -78,6 +83,8 @@ pub struct DataDependencyTag;
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Copy, Serialize, Deserialize)]
 pub struct SyncDependencyTag;
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Copy, Serialize, Deserialize)]
+pub struct SoftSyncDependencyTag;
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Copy, Serialize, Deserialize)]
 pub struct CoAllocationTag;
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Hash, Copy, Serialize, Deserialize)]
 pub struct CoAllocationDependencyTag;
@@ -93,11 +100,27 @@ pub type ShadowScheduleId = Id<ShadowScheduleTag>;
 pub type ClientId = Id<ClientTag>;
 pub type ComponentId = Id<ComponentTag>;
 pub type ProbeReservationId = Id<ProbeReservationTag>;
+pub type ZoneId = Id<ZoneTag>;
 
 // Workflow Domain Aliases
 pub type WorkflowId = Id<WorkflowTag>;
 pub type WorkflowNodeId = Id<WorkflowNodeTag>;
 pub type DataDependencyId = Id<DataDependencyTag>;
 pub type SyncDependencyId = Id<SyncDependencyTag>;
+pub type SoftSyncDependencyId = Id<SoftSyncDependencyTag>;
 pub type CoAllocationId = Id<CoAllocationTag>;
 pub type CoAllocationDependencyId = Id<CoAllocationDependencyTag>;
+
+// In the case where dummy dependencies where scheduled, a dummy VrmComponentId is utilized.
+// This happens in the cases, where network transfers can be skipped, as both endpoints are on the same node.
+lazy_static! {
+    pub static ref DUMMY_COMPONENT_ID: ComponentId = ComponentId::new("ADC INTERNAL JOB");
+}
+
+impl ComponentId {
+    /// Returns true, if this is the `DUMMY_COMPONENT_ID`, i.e., the reservation is handled internally by the ADC
+    /// and not by a real VrmComponent.
+    pub fn is_dummy(&self) -> bool {
+        return self.compare(&DUMMY_COMPONENT_ID);
+    }
+}
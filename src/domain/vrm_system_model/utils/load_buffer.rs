@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::ops::Sub;
 use std::sync::{
     Arc,
     atomic::{AtomicI64, Ordering},
@@ -73,6 +74,24 @@ impl LoadMetric {
     }
 }
 
+/// Element-wise difference of two `LoadMetric`s, e.g. the load a shadow schedule adds to the master schedule.
+///
+/// `avg_reserved_capacity`, `possible_capacity` and `utilization` are subtracted individually and may become negative
+/// (less load than `rhs`). The time window (`start_time`, `end_time`) of `self` is kept.
+impl Sub for LoadMetric {
+    type Output = LoadMetric;
+
+    fn sub(self, rhs: LoadMetric) -> LoadMetric {
+        return LoadMetric::new(
+            self.start_time,
+            self.end_time,
+            self.avg_reserved_capacity - rhs.avg_reserved_capacity,
+            self.possible_capacity - rhs.possible_capacity,
+            self.utilization - rhs.utilization,
+        );
+    }
+}
+
 /// A circular buffer implementation used to track resource capacity usage.
 ///
 /// The `LoadBuffer` records load events over time and calculates utilization metrics.
@@ -29,6 +29,126 @@ impl AnalyticsSystem {
     }
 }
 
+/// A single analytics entry of a finished operation, which captures the same fields as the corresponding tracing event.
+/// The field names (PascalCase) are used as CSV header.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AnalyticsRow {
+    /// Time in seconds since UNIX_EPOCH.
+    pub time: u64,
+    pub log_description: String,
+    pub component_type: String,
+    pub node_component_utilization: Option<f64>,
+    pub node_component_capacity: Option<f64>,
+    pub network_component_utilization: Option<f64>,
+    pub network_component_capacity: Option<f64>,
+    pub component_fragmentation: Option<f64>,
+
+    /// Is None, if the reservation was missing (e.g. deleted/cleaned up), the same holds for all reservation fields.
+    pub reservation_name: Option<String>,
+    pub reservation_capacity: Option<i64>,
+    pub reservation_workload: Option<i64>,
+    pub reservation_state: Option<String>,
+    pub reservation_proceeding: Option<String>,
+    pub number_of_tasks: Option<usize>,
+    pub command: String,
+    pub processing_time: i64,
+}
+
+/// Writes `AnalyticsRow`s as CSV (separated by `;`), so that the analytics can be consumed without a custom tracing subscriber.
+/// The header is written together with the first row, each row is flushed immediately.
+pub struct CsvAnalyticsSink {
+    writer: Mutex<csv::Writer<Box<dyn Write + Send>>>,
+}
+
+impl CsvAnalyticsSink {
+    pub fn new<W: Write + Send + 'static>(writer: W) -> Self {
+        let writer: Box<dyn Write + Send> = Box::new(writer);
+        return Self { writer: Mutex::new(csv::WriterBuilder::new().delimiter(b';').from_writer(writer)) };
+    }
+
+    /// Creates (or truncates) the file at `log_file_path` and writes all recorded rows into it.
+    pub fn from_path(log_file_path: &str) -> std::io::Result<Self> {
+        return Ok(Self::new(File::create(log_file_path)?));
+    }
+
+    pub fn record(&self, row: AnalyticsRow) {
+        let Ok(mut writer) = self.writer.lock() else {
+            log::error!("CsvAnalyticsSinkLockPoisoned: The analytics row of command {} is dropped.", row.command);
+            return;
+        };
+
+        if let Err(error) = writer.serialize(row) {
+            log::error!("CsvAnalyticsSinkWriteFailed: {}", error);
+            return;
+        }
+        if let Err(error) = writer.flush() {
+            log::error!("CsvAnalyticsSinkFlushFailed: {}", error);
+        }
+    }
+}
+
+impl fmt::Debug for CsvAnalyticsSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CsvAnalyticsSink").finish_non_exhaustive()
+    }
+}
+
+/// Accumulates the processing times of finished operations per command (e.g. "Reserve", "Commit"), such that
+/// their distribution can be evaluated at the end of a simulation without parsing the analytics log.
+#[derive(Debug, Default)]
+pub struct AnalyticsAggregator {
+    processing_times: HashMap<String, Vec<i64>>,
+}
+
+impl AnalyticsAggregator {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    pub fn record(&mut self, command: &str, processing_time: i64) {
+        self.processing_times.entry(command.to_string()).or_default().push(processing_time);
+    }
+
+    /// Returns the number of recorded processing times of `command`.
+    pub fn count(&self, command: &str) -> usize {
+        return self.processing_times.get(command).map_or(0, |samples| samples.len());
+    }
+
+    /// Computes the `p`-th percentile of the processing times of `command` using the nearest-rank method.
+    ///
+    /// # Arguments
+    /// * `command` - The command, whose processing times are evaluated.
+    /// * `p` - The percentile in the range 0.0..=100.0, values outside are clamped (0.0 yields the minimum).
+    ///
+    /// # Returns
+    /// `None` if no processing time was recorded for `command`, otherwise the recorded processing time of the percentile.
+    pub fn percentile(&self, command: &str, p: f64) -> Option<i64> {
+        let samples = self.processing_times.get(command)?;
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.clone();
+        sorted.sort_unstable();
+
+        let p = if p.is_nan() { 0.0 } else { p.clamp(0.0, 100.0) };
+        let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+
+        return Some(sorted[rank.saturating_sub(1)]);
+    }
+
+    /// Returns the mean processing time of `command` or `None`, if no processing time was recorded for `command`.
+    pub fn mean(&self, command: &str) -> Option<f64> {
+        let samples = self.processing_times.get(command)?;
+        if samples.is_empty() {
+            return None;
+        }
+
+        return Some(samples.iter().sum::<i64>() as f64 / samples.len() as f64);
+    }
+}
+
 /// Each event consists of a set of key-value-pairs with the measured data or some meta data of the event.
 /// This enum specifies all allowed key values and thus the column in the output file.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
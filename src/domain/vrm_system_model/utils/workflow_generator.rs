@@ -89,6 +89,7 @@ impl WorkflowGenerator {
                     id: task_id.clone(),
                     reservation_state: ReservationStateDto::Open,
                     request_proceeding: ReservationProceedingDto::Commit,
+                    priority: None,
                     link_reservation: links,
                     node_reservation: self.create_default_node(data_deps, sync_deps),
                 });
@@ -102,6 +103,7 @@ impl WorkflowGenerator {
             booking_interval_end: 1000000,
             tasks,
             request_proceeding: ReservationProceedingDto::Commit,
+            priority: None,
             state: ReservationStateDto::Open,
         }
     }
@@ -118,9 +120,10 @@ impl WorkflowGenerator {
             duration: 10,
             cpus: 5,
             is_moldable: true,
+            earliest_start: None,
             current_working_directory: None,
             environment: None,
-            dependencies: DependencyDto { data: data_ids, sync: sync_ids },
+            dependencies: DependencyDto { data: data_ids, sync: sync_ids, soft_sync: vec![] },
             data_out: vec![],
             data_in: vec![],
         }
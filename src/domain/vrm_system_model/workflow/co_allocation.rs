@@ -105,4 +105,9 @@ impl CoAllocation {
         }
         return max_duration;
     }
+
+    /// Returns the latest explicit earliest start of all members, or `None` if no member has an explicit earliest start.
+    pub fn get_explicit_earliest_start(&self, nodes: &HashMap<WorkflowNodeId, WorkflowNode>) -> Option<i64> {
+        return self.members.iter().filter_map(|node_key| nodes.get(node_key)?.earliest_start).max();
+    }
 }
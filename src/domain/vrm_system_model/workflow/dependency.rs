@@ -24,6 +24,27 @@ pub struct DataDependency {
 
     /// Size of the file for transport.
     pub size: i64,
+
+    /// Fixed propagation latency of the transfer in s, which is independent of the size.
+    #[serde(default)]
+    pub latency: i64,
+}
+
+impl DataDependency {
+    /// Estimates the communication time (in s) of this DataDependency.
+    ///
+    /// The estimate consists of the transfer time `size / avg_net_speed` plus the fixed
+    /// propagation `latency`. If `avg_net_speed` is 0, only the latency is considered.
+    pub fn get_communication_time(&self, avg_net_speed: i64) -> i64 {
+        let transfer_time = if avg_net_speed > 0 {
+            self.size / avg_net_speed
+        } else {
+            log::warn!("avg_net_speed is 0, setting transfer_time to 0");
+            0
+        };
+
+        return transfer_time + self.latency;
+    }
 }
 
 /// Represents an edge for synchronous bandwidth (e.g. Co-allocated Communication).
@@ -47,6 +68,46 @@ pub struct SyncDependency {
     pub bandwidth: i64,
 }
 
+/// A borrowed DataDependency or SyncDependency of a Workflow, e.g. the result of a lookup by `ReservationId`.
+#[derive(Debug, Clone, Copy)]
+pub enum DependencyRef<'a> {
+    Data(&'a DataDependency),
+    Sync(&'a SyncDependency),
+}
+
+impl<'a> DependencyRef<'a> {
+    /// Key to Workflow.nodes, which is the sender.
+    pub fn source_node(&self) -> Option<&'a WorkflowNodeId> {
+        return match self {
+            DependencyRef::Data(data_dependency) => data_dependency.source_node.as_ref(),
+            DependencyRef::Sync(sync_dependency) => sync_dependency.source_node.as_ref(),
+        };
+    }
+
+    /// Key to Workflow.nodes, which is the receiver.
+    pub fn target_node(&self) -> Option<&'a WorkflowNodeId> {
+        return match self {
+            DependencyRef::Data(data_dependency) => data_dependency.target_node.as_ref(),
+            DependencyRef::Sync(sync_dependency) => sync_dependency.target_node.as_ref(),
+        };
+    }
+}
+
+/// Represents an edge for a preferred, but not required synchronization.
+/// In contrast to a SyncDependency, the nodes are not merged into one CoAllocation.
+/// Instead placing both nodes on different VrmComponents increases the placement objective by `separation_penalty`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoftSyncDependency {
+    /// Key to Workflow.nodes, which is the sender.
+    pub source_node: Option<WorkflowNodeId>,
+
+    /// Key to Workflow.nodes, which is the receiver.
+    pub target_node: Option<WorkflowNodeId>,
+
+    /// Penalty in s, if source and target node are placed on different VrmComponents.
+    pub separation_penalty: i64,
+}
+
 /// An edge in the "CoAllocations graph" connecting sync groups.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoAllocationDependency {
@@ -2,3 +2,5 @@ pub mod co_allocation;
 pub mod dependency;
 pub mod workflow;
 pub mod workflow_node;
+pub mod workflow_structural_diff;
+pub mod workflow_summary;
@@ -1,25 +1,48 @@
 use core::f64;
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::Hash;
 
-use crate::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use crate::api::workflow_dto::dependency_dto::DependencyDto;
+use crate::api::workflow_dto::reservation_dto::{DataInDto, DataOutDto, NodeReservationDto, ReservationProceedingDto, ReservationStateDto};
 use crate::api::workflow_dto::workflow_dto::{TaskDto, WorkflowDto};
+use crate::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
+use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::heft_sync_workflow_scheduler::HEFTSyncWorkflowScheduler;
+use crate::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowSchedulerBase;
+use crate::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::zones::ZoneCommunicationCost;
 use crate::domain::vrm_system_model::reservation::reservation::{
-    Reservation, ReservationBase, ReservationProceeding, ReservationState, ReservationTrait, ReservationTyp,
+    ASSIGNED_TIME_UNSET, Reservation, ReservationBase, ReservationProceeding, ReservationState, ReservationTrait, ReservationTyp,
 };
 use crate::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
 use crate::domain::vrm_system_model::reservation::{link_reservation::LinkReservation, node_reservation::NodeReservation};
+use crate::domain::vrm_system_model::utils::config::{EXTERNAL_DATA_SOURCE, SOFT_SYNC_SEPARATION_PENALTY};
 use crate::domain::vrm_system_model::utils::id::{
-    ClientId, CoAllocationDependencyId, CoAllocationId, DataDependencyId, ReservationName, SyncDependencyId, WorkflowNodeId,
+    ClientId, CoAllocationDependencyId, CoAllocationId, DataDependencyId, Id, ReservationName, SoftSyncDependencyId, SyncDependencyId, WorkflowNodeId,
 };
 use crate::domain::vrm_system_model::workflow::co_allocation::CoAllocation;
-use crate::domain::vrm_system_model::workflow::dependency::{CoAllocationDependency, DataDependency, SyncDependency};
+use crate::domain::vrm_system_model::workflow::dependency::{
+    CoAllocationDependency, DataDependency, DependencyRef, SoftSyncDependency, SyncDependency,
+};
 use crate::domain::vrm_system_model::workflow::workflow_node::WorkflowNode;
+use crate::domain::vrm_system_model::workflow::workflow_structural_diff::{StructuralElement, WorkflowStructuralDiff};
+use crate::domain::vrm_system_model::workflow::workflow_summary::WorkflowSummary;
 use crate::error::Error;
+use crate::loader::diagnostic::Diagnostic;
 
 use serde::{Deserialize, Serialize};
 use union_find::{QuickUnionUf, UnionBySize, UnionFind};
 
+/// Marking of a WorkflowNode during the depth first search of `validate_acyclic`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VisitColor {
+    /// Not visited yet.
+    White,
+    /// On the current DFS path.
+    Gray,
+    /// Visited, including all its predecessors.
+    Black,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Workflow {
     pub base: ReservationBase,
@@ -29,6 +52,10 @@ pub struct Workflow {
     pub data_dependencies: HashMap<DataDependencyId, DataDependency>,
     pub sync_dependencies: HashMap<SyncDependencyId, SyncDependency>,
 
+    // Soft sync dependencies are not part of the CoAllocations, they only penalize separated placements.
+    #[serde(default)]
+    pub soft_sync_dependencies: HashMap<SoftSyncDependencyId, SoftSyncDependency>,
+
     // The CoAllocations are later utilized for scheduling.
     pub co_allocations: HashMap<CoAllocationId, CoAllocation>,
     pub co_allocation_dependencies: HashMap<CoAllocationDependencyId, CoAllocationDependency>,
@@ -59,6 +86,18 @@ impl Workflow {
     /// This is the main entry point for parsing a DTO into the internal domain model.
     /// Also builds the **CoAllocation graph**, which is later utilized for scheduling.
     pub fn create_form_dto(dto: WorkflowDto, client_id: ClientId, reservation_store: ReservationStore) -> Result<ReservationId, Error> {
+        return Self::create_form_dto_explained(dto, client_id, reservation_store).map(|(workflow_reservation_id, _)| workflow_reservation_id);
+    }
+
+    /// Like `create_form_dto`, but additionally returns all `Diagnostic`s (unresolved `data_in`, dependencies with
+    /// unknown source/target nodes and skipped co-allocation dependencies), which did not abort the construction.
+    pub fn create_form_dto_explained(
+        dto: WorkflowDto,
+        client_id: ClientId,
+        reservation_store: ReservationStore,
+    ) -> Result<(ReservationId, Vec<Diagnostic>), Error> {
+        let mut diagnostics = Vec::new();
+
         // Phase 0: Create the base workflow object
         let base = Self::build_base_workflow(&dto, client_id.clone());
 
@@ -66,36 +105,29 @@ impl Workflow {
         let mut nodes = Self::generate_workflow_nodes(&dto, client_id.clone(), reservation_store.clone());
 
         // Phase 2: Create all Data and Sync dependencies from DTO
-        let (data_dependencies, sync_dependencies) = Self::build_all_dependencies(&dto, client_id, reservation_store.clone())?;
+        let (data_dependencies, sync_dependencies) = Self::build_all_dependencies(&dto, client_id, reservation_store.clone(), &mut diagnostics)?;
+
+        // Phase 2.4: Create all soft sync dependencies, which are not merged into CoAllocations
+        let soft_sync_dependencies = Self::build_soft_sync_dependencies(&dto);
 
         // Phase 3: Populate the adjacency lists (incoming/outgoing) on each node
-        Self::populate_node_adjacency_lists(&mut nodes, &data_dependencies, &sync_dependencies);
+        Self::populate_node_adjacency_lists(&mut nodes, &data_dependencies, &sync_dependencies, &mut diagnostics);
+
+        // Phase 3.1: Reject workflows, whose dependencies contain a cycle (also through the CoAllocations)
+        Self::validate_acyclic(&nodes, &data_dependencies, &sync_dependencies)?;
 
         // Phase 4: Build SyncGroups (co-allocation groups) using a Disjoint Set Union
         let (mut co_allocations, node_to_co_allocation) = Self::build_co_allocations(&nodes, &sync_dependencies)?;
 
         // Phase 5: Build the "CoAllocation Graph" of dependencies *between* SyncGroups
-        let co_allocation_dependencies = Self::build_co_allocation_dependencies(&data_dependencies, &node_to_co_allocation, &mut co_allocations)?;
+        let co_allocation_dependencies =
+            Self::build_co_allocation_dependencies(&data_dependencies, &node_to_co_allocation, &mut co_allocations, &mut diagnostics)?;
 
         // Phase 6: Find the entry/exit points for both graphs
         let (entry_nodes, exit_nodes, entry_co_allocation, exit_co_allocation) = Self::find_entry_exit_points(&nodes, &co_allocations);
 
         // Final-Step: Update all nodes with their final CoAllocation key
-        // Also update co_allocation_key in WorkflowNodes
-        for (node_id, group_id) in node_to_co_allocation {
-            if let Some(node) = nodes.get_mut(&node_id) {
-                node.co_allocation_key = Some(group_id.clone());
-                let reservation_id_opt = node.reservation_id;
-
-                if let Some(co_alloc) = co_allocations.get_mut(&group_id) {
-                    if let Some(rep) = &mut co_alloc.representative {
-                        if rep.reservation_id == reservation_id_opt {
-                            rep.co_allocation_key = Some(group_id);
-                        }
-                    }
-                }
-            }
-        }
+        Self::assign_co_allocation_keys(&mut nodes, &mut co_allocations, node_to_co_allocation);
 
         // Update ReservationStore information
         for (_, link) in &data_dependencies {
@@ -119,6 +151,7 @@ impl Workflow {
             nodes,
             data_dependencies,
             sync_dependencies,
+            soft_sync_dependencies,
             co_allocations,
             co_allocation_dependencies,
             entry_nodes,
@@ -129,7 +162,7 @@ impl Workflow {
 
         let workflow_reservation_id = reservation_store.add(Reservation::Workflow(workflow));
 
-        Ok(workflow_reservation_id)
+        Ok((workflow_reservation_id, diagnostics))
     }
 
     /// **Phase 0: Build Base Workflow**
@@ -145,13 +178,15 @@ impl Workflow {
             arrival_time: dto.arrival_time,
             booking_interval_start: dto.booking_interval_start,
             booking_interval_end: dto.booking_interval_end,
-            assigned_start: 0,
-            assigned_end: 0,
+            assigned_start: ASSIGNED_TIME_UNSET,
+            assigned_end: ASSIGNED_TIME_UNSET,
             task_duration: 0,     // Duration is an aggregate of nodes
             reserved_capacity: 0, // Capacity is an aggregate of nodes
             is_moldable: false,
             moldable_work: 0,
             frag_delta: f64::MAX,
+            is_moveable: false,
+            priority: dto.priority.unwrap_or(0),
         }
     }
 
@@ -177,13 +212,15 @@ impl Workflow {
                 arrival_time: dto.arrival_time,
                 booking_interval_start: dto.booking_interval_start,
                 booking_interval_end: dto.booking_interval_end,
-                assigned_start: 0, // Not scheduled yet
-                assigned_end: 0,   // Not scheduled yet
+                assigned_start: ASSIGNED_TIME_UNSET, // Not scheduled yet
+                assigned_end: ASSIGNED_TIME_UNSET,   // Not scheduled yet
                 task_duration: node_res_dto.duration,
                 reserved_capacity: node_res_dto.cpus,
                 is_moldable: node_res_dto.is_moldable,
                 moldable_work: node_res_dto.duration * node_res_dto.cpus,
                 frag_delta: f64::MAX,
+                is_moveable: false,
+                priority: task_dto.priority.or(dto.priority).unwrap_or(0),
             };
 
             let node_reservation = NodeReservation {
@@ -206,6 +243,7 @@ impl Workflow {
                 incoming_sync: Vec::new(),
                 outgoing_sync: Vec::new(),
                 co_allocation_key: None, // See Phase 4
+                earliest_start: node_res_dto.earliest_start,
             };
 
             nodes.insert(node_id, workflow_node);
@@ -220,15 +258,21 @@ impl Workflow {
     /// 2. Connect them using `data_in`.
     /// 3. Create implicit dependencies from `dependencies: { data, sync }`.
     ///
-    /// Returns the complete maps of data and sync dependencies.
+    /// The LinkReservations of the dependencies are created with `ReservationStore::get_or_insert_by_name`, hence all
+    /// consumers of the same `data_out` port (fan-out) share one reservation. The names are not used by any reservation
+    /// in the store before the build, such that a Workflow parsed twice does not share the reservations of the first one.
+    ///
+    /// Returns the complete maps of data and sync dependencies. A `data_in` without a matching `data_out`
+    /// (except for `EXTERNAL_DATA_SOURCE`) is reported in `diagnostics`.
     pub fn build_all_dependencies(
         dto: &WorkflowDto,
         client_id: ClientId,
         reservation_store: ReservationStore,
+        diagnostics: &mut Vec<Diagnostic>,
     ) -> Result<(HashMap<DataDependencyId, DataDependency>, HashMap<SyncDependencyId, SyncDependency>), Error> {
         let mut data_dependencies = HashMap::new();
         let mut sync_dependencies = HashMap::new();
-        let mut dangling_deps: HashMap<String, DanglingDependency> = HashMap::new();
+        let mut dangling_deps: HashMap<String, (DanglingDependency, Reservation)> = HashMap::new();
         let workflow_id = &dto.id;
 
         // Phase 2.1: Process DataOut
@@ -242,7 +286,7 @@ impl Workflow {
                 // Key to find the dangling dependency later
                 let dangling_key = format!("{}/{}", source_node_id, port_name);
 
-                let dep_id_str = format!("{}.{}.{}", workflow_id, source_node_id, port_name);
+                let dep_id_str = Self::get_unused_dependency_name(format!("{}.{}.{}", workflow_id, source_node_id, port_name), &reservation_store);
                 let dep_name = ReservationName::new(dep_id_str.clone());
 
                 let mut dep_base = ReservationBase {
@@ -254,13 +298,15 @@ impl Workflow {
                     arrival_time: dto.arrival_time,
                     booking_interval_start: dto.booking_interval_start,
                     booking_interval_end: dto.booking_interval_end,
-                    assigned_start: 0,
-                    assigned_end: 0,
+                    assigned_start: ASSIGNED_TIME_UNSET,
+                    assigned_end: ASSIGNED_TIME_UNSET,
                     task_duration: 1, // Default for links
                     reserved_capacity: 0,
                     is_moldable: false,
                     moldable_work: 0,
                     frag_delta: f64::MAX,
+                    is_moveable: false,
+                    priority: 0,
                 };
 
                 // DataDependency (file transfer)
@@ -269,16 +315,17 @@ impl Workflow {
                     dep_base.reserved_capacity = size;
                     dep_base.moldable_work = size * dep_base.task_duration;
                     let link_res = LinkReservation { base: dep_base, start_point: None, end_point: None };
-                    let reservation_id = reservation_store.add(Reservation::Link(link_res));
 
+                    // The reservation is created, as soon as the first consumer is connected
                     let data_dep = DataDependency {
-                        reservation_id,
+                        reservation_id: ReservationId::default(),
                         source_node: Some(WorkflowNodeId::new(source_node_id.clone())),
                         target_node: None,
                         port_name: port_name.clone(),
                         size,
+                        latency: data_out.latency.unwrap_or(0),
                     };
-                    dangling_deps.insert(dangling_key, DanglingDependency::Data(data_dep));
+                    dangling_deps.insert(dangling_key, (DanglingDependency::Data(data_dep), Reservation::Link(link_res)));
                 }
                 // SyncDependency
                 else if let Some(bandwidth) = data_out.bandwidth {
@@ -286,21 +333,26 @@ impl Workflow {
                     dep_base.reserved_capacity = bandwidth;
                     dep_base.moldable_work = bandwidth * dep_base.task_duration;
                     let link_res = LinkReservation { base: dep_base, start_point: None, end_point: None };
-                    let reservation_id = reservation_store.add(Reservation::Link(link_res));
 
+                    // The reservation is created, as soon as the first consumer is connected
                     let sync_dep = SyncDependency {
-                        reservation_id,
+                        reservation_id: ReservationId::default(),
                         source_node: Some(WorkflowNodeId::new(source_node_id.clone())),
                         target_node: None,
                         port_name: port_name.clone(),
                         bandwidth,
                     };
-                    dangling_deps.insert(dangling_key, DanglingDependency::Sync(sync_dep));
+                    dangling_deps.insert(dangling_key, (DanglingDependency::Sync(sync_dep), Reservation::Link(link_res)));
                 }
             }
         }
 
         // Phase 2.2: Process DataIn
+        let mut number_of_consumers: HashMap<String, usize> = HashMap::new();
+        for data_in in dto.tasks.iter().flat_map(|task_dto| &task_dto.node_reservation.data_in) {
+            *number_of_consumers.entry(format!("{}/{}", data_in.source_reservation, data_in.source_port)).or_default() += 1;
+        }
+
         for task_dto in &dto.tasks {
             let target_node_id = WorkflowNodeId::new(task_dto.id.clone());
             let node_res_dto = &task_dto.node_reservation;
@@ -308,24 +360,31 @@ impl Workflow {
             for data_in in &node_res_dto.data_in {
                 let dangling_key = format!("{}/{}", data_in.source_reservation, data_in.source_port);
 
-                if let Some(dangling_dep) = dangling_deps.remove(&dangling_key) {
-                    match dangling_dep {
+                if let Some((dangling_dep, port_reservation)) = dangling_deps.get(&dangling_key) {
+                    // All consumers of the port share its reservation, the dependencies of a fan-out are distinguished by their target
+                    let name = port_reservation.get_name();
+                    let reservation_id = reservation_store.get_or_insert_by_name(name.clone(), || port_reservation.clone());
+                    let dep_id_str =
+                        if number_of_consumers[&dangling_key] > 1 { format!("{}.{}", name.id, target_node_id.id) } else { name.id.clone() };
+
+                    match dangling_dep.clone() {
                         DanglingDependency::Data(mut data_dep) => {
+                            data_dep.reservation_id = reservation_id;
                             data_dep.target_node = Some(target_node_id.clone());
-                            let name = reservation_store.get_name_for_key(data_dep.reservation_id).unwrap();
-                            let dep_id = DataDependencyId::new(name.id);
-                            data_dependencies.insert(dep_id, data_dep);
+                            data_dependencies.insert(DataDependencyId::new(dep_id_str), data_dep);
                         }
                         DanglingDependency::Sync(mut sync_dep) => {
+                            sync_dep.reservation_id = reservation_id;
                             sync_dep.target_node = Some(target_node_id.clone());
-                            let name = reservation_store.get_name_for_key(sync_dep.reservation_id).unwrap();
-                            let dep_id = SyncDependencyId::new(name.id);
-                            sync_dependencies.insert(dep_id, sync_dep);
+                            sync_dependencies.insert(SyncDependencyId::new(dep_id_str), sync_dep);
                         }
                     }
-                } else {
+                } else if data_in.source_reservation != EXTERNAL_DATA_SOURCE {
                     // Dependency source not found!"
-                    log::warn!("Could not find source for DataIn: {}", dangling_key);
+                    diagnostics.push(Diagnostic::warning(
+                        format!("Could not find source for DataIn: {}", dangling_key),
+                        format!("{}/{}", workflow_id, task_dto.id),
+                    ));
                 }
             }
         }
@@ -388,7 +447,8 @@ impl Workflow {
         reservation_store: ReservationStore,
     ) {
         for source_id in source_ids {
-            let dep_id_str = format!("{}.{}.{}.{}", workflow_id, dep_type, source_id, target_node_id);
+            let dep_id_str =
+                Self::get_implicit_dependency_id(workflow_id, dep_type, source_id, target_node_id, data_deps, sync_deps, &reservation_store);
 
             let dep_base = ReservationBase {
                 name: ReservationName::new(dep_id_str.clone()),
@@ -399,16 +459,21 @@ impl Workflow {
                 arrival_time,
                 booking_interval_start: booking_start,
                 booking_interval_end: booking_end,
-                assigned_start: 0,
-                assigned_end: 0,
+                assigned_start: ASSIGNED_TIME_UNSET,
+                assigned_end: ASSIGNED_TIME_UNSET,
                 task_duration: 0,
                 reserved_capacity: 0,
                 is_moldable: false,
                 moldable_work: 0,
                 frag_delta: f64::MAX,
+                is_moveable: false,
+                priority: 0,
             };
-            let link_res = LinkReservation { base: dep_base, start_point: None, end_point: None };
-            let reservation_id = reservation_store.add(Reservation::Link(link_res));
+            // Reuse the dependency reservation, if the same dependency is referenced multiple times within this Workflow.
+            // The id is only used by this build (see `get_implicit_dependency_id`), hence a re-parsed Workflow gets its own reservations.
+            let reservation_id = reservation_store.get_or_insert_by_name(ReservationName::new(dep_id_str.clone()), || {
+                Reservation::Link(LinkReservation { base: dep_base, start_point: None, end_point: None })
+            });
 
             if dep_type == "data" {
                 let data_dep = DataDependency {
@@ -417,6 +482,7 @@ impl Workflow {
                     target_node: Some(WorkflowNodeId::new(target_node_id.to_string())),
                     port_name: "data".to_string(),
                     size: 0,
+                    latency: 0,
                 };
                 data_deps.insert(DataDependencyId::new(dep_id_str), data_dep);
             } else if dep_type == "sync" {
@@ -432,28 +498,111 @@ impl Workflow {
         }
     }
 
+    /// Returns the id of the implicit dependency of `dep_type` ("data" or "sync") from `source_id` to `target_node_id`.
+    ///
+    /// The id is `{workflow}.{dep_type}.{source}.{target}`. If this id is already used by another dependency (e.g. by an
+    /// explicit port of a node, whose id contains dots) or by a reservation in the `reservation_store` (e.g. of the same
+    /// Workflow parsed before), an incrementing index is appended (`.1`, `.2`, ...), such that the implicit dependency does
+    /// not overwrite or share it. The same implicit dependency listed multiple times keeps its id.
+    fn get_implicit_dependency_id(
+        workflow_id: &str,
+        dep_type: &str,
+        source_id: &str,
+        target_node_id: &str,
+        data_deps: &HashMap<DataDependencyId, DataDependency>,
+        sync_deps: &HashMap<SyncDependencyId, SyncDependency>,
+        reservation_store: &ReservationStore,
+    ) -> String {
+        let source = WorkflowNodeId::new(source_id);
+        let target = WorkflowNodeId::new(target_node_id);
+        let base_id = format!("{}.{}.{}.{}", workflow_id, dep_type, source_id, target_node_id);
+
+        let mut dep_id_str = base_id.clone();
+        let mut index = 1;
+
+        loop {
+            let data_dep = data_deps.get(&DataDependencyId::new(dep_id_str.clone()));
+            let sync_dep = sync_deps.get(&SyncDependencyId::new(dep_id_str.clone()));
+
+            let is_same_dependency = match (dep_type, data_dep, sync_dep) {
+                ("data", Some(dep), None) => {
+                    dep.port_name == dep_type && dep.source_node.as_ref() == Some(&source) && dep.target_node.as_ref() == Some(&target)
+                }
+                ("sync", None, Some(dep)) => {
+                    dep.port_name == dep_type && dep.source_node.as_ref() == Some(&source) && dep.target_node.as_ref() == Some(&target)
+                }
+                _ => false,
+            };
+
+            let is_unused =
+                data_dep.is_none() && sync_dep.is_none() && reservation_store.get_by_name(&ReservationName::new(dep_id_str.clone())).is_none();
+            if is_same_dependency || is_unused {
+                return dep_id_str;
+            }
+
+            dep_id_str = format!("{}.{}", base_id, index);
+            index += 1;
+        }
+    }
+
+    /// Returns `base_name`, if no reservation in the `reservation_store` carries this name. Otherwise (e.g. the same
+    /// Workflow was parsed before), an incrementing index is appended (`.1`, `.2`, ...) until the name is unused.
+    fn get_unused_dependency_name(base_name: String, reservation_store: &ReservationStore) -> String {
+        let mut name = base_name.clone();
+        let mut index = 1;
+
+        while reservation_store.get_by_name(&ReservationName::new(name.clone())).is_some() {
+            name = format!("{}.{}", base_name, index);
+            index += 1;
+        }
+        return name;
+    }
+
+    /// **Phase 2.4: Build Soft Sync Dependencies**
+    ///
+    /// Creates a `SoftSyncDependency` for each entry of `dependencies: { soft_sync }`.
+    /// Soft sync dependencies have no LinkReservation and are not merged into CoAllocations.
+    pub fn build_soft_sync_dependencies(dto: &WorkflowDto) -> HashMap<SoftSyncDependencyId, SoftSyncDependency> {
+        let mut soft_sync_dependencies = HashMap::new();
+
+        for task_dto in &dto.tasks {
+            for source_id in &task_dto.node_reservation.dependencies.soft_sync {
+                let dep_id_str = format!("{}.soft_sync.{}.{}", dto.id, source_id, task_dto.id);
+
+                let soft_sync_dep = SoftSyncDependency {
+                    source_node: Some(WorkflowNodeId::new(source_id.clone())),
+                    target_node: Some(WorkflowNodeId::new(task_dto.id.clone())),
+                    separation_penalty: SOFT_SYNC_SEPARATION_PENALTY,
+                };
+                soft_sync_dependencies.insert(SoftSyncDependencyId::new(dep_id_str), soft_sync_dep);
+            }
+        }
+        return soft_sync_dependencies;
+    }
+
     /// **Phase 3: Populate Node Adjacency Lists**
     ///
     /// Connects the `WorkflowNode`s by populating their `incoming_` and `outgoing_`
-    /// `Vec`s with the dependency IDs.
+    /// `Vec`s with the dependency IDs. Dependencies with an unknown source or target node are reported in `diagnostics`.
     pub fn populate_node_adjacency_lists(
         nodes: &mut HashMap<WorkflowNodeId, WorkflowNode>,
         data_dependencies: &HashMap<DataDependencyId, DataDependency>,
         sync_dependencies: &HashMap<SyncDependencyId, SyncDependency>,
+        diagnostics: &mut Vec<Diagnostic>,
     ) {
         for (dep_id, data_dep) in data_dependencies {
             if let Some(ref source_id) = data_dep.source_node {
                 if let Some(source_node) = nodes.get_mut(source_id) {
                     source_node.outgoing_data.push(dep_id.clone());
                 } else {
-                    log::warn!("DataDep source node '{}' not found for dep '{}'", source_id, dep_id);
+                    diagnostics.push(Diagnostic::warning(format!("DataDep source node '{}' not found", source_id), dep_id.to_string()));
                 }
             }
             if let Some(ref target_id) = data_dep.target_node {
                 if let Some(target_node) = nodes.get_mut(target_id) {
                     target_node.incoming_data.push(dep_id.clone());
                 } else {
-                    log::warn!("DataDep target node '{}' not found for dep '{}'", target_id, dep_id);
+                    diagnostics.push(Diagnostic::warning(format!("DataDep target node '{}' not found", target_id), dep_id.to_string()));
                 }
             }
         }
@@ -463,19 +612,140 @@ impl Workflow {
                 if let Some(source_node) = nodes.get_mut(source_id) {
                     source_node.outgoing_sync.push(dep_id.clone());
                 } else {
-                    log::warn!("SyncDep source node '{}' not found for dep '{}'", source_id, dep_id);
+                    diagnostics.push(Diagnostic::warning(format!("SyncDep source node '{}' not found", source_id), dep_id.to_string()));
                 }
             }
             if let Some(ref target_id) = sync_dep.target_node {
                 if let Some(target_node) = nodes.get_mut(target_id) {
                     target_node.incoming_sync.push(dep_id.clone());
                 } else {
-                    log::warn!("SyncDep target node '{}' not found for dep '{}'", target_id, dep_id);
+                    diagnostics.push(Diagnostic::warning(format!("SyncDep target node '{}' not found", target_id), dep_id.to_string()));
                 }
             }
         }
     }
 
+    /// **Phase 3.1: Validate Acyclic Dependencies**
+    ///
+    /// WorkflowNodes connected by SyncDependencies only demand a simultaneous start, hence they are merged into one
+    /// group (union-find over the SyncDependencies) and a cycle consisting only of SyncDependencies (e.g. sync declared
+    /// on both nodes) is valid. A color-marking DFS over the DataDependencies between these groups checks, that the
+    /// groups form a DAG. A DataDependency within a group can never be fulfilled and is reported as a cycle as well.
+    ///
+    /// # Returns
+    /// `Err(Error::CyclicWorkflow)` with the first detected cycle in dependency order, i.e. each node is a
+    /// predecessor of (or synchronized with) the next one and the last node is a predecessor of the first one.
+    pub fn validate_acyclic(
+        nodes: &HashMap<WorkflowNodeId, WorkflowNode>,
+        data_dependencies: &HashMap<DataDependencyId, DataDependency>,
+        sync_dependencies: &HashMap<SyncDependencyId, SyncDependency>,
+    ) -> Result<(), Error> {
+        let mut node_ids: Vec<&WorkflowNodeId> = nodes.keys().collect();
+        node_ids.sort();
+        let node_index: HashMap<&WorkflowNodeId, usize> = node_ids.iter().enumerate().map(|(index, node_id)| (*node_id, index)).collect();
+
+        let mut dsu = QuickUnionUf::<UnionBySize>::new(node_ids.len());
+        for sync_dep in sync_dependencies.values() {
+            let source_index = sync_dep.source_node.as_ref().and_then(|node_id| node_index.get(node_id));
+            let target_index = sync_dep.target_node.as_ref().and_then(|node_id| node_index.get(node_id));
+
+            if let (Some(source_index), Some(target_index)) = (source_index, target_index) {
+                dsu.union(*source_index, *target_index);
+            }
+        }
+        let groups: Vec<usize> = (0..node_ids.len()).map(|index| dsu.find(index)).collect();
+
+        // Incoming DataDependencies (source node, target node) of each group in the order of the node ids
+        let mut incoming_data: HashMap<usize, Vec<(usize, usize)>> = HashMap::new();
+        for (target_index, target_id) in node_ids.iter().enumerate() {
+            let mut source_indices: Vec<usize> = nodes[*target_id]
+                .incoming_data
+                .iter()
+                .filter_map(|dep_id| node_index.get(data_dependencies.get(dep_id)?.source_node.as_ref()?).copied())
+                .collect();
+            source_indices.sort();
+
+            for source_index in source_indices {
+                if groups[source_index] == groups[target_index] {
+                    let mut cycle = vec![node_ids[source_index].clone()];
+                    if source_index != target_index {
+                        cycle.push(node_ids[target_index].clone());
+                    }
+                    log::error!("ErrorCyclicWorkflow: The data dependency {:?} connects synchronized nodes.", cycle);
+                    return Err(Error::CyclicWorkflow { cycle });
+                }
+                incoming_data.entry(groups[target_index]).or_default().push((source_index, target_index));
+            }
+        }
+
+        let mut colors = vec![VisitColor::White; node_ids.len()];
+        for index in 0..node_ids.len() {
+            if colors[groups[index]] == VisitColor::White {
+                let mut path = Vec::new();
+                if let Some(cycle) = Self::find_cycle(groups[index], None, &groups, &incoming_data, &mut colors, &mut path) {
+                    let cycle: Vec<WorkflowNodeId> = cycle.into_iter().map(|index| node_ids[index].clone()).collect();
+                    log::error!("ErrorCyclicWorkflow: The dependencies of the workflow contain the cycle {:?}.", cycle);
+                    return Err(Error::CyclicWorkflow { cycle });
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    /// DFS step of `validate_acyclic`, which follows the incoming DataDependencies of the group `group`.
+    /// `path` contains the current DFS path and for each group the DataDependency (source node, target node), over
+    /// which it was reached from its successor.
+    ///
+    /// # Returns
+    /// The node indices of the first detected cycle in dependency order.
+    fn find_cycle(
+        group: usize,
+        reached_via: Option<(usize, usize)>,
+        groups: &[usize],
+        incoming_data: &HashMap<usize, Vec<(usize, usize)>>,
+        colors: &mut Vec<VisitColor>,
+        path: &mut Vec<(usize, Option<(usize, usize)>)>,
+    ) -> Option<Vec<usize>> {
+        colors[group] = VisitColor::Gray;
+        path.push((group, reached_via));
+
+        for (source_index, target_index) in incoming_data.get(&group).into_iter().flatten().copied() {
+            let predecessor = groups[source_index];
+
+            match colors[predecessor] {
+                VisitColor::White => {
+                    if let Some(cycle) = Self::find_cycle(predecessor, Some((source_index, target_index)), groups, incoming_data, colors, path) {
+                        return Some(cycle);
+                    }
+                }
+                VisitColor::Gray => {
+                    let cycle_start = path.iter().position(|(path_group, _)| *path_group == predecessor).unwrap();
+                    let edges =
+                        path[cycle_start + 1..].iter().rev().filter_map(|(_, edge)| *edge).chain(std::iter::once((source_index, target_index)));
+
+                    // Consecutive DataDependencies are connected by the synchronized nodes of a group
+                    let mut cycle: Vec<usize> = Vec::new();
+                    for (edge_source, edge_target) in edges {
+                        if cycle.last() != Some(&edge_source) {
+                            cycle.push(edge_source);
+                        }
+                        cycle.push(edge_target);
+                    }
+                    if cycle.len() > 1 && cycle.first() == cycle.last() {
+                        cycle.pop();
+                    }
+                    return Some(cycle);
+                }
+                // Already validated
+                VisitColor::Black => {}
+            }
+        }
+
+        path.pop();
+        colors[group] = VisitColor::Black;
+        return None;
+    }
+
     /// **Phase 4: Build CoAllocation Graph**
     ///
     /// Identifies co-allocation groups. It uses a Disjoint Set Union (DSU) structure
@@ -599,12 +869,18 @@ impl Workflow {
     ///
     /// This iterates over all `DataDependency`s and, if a dependency links
     /// nodes in two *different* `CoAllocation`s, its creates an edge between those groups.
+    ///
+    /// Returns an error if two `CoAllocation`s depend on each other (bidirectional edge),
+    /// because such a cycle of length 2 prevents the rank calculation.
+    /// Skipped dependencies, whose source or target node is not part of any `CoAllocation`, are reported in `diagnostics`.
     pub fn build_co_allocation_dependencies(
         data_dependencies: &HashMap<DataDependencyId, DataDependency>,
         node_to_co_allocation: &HashMap<WorkflowNodeId, CoAllocationId>,
         co_allocation: &mut HashMap<CoAllocationId, CoAllocation>,
+        diagnostics: &mut Vec<Diagnostic>,
     ) -> Result<HashMap<CoAllocationDependencyId, CoAllocationDependency>, Error> {
         let mut co_allocation_dependencies = HashMap::new();
+        let mut co_allocation_edges: HashSet<(CoAllocationId, CoAllocationId)> = HashSet::new();
 
         for (dep_id, data_dep) in data_dependencies {
             if let (Some(source_node), Some(target_node)) = (&data_dep.source_node, &data_dep.target_node) {
@@ -613,6 +889,14 @@ impl Workflow {
                 {
                     // Only create sync group edges between *different* CoAllocations
                     if source_co_allocation_id != target_co_allocation_id {
+                        if co_allocation_edges.contains(&(target_co_allocation_id.clone(), source_co_allocation_id.clone())) {
+                            return Err(Error::ModelConstructionError(format!(
+                                "Bidirectional co-allocation dependency between '{}' and '{}' (data dependency '{}') creates a cycle in the co-allocation graph.",
+                                source_co_allocation_id, target_co_allocation_id, dep_id
+                            )));
+                        }
+                        co_allocation_edges.insert((source_co_allocation_id.clone(), target_co_allocation_id.clone()));
+
                         let co_allocation_dep_id = CoAllocationDependencyId::new(dep_id.id.clone());
                         let co_allocation_dep = CoAllocationDependency {
                             id: co_allocation_dep_id.clone(),
@@ -634,12 +918,13 @@ impl Workflow {
                         }
                     }
                 } else {
-                    log::warn!(
-                        "Skipping co_allocation dependency '{}' because source ('{}') or target ('{}') node was not found in co_allocation map.",
-                        dep_id,
-                        source_node,
-                        target_node
-                    );
+                    diagnostics.push(Diagnostic::warning(
+                        format!(
+                            "Skipping co_allocation dependency because source ('{}') or target ('{}') node was not found in co_allocation map.",
+                            source_node, target_node
+                        ),
+                        dep_id.to_string(),
+                    ));
                 }
             }
         }
@@ -651,23 +936,56 @@ impl Workflow {
     /// Finds all entry/exit nodes for the base graph and all entry/exit
     /// groups for the CoAllocation graph. This is used by the scheduler to find
     /// the starting points for traversal.
+    ///
+    /// All returned ids are sorted by their string form, so the traversal order does not depend on the HashMap order.
     pub fn find_entry_exit_points(
         nodes: &HashMap<WorkflowNodeId, WorkflowNode>,
         co_allocation: &HashMap<CoAllocationId, CoAllocation>,
     ) -> (Vec<WorkflowNodeId>, Vec<WorkflowNodeId>, Vec<CoAllocationId>, Vec<CoAllocationId>) {
-        let entry_nodes = nodes.iter().filter(|(_, n)| n.incoming_data.is_empty() && n.incoming_sync.is_empty()).map(|(k, _)| k.clone()).collect();
+        let mut entry_nodes: Vec<WorkflowNodeId> =
+            nodes.iter().filter(|(_, n)| n.incoming_data.is_empty() && n.incoming_sync.is_empty()).map(|(k, _)| k.clone()).collect();
 
-        let exit_nodes = nodes.iter().filter(|(_, n)| n.outgoing_data.is_empty() && n.outgoing_sync.is_empty()).map(|(k, _)| k.clone()).collect();
+        let mut exit_nodes: Vec<WorkflowNodeId> =
+            nodes.iter().filter(|(_, n)| n.outgoing_data.is_empty() && n.outgoing_sync.is_empty()).map(|(k, _)| k.clone()).collect();
 
         // Find Entry/Exit SyncGroups based on the *overlay* graph
-        let entry_co_allocation =
+        let mut entry_co_allocation: Vec<CoAllocationId> =
             co_allocation.values().filter(|on| on.incoming_co_allocation_dependencies.is_empty()).map(|on| on.id.clone()).collect();
 
-        let exit_co_allocation =
+        let mut exit_co_allocation: Vec<CoAllocationId> =
             co_allocation.values().filter(|on| on.outgoing_co_allocation_dependencies.is_empty()).map(|on| on.id.clone()).collect();
 
+        entry_nodes.sort_by(|a, b| a.id.cmp(&b.id));
+        exit_nodes.sort_by(|a, b| a.id.cmp(&b.id));
+        entry_co_allocation.sort_by(|a, b| a.id.cmp(&b.id));
+        exit_co_allocation.sort_by(|a, b| a.id.cmp(&b.id));
+
         (entry_nodes, exit_nodes, entry_co_allocation, exit_co_allocation)
     }
+
+    /// **Final-Step: Assign CoAllocation Keys**
+    ///
+    /// Updates the `co_allocation_key` of all `WorkflowNode`s and of the `representative` of each `CoAllocation`.
+    pub fn assign_co_allocation_keys(
+        nodes: &mut HashMap<WorkflowNodeId, WorkflowNode>,
+        co_allocations: &mut HashMap<CoAllocationId, CoAllocation>,
+        node_to_co_allocation: HashMap<WorkflowNodeId, CoAllocationId>,
+    ) {
+        for (node_id, group_id) in node_to_co_allocation {
+            if let Some(node) = nodes.get_mut(&node_id) {
+                node.co_allocation_key = Some(group_id.clone());
+                let reservation_id_opt = node.reservation_id;
+
+                if let Some(co_alloc) = co_allocations.get_mut(&group_id) {
+                    if let Some(rep) = &mut co_alloc.representative {
+                        if rep.reservation_id == reservation_id_opt {
+                            rep.co_allocation_key = Some(group_id);
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 pub fn map_reservation_state(dto_state: ReservationStateDto) -> ReservationState {
@@ -699,10 +1017,41 @@ impl Workflow {
     /// This function also computes the number of nodes in the critical upward path
     /// (`number_of_nodes_critical_path_upwards`) for all nodes.
     ///
-    /// A `Vec<Option<WorkflowNode>>` containing the `representative` node for
+    /// A `Vec<WorkflowNode>` containing the `representative` node for
     /// every `CoAllocation` in the workflow, ordered by `rank_upward` in descending
     /// order (largest ranks are first).
-    pub fn calculate_upward_rank(&mut self, avg_net_speed: i64, reservation_store: &ReservationStore) -> Vec<WorkflowNode> {
+    ///
+    /// The durations of the `CoAllocation`s are read once from the `reservation_store` at the beginning of the pass,
+    /// hence the ranks must be recomputed if a member reservation is modified (e.g. via `adjust_capacity` or `set_task_duration`).
+    ///
+    /// Returns `Error::NoNetworkCapacity` if `avg_net_speed <= 0` and any data dependency has to transfer data,
+    /// as all transfers would appear instantaneous.
+    pub fn calculate_upward_rank(&mut self, avg_net_speed: i64, reservation_store: &ReservationStore) -> Result<Vec<WorkflowNode>, Error> {
+        self.validate_avg_net_speed(avg_net_speed)?;
+
+        return self.calculate_upward_rank_by(|data_dependency| data_dependency.get_communication_time(avg_net_speed), reservation_store);
+    }
+
+    /// Like `calculate_upward_rank`, but the communication time of each data dependency is the zone-aware estimate
+    /// of `ZoneCommunicationCost::get_average_communication_time`, so data dependencies, which likely stay within a
+    /// locality zone, contribute less to the rank.
+    pub fn calculate_upward_rank_with_zones(
+        &mut self,
+        zone_communication_cost: &ZoneCommunicationCost,
+        reservation_store: &ReservationStore,
+    ) -> Result<Vec<WorkflowNode>, Error> {
+        self.validate_avg_net_speed(zone_communication_cost.average_link_speed)?;
+
+        return self
+            .calculate_upward_rank_by(|data_dependency| zone_communication_cost.get_average_communication_time(data_dependency), reservation_store);
+    }
+
+    /// Computes the upward ranks, the `communication_time` of each data dependency is provided by the caller.
+    fn calculate_upward_rank_by<F>(&mut self, communication_time: F, reservation_store: &ReservationStore) -> Result<Vec<WorkflowNode>, Error>
+    where
+        F: Fn(&DataDependency) -> i64,
+    {
+        let co_allocation_durations = self.get_co_allocation_durations(reservation_store);
         let mut finished_node_keys: Vec<CoAllocationId> = Vec::with_capacity(self.co_allocations.len());
         let mut queue: Vec<CoAllocationId> = Vec::new();
 
@@ -729,7 +1078,7 @@ impl Workflow {
                 continue;
             }
 
-            let node_duration = node.get_co_allocation_duration(&self.nodes, &reservation_store);
+            let node_duration = co_allocation_durations[&next_key];
             let outgoing_deps = node.outgoing_co_allocation_dependencies.clone();
             let mut rank = node_duration;
             let mut number_of_nodes_critical_path = 1;
@@ -743,14 +1092,8 @@ impl Workflow {
                         is_successor_without_rank = true;
                         queue.push(target_key.clone());
                     } else {
-                        let size = self.data_dependencies.get(&outgoing_dep.data_dependency).expect("Data dependency not found").size;
-
-                        let communication_time = if avg_net_speed > 0 {
-                            size / avg_net_speed
-                        } else {
-                            log::warn!("avg_net_speed is 0, setting communication_time to 0");
-                            0
-                        };
+                        let communication_time =
+                            communication_time(self.data_dependencies.get(&outgoing_dep.data_dependency).expect("Data dependency not found"));
 
                         let successor_rank = target_group.rank_upward;
                         let new_possible_rank = node_duration + communication_time + successor_rank;
@@ -778,15 +1121,348 @@ impl Workflow {
             }
         }
 
-        // 6. Build sortedList
+        // 6. Build sortedList, equal ranks are ordered by the CoAllocation id
         finished_node_keys.sort_by(|a_key, b_key| {
             let a_rank = self.co_allocations.get(a_key).unwrap().rank_upward;
             let b_rank = self.co_allocations.get(b_key).unwrap().rank_upward;
-            b_rank.cmp(&a_rank)
+            b_rank.cmp(&a_rank).then_with(|| a_key.id.cmp(&b_key.id))
         });
 
         // 7. Map keys to the representative nodes
-        return finished_node_keys.into_iter().map(|key| self.co_allocations.get(&key).unwrap().representative.clone().unwrap()).collect();
+        return Ok(finished_node_keys.into_iter().map(|key| self.co_allocations.get(&key).unwrap().representative.clone().unwrap()).collect());
+    }
+
+    /// Updates the upward ranks after the duration of the single `CoAllocation` `changed` was modified (e.g. by a moldable reshape).
+    ///
+    /// The upward rank of a `CoAllocation` depends only on its successors, hence only the ranks of `changed` and of its
+    /// ancestors are recomputed, while the cached ranks of all other `CoAllocation`s are reused.
+    /// The result equals a full `calculate_upward_rank` pass, as long as the ranks were up to date before the modification.
+    ///
+    /// # Arguments
+    /// * `changed` - The `CoAllocation`, whose duration was modified.
+    /// * `avg_net_speed` - The average network speed, which was also used for the previous rank pass.
+    /// * `reservation_store` - Store holding the member reservations, used to read the durations of the `CoAllocation`s.
+    ///
+    /// # Returns
+    /// `Error::NoNetworkCapacity` under the same conditions as `calculate_upward_rank`.
+    pub fn update_rank_from(&mut self, changed: CoAllocationId, avg_net_speed: i64, reservation_store: &ReservationStore) -> Result<(), Error> {
+        self.validate_avg_net_speed(avg_net_speed)?;
+
+        if !self.co_allocations.contains_key(&changed) {
+            log::warn!("WorkflowUpdateRankUnknownCoAllocation: CoAllocation '{}' not found in workflow {}.", changed, self.base.name);
+            return Ok(());
+        }
+
+        // 1. Collect the changed CoAllocation and all its ancestors
+        let mut affected: HashSet<CoAllocationId> = HashSet::from([changed.clone()]);
+        let mut stack: Vec<CoAllocationId> = vec![changed];
+
+        while let Some(key) = stack.pop() {
+            for incoming_dep in &self.co_allocations[&key].incoming_co_allocation_dependencies {
+                if self.co_allocations.contains_key(&incoming_dep.source_group) && affected.insert(incoming_dep.source_group.clone()) {
+                    stack.push(incoming_dep.source_group.clone());
+                }
+            }
+        }
+
+        // 2. Count the outgoing dependencies into affected CoAllocations, which have to be recomputed first
+        let mut pending_successors: HashMap<CoAllocationId, usize> = affected
+            .iter()
+            .map(|key| {
+                let count = self.co_allocations[key]
+                    .outgoing_co_allocation_dependencies
+                    .iter()
+                    .filter(|outgoing_dep| affected.contains(&outgoing_dep.target_group))
+                    .count();
+                (key.clone(), count)
+            })
+            .collect();
+
+        let mut ready: Vec<CoAllocationId> = pending_successors.iter().filter(|(_, count)| **count == 0).map(|(key, _)| key.clone()).collect();
+
+        // 3. Recompute the ranks in reverse topological order, successors outside of the affected set keep their cached rank
+        while let Some(key) = ready.pop() {
+            let co_allocation = &self.co_allocations[&key];
+            let node_duration = co_allocation.get_co_allocation_duration(&self.nodes, reservation_store);
+            let mut rank = node_duration;
+            let mut number_of_nodes_critical_path = 1;
+
+            for outgoing_dep in &co_allocation.outgoing_co_allocation_dependencies {
+                let Some(target_group) = self.co_allocations.get(&outgoing_dep.target_group) else {
+                    log::warn!("Target CoAllocation '{}' not found.", outgoing_dep.target_group);
+                    continue;
+                };
+
+                let communication_time = self
+                    .data_dependencies
+                    .get(&outgoing_dep.data_dependency)
+                    .expect("Data dependency not found")
+                    .get_communication_time(avg_net_speed);
+                let new_possible_rank = node_duration + communication_time + target_group.rank_upward;
+
+                if rank < new_possible_rank {
+                    rank = new_possible_rank;
+                    number_of_nodes_critical_path = target_group.number_of_nodes_critical_path_upwards + 1;
+                }
+            }
+
+            let incoming_deps = co_allocation.incoming_co_allocation_dependencies.clone();
+            let updated = self.co_allocations.get_mut(&key).expect("CoAllocation must exist");
+            updated.rank_upward = rank;
+            updated.number_of_nodes_critical_path_upwards = number_of_nodes_critical_path;
+
+            for incoming_dep in &incoming_deps {
+                if let Some(count) = pending_successors.get_mut(&incoming_dep.source_group) {
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.push(incoming_dep.source_group.clone());
+                    }
+                }
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Ensures that the data dependencies of the workflow can be transferred with `avg_net_speed`.
+    /// If all data dependencies have a size of 0, nothing is transferred and any speed is accepted.
+    fn validate_avg_net_speed(&self, avg_net_speed: i64) -> Result<(), Error> {
+        if avg_net_speed <= 0 && self.data_dependencies.values().any(|data_dependency| data_dependency.size != 0) {
+            log::error!(
+                "ErrorWorkflowNoNetworkCapacity: Average network speed of {} for workflow {} with non-empty data dependencies.",
+                avg_net_speed,
+                self.base.name
+            );
+            return Err(Error::NoNetworkCapacity);
+        }
+        return Ok(());
+    }
+
+    /// Returns the duration of every `CoAllocation`, so that a rank pass does not recompute the duration
+    /// each time a `CoAllocation` is visited again while waiting for its successors (predecessors).
+    fn get_co_allocation_durations(&self, reservation_store: &ReservationStore) -> HashMap<CoAllocationId, i64> {
+        return self
+            .co_allocations
+            .iter()
+            .map(|(co_allocation_key, co_allocation)| {
+                (co_allocation_key.clone(), co_allocation.get_co_allocation_duration(&self.nodes, reservation_store))
+            })
+            .collect();
+    }
+
+    /// Computes the theoretical lower bound of the makespan of the Workflow.
+    ///
+    /// The bound ignores any resource contention (unlimited resources) and is therefore the length of the
+    /// critical path, which is the maximal upward rank of all entry `CoAllocation`s.
+    /// Comparing the makespan of the actual placement with this bound quantifies the scheduling inefficiency.
+    pub fn lower_bound_makespan(&mut self, avg_net_speed: i64, reservation_store: &ReservationStore) -> Result<i64, Error> {
+        self.calculate_upward_rank(avg_net_speed, reservation_store)?;
+
+        return Ok(self
+            .entry_co_allocation
+            .iter()
+            .filter_map(|co_allocation_key| self.co_allocations.get(co_allocation_key))
+            .map(|co_allocation| co_allocation.rank_upward)
+            .max()
+            .unwrap_or(0));
+    }
+
+    /// Extracts the critical path of the Workflow from the already computed upward ranks.
+    ///
+    /// The path starts at the entry `CoAllocation` with the highest `rank_upward` and follows in each step the
+    /// successor, which determined the rank of the current `CoAllocation` (i.e. whose rank plus the communication time
+    /// of the connecting dependency equals the rank minus the duration), until an exit is reached.
+    /// Ties are broken by the smallest `CoAllocationId`, so the result is deterministic.
+    ///
+    /// `calculate_upward_rank` must be called beforehand with the same `avg_net_speed`, otherwise all ranks are 0
+    /// and only the entry is returned.
+    ///
+    /// # Arguments
+    /// * `avg_net_speed` - The average network speed, used to compute the communication times of the dependencies.
+    /// * `reservation_store` - Store holding the member reservations, used to read the durations of the `CoAllocation`s.
+    ///
+    /// # Returns
+    /// The length of the critical path (the makespan without resource contention) and the ids of all
+    /// `WorkflowNode`s on the path, ordered from entry to exit (members of one `CoAllocation` are adjacent).
+    pub fn critical_path(&self, avg_net_speed: i64, reservation_store: &ReservationStore) -> (i64, Vec<WorkflowNodeId>) {
+        return self.critical_path_by(|data_dependency| data_dependency.get_communication_time(avg_net_speed), reservation_store);
+    }
+
+    /// Like `critical_path`, but for upward ranks computed by `calculate_upward_rank_with_zones` with the same
+    /// `zone_communication_cost`.
+    pub fn critical_path_with_zones(
+        &self,
+        zone_communication_cost: &ZoneCommunicationCost,
+        reservation_store: &ReservationStore,
+    ) -> (i64, Vec<WorkflowNodeId>) {
+        return self.critical_path_by(|data_dependency| zone_communication_cost.get_average_communication_time(data_dependency), reservation_store);
+    }
+
+    /// Extracts the critical path, the `communication_time` of each data dependency must match the one of the rank calculation.
+    fn critical_path_by<F>(&self, communication_time: F, reservation_store: &ReservationStore) -> (i64, Vec<WorkflowNodeId>)
+    where
+        F: Fn(&DataDependency) -> i64,
+    {
+        let co_allocation_durations = self.get_co_allocation_durations(reservation_store);
+        let mut path: Vec<WorkflowNodeId> = Vec::new();
+
+        let Some(mut current_key) = self
+            .entry_co_allocation
+            .iter()
+            .filter(|co_allocation_key| self.co_allocations.contains_key(*co_allocation_key))
+            .max_by(|a_key, b_key| self.co_allocations[*a_key].rank_upward.cmp(&self.co_allocations[*b_key].rank_upward).then(b_key.cmp(a_key)))
+            .cloned()
+        else {
+            return (0, path);
+        };
+
+        let length = self.co_allocations[&current_key].rank_upward;
+
+        // The number of steps is bounded by the number of CoAllocations to avoid endless loops in malformed workflows
+        for _ in 0..self.co_allocations.len() {
+            let current = &self.co_allocations[&current_key];
+            path.extend(current.members.iter().cloned());
+
+            let remaining_rank = current.rank_upward - co_allocation_durations[&current_key];
+            let next_key = current
+                .outgoing_co_allocation_dependencies
+                .iter()
+                .filter_map(|outgoing_dep| {
+                    let successor = self.co_allocations.get(&outgoing_dep.target_group)?;
+                    let communication_time = communication_time(self.data_dependencies.get(&outgoing_dep.data_dependency)?);
+                    Some((successor, communication_time))
+                })
+                .filter(|(successor, communication_time)| successor.rank_upward + communication_time == remaining_rank)
+                .map(|(successor, _)| successor.id.clone())
+                .min();
+
+            match next_key {
+                Some(next_key) => current_key = next_key,
+                None => break,
+            }
+        }
+
+        return (length, path);
+    }
+
+    /// Computes the slack (`spare_time`) of every `CoAllocation` from the already computed ranks.
+    ///
+    /// The slack is the makespan of the Workflow (the maximal upward rank of all entry `CoAllocation`s) minus
+    /// the length of the longest path through the `CoAllocation` (`rank_upward + rank_downward - duration`).
+    /// `CoAllocation`s with a slack of 0 lie on the critical path and are marked as not moveable.
+    ///
+    /// `calculate_upward_rank` and `calculate_downward_rank` must be called beforehand.
+    ///
+    /// # Arguments
+    /// * `reservation_store` - Store holding the member reservations, used to read the durations of the `CoAllocation`s.
+    pub fn compute_slack(&mut self, reservation_store: &ReservationStore) {
+        let co_allocation_durations = self.get_co_allocation_durations(reservation_store);
+        let makespan = self
+            .entry_co_allocation
+            .iter()
+            .filter_map(|co_allocation_key| self.co_allocations.get(co_allocation_key))
+            .map(|co_allocation| co_allocation.rank_upward)
+            .max()
+            .unwrap_or(0);
+
+        for (co_allocation_key, co_allocation) in self.co_allocations.iter_mut() {
+            let duration = co_allocation_durations.get(co_allocation_key).copied().unwrap_or(0);
+            co_allocation.spare_time = makespan - (co_allocation.rank_upward + co_allocation.rank_downward - duration);
+
+            if co_allocation.spare_time == 0 {
+                co_allocation.is_moveable = false;
+            }
+        }
+    }
+
+    /// Summarizes the size of the Workflow from its graph maps and the already computed ranks.
+    ///
+    /// The depth is the `number_of_nodes_critical_path_upwards` of the entry `CoAllocation` with the highest `rank_upward`,
+    /// therefore `calculate_upward_rank` must be called beforehand, otherwise the depth is 0.
+    ///
+    /// # Arguments
+    /// * `reservation_store` - Store holding the node reservations, used to read their durations and capacities.
+    ///
+    /// # Returns
+    /// The `WorkflowSummary` of the Workflow.
+    pub fn summary(&self, reservation_store: &ReservationStore) -> WorkflowSummary {
+        let total_compute_work = self
+            .nodes
+            .values()
+            .map(|node| reservation_store.get_task_duration(node.reservation_id) * reservation_store.get_reserved_capacity(node.reservation_id))
+            .sum();
+
+        let depth = self
+            .entry_co_allocation
+            .iter()
+            .filter_map(|co_allocation_key| self.co_allocations.get(co_allocation_key))
+            .max_by(|a, b| a.rank_upward.cmp(&b.rank_upward).then(b.id.cmp(&a.id)))
+            .map(|co_allocation| co_allocation.number_of_nodes_critical_path_upwards)
+            .unwrap_or(0);
+
+        return WorkflowSummary {
+            node_count: self.nodes.len(),
+            co_allocation_count: self.co_allocations.len(),
+            total_compute_work,
+            total_data_volume: self.data_dependencies.values().map(|data_dependency| data_dependency.size).sum(),
+            depth,
+        };
+    }
+
+    /// Returns `true`, if both Workflows have the same graph structure (see `structural_diff`).
+    pub fn structurally_equal(&self, other: &Workflow) -> bool {
+        return self.structural_diff(other).is_empty();
+    }
+
+    /// Compares the graph structure of two Workflows, e.g. to assert that equivalent DTOs result in the same Workflow.
+    ///
+    /// Compared are the node ids, the dependencies by their source, target and port and the members of the
+    /// CoAllocations. Generated ids (dependency ids, CoAllocation ids, reservation ids) and the iteration order
+    /// of the graph maps are ignored.
+    ///
+    /// # Returns
+    /// The `WorkflowStructuralDiff` with all elements, which are only contained in one of the Workflows.
+    pub fn structural_diff(&self, other: &Workflow) -> WorkflowStructuralDiff {
+        let mut other_elements = other.structural_elements();
+        let mut only_in_self = Vec::new();
+
+        for element in self.structural_elements() {
+            match other_elements.iter().position(|other_element| *other_element == element) {
+                Some(index) => {
+                    other_elements.remove(index);
+                }
+                None => only_in_self.push(element),
+            }
+        }
+
+        return WorkflowStructuralDiff { only_in_self, only_in_other: other_elements };
+    }
+
+    /// Returns all `StructuralElement`s of the Workflow in ascending order, duplicates are kept.
+    fn structural_elements(&self) -> Vec<StructuralElement> {
+        let mut elements: Vec<StructuralElement> = self.nodes.keys().map(|node_id| StructuralElement::Node(node_id.clone())).collect();
+
+        elements.extend(self.data_dependencies.values().map(|dep| StructuralElement::DataDependency {
+            source: dep.source_node.clone(),
+            target: dep.target_node.clone(),
+            port: dep.port_name.clone(),
+        }));
+        elements.extend(self.sync_dependencies.values().map(|dep| StructuralElement::SyncDependency {
+            source: dep.source_node.clone(),
+            target: dep.target_node.clone(),
+            port: dep.port_name.clone(),
+        }));
+        elements.extend(
+            self.soft_sync_dependencies
+                .values()
+                .map(|dep| StructuralElement::SoftSyncDependency { source: dep.source_node.clone(), target: dep.target_node.clone() }),
+        );
+        elements.extend(
+            self.co_allocations.values().map(|co_allocation| StructuralElement::CoAllocation(co_allocation.members.iter().cloned().collect())),
+        );
+
+        elements.sort();
+        return elements;
     }
 
     /// Computes the downward rank for all `CoAllocation`s in the Workflow.
@@ -796,10 +1472,38 @@ impl Workflow {
     /// This function also computes the number of nodes in the critical downward path
     /// (`number_of_nodes_critical_path_downwards`) for all nodes.
     ///
-    /// A `Vec<Option<WorkflowNode>>` containing the `representative` node for
+    /// A `Vec<WorkflowNode>` containing the `representative` node for
     /// every `CoAllocation` in the workflow, ordered by `rank_downward` in descending
     /// order (largest ranks are first).
-    fn calculate_downward_rank(mut self, avg_net_speed: i64, reservation_store: ReservationStore) -> Vec<Option<WorkflowNode>> {
+    ///
+    /// Like in `calculate_upward_rank`, the durations of the `CoAllocation`s are read once at the beginning of the pass
+    /// and `Error::NoNetworkCapacity` is returned for an `avg_net_speed <= 0` with data to transfer.
+    pub fn calculate_downward_rank(&mut self, avg_net_speed: i64, reservation_store: &ReservationStore) -> Result<Vec<WorkflowNode>, Error> {
+        self.validate_avg_net_speed(avg_net_speed)?;
+
+        return self.calculate_downward_rank_by(|data_dependency| data_dependency.get_communication_time(avg_net_speed), reservation_store);
+    }
+
+    /// Like `calculate_downward_rank`, but with the zone-aware communication time (see `calculate_upward_rank_with_zones`).
+    pub fn calculate_downward_rank_with_zones(
+        &mut self,
+        zone_communication_cost: &ZoneCommunicationCost,
+        reservation_store: &ReservationStore,
+    ) -> Result<Vec<WorkflowNode>, Error> {
+        self.validate_avg_net_speed(zone_communication_cost.average_link_speed)?;
+
+        return self.calculate_downward_rank_by(
+            |data_dependency| zone_communication_cost.get_average_communication_time(data_dependency),
+            reservation_store,
+        );
+    }
+
+    /// Computes the downward ranks, the `communication_time` of each data dependency is provided by the caller.
+    fn calculate_downward_rank_by<F>(&mut self, communication_time: F, reservation_store: &ReservationStore) -> Result<Vec<WorkflowNode>, Error>
+    where
+        F: Fn(&DataDependency) -> i64,
+    {
+        let co_allocation_durations = self.get_co_allocation_durations(reservation_store);
         let mut finished_node_keys: Vec<CoAllocationId> = Vec::with_capacity(self.co_allocations.len());
         let mut queue: Vec<CoAllocationId> = Vec::new();
 
@@ -825,7 +1529,7 @@ impl Workflow {
                 continue;
             }
 
-            let node_duration = node.get_co_allocation_duration(&self.nodes, &reservation_store);
+            let node_duration = co_allocation_durations[&next_key];
             let incoming_deps = node.incoming_co_allocation_dependencies.clone();
 
             let mut rank = node_duration;
@@ -841,14 +1545,8 @@ impl Workflow {
 
                         queue.push(source_key.clone());
                     } else {
-                        let size = self.data_dependencies.get(&incoming_dep.data_dependency).expect("Data dependency not found").size;
-
-                        let communication_time = if avg_net_speed > 0 {
-                            size / avg_net_speed
-                        } else {
-                            log::warn!("avg_net_speed is 0, setting communication_time to 0");
-                            0
-                        };
+                        let communication_time =
+                            communication_time(self.data_dependencies.get(&incoming_dep.data_dependency).expect("Data dependency not found"));
 
                         let predecessor_rank = source_group.rank_downward;
                         let new_possible_rank = node_duration + communication_time + predecessor_rank;
@@ -878,10 +1576,10 @@ impl Workflow {
         finished_node_keys.sort_by(|a_key, b_key| {
             let a_rank = self.co_allocations.get(a_key).unwrap().rank_downward;
             let b_rank = self.co_allocations.get(b_key).unwrap().rank_downward;
-            b_rank.cmp(&a_rank)
+            b_rank.cmp(&a_rank).then_with(|| a_key.id.cmp(&b_key.id))
         });
 
-        return finished_node_keys.into_iter().map(|key| self.co_allocations.get(&key).unwrap().representative.clone()).collect();
+        return Ok(finished_node_keys.into_iter().map(|key| self.co_allocations.get(&key).unwrap().representative.clone().unwrap()).collect());
     }
 }
 
@@ -932,15 +1630,20 @@ impl Workflow {
      * @param res Reservation represented by a Request(Reservation) in the Workflow
      */
     fn update_workflow_assigned_start_and_end(&mut self, reservation_store: ReservationStore, reservation_id: ReservationId) {
-        if self.base.assigned_start == i64::MIN
-            || (reservation_store.get_assigned_start(reservation_id) < self.base.assigned_start
-                && reservation_store.get_assigned_start(reservation_id) != i64::MIN)
-        {
-            self.base.set_assigned_start(reservation_store.get_assigned_start(reservation_id));
+        let assigned_start = reservation_store.get_assigned_start(reservation_id);
+        let assigned_end = reservation_store.get_assigned_end(reservation_id);
+
+        // Unscheduled reservations must not affect the window of the workflow
+        if assigned_start == ASSIGNED_TIME_UNSET || assigned_end == ASSIGNED_TIME_UNSET {
+            return;
+        }
+
+        if self.base.assigned_start == ASSIGNED_TIME_UNSET || assigned_start < self.base.assigned_start {
+            self.base.set_assigned_start(assigned_start);
         }
 
-        if self.base.assigned_end == i64::MIN || reservation_store.get_assigned_end(reservation_id) > self.base.assigned_end {
-            self.base.set_assigned_end(reservation_store.get_assigned_end(reservation_id));
+        if self.base.assigned_end == ASSIGNED_TIME_UNSET || assigned_end > self.base.assigned_end {
+            self.base.set_assigned_end(assigned_end);
         }
     }
 
@@ -965,4 +1668,721 @@ impl Workflow {
 
         return workflow_res_ids;
     }
+
+    /// Returns the WorkflowNode, which is represented by the provided reservation, e.g. to name the owning node of a failed commit.
+    ///
+    /// The nodes are scanned linearly (O(n)), as the lookup is only utilized for error reporting.
+    pub fn node_for_reservation(&self, reservation_id: ReservationId) -> Option<&WorkflowNode> {
+        return self.nodes.values().find(|node| node.reservation_id == reservation_id);
+    }
+
+    /// Returns the id of the WorkflowNode, which is represented by the provided reservation.
+    ///
+    /// The nodes are scanned linearly (O(n)), as the lookup is only utilized for error reporting.
+    pub fn node_id_for_reservation(&self, reservation_id: ReservationId) -> Option<WorkflowNodeId> {
+        return self.nodes.iter().find(|(_, node)| node.reservation_id == reservation_id).map(|(node_id, _)| node_id.clone());
+    }
+
+    /// Returns the DataDependency or SyncDependency, which is represented by the provided reservation.
+    ///
+    /// The dependencies are scanned linearly (O(n)), as the lookup is only utilized for error reporting.
+    pub fn dependency_for_reservation(&self, reservation_id: ReservationId) -> Option<DependencyRef<'_>> {
+        if let Some(data_dependency) = self.data_dependencies.values().find(|data_dep| data_dep.reservation_id == reservation_id) {
+            return Some(DependencyRef::Data(data_dependency));
+        }
+
+        return self
+            .sync_dependencies
+            .values()
+            .find(|sync_dep| sync_dep.reservation_id == reservation_id)
+            .map(|sync_dependency| DependencyRef::Sync(sync_dependency));
+    }
+
+    /// Returns all nodes, which are soft synchronized with the given node, together with the separation penalty
+    /// of the corresponding SoftSyncDependency.
+    pub fn get_soft_sync_partners(&self, node_id: &WorkflowNodeId) -> Vec<(WorkflowNodeId, i64)> {
+        let mut partners = Vec::new();
+
+        for soft_sync_dep in self.soft_sync_dependencies.values() {
+            let partner = match (&soft_sync_dep.source_node, &soft_sync_dep.target_node) {
+                (Some(source), Some(target)) if source == node_id => target,
+                (Some(source), Some(target)) if target == node_id => source,
+                _ => continue,
+            };
+            partners.push((partner.clone(), soft_sync_dep.separation_penalty));
+        }
+        return partners;
+    }
+
+    /// Returns the ready frontier of the Workflow for a pull-based execution, i.e. all nodes, which are not executed yet
+    /// (not `Finished`, `Rejected` or `Deleted`) and whose CoAllocation is ready. The nodes of a CoAllocation must start
+    /// simultaneously, hence a CoAllocation is ready as a unit, as soon as the source nodes of all DataDependencies
+    /// entering it from outside are `Finished`. A data predecessor, which is only reserved (e.g. `ReserveAnswer` or
+    /// `Committed`), does not release its successors.
+    ///
+    /// The `incoming_sync` of a node is deliberately not awaited: a SyncDependency only connects nodes of the same
+    /// CoAllocation, which run concurrently, so a sync partner never has to be `Finished` before the node starts.
+    /// DataDependencies between co-allocated nodes are rejected as cyclic on construction.
+    /// Marking a returned node as `Finished` in the `reservation_store` releases its successors.
+    ///
+    /// # Arguments
+    /// * `reservation_store` - Store holding the node reservations, used to read their states.
+    ///
+    /// # Returns
+    /// The ids of the ready nodes in ascending order.
+    pub fn ready_nodes(&self, reservation_store: &ReservationStore) -> Vec<WorkflowNodeId> {
+        let is_finished = |node_id: &Option<WorkflowNodeId>| {
+            node_id
+                .as_ref()
+                .and_then(|node_id| self.nodes.get(node_id))
+                .is_some_and(|node| reservation_store.get_state(node.reservation_id) == ReservationState::Finished)
+        };
+
+        let is_co_allocation_ready = |node_id: &WorkflowNodeId, node: &WorkflowNode| {
+            let members: Vec<WorkflowNodeId> = match node.co_allocation_key.as_ref().and_then(|key| self.co_allocations.get(key)) {
+                Some(co_allocation) => co_allocation.members.clone(),
+                None => vec![node_id.clone()],
+            };
+
+            members
+                .iter()
+                .filter_map(|member_id| self.nodes.get(member_id))
+                .flat_map(|member| member.incoming_data.iter().filter_map(|dep_id| self.data_dependencies.get(dep_id)))
+                .filter(|data_dep| !data_dep.source_node.as_ref().is_some_and(|source_node| members.contains(source_node)))
+                .all(|data_dep| is_finished(&data_dep.source_node))
+        };
+
+        let mut ready: Vec<WorkflowNodeId> = self
+            .nodes
+            .iter()
+            .filter(|(_, node)| {
+                !matches!(
+                    reservation_store.get_state(node.reservation_id),
+                    ReservationState::Finished | ReservationState::Rejected | ReservationState::Deleted
+                )
+            })
+            .filter(|(node_id, node)| is_co_allocation_ready(node_id, node))
+            .map(|(node_id, _)| node_id.clone())
+            .collect();
+
+        ready.sort();
+        return ready;
+    }
+
+    /// Returns all nodes in topological order over the DataDependencies and SyncDependencies (Kahn's algorithm),
+    /// i.e. each node is placed after all its predecessors. Ready nodes are taken in ascending id order, so the order
+    /// is deterministic. Of a SyncDependency declared on both nodes, only the edge to the greater node id is followed,
+    /// as such a pair only demands a simultaneous start (see `validate_acyclic`).
+    ///
+    /// # Returns
+    /// `Err(Error::CyclicWorkflow)` if the dependencies contain a cycle.
+    pub fn topological_order(&self) -> Result<Vec<WorkflowNodeId>, Error> {
+        let sync_edges: HashSet<(WorkflowNodeId, WorkflowNodeId)> =
+            self.sync_dependencies.values().filter_map(|sync_dep| Some((sync_dep.source_node.clone()?, sync_dep.target_node.clone()?))).collect();
+
+        let mut edges: HashSet<(WorkflowNodeId, WorkflowNodeId)> =
+            self.data_dependencies.values().filter_map(|data_dep| Some((data_dep.source_node.clone()?, data_dep.target_node.clone()?))).collect();
+        for (source, target) in &sync_edges {
+            if source > target && sync_edges.contains(&(target.clone(), source.clone())) {
+                continue;
+            }
+            edges.insert((source.clone(), target.clone()));
+        }
+
+        let mut in_degree: HashMap<WorkflowNodeId, usize> = self.nodes.keys().map(|node_id| (node_id.clone(), 0)).collect();
+        let mut successors: HashMap<WorkflowNodeId, Vec<WorkflowNodeId>> = HashMap::new();
+        for (source, target) in edges {
+            if !self.nodes.contains_key(&source) {
+                continue;
+            }
+            let Some(target_in_degree) = in_degree.get_mut(&target) else {
+                continue;
+            };
+            *target_in_degree += 1;
+            successors.entry(source).or_default().push(target);
+        }
+
+        let mut ready: BTreeSet<WorkflowNodeId> =
+            in_degree.iter().filter(|(_, node_in_degree)| **node_in_degree == 0).map(|(node_id, _)| node_id.clone()).collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+
+        while let Some(node_id) = ready.pop_first() {
+            for successor_id in successors.get(&node_id).into_iter().flatten() {
+                let successor_in_degree = in_degree.get_mut(successor_id).unwrap();
+                *successor_in_degree -= 1;
+                if *successor_in_degree == 0 {
+                    ready.insert(successor_id.clone());
+                }
+            }
+            order.push(node_id);
+        }
+
+        if order.len() < self.nodes.len() {
+            // Prefer the exact cycle of the DFS, otherwise report all nodes, which could not be ordered
+            Self::validate_acyclic(&self.nodes, &self.data_dependencies, &self.sync_dependencies)?;
+
+            let mut cycle: Vec<WorkflowNodeId> =
+                in_degree.into_iter().filter(|(_, node_in_degree)| *node_in_degree > 0).map(|(node_id, _)| node_id).collect();
+            cycle.sort();
+            log::error!("ErrorCyclicWorkflow: The nodes {:?} of workflow {} can not be ordered topologically.", cycle, self.base.get_name());
+            return Err(Error::CyclicWorkflow { cycle });
+        }
+        return Ok(order);
+    }
+
+    /// Exports the workflow graph in the Graphviz DOT format, e.g. to render it with `dot -Tsvg`.
+    ///
+    /// Each WorkflowNode is labeled with its task duration and reserved capacity. DataDependencies are drawn as
+    /// solid edges labeled with their size, SyncDependencies as dashed edges labeled with their bandwidth.
+    /// The members of each CoAllocation are grouped in a cluster. Entry nodes are colored green, exit nodes red.
+    /// Dependencies without source or target node (e.g. external inputs) are omitted.
+    ///
+    /// # Arguments
+    /// * `reservation_store` - Store, which holds the node reservations of this workflow.
+    ///
+    /// # Returns
+    /// The DOT representation of the workflow. Nodes, edges and clusters are sorted by id, so the output is deterministic.
+    pub fn to_dot(&self, reservation_store: &ReservationStore) -> String {
+        let mut dot = format!("digraph \"{}\" {{\n", self.base.name);
+
+        let mut co_allocations: Vec<&CoAllocation> = self.co_allocations.values().collect();
+        co_allocations.sort_by_key(|co_allocation| co_allocation.id.clone());
+
+        for (index, co_allocation) in co_allocations.iter().enumerate() {
+            let mut members = co_allocation.members.clone();
+            members.sort();
+
+            dot.push_str(&format!("    subgraph cluster_{} {{\n", index));
+            dot.push_str(&format!("        label=\"{}\";\n", co_allocation.id));
+            for member in members {
+                dot.push_str(&format!("        \"{}\";\n", member));
+            }
+            dot.push_str("    }\n");
+        }
+
+        let mut node_ids: Vec<&WorkflowNodeId> = self.nodes.keys().collect();
+        node_ids.sort();
+
+        for node_id in node_ids {
+            let reservation_id = self.nodes[node_id].reservation_id;
+            let color = if self.entry_nodes.contains(node_id) {
+                ", style=filled, fillcolor=green"
+            } else if self.exit_nodes.contains(node_id) {
+                ", style=filled, fillcolor=red"
+            } else {
+                ""
+            };
+
+            dot.push_str(&format!(
+                "    \"{}\" [label=\"{}\\nduration: {}\\ncapacity: {}\"{}];\n",
+                node_id,
+                node_id,
+                reservation_store.get_task_duration(reservation_id),
+                reservation_store.get_reserved_capacity(reservation_id),
+                color
+            ));
+        }
+
+        let mut data_dependencies: Vec<(&DataDependencyId, &DataDependency)> = self.data_dependencies.iter().collect();
+        data_dependencies.sort_by_key(|(dep_id, _)| *dep_id);
+
+        for (_, data_dep) in data_dependencies {
+            if let (Some(source), Some(target)) = (&data_dep.source_node, &data_dep.target_node) {
+                dot.push_str(&format!("    \"{}\" -> \"{}\" [style=solid, label=\"size: {}\"];\n", source, target, data_dep.size));
+            }
+        }
+
+        let mut sync_dependencies: Vec<(&SyncDependencyId, &SyncDependency)> = self.sync_dependencies.iter().collect();
+        sync_dependencies.sort_by_key(|(dep_id, _)| *dep_id);
+
+        for (_, sync_dep) in sync_dependencies {
+            if let (Some(source), Some(target)) = (&sync_dep.source_node, &sync_dep.target_node) {
+                dot.push_str(&format!("    \"{}\" -> \"{}\" [style=dashed, label=\"bandwidth: {}\"];\n", source, target, sync_dep.bandwidth));
+            }
+        }
+
+        dot.push_str("}\n");
+        return dot;
+    }
+
+    /// Renders a human-readable tree of the workflow for the CLI inspection, which stays readable for big graphs
+    /// (unlike the `Debug` output).
+    ///
+    /// Each CoAllocation is listed with its ranks, followed by its indented members with their task duration and
+    /// reserved capacity. Below each member its outgoing DataDependencies (`->`, with size) and SyncDependencies
+    /// (`=>`, with bandwidth) are listed. Dependencies without target node (e.g. external outputs) are omitted.
+    ///
+    /// # Arguments
+    /// * `reservation_store` - Store, which holds the node reservations of this workflow.
+    ///
+    /// # Returns
+    /// The tree, in which the CoAllocations are sorted by `rank_upward` in descending order (equal ranks by id),
+    /// members and edges by id. The ranks are only meaningful, if they were computed beforehand (see `calculate_upward_rank`).
+    pub fn pretty_print(&self, reservation_store: &ReservationStore) -> String {
+        let mut tree = format!("Workflow {}\n", self.base.name);
+
+        let mut outgoing_edges: HashMap<&WorkflowNodeId, Vec<String>> = HashMap::new();
+        for data_dep in self.data_dependencies.values() {
+            if let (Some(source), Some(target)) = (&data_dep.source_node, &data_dep.target_node) {
+                outgoing_edges.entry(source).or_default().push(format!("-> {} (data, size: {})", target, data_dep.size));
+            }
+        }
+        for sync_dep in self.sync_dependencies.values() {
+            if let (Some(source), Some(target)) = (&sync_dep.source_node, &sync_dep.target_node) {
+                outgoing_edges.entry(source).or_default().push(format!("=> {} (sync, bandwidth: {})", target, sync_dep.bandwidth));
+            }
+        }
+
+        let mut co_allocations: Vec<&CoAllocation> = self.co_allocations.values().collect();
+        co_allocations.sort_by(|a, b| b.rank_upward.cmp(&a.rank_upward).then_with(|| a.id.cmp(&b.id)));
+
+        for co_allocation in co_allocations {
+            tree.push_str(&format!(
+                "  CoAllocation {} (rank_upward: {}, rank_downward: {})\n",
+                co_allocation.id, co_allocation.rank_upward, co_allocation.rank_downward
+            ));
+
+            let mut members = co_allocation.members.clone();
+            members.sort();
+
+            for member in &members {
+                let Some(node) = self.nodes.get(member) else {
+                    log::warn!("WorkflowPrettyPrintMissingNode: Member {} of CoAllocation {} not found.", member, co_allocation.id);
+                    continue;
+                };
+
+                tree.push_str(&format!(
+                    "    - {} (duration: {}, capacity: {})\n",
+                    member,
+                    reservation_store.get_task_duration(node.reservation_id),
+                    reservation_store.get_reserved_capacity(node.reservation_id)
+                ));
+
+                if let Some(edges) = outgoing_edges.get_mut(member) {
+                    edges.sort();
+                    for edge in edges.iter() {
+                        tree.push_str(&format!("        {}\n", edge));
+                    }
+                }
+            }
+        }
+        return tree;
+    }
+
+    /// Reconstructs the WorkflowDto of this workflow, e.g. to write a modified workflow back into a file.
+    ///
+    /// The tasks are reconstructed from the node reservations in the `reservation_store`. Dependencies, which were
+    /// created by a `data_out` port, are emitted as `data_out` of the source task and as `data_in` of the target task.
+    /// Implicit dependencies (see `create_implicit_dependencies`) are emitted into `dependencies.data` and
+    /// `dependencies.sync` of the target task. Information, which is not part of the internal model (file names,
+    /// link reservations, ports without a target task in this workflow), is not restored.
+    ///
+    /// # Arguments
+    /// * `reservation_store` - Store, which holds the node reservations of this workflow.
+    ///
+    /// # Returns
+    /// The WorkflowDto, whose tasks and dependencies are sorted by id.
+    pub fn to_dto(&self, reservation_store: &ReservationStore) -> WorkflowDto {
+        let workflow_id = self.base.name.id.clone();
+        let mut tasks: BTreeMap<WorkflowNodeId, TaskDto> = BTreeMap::new();
+
+        for (node_id, node) in &self.nodes {
+            let Some(handle) = reservation_store.get(node.reservation_id) else {
+                log::warn!("WorkflowToDtoMissingNodeReservation: Node {} of workflow {} is not part of the ReservationStore.", node_id, workflow_id);
+                continue;
+            };
+            let reservation = handle.read().unwrap();
+            let Reservation::Node(ref node_reservation) = *reservation else {
+                log::warn!("WorkflowToDtoInvalidNodeReservation: Node {} of workflow {} is not a NodeReservation.", node_id, workflow_id);
+                continue;
+            };
+
+            let task_dto = TaskDto {
+                id: node_id.id.clone(),
+                reservation_state: ReservationStateDto::from_reservation_state(node_reservation.base.state),
+                request_proceeding: ReservationProceedingDto::from_reservation_proceeding(node_reservation.base.request_proceeding),
+                priority: (node_reservation.base.priority != self.base.priority).then_some(node_reservation.base.priority),
+                link_reservation: Vec::new(),
+                node_reservation: NodeReservationDto {
+                    current_working_directory: node_reservation.current_working_directory.clone(),
+                    environment: node_reservation.environment.clone(),
+                    task_path: node_reservation.task_path.clone(),
+                    output_path: node_reservation.output_path.clone(),
+                    error_path: node_reservation.error_path.clone(),
+                    duration: node_reservation.base.task_duration,
+                    cpus: node_reservation.base.reserved_capacity,
+                    is_moldable: node_reservation.base.is_moldable,
+                    earliest_start: node.earliest_start,
+                    dependencies: DependencyDto { data: Vec::new(), sync: Vec::new(), soft_sync: Vec::new() },
+                    data_out: Vec::new(),
+                    data_in: Vec::new(),
+                },
+            };
+            tasks.insert(node_id.clone(), task_dto);
+        }
+
+        let mut data_dependencies: Vec<(&DataDependencyId, &DataDependency)> = self.data_dependencies.iter().collect();
+        data_dependencies.sort_by_key(|(dep_id, _)| *dep_id);
+
+        for (dep_id, data_dep) in data_dependencies {
+            let (Some(source), Some(target)) = (&data_dep.source_node, &data_dep.target_node) else {
+                continue;
+            };
+
+            if Self::is_implicit_dependency(&workflow_id, &dep_id.id, "data", source, target) {
+                if let Some(target_task) = tasks.get_mut(target) {
+                    target_task.node_reservation.dependencies.data.push(source.id.clone());
+                }
+                continue;
+            }
+
+            let data_out = DataOutDto {
+                name: data_dep.port_name.clone(),
+                file: None,
+                size: Some(data_dep.size),
+                bandwidth: None,
+                latency: if data_dep.latency != 0 { Some(data_dep.latency) } else { None },
+            };
+            Self::add_port_dependency(&mut tasks, source, target, data_out);
+        }
+
+        let mut sync_dependencies: Vec<(&SyncDependencyId, &SyncDependency)> = self.sync_dependencies.iter().collect();
+        sync_dependencies.sort_by_key(|(dep_id, _)| *dep_id);
+
+        for (dep_id, sync_dep) in sync_dependencies {
+            let (Some(source), Some(target)) = (&sync_dep.source_node, &sync_dep.target_node) else {
+                continue;
+            };
+
+            if Self::is_implicit_dependency(&workflow_id, &dep_id.id, "sync", source, target) {
+                if let Some(target_task) = tasks.get_mut(target) {
+                    target_task.node_reservation.dependencies.sync.push(source.id.clone());
+                }
+                continue;
+            }
+
+            let data_out =
+                DataOutDto { name: sync_dep.port_name.clone(), file: None, size: None, bandwidth: Some(sync_dep.bandwidth), latency: None };
+            Self::add_port_dependency(&mut tasks, source, target, data_out);
+        }
+
+        let mut soft_sync_dependencies: Vec<(&SoftSyncDependencyId, &SoftSyncDependency)> = self.soft_sync_dependencies.iter().collect();
+        soft_sync_dependencies.sort_by_key(|(dep_id, _)| *dep_id);
+
+        for (_, soft_sync_dep) in soft_sync_dependencies {
+            let (Some(source), Some(target)) = (&soft_sync_dep.source_node, &soft_sync_dep.target_node) else {
+                continue;
+            };
+
+            if let Some(target_task) = tasks.get_mut(target) {
+                target_task.node_reservation.dependencies.soft_sync.push(source.id.clone());
+            }
+        }
+
+        return WorkflowDto {
+            id: workflow_id,
+            arrival_time: self.base.arrival_time,
+            booking_interval_start: self.base.booking_interval_start,
+            booking_interval_end: self.base.booking_interval_end,
+            state: ReservationStateDto::from_reservation_state(self.base.state),
+            request_proceeding: ReservationProceedingDto::from_reservation_proceeding(self.base.request_proceeding),
+            priority: (self.base.priority != 0).then_some(self.base.priority),
+            tasks: tasks.into_values().collect(),
+        };
+    }
+
+    /// Returns true, if the dependency id was generated by `create_implicit_dependencies` (see `get_implicit_dependency_id`).
+    fn is_implicit_dependency(workflow_id: &str, dep_id: &str, dep_type: &str, source: &WorkflowNodeId, target: &WorkflowNodeId) -> bool {
+        let base_id = format!("{}.{}.{}.{}", workflow_id, dep_type, source, target);
+
+        let Some(suffix) = dep_id.strip_prefix(&base_id) else {
+            return false;
+        };
+        return suffix.is_empty() || suffix.strip_prefix('.').is_some_and(|index| !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()));
+    }
+
+    /// Adds the `data_out` port to the source task and the matching `data_in` to the target task.
+    fn add_port_dependency(tasks: &mut BTreeMap<WorkflowNodeId, TaskDto>, source: &WorkflowNodeId, target: &WorkflowNodeId, data_out: DataOutDto) {
+        let data_in = DataInDto { source_reservation: source.id.clone(), source_port: data_out.name.clone(), file: None };
+
+        if let Some(source_task) = tasks.get_mut(source) {
+            source_task.node_reservation.data_out.push(data_out);
+        }
+        if let Some(target_task) = tasks.get_mut(target) {
+            target_task.node_reservation.data_in.push(data_in);
+        }
+    }
+
+    /// Incrementally reschedules this already placed workflow on the master schedule of the `adc`.
+    ///
+    /// CoAllocations contained in `fixed_windows` are not moveable and keep their window, all other
+    /// CoAllocations are placed again around them (see `HEFTSyncWorkflowScheduler::reschedule_workflow`).
+    ///
+    /// # Returns
+    /// `true` if all moveable CoAllocations were placed again, otherwise `false`.
+    pub fn reschedule(&mut self, fixed_windows: &HashMap<CoAllocationId, (i64, i64)>, adc: &mut ADC) -> bool {
+        let mut scheduler = HEFTSyncWorkflowScheduler { base: WorkflowSchedulerBase::new(adc.reservation_store.clone()) };
+        return scheduler.reschedule_workflow(self, fixed_windows, adc);
+    }
+
+    /// Splits all moldable nodes, whose reserved capacity exceeds `max_capacity`, into co-allocated sub-nodes.
+    ///
+    /// The original node keeps its id and its data dependencies, but is reduced to the capacity of the first part.
+    /// For each further part a new node `<node_id>.split.<i>` is created, which is linked to the original node by a
+    /// synthetic `SyncDependency`. Hence, all parts are placed as one CoAllocation, i.e. they start and end together.
+    /// The capacities of the parts differ at most by one and their moldable work sums up to the moldable work of the
+    /// original node. Non-moldable oversized nodes are left untouched.
+    ///
+    /// # Arguments
+    /// * `max_capacity` - Largest capacity a single node is allowed to request, e.g. the capacity of the largest VrmComponent.
+    /// * `reservation_store` - Store, which holds the node reservations of this workflow.
+    ///
+    /// # Returns
+    /// The ids of all newly created sub-nodes, or an error if `max_capacity` is not positive.
+    pub fn split_oversized_nodes(&mut self, max_capacity: i64, reservation_store: ReservationStore) -> Result<Vec<WorkflowNodeId>, Error> {
+        if max_capacity <= 0 {
+            return Err(Error::ModelConstructionError(format!(
+                "Splitting of workflow nodes requires a positive max capacity, got {}.",
+                max_capacity
+            )));
+        }
+
+        let mut oversized_node_ids: Vec<WorkflowNodeId> = self
+            .nodes
+            .iter()
+            .filter(|(_, node)| reservation_store.get_reserved_capacity(node.reservation_id) > max_capacity)
+            .map(|(node_id, _)| node_id.clone())
+            .collect();
+        oversized_node_ids.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut split_node_ids = Vec::new();
+
+        for node_id in oversized_node_ids {
+            let node_res_id = self.nodes.get(&node_id).unwrap().reservation_id;
+            let Some(node_reservation) = reservation_store.get_node_reservation_snapshot(node_res_id) else {
+                log::error!("ErrorWorkflowSplitNode: Node reservation of {} was not found in the store.", node_id);
+                continue;
+            };
+
+            if !node_reservation.base.is_moldable {
+                log::warn!("WorkflowSplitNode: Node {} exceeds the max capacity {}, but is not moldable.", node_id, max_capacity);
+                continue;
+            }
+
+            let capacity = node_reservation.base.reserved_capacity;
+            let moldable_work = node_reservation.base.moldable_work;
+            let number_of_parts = (capacity + max_capacity - 1) / max_capacity;
+
+            // Distribute capacity and moldable work, the remainder goes to the first parts
+            let part_capacities: Vec<i64> =
+                (0..number_of_parts).map(|i| capacity / number_of_parts + if i < capacity % number_of_parts { 1 } else { 0 }).collect();
+            let mut part_works: Vec<i64> = part_capacities.iter().map(|part_capacity| moldable_work * part_capacity / capacity).collect();
+            part_works[0] += moldable_work - part_works.iter().sum::<i64>();
+
+            // The original node becomes the first part
+            if let Some(handle) = reservation_store.get(node_res_id) {
+                let mut res = handle.write().unwrap();
+                if let Reservation::Node(node) = &mut *res {
+                    node.base.reserved_capacity = part_capacities[0];
+                    node.base.moldable_work = part_works[0];
+                }
+            }
+
+            for part in 1..number_of_parts as usize {
+                let sub_node_id = WorkflowNodeId::new(format!("{}.split.{}", node_id, part));
+
+                let mut sub_node_reservation = node_reservation.clone();
+                sub_node_reservation.base.name = ReservationName::new(sub_node_id.id.clone());
+                sub_node_reservation.base.reserved_capacity = part_capacities[part];
+                sub_node_reservation.base.moldable_work = part_works[part];
+                let sub_node_res_id = reservation_store.add(Reservation::Node(sub_node_reservation));
+
+                // Synthetic SyncDependency, which co-allocates the sub-node with the original node
+                let dep_id_str = format!("{}.sync.{}.{}", self.base.name, node_id, sub_node_id);
+                let dep_base = ReservationBase {
+                    name: ReservationName::new(dep_id_str.clone()),
+                    client_id: node_reservation.base.client_id.clone(),
+                    handler_id: None,
+                    state: ReservationState::Open,
+                    request_proceeding: node_reservation.base.request_proceeding,
+                    arrival_time: node_reservation.base.arrival_time,
+                    booking_interval_start: node_reservation.base.booking_interval_start,
+                    booking_interval_end: node_reservation.base.booking_interval_end,
+                    assigned_start: ASSIGNED_TIME_UNSET,
+                    assigned_end: ASSIGNED_TIME_UNSET,
+                    task_duration: 0,
+                    reserved_capacity: 0,
+                    is_moldable: false,
+                    moldable_work: 0,
+                    frag_delta: f64::MAX,
+                    is_moveable: false,
+                    priority: 0,
+                };
+                let link_res =
+                    LinkReservation { base: dep_base, start_point: Some(node_id.clone().cast()), end_point: Some(sub_node_id.clone().cast()) };
+                let link_res_id = reservation_store.add(Reservation::Link(link_res));
+
+                let sync_dep_id = SyncDependencyId::new(dep_id_str);
+                let sync_dep = SyncDependency {
+                    reservation_id: link_res_id,
+                    source_node: Some(node_id.clone()),
+                    target_node: Some(sub_node_id.clone()),
+                    port_name: "sync".to_string(),
+                    bandwidth: 0,
+                };
+                self.sync_dependencies.insert(sync_dep_id.clone(), sync_dep);
+
+                self.nodes.get_mut(&node_id).unwrap().outgoing_sync.push(sync_dep_id.clone());
+                let sub_node = WorkflowNode {
+                    reservation_id: sub_node_res_id,
+                    incoming_data: Vec::new(),
+                    outgoing_data: Vec::new(),
+                    incoming_sync: vec![sync_dep_id],
+                    outgoing_sync: Vec::new(),
+                    co_allocation_key: None,
+                    earliest_start: self.nodes.get(&node_id).unwrap().earliest_start,
+                };
+                self.nodes.insert(sub_node_id.clone(), sub_node);
+                split_node_ids.push(sub_node_id);
+            }
+
+            log::debug!("WorkflowSplitNode: Split node {} with capacity {} into {} parts {:?}.", node_id, capacity, number_of_parts, part_capacities);
+        }
+
+        if !split_node_ids.is_empty() {
+            self.rebuild_co_allocation_graph()?;
+        }
+
+        return Ok(split_node_ids);
+    }
+
+    /// Merges two independent workflows into one workflow, which is submitted and scheduled as one unit.
+    ///
+    /// To avoid collisions, all node and dependency ids are prefixed with `<workflow_name>.` of their original workflow.
+    /// As both graphs are disjoint, no dependency crosses them and the CoAllocations, the CoAllocation graph and the
+    /// entry/exit points are rebuilt from the merged nodes. The booking interval of the merged workflow covers the
+    /// booking intervals of both workflows.
+    ///
+    /// # Arguments
+    /// * `other` - The workflow, which is merged into this workflow.
+    /// * `reservation_store` - Store, which holds the link reservations of both workflows.
+    ///
+    /// # Returns
+    /// The merged workflow, or `Error::IdCollision` if an id is still not unique after prefixing.
+    pub fn merge(self, other: Workflow, reservation_store: &ReservationStore) -> Result<Workflow, Error> {
+        let other_name = other.base.name.clone();
+        let self_prefix = format!("{}.", self.base.name);
+        let other_prefix = format!("{}.", other_name);
+
+        let mut merged = self.with_id_prefix(&self_prefix);
+        let other = other.with_id_prefix(&other_prefix);
+
+        Self::merge_map(&mut merged.nodes, other.nodes)?;
+        Self::merge_map(&mut merged.data_dependencies, other.data_dependencies)?;
+        Self::merge_map(&mut merged.sync_dependencies, other.sync_dependencies)?;
+        Self::merge_map(&mut merged.soft_sync_dependencies, other.soft_sync_dependencies)?;
+
+        merged.base.name = ReservationName::new(format!("{}+{}", merged.base.name, other_name));
+        merged.base.arrival_time = merged.base.arrival_time.min(other.base.arrival_time);
+        merged.base.booking_interval_start = merged.base.booking_interval_start.min(other.base.booking_interval_start);
+        merged.base.booking_interval_end = merged.base.booking_interval_end.max(other.base.booking_interval_end);
+
+        merged.rebuild_co_allocation_graph()?;
+
+        // Update ReservationStore information
+        for (_, link) in &merged.data_dependencies {
+            if let Some(res_handle) = reservation_store.get(link.reservation_id) {
+                let mut res = res_handle.write().unwrap();
+                res.as_link_mut().unwrap().set_start_point(Some(link.source_node.clone().unwrap().cast()));
+                res.as_link_mut().unwrap().set_end_point(Some(link.target_node.clone().unwrap().cast()));
+            }
+        }
+
+        return Ok(merged);
+    }
+
+    /// Prefixes the ids of all nodes and dependencies (including all references to them) with `prefix`.
+    /// The CoAllocations are dropped and must be rebuilt afterwards.
+    fn with_id_prefix(mut self, prefix: &str) -> Self {
+        let prefixed = |id: &WorkflowNodeId| WorkflowNodeId::new(format!("{}{}", prefix, id));
+
+        self.nodes = self
+            .nodes
+            .into_iter()
+            .map(|(node_id, mut node)| {
+                node.incoming_data = node.incoming_data.iter().map(|dep_id| DataDependencyId::new(format!("{}{}", prefix, dep_id))).collect();
+                node.outgoing_data = node.outgoing_data.iter().map(|dep_id| DataDependencyId::new(format!("{}{}", prefix, dep_id))).collect();
+                node.incoming_sync = node.incoming_sync.iter().map(|dep_id| SyncDependencyId::new(format!("{}{}", prefix, dep_id))).collect();
+                node.outgoing_sync = node.outgoing_sync.iter().map(|dep_id| SyncDependencyId::new(format!("{}{}", prefix, dep_id))).collect();
+                node.co_allocation_key = None;
+                (prefixed(&node_id), node)
+            })
+            .collect();
+
+        self.data_dependencies = self
+            .data_dependencies
+            .into_iter()
+            .map(|(dep_id, mut data_dep)| {
+                data_dep.source_node = data_dep.source_node.as_ref().map(prefixed);
+                data_dep.target_node = data_dep.target_node.as_ref().map(prefixed);
+                (DataDependencyId::new(format!("{}{}", prefix, dep_id)), data_dep)
+            })
+            .collect();
+
+        self.sync_dependencies = self
+            .sync_dependencies
+            .into_iter()
+            .map(|(dep_id, mut sync_dep)| {
+                sync_dep.source_node = sync_dep.source_node.as_ref().map(prefixed);
+                sync_dep.target_node = sync_dep.target_node.as_ref().map(prefixed);
+                (SyncDependencyId::new(format!("{}{}", prefix, dep_id)), sync_dep)
+            })
+            .collect();
+
+        self.soft_sync_dependencies = self
+            .soft_sync_dependencies
+            .into_iter()
+            .map(|(dep_id, mut soft_sync_dep)| {
+                soft_sync_dep.source_node = soft_sync_dep.source_node.as_ref().map(prefixed);
+                soft_sync_dep.target_node = soft_sync_dep.target_node.as_ref().map(prefixed);
+                (SoftSyncDependencyId::new(format!("{}{}", prefix, dep_id)), soft_sync_dep)
+            })
+            .collect();
+
+        self.co_allocations.clear();
+        self.co_allocation_dependencies.clear();
+        return self;
+    }
+
+    /// Moves all entries of `source` into `target`, rejecting ids, which are already contained in `target`.
+    fn merge_map<T, V>(target: &mut HashMap<Id<T>, V>, source: HashMap<Id<T>, V>) -> Result<(), Error>
+    where
+        Id<T>: Hash + Eq,
+    {
+        for (id, value) in source {
+            if target.contains_key(&id) {
+                return Err(Error::IdCollision { id: id.id });
+            }
+            target.insert(id, value);
+        }
+        return Ok(());
+    }
+
+    /// Rebuilds the CoAllocations, the CoAllocation graph and the entry/exit points after the nodes or
+    /// sync dependencies of the workflow have been changed (see Phase 4 - 6 of `create_form_dto`).
+    fn rebuild_co_allocation_graph(&mut self) -> Result<(), Error> {
+        let (mut co_allocations, node_to_co_allocation) = Self::build_co_allocations(&self.nodes, &self.sync_dependencies)?;
+        let co_allocation_dependencies =
+            Self::build_co_allocation_dependencies(&self.data_dependencies, &node_to_co_allocation, &mut co_allocations, &mut Vec::new())?;
+        let (entry_nodes, exit_nodes, entry_co_allocation, exit_co_allocation) = Self::find_entry_exit_points(&self.nodes, &co_allocations);
+        Self::assign_co_allocation_keys(&mut self.nodes, &mut co_allocations, node_to_co_allocation);
+
+        self.co_allocations = co_allocations;
+        self.co_allocation_dependencies = co_allocation_dependencies;
+        self.entry_nodes = entry_nodes;
+        self.exit_nodes = exit_nodes;
+        self.entry_co_allocation = entry_co_allocation;
+        self.exit_co_allocation = exit_co_allocation;
+        return Ok(());
+    }
 }
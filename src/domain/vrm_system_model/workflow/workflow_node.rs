@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 
 use crate::domain::vrm_system_model::{
-    reservation::reservation_store::{ReservationId, ReservationStore},
+    reservation::{
+        reservation::ASSIGNED_TIME_UNSET,
+        reservation_store::{ReservationId, ReservationStore},
+    },
     utils::id::{CoAllocationId, DataDependencyId, SyncDependencyId},
     workflow::workflow::Workflow,
 };
@@ -20,6 +23,9 @@ pub struct WorkflowNode {
     /// Key of the Workflow.co_allocations HashMap.
     /// HashMap contains all other nodes in the same sync group, including this node.
     pub co_allocation_key: Option<CoAllocationId>,
+
+    /// Explicit lower bound on the start of this node in s (VRM time), in addition to the starts derived from its dependencies.
+    pub earliest_start: Option<i64>,
 }
 
 impl WorkflowNode {
@@ -28,11 +34,16 @@ impl WorkflowNode {
         let assigned_start = reservation_store.get_assigned_start(self.reservation_id);
         let assigned_end = reservation_store.get_assigned_end(self.reservation_id);
 
-        if workflow.base.assigned_start == i64::MIN || (assigned_start < workflow.base.assigned_start && assigned_start != i64::MIN) {
+        // Unscheduled reservations must not affect the window of the workflow
+        if assigned_start == ASSIGNED_TIME_UNSET || assigned_end == ASSIGNED_TIME_UNSET {
+            return;
+        }
+
+        if workflow.base.assigned_start == ASSIGNED_TIME_UNSET || assigned_start < workflow.base.assigned_start {
             workflow.base.set_assigned_start(assigned_start);
         }
 
-        if workflow.base.assigned_end == i64::MIN || assigned_end > workflow.base.assigned_end {
+        if workflow.base.assigned_end == ASSIGNED_TIME_UNSET || assigned_end > workflow.base.assigned_end {
             workflow.base.set_assigned_end(assigned_end);
         }
     }
@@ -0,0 +1,45 @@
+use std::collections::BTreeSet;
+
+use crate::domain::vrm_system_model::utils::id::WorkflowNodeId;
+
+/// An element of the graph structure of a Workflow, independent of generated ids (dependency ids, CoAllocation ids,
+/// reservation ids) and of the iteration order of the graph maps (see `Workflow::structural_diff`).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StructuralElement {
+    Node(WorkflowNodeId),
+    DataDependency {
+        source: Option<WorkflowNodeId>,
+        target: Option<WorkflowNodeId>,
+        port: String,
+    },
+    SyncDependency {
+        source: Option<WorkflowNodeId>,
+        target: Option<WorkflowNodeId>,
+        port: String,
+    },
+    SoftSyncDependency {
+        source: Option<WorkflowNodeId>,
+        target: Option<WorkflowNodeId>,
+    },
+
+    /// The members of a CoAllocation.
+    CoAllocation(BTreeSet<WorkflowNodeId>),
+}
+
+/// Structural difference of two Workflows, computed by `Workflow::structural_diff`.
+/// Elements contained multiple times (e.g. parallel dependencies with the same port) are listed once per surplus occurrence.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkflowStructuralDiff {
+    /// Elements of the first Workflow, which are missing in the second one (sorted).
+    pub only_in_self: Vec<StructuralElement>,
+
+    /// Elements of the second Workflow, which are missing in the first one (sorted).
+    pub only_in_other: Vec<StructuralElement>,
+}
+
+impl WorkflowStructuralDiff {
+    /// Returns `true`, if both Workflows are structurally equal.
+    pub fn is_empty(&self) -> bool {
+        return self.only_in_self.is_empty() && self.only_in_other.is_empty();
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Statistics of a Workflow for reporting, computed by `Workflow::summary`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowSummary {
+    /// Number of WorkflowNodes (compute tasks).
+    pub node_count: usize,
+
+    /// Number of CoAllocations, i.e. the number of units scheduled by a WorkflowScheduler.
+    pub co_allocation_count: usize,
+
+    /// Sum of `task_duration * reserved_capacity` of all WorkflowNodes.
+    pub total_compute_work: i64,
+
+    /// Sum of the `size` of all DataDependencies.
+    pub total_data_volume: i64,
+
+    /// Number of CoAllocations on the longest path from an entry to an exit CoAllocation.
+    pub depth: i64,
+}
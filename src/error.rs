@@ -1,5 +1,10 @@
 use thiserror::Error;
 
+use crate::api::workflow_dto::validation::ValidationError;
+use crate::domain::vrm_system_model::reservation::reservation::ReservationState;
+use crate::domain::vrm_system_model::reservation::reservation_store::ReservationId;
+use crate::domain::vrm_system_model::utils::id::{AdcId, ComponentId, WorkflowNodeId};
+
 #[derive(Debug, Error)]
 pub enum Error {
     #[error("File not found or could not be read: {0}")]
@@ -8,12 +13,51 @@ pub enum Error {
     #[error("Failed to parse system model JSON: {0}")]
     DeserializationError(#[from] serde_json::Error),
 
+    #[error("failed to parse {path} at line {line} col {column}: {message}")]
+    Parse { path: String, line: usize, column: usize, message: String },
+
+    #[error("Failed to parse system model YAML: {0}")]
+    YamlDeserializationError(#[from] serde_yaml::Error),
+
+    #[error("Unsupported file format: .{ext} (supported: .json, .yaml, .yml)")]
+    UnsupportedFormat { ext: String },
+
+    #[error("Failed to load {} workflow files: {failures:?}", failures.len())]
+    BatchLoad { failures: Vec<(String, String)> },
+
+    #[error("Workflow file is semantically invalid ({} problems): {errors:?}", errors.len())]
+    Validation { errors: Vec<ValidationError> },
+
     #[error("Failed to build internal domain model: {0}")]
     ModelConstructionError(String),
 
     #[error("Failed to build VRM system model:")]
     VrmSystemModelConstructionError,
 
+    #[error("Workflow dependencies contain a cycle: {cycle:?}")]
+    CyclicWorkflow { cycle: Vec<WorkflowNodeId> },
+
+    #[error("VrmComponent {component} is not registered at ADC {adc}")]
+    ComponentNotFound { adc: AdcId, component: ComponentId },
+
+    #[error("Id {id} is not unique after merging the workflows")]
+    IdCollision { id: String },
+
+    #[error("Average network speed is 0, but the workflow contains data dependencies to transfer")]
+    NoNetworkCapacity,
+
+    #[error("Illegal reservation state transition from {from:?} to {to:?}")]
+    IllegalStateTransition { from: ReservationState, to: ReservationState },
+
+    #[error("Workflow {workflow:?} has no tracked subtasks")]
+    WorkflowNotTracked { workflow: ReservationId },
+
+    #[error("Commit of subtask {subtask:?} failed, workflow {workflow:?} was rolled back")]
+    WorkflowCommitFailed { workflow: ReservationId, subtask: ReservationId },
+
+    #[error("Commit of subtask {subtask:?} failed and the rollback of workflow {workflow:?} failed for the subtasks {not_rolled_back:?}")]
+    WorkflowRollbackFailed { workflow: ReservationId, subtask: ReservationId, not_rolled_back: Vec<ReservationId> },
+
     #[error("Conversion error: {0}")]
     Conversion(#[from] ConversionError),
 }
@@ -1,24 +1,61 @@
 use crate::api::workflow_dto::client_dto::ClientsDto;
+use crate::api::workflow_dto::validation::ValidationError;
 use crate::domain::vrm_system_model::client::client::Clients;
 use crate::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
-use crate::error::Result;
-use crate::loader::parser::parse_json_file;
+use crate::error::{Error, Result};
+use crate::loader::diagnostic::Diagnostic;
+use crate::loader::parser::parse_workflow_file;
 
 pub mod api;
 pub mod domain;
 pub mod error;
 pub mod loader;
 pub mod logger;
+#[cfg(feature = "test-util")]
+pub mod test_support;
 
 pub fn generate_system_model(file_path: &str, reservation_store: ReservationStore) -> Result<Clients> {
+    return build_system_model(file_path, reservation_store, false).map(|(system_model, _)| system_model);
+}
+
+/// Dry-run variant of `generate_system_model`, which collects the problems found during the construction
+/// as structured `Diagnostic`s instead of only logging them.
+///
+/// Unlike `generate_system_model`, a `data_in` without a matching `data_out` does not fail the validation,
+/// but is reported as a `Diagnostic`. All other validation errors are still returned as `Error::Validation`.
+pub fn generate_system_model_explained(file_path: &str, reservation_store: ReservationStore) -> Result<(Clients, Vec<Diagnostic>)> {
+    return build_system_model(file_path, reservation_store, true);
+}
+
+fn build_system_model(file_path: &str, reservation_store: ReservationStore, allow_dangling_data_in: bool) -> Result<(Clients, Vec<Diagnostic>)> {
     logger::init();
     log::info!("Logger initialized. Starting SystemModel construction.");
 
-    let root_dto: ClientsDto = parse_json_file::<ClientsDto>(file_path)?;
-    log::info!("JSON file parsed successfully.");
+    let root_dto: ClientsDto = parse_workflow_file::<ClientsDto>(file_path)?;
+    log::info!("Workflow file parsed successfully.");
+
+    if let Err(errors) = root_dto.validate() {
+        let errors: Vec<ValidationError> =
+            errors.into_iter().filter(|error| !(allow_dangling_data_in && matches!(error, ValidationError::DanglingDataIn { .. }))).collect();
+        if !errors.is_empty() {
+            return Err(Error::Validation { errors });
+        }
+    }
+    log::info!("Workflow file validated successfully.");
 
-    let system_model = Clients::from_dto(root_dto, reservation_store)?;
+    let (system_model, diagnostics) = Clients::from_dto_explained(root_dto, reservation_store)?;
     log::info!("Internal SystemModel constructed successfully.");
 
+    Ok((system_model, diagnostics))
+}
+
+/// Builds the SystemModel like `generate_system_model` and writes its JSON snapshot (see `Clients::to_json_pretty`)
+/// to `out_path`, e.g. to diff the constructed model between runs.
+pub fn generate_and_dump(file_path: &str, out_path: &str, reservation_store: ReservationStore) -> Result<Clients> {
+    let system_model = generate_system_model(file_path, reservation_store.clone())?;
+
+    std::fs::write(out_path, system_model.to_json_pretty(&reservation_store)?)?;
+    log::info!("SystemModel was written to {}.", out_path);
+
     Ok(system_model)
 }
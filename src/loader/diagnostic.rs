@@ -0,0 +1,34 @@
+use std::fmt;
+
+/// Severity of a `Diagnostic`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSeverity {
+    Info,
+    Warning,
+}
+
+/// A problem, which was found during the construction of the SystemModel, but did not abort it
+/// (e.g. a `data_in` without a matching `data_out`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: DiagnosticSeverity,
+    pub message: String,
+
+    /// Identifies the affected element, e.g. `<workflow>/<task>` or the id of a dependency.
+    pub context: String,
+}
+
+impl Diagnostic {
+    /// Creates a `Diagnostic` with `DiagnosticSeverity::Warning` and logs it, so that the model construction
+    /// reports the problem even if the collected diagnostics are discarded.
+    pub fn warning(message: String, context: String) -> Self {
+        log::warn!("{} ({})", message, context);
+        return Diagnostic { severity: DiagnosticSeverity::Warning, message, context };
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:?}] {}: {}", self.severity, self.context, self.message)
+    }
+}
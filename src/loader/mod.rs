@@ -1 +1,2 @@
+pub mod diagnostic;
 pub mod parser;
\ No newline at end of file
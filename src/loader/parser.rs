@@ -1,6 +1,8 @@
 use serde::de::DeserializeOwned;
 use std::fs;
+use std::path::{Path, PathBuf};
 
+use crate::api::workflow_dto::workflow_dto::WorkflowDto;
 use crate::error::{Error, Result};
 
 /// Parses a JSON file into a given type `T`.
@@ -10,11 +12,90 @@ use crate::error::{Error, Result};
 ///
 /// Errors are automatically converted into `crate::error::Error` variants:
 /// - `Error::IoError` if the file cannot be read.
-/// - `Error::DeserializationError` if the JSON is malformed.
+/// - `Error::Parse` if the JSON is malformed, containing the file path and the position (line and column) of the error.
 pub fn parse_json_file<T: DeserializeOwned>(file_path: &str) -> Result<T> {
     let data = fs::read_to_string(file_path).map_err(|e| Error::IoError(e))?;
 
-    let parsed_data: T = serde_json::from_str(&data).map_err(|e| Error::DeserializationError(e))?;
+    let parsed_data: T = serde_json::from_str(&data).map_err(|e| {
+        // The Display of serde_json appends the position, which is already part of Error::Parse
+        let message = e.to_string();
+        let position_suffix = format!(" at line {} column {}", e.line(), e.column());
+        Error::Parse {
+            path: file_path.to_string(),
+            line: e.line(),
+            column: e.column(),
+            message: message.strip_suffix(&position_suffix).unwrap_or(&message).to_string(),
+        }
+    })?;
 
     Ok(parsed_data)
 }
+
+/// Parses a YAML file into a given type `T`.
+///
+/// Errors are automatically converted into `crate::error::Error` variants:
+/// - `Error::IoError` if the file cannot be read.
+/// - `Error::YamlDeserializationError` if the YAML is malformed.
+pub fn parse_yaml_file<T: DeserializeOwned>(file_path: &str) -> Result<T> {
+    let data = fs::read_to_string(file_path).map_err(|e| Error::IoError(e))?;
+
+    let parsed_data: T = serde_yaml::from_str(&data).map_err(|e| Error::YamlDeserializationError(e))?;
+
+    Ok(parsed_data)
+}
+
+/// Parses a workflow file into a given type `T`, the parser is selected by the file extension:
+/// - `.yaml` and `.yml` are parsed as YAML.
+/// - `.json`, files without extension and all other file extensions are parsed as JSON.
+pub fn parse_workflow_file<T: DeserializeOwned>(file_path: &str) -> Result<T> {
+    let ext = Path::new(file_path).extension().map(|ext| ext.to_string_lossy().to_lowercase()).unwrap_or_default();
+
+    match ext.as_str() {
+        "yaml" | "yml" => parse_yaml_file(file_path),
+        _ => parse_json_file(file_path),
+    }
+}
+
+/// Loads all workflow files (`.json`, `.yaml` and `.yml`) of the directory `dir` (not recursive), e.g. for batch experiments.
+/// All other files are ignored.
+///
+/// # Arguments
+/// * `dir` - Directory containing one `WorkflowDto` per file.
+/// * `collect_errors` - If true, all files are parsed and every failing file is reported, otherwise the loading
+///   stops at the first failing file.
+///
+/// # Returns
+/// The `WorkflowDto`s sorted by the file name, or `Error::BatchLoad` with the path and the error message of each
+/// failing file. `Error::IoError` is returned if the directory cannot be read.
+pub fn load_workflows_from_dir(dir: &str, collect_errors: bool) -> Result<Vec<WorkflowDto>> {
+    let mut file_paths: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file())
+        .filter(|path| path.extension().is_some_and(|ext| matches!(ext.to_string_lossy().to_lowercase().as_str(), "json" | "yaml" | "yml")))
+        .collect();
+    file_paths.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+
+    let mut workflows = Vec::with_capacity(file_paths.len());
+    let mut failures: Vec<(String, String)> = Vec::new();
+
+    for file_path in file_paths {
+        let file_path = file_path.to_string_lossy().to_string();
+
+        match parse_workflow_file::<WorkflowDto>(&file_path) {
+            Ok(workflow) => workflows.push(workflow),
+            Err(error) => {
+                log::warn!("LoadWorkflowsFromDirFailed: Workflow file {} could not be loaded: {}", file_path, error);
+                failures.push((file_path, error.to_string()));
+
+                if !collect_errors {
+                    break;
+                }
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        return Err(Error::BatchLoad { failures });
+    }
+    return Ok(workflows);
+}
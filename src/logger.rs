@@ -7,29 +7,127 @@ use std::fs;
 const LOG_DIR: &str = "logs";
 const LOG_FILE: &str = "system.log";
 
-/// Initializes the global logger.
+/// Environment variable with a filter directive string (see `init_with_filter`), takes precedence over `RUST_LOG`.
+pub const LOG_FILTER_ENV_VAR: &str = "VRM_LOG";
+
+/// Short log targets, which can be used in filter directives instead of the full module paths.
+///
+/// | Short target       | Module path                                                                              |
+/// |--------------------|------------------------------------------------------------------------------------------|
+/// | `vrm`              | `vrm_rust_workflow` (whole crate)                                                        |
+/// | `vrm::loader`      | `vrm_rust_workflow::loader` (JSON/YAML parser)                                           |
+/// | `vrm::api`         | `vrm_rust_workflow::api` (DTOs and their validation)                                     |
+/// | `vrm::simulator`   | `vrm_rust_workflow::domain::simulator`                                                   |
+/// | `vrm::workflow`    | `vrm_rust_workflow::domain::vrm_system_model::workflow`                                  |
+/// | `vrm::reservation` | `vrm_rust_workflow::domain::vrm_system_model::reservation` (ReservationStore, listeners) |
+/// | `vrm::schedule`    | `vrm_rust_workflow::domain::vrm_system_model::schedule` (slotted schedules)              |
+/// | `vrm::rms`         | `vrm_rust_workflow::domain::vrm_system_model::rms` (local RMS, e.g. Slurm)               |
+/// | `vrm::grid`        | `vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system`           |
+/// | `vrm::adc`         | `...::grid_resource_management_system::adc`                                              |
+/// | `vrm::manager`     | `...::grid_resource_management_system::vrm_component_manager`                            |
+/// | `vrm::scheduler`   | `...::grid_resource_management_system::scheduler` (workflow schedulers)                  |
+///
+/// All other targets (e.g. full module paths or dependencies like `actix`) are used as given.
+/// The analytics events are emitted via `tracing` with the target `analytics` and are not affected by the filter.
+pub const LOG_TARGET_ALIASES: &[(&str, &str)] = &[
+    ("vrm", "vrm_rust_workflow"),
+    ("vrm::loader", "vrm_rust_workflow::loader"),
+    ("vrm::api", "vrm_rust_workflow::api"),
+    ("vrm::simulator", "vrm_rust_workflow::domain::simulator"),
+    ("vrm::workflow", "vrm_rust_workflow::domain::vrm_system_model::workflow"),
+    ("vrm::reservation", "vrm_rust_workflow::domain::vrm_system_model::reservation"),
+    ("vrm::schedule", "vrm_rust_workflow::domain::vrm_system_model::schedule"),
+    ("vrm::rms", "vrm_rust_workflow::domain::vrm_system_model::rms"),
+    ("vrm::grid", "vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system"),
+    ("vrm::adc", "vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::adc"),
+    ("vrm::manager", "vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager"),
+    ("vrm::scheduler", "vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler"),
+];
+
+/// A parsed filter directive string, e.g. `"info,vrm::scheduler=debug,vrm::loader=warn"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilter {
+    /// Level of all targets without own directive.
+    pub default_level: LevelFilter,
+
+    /// Levels of specific targets (and their sub modules), short targets are already resolved to module paths.
+    pub directives: Vec<(String, LevelFilter)>,
+}
+
+impl LogFilter {
+    /// Parses a comma separated list of directives. Each directive is either a level (`debug`), which sets the
+    /// default level, or a `target=level` pair. Invalid directives are reported on stderr and skipped.
+    pub fn parse(filter: &str, default_level: LevelFilter) -> Self {
+        let mut log_filter = LogFilter { default_level, directives: Vec::new() };
+
+        for directive in filter.split(',').map(str::trim).filter(|directive| !directive.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level)) => match level.trim().parse::<LevelFilter>() {
+                    Ok(level) => log_filter.directives.push((Self::resolve_target(target.trim()), level)),
+                    Err(_) => eprintln!("Ignoring log filter directive '{}': unknown level '{}'.", directive, level),
+                },
+                None => match directive.parse::<LevelFilter>() {
+                    Ok(level) => log_filter.default_level = level,
+                    Err(_) => eprintln!("Ignoring log filter directive '{}': expected 'level' or 'target=level'.", directive),
+                },
+            }
+        }
+        return log_filter;
+    }
+
+    /// Resolves a short target (see `LOG_TARGET_ALIASES`) to its module path.
+    fn resolve_target(target: &str) -> String {
+        return LOG_TARGET_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == target)
+            .map(|(_, module_path)| module_path.to_string())
+            .unwrap_or_else(|| target.to_string());
+    }
+}
+
+/// Initializes the global logger with the default filter.
 ///
 /// This function should be called once at the very beginning of the
 /// application's `main` function.
 ///
-/// Log level is controlled by the `RUST_LOG` environment variable.
-/// Example: `RUST_LOG=info cargo run`
+/// The filter is taken from the `VRM_LOG` environment variable (see `init_with_filter` for the syntax) or,
+/// if not set, from the `RUST_LOG` environment variable.
+/// Example: `VRM_LOG=info,vrm::scheduler=debug cargo run`
 ///
-/// If `RUST_LOG` is not set, it defaults to `info`.
-/// Logs will be written to `logs/workflow_loader.log` and the console.
+/// If neither is set, it defaults to `debug`.
+/// Logs will be written to `logs/system.log` and the console.
 pub fn init() {
+    init_logger(None);
+}
+
+/// Initializes the global logger with an explicit filter directive string, which takes precedence over `VRM_LOG`.
+///
+/// # Arguments
+/// * `filter` - Comma separated directives, e.g. `"info,vrm::scheduler=debug,vrm::loader=info"`.
+///   A directive is either a level (default of all targets) or `target=level`, where target is a module
+///   path or one of the short targets of `LOG_TARGET_ALIASES`.
+pub fn init_with_filter(filter: &str) {
+    init_logger(Some(filter));
+}
+
+fn init_logger(filter: Option<&str>) {
     if let Err(e) = fs::create_dir_all(LOG_DIR) {
         eprintln!("Failed to create log directory at '{}': {}", LOG_DIR, e);
     }
 
     let log_file_path = format!("{}/{}", LOG_DIR, LOG_FILE);
 
-    // Get the log level from RUST_LOG, defaulting to "info" (RUST_LOG=debug or RUST_LOG=warn)
-    let log_level = std::env::var("RUST_LOG").unwrap_or_else(|_| "debug".to_string());
+    // Explicit filter > VRM_LOG > RUST_LOG (RUST_LOG=debug or RUST_LOG=warn), defaulting to "debug"
+    let filter = match filter {
+        Some(filter) => filter.to_string(),
+        None => std::env::var(LOG_FILTER_ENV_VAR).or_else(|_| std::env::var("RUST_LOG")).unwrap_or_else(|_| "debug".to_string()),
+    };
+    let log_filter = LogFilter::parse(&filter, LevelFilter::Debug);
 
-    let log_level_filter = log_level.parse::<LevelFilter>().unwrap_or(LevelFilter::Debug);
-
-    let base_config = Dispatch::new().level(log_level_filter).level_for("serde", LevelFilter::Warn).level_for("uuid", LevelFilter::Warn);
+    let mut base_config = Dispatch::new().level(log_filter.default_level).level_for("serde", LevelFilter::Warn).level_for("uuid", LevelFilter::Warn);
+    for (target, level) in log_filter.directives {
+        base_config = base_config.level_for(target, level);
+    }
 
     let console_config = Dispatch::new()
         .format(|out, message, record| {
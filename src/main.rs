@@ -47,6 +47,10 @@ struct Args {
     /// Disables Logging
     #[arg(short = 'l', long)]
     disable_logging: bool,
+
+    /// Log filter directives, e.g. "info,vrm::scheduler=debug" (overrides VRM_LOG)
+    #[arg(long)]
+    log_filter: Option<String>,
 }
 
 #[tokio::main]
@@ -57,7 +61,10 @@ async fn main() {
     if args.disable_logging {
         log::set_max_level(log::LevelFilter::Off);
     } else {
-        logger::init();
+        match &args.log_filter {
+            Some(log_filter) => logger::init_with_filter(log_filter),
+            None => logger::init(),
+        }
         AnalyticsSystem::init(args.output_file);
     }
 
@@ -0,0 +1,3 @@
+//! Helpers to construct domain objects in tests, only available with the `test-util` feature.
+
+pub mod workflow_builder;
@@ -0,0 +1,122 @@
+use crate::api::workflow_dto::dependency_dto::DependencyDto;
+use crate::api::workflow_dto::reservation_dto::{DataInDto, DataOutDto, NodeReservationDto, ReservationProceedingDto, ReservationStateDto};
+use crate::api::workflow_dto::workflow_dto::{TaskDto, WorkflowDto};
+use crate::domain::vrm_system_model::reservation::reservation::Reservation;
+use crate::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use crate::domain::vrm_system_model::utils::id::ClientId;
+use crate::domain::vrm_system_model::workflow::workflow::Workflow;
+
+/// Fluent builder, which constructs a `Workflow` without hand-constructing `TaskDto`s.
+///
+/// Each edge is expressed as a `data_out` port of the source task and a matching `data_in` of the target task,
+/// hence the built Workflow is identical to a Workflow parsed from the equivalent workflow file.
+///
+/// # Example
+/// ```ignore
+/// let workflow = WorkflowBuilder::new("Diamond").node("c0", 50, 2).node("c1", 50, 2).data_edge("c0", "c1", 50).build(store);
+/// ```
+#[derive(Debug, Clone)]
+pub struct WorkflowBuilder {
+    dto: WorkflowDto,
+    client_id: ClientId,
+}
+
+impl WorkflowBuilder {
+    /// Creates an empty Workflow with the booking interval [10, 1000000], which is reserved by the client "test_client".
+    pub fn new(workflow_id: &str) -> Self {
+        return Self {
+            dto: WorkflowDto {
+                id: workflow_id.to_string(),
+                arrival_time: 0,
+                booking_interval_start: 10,
+                booking_interval_end: 1000000,
+                state: ReservationStateDto::Open,
+                request_proceeding: ReservationProceedingDto::Reserve,
+                priority: None,
+                tasks: Vec::new(),
+            },
+            client_id: ClientId::new("test_client".to_string()),
+        };
+    }
+
+    pub fn booking_interval(mut self, start: i64, end: i64) -> Self {
+        self.dto.booking_interval_start = start;
+        self.dto.booking_interval_end = end;
+        return self;
+    }
+
+    pub fn client(mut self, client_id: &str) -> Self {
+        self.client_id = ClientId::new(client_id.to_string());
+        return self;
+    }
+
+    /// Adds a task `id`, which runs for `duration` seconds on `cpus` cpus.
+    pub fn node(mut self, id: &str, duration: i64, cpus: i64) -> Self {
+        self.dto.tasks.push(TaskDto {
+            id: id.to_string(),
+            reservation_state: ReservationStateDto::Open,
+            request_proceeding: ReservationProceedingDto::Commit,
+            priority: None,
+            node_reservation: NodeReservationDto {
+                current_working_directory: None,
+                environment: None,
+                task_path: "/bin/true".to_string(),
+                output_path: None,
+                error_path: None,
+                duration,
+                cpus,
+                is_moldable: false,
+                earliest_start: None,
+                dependencies: DependencyDto { data: vec![], sync: vec![], soft_sync: vec![] },
+                data_out: vec![],
+                data_in: vec![],
+            },
+            link_reservation: vec![],
+        });
+        return self;
+    }
+
+    /// Adds a data dependency (file transfer of `size`) from task `source` to task `target`.
+    pub fn data_edge(self, source: &str, target: &str, size: i64) -> Self {
+        let data_out = DataOutDto { name: format!("{}_to_{}", source, target), file: None, size: Some(size), bandwidth: None, latency: None };
+        return self.port_edge(source, target, data_out);
+    }
+
+    /// Adds a sync dependency (channel with `bandwidth`) between task `source` and task `target`, both are co-allocated.
+    pub fn sync_edge(self, source: &str, target: &str, bandwidth: i64) -> Self {
+        let data_out = DataOutDto { name: format!("{}_sync_{}", source, target), file: None, size: None, bandwidth: Some(bandwidth), latency: None };
+        return self.port_edge(source, target, data_out);
+    }
+
+    /// Constructs the Workflow and adds it (and its node and link reservations) to the `reservation_store`.
+    ///
+    /// Panics, if an edge references an unknown task or the Workflow is invalid (e.g. contains a cycle).
+    pub fn build(self, reservation_store: ReservationStore) -> Workflow {
+        let workflow_id = self.dto.id.clone();
+        let reservation_id = Workflow::create_form_dto(self.dto, self.client_id, reservation_store.clone())
+            .unwrap_or_else(|error| panic!("WorkflowBuilder: Workflow {} could not be constructed: {:?}", workflow_id, error));
+
+        let Some(Reservation::Workflow(workflow)) = reservation_store.get_reservation_snapshot(reservation_id) else {
+            panic!("WorkflowBuilder: Workflow {} is not part of the ReservationStore.", workflow_id);
+        };
+        return workflow;
+    }
+
+    fn port_edge(mut self, source: &str, target: &str, data_out: DataOutDto) -> Self {
+        let data_in = DataInDto { source_reservation: source.to_string(), source_port: data_out.name.clone(), file: None };
+
+        self.get_task_mut(source).node_reservation.data_out.push(data_out);
+        self.get_task_mut(target).node_reservation.data_in.push(data_in);
+        return self;
+    }
+
+    fn get_task_mut(&mut self, id: &str) -> &mut TaskDto {
+        let workflow_id = self.dto.id.clone();
+        return self
+            .dto
+            .tasks
+            .iter_mut()
+            .find(|task| task.id == id)
+            .unwrap_or_else(|| panic!("WorkflowBuilder: Task {} of workflow {} must be added with node() before its edges.", id, workflow_id));
+    }
+}
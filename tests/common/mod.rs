@@ -13,11 +13,18 @@ use vrm_rust_workflow::api::workflow_dto::workflow_dto::{TaskDto, WorkflowDto};
 use vrm_rust_workflow::domain::simulator::simulator::{GlobalClock, GlobalClockDto};
 use vrm_rust_workflow::domain::vrm_system_model::client::client::Clients;
 use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::heft_sync_workflow_scheduler::HEFTSyncWorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowSchedulerBase;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_order::VrmComponentOrder;
 use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_trait::VrmComponent;
 use vrm_rust_workflow::domain::vrm_system_model::reservation::node_reservation::NodeReservation;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::probe_reservations::{ProbeReservationComparator, ProbeReservations};
 use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationBase, ReservationProceeding, ReservationState};
-use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
-use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ReservationName};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::rms::rms::RmsLoadMetric;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ClientId, ComponentId, ReservationName, RouterId, ShadowScheduleId};
 use vrm_rust_workflow::domain::vrm_system_model::vrm_manager::VrmManager;
 use vrm_rust_workflow::domain::vrm_system_model::{client, workflow};
 
@@ -48,6 +55,8 @@ pub fn create_node_reservation(
         is_moldable: false,
         moldable_work: duration,
         frag_delta: 0.0,
+        is_moveable: false,
+        priority: 0,
     };
 
     let node_res = NodeReservation {
@@ -63,10 +72,46 @@ pub fn create_node_reservation(
 }
 
 pub async fn create_dummy_aci(clock: Arc<GlobalClock>, reservation_store: ReservationStore) -> AcI {
-    let dto = get_aci_dto("ADC-001".to_string());
+    return create_dummy_aci_with_id("AcI-001", clock, reservation_store).await;
+}
+
+/// Creates the AcI of `get_aci_dto` (4 nodes with 256 CPUs each) with the id `aci_id`.
+pub async fn create_dummy_aci_with_id(aci_id: &str, clock: Arc<GlobalClock>, reservation_store: ReservationStore) -> AcI {
+    let mut dto = get_aci_dto("ADC-001".to_string());
+    dto.id = aci_id.to_string();
     return AcI::from_dto(dto, clock, reservation_store).await.expect("Error in the AcI Mock process happened.");
 }
 
+/// Creates the ADC "ADC-001" without WorkflowScheduler, which requests the `vrm_components` in
+/// `VrmComponentOrder::OrderStartFirst` and has a local schedule of 60 slots of 60 s.
+pub fn create_adc(vrm_components: Vec<Box<dyn VrmComponent + Send>>, clock: Arc<GlobalClock>, reservation_store: ReservationStore) -> ADC {
+    let registry = RegistryClient::new();
+    let component_proxies = vrm_components.into_iter().map(|vrm_component| registry.spawn_component(vrm_component)).collect();
+
+    return ADC::new(
+        AdcId::new("ADC-001"),
+        component_proxies,
+        registry,
+        reservation_store,
+        None,
+        VrmComponentOrder::OrderStartFirst,
+        60,
+        clock,
+        60,
+        60,
+    );
+}
+
+/// Creates the ADC of `create_adc` with the single AcI of `create_dummy_aci`.
+pub async fn create_adc_with_dummy_aci(clock: Arc<GlobalClock>, reservation_store: ReservationStore) -> ADC {
+    let aci = create_dummy_aci(clock.clone(), reservation_store.clone()).await;
+    return create_adc(vec![Box::new(aci)], clock, reservation_store);
+}
+
+pub fn create_heft_sync_scheduler(reservation_store: ReservationStore) -> HEFTSyncWorkflowScheduler {
+    return HEFTSyncWorkflowScheduler { base: WorkflowSchedulerBase::new(reservation_store) };
+}
+
 pub fn get_aci_dto(connected_to_adc: String) -> AcIDto {
     let grid_nodes = vec![
         GridNodeDto { id: "Node-001".to_string(), cpus: 256, connected_to_router: vec!["Router-001".to_string()] },
@@ -147,6 +192,7 @@ pub fn get_direct_mapping_workflow_dto(
         booking_interval_start: 10,
         booking_interval_end: 1000000,
         request_proceeding: workflow_proceeding,
+        priority: None,
         state: workflow_state,
 
         tasks: vec![
@@ -155,6 +201,7 @@ pub fn get_direct_mapping_workflow_dto(
                 id: "c0".to_string(),
                 reservation_state: ReservationStateDto::Open,
                 request_proceeding: ReservationProceedingDto::Commit,
+                priority: None,
                 node_reservation: NodeReservationDto {
                     task_path: task_path.clone(),
                     output_path: output_path.clone(),
@@ -163,13 +210,15 @@ pub fn get_direct_mapping_workflow_dto(
                     environment: environment.clone(),
                     duration: 50,
                     is_moldable: false,
+                    earliest_start: None,
                     cpus: 2,
-                    dependencies: DependencyDto { data: vec![], sync: vec![] },
+                    dependencies: DependencyDto { data: vec![], sync: vec![], soft_sync: vec![] },
                     data_out: vec![DataOutDto {
                         name: "preprocessed_data".to_string(),
                         file: Some("preprocessed.h5".to_string()),
                         size: Some(50),
                         bandwidth: Some(10),
+                        latency: None,
                     }],
                     data_in: vec![DataInDto {
                         source_reservation: "EXTERNAL".to_string(),
@@ -187,6 +236,7 @@ pub fn get_direct_mapping_workflow_dto(
                 id: "c1".to_string(),
                 reservation_state: ReservationStateDto::Open,
                 request_proceeding: ReservationProceedingDto::Commit,
+                priority: None,
                 node_reservation: NodeReservationDto {
                     task_path: task_path.clone(),
                     output_path: output_path.clone(),
@@ -195,13 +245,15 @@ pub fn get_direct_mapping_workflow_dto(
                     environment: environment.clone(),
                     duration: 50,
                     is_moldable: false,
+                    earliest_start: None,
                     cpus: 2,
-                    dependencies: DependencyDto { data: vec!["c0".to_string()], sync: vec![] },
+                    dependencies: DependencyDto { data: vec!["c0".to_string()], sync: vec![], soft_sync: vec![] },
                     data_out: vec![DataOutDto {
                         name: "preprocessed_data".to_string(),
                         file: Some("preprocessed.h5".to_string()),
                         size: Some(50),
                         bandwidth: Some(10),
+                        latency: None,
                     }],
                     data_in: vec![DataInDto {
                         source_reservation: "EXTERNAL".to_string(),
@@ -221,6 +273,7 @@ pub fn get_direct_mapping_workflow_dto(
                 id: "c2".to_string(),
                 reservation_state: ReservationStateDto::Open,
                 request_proceeding: ReservationProceedingDto::Commit,
+                priority: None,
                 node_reservation: NodeReservationDto {
                     task_path: task_path.clone(),
                     output_path: output_path.clone(),
@@ -229,13 +282,15 @@ pub fn get_direct_mapping_workflow_dto(
                     environment: environment.clone(),
                     duration: 50,
                     is_moldable: false,
+                    earliest_start: None,
                     cpus: 2,
-                    dependencies: DependencyDto { data: vec!["c0".to_string()], sync: vec![] },
+                    dependencies: DependencyDto { data: vec!["c0".to_string()], sync: vec![], soft_sync: vec![] },
                     data_out: vec![DataOutDto {
                         name: "preprocessed_data".to_string(),
                         file: Some("preprocessed.h5".to_string()),
                         size: Some(50),
                         bandwidth: Some(10),
+                        latency: None,
                     }],
                     data_in: vec![DataInDto {
                         source_reservation: "EXTERNAL".to_string(),
@@ -255,6 +310,7 @@ pub fn get_direct_mapping_workflow_dto(
                 id: "c3".to_string(),
                 reservation_state: ReservationStateDto::Open,
                 request_proceeding: ReservationProceedingDto::Commit,
+                priority: None,
                 node_reservation: NodeReservationDto {
                     task_path: task_path.clone(),
                     output_path: output_path,
@@ -263,13 +319,15 @@ pub fn get_direct_mapping_workflow_dto(
                     environment: environment.clone(),
                     duration: 50,
                     is_moldable: false,
+                    earliest_start: None,
                     cpus: 2,
-                    dependencies: DependencyDto { data: vec!["c1".to_string(), "c2".to_string()], sync: vec![] },
+                    dependencies: DependencyDto { data: vec!["c1".to_string(), "c2".to_string()], sync: vec![], soft_sync: vec![] },
                     data_out: vec![DataOutDto {
                         name: "preprocessed_data".to_string(),
                         file: Some("preprocessed.h5".to_string()),
                         size: Some(50),
                         bandwidth: Some(10),
+                        latency: None,
                     }],
                     data_in: vec![DataInDto {
                         source_reservation: "EXTERNAL".to_string(),
@@ -301,6 +359,7 @@ pub fn get_workflow_dto_with_one_task(
         booking_interval_end: 100,
         state: task_reservation_state,
         request_proceeding: task_reservation_proceeding,
+        priority: None,
 
         tasks: vec![
             // Task c0
@@ -308,6 +367,7 @@ pub fn get_workflow_dto_with_one_task(
                 id: "c0".to_string(),
                 reservation_state: task_reservation_state,
                 request_proceeding: task_reservation_proceeding,
+                priority: None,
                 node_reservation: NodeReservationDto {
                     task_path: task_path.clone(),
                     output_path: output_path.clone(),
@@ -316,13 +376,15 @@ pub fn get_workflow_dto_with_one_task(
                     environment: environment.clone(),
                     duration: 50,
                     is_moldable: false,
+                    earliest_start: None,
                     cpus: 2,
-                    dependencies: DependencyDto { data: vec![], sync: vec![] },
+                    dependencies: DependencyDto { data: vec![], sync: vec![], soft_sync: vec![] },
                     data_out: vec![DataOutDto {
                         name: "preprocessed_data".to_string(),
                         file: Some("preprocessed.h5".to_string()),
                         size: Some(50),
                         bandwidth: Some(10),
+                        latency: None,
                     }],
                     data_in: vec![DataInDto {
                         source_reservation: "EXTERNAL".to_string(),
@@ -344,3 +406,248 @@ pub fn get_clients(client_id: String, workflow_dto: WorkflowDto, reservation_sto
     let clients_dto = ClientsDto { clients: vec![client_dto] };
     return Clients::from_dto(clients_dto, reservation_store).expect("Getting Clients was not possible.");
 }
+
+/// Mock VrmComponent, which answers the probes of the wrapped AcI, but rejects all reserve requests (e.g. because
+/// the AcI was filled up in between). The reserve requests are answered with `answer_state`, an answer state of
+/// `ReservationState::ReserveProbeReservation` simulates an AcI, which never answers.
+#[derive(Debug)]
+pub struct RejectingReserveComponent {
+    pub aci: AcI,
+    pub reservation_store: ReservationStore,
+    pub answer_state: ReservationState,
+}
+
+impl VrmComponent for RejectingReserveComponent {
+    fn get_id(&self) -> ComponentId {
+        self.aci.get_id()
+    }
+
+    fn get_total_capacity(&self) -> i64 {
+        self.aci.get_total_capacity()
+    }
+
+    fn get_total_link_capacity(&self) -> i64 {
+        self.aci.get_total_link_capacity()
+    }
+
+    fn get_link_resource_count(&self) -> usize {
+        self.aci.get_link_resource_count()
+    }
+
+    fn get_total_node_capacity(&self) -> i64 {
+        self.aci.get_total_node_capacity()
+    }
+
+    fn can_handel(&self, res: Reservation) -> bool {
+        self.aci.can_handel(res)
+    }
+
+    fn probe(&mut self, reservation_id: ReservationId, shadow_schedule_id: Option<ShadowScheduleId>) -> ProbeReservations {
+        self.aci.probe(reservation_id, shadow_schedule_id)
+    }
+
+    fn probe_best(
+        &mut self,
+        reservation_id: ReservationId,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+        probe_reservation_comparator: ProbeReservationComparator,
+    ) -> ProbeReservations {
+        self.aci.probe_best(reservation_id, shadow_schedule_id, probe_reservation_comparator)
+    }
+
+    fn reserve(&mut self, reservation_id: ReservationId, _shadow_schedule_id: Option<ShadowScheduleId>) -> ReservationId {
+        self.reservation_store.update_state(reservation_id, self.answer_state);
+        reservation_id
+    }
+
+    fn commit(&mut self, reservation_id: ReservationId) -> bool {
+        self.aci.commit(reservation_id)
+    }
+
+    fn delete(&mut self, reservation_id: ReservationId, shadow_schedule_id: Option<ShadowScheduleId>) -> ReservationId {
+        self.aci.delete(reservation_id, shadow_schedule_id)
+    }
+
+    fn get_satisfaction(&mut self, start: i64, end: i64, shadow_schedule_id: Option<ShadowScheduleId>) -> f64 {
+        self.aci.get_satisfaction(start, end, shadow_schedule_id)
+    }
+
+    fn get_system_satisfaction(&mut self, shadow_schedule_id: Option<ShadowScheduleId>) -> f64 {
+        self.aci.get_system_satisfaction(shadow_schedule_id)
+    }
+
+    fn create_shadow_schedule(&mut self, shadow_schedule_id: ShadowScheduleId) -> bool {
+        self.aci.create_shadow_schedule(shadow_schedule_id)
+    }
+
+    fn delete_shadow_schedule(&mut self, shadow_schedule_id: ShadowScheduleId) -> bool {
+        self.aci.delete_shadow_schedule(shadow_schedule_id)
+    }
+
+    fn commit_shadow_schedule(&mut self, shadow_schedule_id: ShadowScheduleId) -> bool {
+        self.aci.commit_shadow_schedule(shadow_schedule_id)
+    }
+
+    fn get_load_metric_up_to_date(&mut self, start: i64, end: i64, shadow_schedule_id: Option<ShadowScheduleId>) -> RmsLoadMetric {
+        self.aci.get_load_metric_up_to_date(start, end, shadow_schedule_id)
+    }
+
+    fn get_load_metric(&self, start: i64, end: i64, shadow_schedule_id: Option<ShadowScheduleId>) -> RmsLoadMetric {
+        self.aci.get_load_metric(start, end, shadow_schedule_id)
+    }
+
+    fn get_simulation_load_metric(&mut self, shadow_schedule_id: Option<ShadowScheduleId>) -> RmsLoadMetric {
+        self.aci.get_simulation_load_metric(shadow_schedule_id)
+    }
+}
+
+/// Hook of a `HookedComponent`, which answers a request instead of the wrapped AcI.
+pub type Hook<F> = Option<Box<F>>;
+
+/// Hooks of a `HookedComponent`. A set hook answers its request instead of the wrapped AcI and gets access to the AcI,
+/// e.g. to record or delay a request before forwarding it. The `get_load_metric` hook also answers the up-to-date load
+/// metric and the simulation load metric (time window 0 to `i64::MAX`).
+#[derive(Default)]
+pub struct VrmComponentHooks {
+    pub get_total_capacity: Hook<dyn Fn(&AcI) -> i64 + Send + Sync>,
+    pub get_total_node_capacity: Hook<dyn Fn(&AcI) -> i64 + Send + Sync>,
+    pub get_router_list: Hook<dyn Fn(&AcI) -> Vec<RouterId> + Send + Sync>,
+    pub can_handel: Hook<dyn Fn(&AcI, Reservation) -> bool + Send + Sync>,
+    pub reserve: Hook<dyn Fn(&mut AcI, ReservationId, Option<ShadowScheduleId>) -> ReservationId + Send + Sync>,
+    pub get_satisfaction: Hook<dyn Fn(&mut AcI, i64, i64, Option<ShadowScheduleId>) -> f64 + Send + Sync>,
+    pub get_system_satisfaction: Hook<dyn Fn(&mut AcI, Option<ShadowScheduleId>) -> f64 + Send + Sync>,
+    pub get_load_metric: Hook<dyn Fn(&AcI, i64, i64, Option<ShadowScheduleId>) -> RmsLoadMetric + Send + Sync>,
+}
+
+/// Mock VrmComponent, which forwards all requests to the wrapped AcI, unless a hook is set for the request (see `VrmComponentHooks`).
+pub struct HookedComponent {
+    pub aci: AcI,
+    pub hooks: VrmComponentHooks,
+}
+
+impl std::fmt::Debug for HookedComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HookedComponent").field("aci", &self.aci).finish_non_exhaustive()
+    }
+}
+
+impl VrmComponent for HookedComponent {
+    fn get_id(&self) -> ComponentId {
+        self.aci.get_id()
+    }
+
+    fn get_total_capacity(&self) -> i64 {
+        match &self.hooks.get_total_capacity {
+            Some(hook) => hook(&self.aci),
+            None => self.aci.get_total_capacity(),
+        }
+    }
+
+    fn get_total_link_capacity(&self) -> i64 {
+        self.aci.get_total_link_capacity()
+    }
+
+    fn get_link_resource_count(&self) -> usize {
+        self.aci.get_link_resource_count()
+    }
+
+    fn get_total_node_capacity(&self) -> i64 {
+        match &self.hooks.get_total_node_capacity {
+            Some(hook) => hook(&self.aci),
+            None => self.aci.get_total_node_capacity(),
+        }
+    }
+
+    fn get_router_list(&self) -> Vec<RouterId> {
+        match &self.hooks.get_router_list {
+            Some(hook) => hook(&self.aci),
+            None => self.aci.get_router_list(),
+        }
+    }
+
+    fn contains_router(&self, router_id: &RouterId) -> bool {
+        self.aci.contains_router(router_id)
+    }
+
+    fn can_handel(&self, res: Reservation) -> bool {
+        match &self.hooks.can_handel {
+            Some(hook) => hook(&self.aci, res),
+            None => self.aci.can_handel(res),
+        }
+    }
+
+    fn probe(&mut self, reservation_id: ReservationId, shadow_schedule_id: Option<ShadowScheduleId>) -> ProbeReservations {
+        self.aci.probe(reservation_id, shadow_schedule_id)
+    }
+
+    fn probe_best(
+        &mut self,
+        reservation_id: ReservationId,
+        shadow_schedule_id: Option<ShadowScheduleId>,
+        probe_reservation_comparator: ProbeReservationComparator,
+    ) -> ProbeReservations {
+        self.aci.probe_best(reservation_id, shadow_schedule_id, probe_reservation_comparator)
+    }
+
+    fn reserve(&mut self, reservation_id: ReservationId, shadow_schedule_id: Option<ShadowScheduleId>) -> ReservationId {
+        match &self.hooks.reserve {
+            Some(hook) => hook(&mut self.aci, reservation_id, shadow_schedule_id),
+            None => self.aci.reserve(reservation_id, shadow_schedule_id),
+        }
+    }
+
+    fn commit(&mut self, reservation_id: ReservationId) -> bool {
+        self.aci.commit(reservation_id)
+    }
+
+    fn delete(&mut self, reservation_id: ReservationId, shadow_schedule_id: Option<ShadowScheduleId>) -> ReservationId {
+        self.aci.delete(reservation_id, shadow_schedule_id)
+    }
+
+    fn get_satisfaction(&mut self, start: i64, end: i64, shadow_schedule_id: Option<ShadowScheduleId>) -> f64 {
+        match &self.hooks.get_satisfaction {
+            Some(hook) => hook(&mut self.aci, start, end, shadow_schedule_id),
+            None => self.aci.get_satisfaction(start, end, shadow_schedule_id),
+        }
+    }
+
+    fn get_system_satisfaction(&mut self, shadow_schedule_id: Option<ShadowScheduleId>) -> f64 {
+        match &self.hooks.get_system_satisfaction {
+            Some(hook) => hook(&mut self.aci, shadow_schedule_id),
+            None => self.aci.get_system_satisfaction(shadow_schedule_id),
+        }
+    }
+
+    fn create_shadow_schedule(&mut self, shadow_schedule_id: ShadowScheduleId) -> bool {
+        self.aci.create_shadow_schedule(shadow_schedule_id)
+    }
+
+    fn delete_shadow_schedule(&mut self, shadow_schedule_id: ShadowScheduleId) -> bool {
+        self.aci.delete_shadow_schedule(shadow_schedule_id)
+    }
+
+    fn commit_shadow_schedule(&mut self, shadow_schedule_id: ShadowScheduleId) -> bool {
+        self.aci.commit_shadow_schedule(shadow_schedule_id)
+    }
+
+    fn get_load_metric_up_to_date(&mut self, start: i64, end: i64, shadow_schedule_id: Option<ShadowScheduleId>) -> RmsLoadMetric {
+        match &self.hooks.get_load_metric {
+            Some(hook) => hook(&self.aci, start, end, shadow_schedule_id),
+            None => self.aci.get_load_metric_up_to_date(start, end, shadow_schedule_id),
+        }
+    }
+
+    fn get_load_metric(&self, start: i64, end: i64, shadow_schedule_id: Option<ShadowScheduleId>) -> RmsLoadMetric {
+        match &self.hooks.get_load_metric {
+            Some(hook) => hook(&self.aci, start, end, shadow_schedule_id),
+            None => self.aci.get_load_metric(start, end, shadow_schedule_id),
+        }
+    }
+
+    fn get_simulation_load_metric(&mut self, shadow_schedule_id: Option<ShadowScheduleId>) -> RmsLoadMetric {
+        match &self.hooks.get_load_metric {
+            Some(hook) => hook(&self.aci, 0, i64::MAX, shadow_schedule_id),
+            None => self.aci.get_simulation_load_metric(shadow_schedule_id),
+        }
+    }
+}
@@ -1,3 +1,35 @@
+pub mod test_adc_commit_timeout;
+pub mod test_analytics_aggregator;
+pub mod test_csv_analytics_sink;
+pub mod test_global_clock_event_queue;
+pub mod test_load_workflows_from_dir;
+pub mod test_logger_filter;
+pub mod test_network_release_path;
+pub mod test_network_topology_shortest_path;
+pub mod test_null_network_schedule;
+pub mod test_probe_reservations_merge;
+pub mod test_probe_reservations_summary;
+pub mod test_probe_reservations_validate_booking;
+pub mod test_reservation_state_transition;
+pub mod test_reservation_store_concurrent_add;
+pub mod test_reservation_store_gc;
+pub mod test_reservation_store_get_or_insert;
+pub mod test_reservation_store_health_check;
+pub mod test_reservation_store_query;
+pub mod test_reservation_store_snapshot_isolation;
+pub mod test_reservation_store_typed_snapshot;
+pub mod test_reservation_store_with_reservation_mut;
+pub mod test_slotted_schedule_backfill;
+pub mod test_slotted_schedule_compaction;
+pub mod test_slotted_schedule_extend_window;
+pub mod test_slotted_schedule_fragmentation_by_slot;
+pub mod test_slotted_schedule_free_capacity_profile;
+pub mod test_slotted_schedule_reserve_all_or_none;
+pub mod test_slotted_schedule_snapshot;
+pub mod test_slotted_schedule_time_slot_conversion;
+pub mod test_system_model_diagnostics;
+pub mod test_system_model_export;
+pub mod test_system_model_feasibility;
 pub mod test_vrm_advance_reservation;
 pub mod vrm_components;
 pub mod workflow;
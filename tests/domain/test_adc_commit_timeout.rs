@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::Ordering;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ComponentId, ReservationName};
+
+use crate::common::{create_adc_with_dummy_aci, create_node_reservation};
+
+const COMMIT_TIMEOUT: i64 = 60;
+
+/// A reserved reservation, which is not committed within the commit timeout, is expired and its slots are freed.
+#[tokio::test]
+async fn test_expire_stale_reservation_frees_slot() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock.clone(), store.clone()).await;
+    adc.commit_timeout = COMMIT_TIMEOUT;
+
+    let load_before = adc.manager.get_load_metric(0, 600, None).node_load_metric.map(|metric| metric.utilization);
+
+    let res_id = store.add(create_node_reservation(ReservationName::new("Stale-Job"), 2, 0, 120, ReservationState::Open, clock.clone()));
+    let mut grid_component_res_database: HashMap<ReservationId, ComponentId> = HashMap::new();
+    adc.submit_task_at_first_grid_component(res_id, None, &mut grid_component_res_database);
+
+    assert_eq!(store.get_state(res_id), ReservationState::ReserveAnswer, "Reserve was not successful.");
+    assert!(adc.manager.not_committed_reservations.contains_key(&res_id));
+    assert_eq!(adc.manager.reserve_times.get(&res_id), Some(&0));
+    assert_ne!(adc.manager.get_load_metric(0, 600, None).node_load_metric.map(|metric| metric.utilization), load_before);
+
+    // The commit timeout is not exceeded yet
+    clock.reference_start_time.store(COMMIT_TIMEOUT, Ordering::Relaxed);
+    assert!(adc.expire_stale_reservations(clock.get_system_time_s()).is_empty());
+    assert_eq!(store.get_state(res_id), ReservationState::ReserveAnswer);
+
+    clock.reference_start_time.store(COMMIT_TIMEOUT + 1, Ordering::Relaxed);
+    assert_eq!(adc.expire_stale_reservations(clock.get_system_time_s()), vec![res_id]);
+
+    assert_eq!(store.get_state(res_id), ReservationState::Rejected);
+    assert!(adc.manager.not_committed_reservations.is_empty());
+    assert!(adc.manager.reserve_times.is_empty());
+    assert!(adc.manager.get_handler_id(res_id).is_none());
+    assert_eq!(adc.manager.get_load_metric(0, 600, None).node_load_metric.map(|metric| metric.utilization), load_before, "Slot was not freed.");
+}
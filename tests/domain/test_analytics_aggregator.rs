@@ -0,0 +1,56 @@
+use std::sync::{Arc, Mutex};
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::AdcId;
+use vrm_rust_workflow::domain::vrm_system_model::utils::statistics::AnalyticsAggregator;
+
+#[test]
+fn test_analytics_aggregator_median_and_mean() {
+    let mut aggregator = AnalyticsAggregator::new();
+    for processing_time in [7, 1, 5, 3, 9] {
+        aggregator.record("Reserve", processing_time);
+    }
+    aggregator.record("Commit", 100);
+
+    assert_eq!(aggregator.count("Reserve"), 5);
+    assert_eq!(aggregator.percentile("Reserve", 50.0), Some(5));
+    assert_eq!(aggregator.percentile("Reserve", 0.0), Some(1));
+    assert_eq!(aggregator.percentile("Reserve", 100.0), Some(9));
+    assert_eq!(aggregator.percentile("Reserve", 80.0), Some(7));
+    assert_eq!(aggregator.mean("Reserve"), Some(5.0));
+
+    // The samples of different commands are not mixed
+    assert_eq!(aggregator.percentile("Commit", 50.0), Some(100));
+    assert_eq!(aggregator.mean("Commit"), Some(100.0));
+}
+
+#[test]
+fn test_analytics_aggregator_unknown_command() {
+    let aggregator = AnalyticsAggregator::new();
+
+    assert_eq!(aggregator.count("Delete"), 0);
+    assert_eq!(aggregator.percentile("Delete", 50.0), None);
+    assert_eq!(aggregator.mean("Delete"), None);
+}
+
+#[test]
+fn test_vrm_component_manager_feeds_attached_aggregator() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock, ReservationStore::new(), 60, 60);
+
+    // Without an attached aggregator the processing times are dropped
+    manager.record_processing_time("Reserve", 42);
+
+    let aggregator = Arc::new(Mutex::new(AnalyticsAggregator::new()));
+    manager.attach_analytics_aggregator(aggregator.clone());
+    for processing_time in [4, 2, 6] {
+        manager.record_processing_time("Reserve", processing_time);
+    }
+
+    let aggregator = aggregator.lock().unwrap();
+    assert_eq!(aggregator.count("Reserve"), 3);
+    assert_eq!(aggregator.percentile("Reserve", 50.0), Some(4));
+    assert_eq!(aggregator.mean("Reserve"), Some(4.0));
+}
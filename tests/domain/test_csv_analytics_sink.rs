@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::heft_sync_workflow_scheduler::HEFTSyncWorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_trait::VrmComponent;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ClientId;
+use vrm_rust_workflow::domain::vrm_system_model::utils::statistics::CsvAnalyticsSink;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, get_direct_mapping_workflow_dto};
+
+const EXPECTED_HEADER: &str = "Time;LogDescription;ComponentType;NodeComponentUtilization;NodeComponentCapacity;NetworkComponentUtilization;NetworkComponentCapacity;ComponentFragmentation;ReservationName;ReservationCapacity;ReservationWorkload;ReservationState;ReservationProceeding;NumberOfTasks;Command;ProcessingTime";
+
+/// Reserves a feasible and an infeasible workflow, each finished reserve operation results in exactly one CSV row.
+#[tokio::test]
+async fn test_csv_analytics_sink_records_one_row_per_operation() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+    adc.workflow_scheduler = Some(HEFTSyncWorkflowScheduler::new(store.clone()));
+
+    let log_file_path = std::env::temp_dir().join(format!("test_csv_analytics_sink_{}.csv", std::process::id()));
+    let analytics_sink = CsvAnalyticsSink::from_path(log_file_path.to_str().unwrap()).expect("Failed to create analytics file.");
+    adc.attach_analytics_sink(Arc::new(analytics_sink));
+
+    let client_id = ClientId::new("Test-Client-001");
+    let feasible_workflow = Workflow::create_form_dto(
+        get_direct_mapping_workflow_dto("Test-Feasible-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open),
+        client_id.clone(),
+        store.clone(),
+    )
+    .expect("Workflow construction failed.");
+
+    // c1 and c2 can not start before c0 is finished, hence the deadline is missed
+    let mut infeasible_workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Infeasible-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    infeasible_workflow_dto.booking_interval_end = 100;
+    let infeasible_workflow = Workflow::create_form_dto(infeasible_workflow_dto, client_id, store.clone()).expect("Workflow construction failed.");
+
+    adc.reserve(feasible_workflow, None);
+    adc.reserve(infeasible_workflow, None);
+    assert_eq!(store.get_state(feasible_workflow), ReservationState::ReserveAnswer);
+    assert_eq!(store.get_state(infeasible_workflow), ReservationState::Rejected);
+
+    let content = std::fs::read_to_string(&log_file_path).expect("Failed to read analytics file.");
+    let _ = std::fs::remove_file(&log_file_path);
+    let lines: Vec<&str> = content.lines().collect();
+
+    assert_eq!(lines.len(), 3, "Expected header and one row per reserve operation, got:\n{}", content);
+    assert_eq!(lines[0], EXPECTED_HEADER);
+
+    let command_column = EXPECTED_HEADER.split(';').position(|header| header == "Command").unwrap();
+    let state_column = EXPECTED_HEADER.split(';').position(|header| header == "ReservationState").unwrap();
+    let name_column = EXPECTED_HEADER.split(';').position(|header| header == "ReservationName").unwrap();
+
+    let rows: Vec<Vec<&str>> = lines[1..].iter().map(|line| line.split(';').collect()).collect();
+    assert_eq!(rows[0][name_column], "Test-Feasible-Workflow");
+    assert_eq!(rows[0][state_column], "ReserveAnswer");
+    assert_eq!(rows[1][name_column], "Test-Infeasible-Workflow");
+    assert_eq!(rows[1][state_column], "Rejected");
+    assert!(rows.iter().all(|row| row[command_column] == "Reserve"));
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::event_queue::SimEvent;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ReservationName;
+
+use crate::common::create_node_reservation;
+
+/// Three events are scheduled out of order. The clock jumps from event to event and the events fire in timestamp order.
+#[test]
+fn test_events_fire_in_timestamp_order() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let mut store = ReservationStore::new();
+
+    let first = store.add(create_node_reservation(ReservationName::new("First"), 1, 100, 300, ReservationState::Open, clock.clone()));
+    let second = store.add(create_node_reservation(ReservationName::new("Second"), 1, 200, 400, ReservationState::Open, clock.clone()));
+
+    clock.schedule_event(300, SimEvent::ReservationEnd(first));
+    clock.schedule_event(100, SimEvent::ReservationStart(first));
+    clock.schedule_event(200, SimEvent::ReservationStart(second));
+
+    // No event is due before the clock is advanced
+    assert!(clock.pop_due_events().is_empty());
+
+    let mut fired_events = Vec::new();
+    while let Some(next_event_time) = clock.advance_to_next_event() {
+        assert_eq!(clock.get_system_time_s(), next_event_time);
+        fired_events.extend(clock.pop_due_events());
+    }
+
+    assert_eq!(
+        fired_events,
+        vec![(100, SimEvent::ReservationStart(first)), (200, SimEvent::ReservationStart(second)), (300, SimEvent::ReservationEnd(first))]
+    );
+    assert_eq!(clock.get_system_time_s(), 300);
+    assert!(clock.event_queue.is_empty());
+    assert_eq!(clock.advance_to_next_event(), None);
+}
+
+/// An event in the past is due immediately, but the clock is not moved backwards.
+#[test]
+fn test_overdue_event_does_not_move_clock_backwards() {
+    let clock = Arc::new(GlobalClock::new(true));
+
+    clock.schedule_event(500, SimEvent::ReservationEnd(Default::default()));
+    assert_eq!(clock.advance_to_next_event(), Some(500));
+    assert_eq!(clock.pop_due_events().len(), 1);
+
+    clock.schedule_event(100, SimEvent::ReservationStart(Default::default()));
+    assert_eq!(clock.advance_to_next_event(), Some(100));
+    assert_eq!(clock.get_system_time_s(), 500);
+    assert_eq!(clock.pop_due_events().len(), 1);
+}
@@ -0,0 +1,64 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::error::Error;
+use vrm_rust_workflow::loader::parser::load_workflows_from_dir;
+
+use crate::common::get_workflow_dto_with_one_task;
+
+/// Creates an empty temporary directory, which is unique for the test and the process.
+fn create_temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    return dir;
+}
+
+/// Writes a valid workflow as JSON to `b.json` and as YAML to `a.yaml`, and a file, which is ignored.
+fn write_valid_workflows(dir: &std::path::Path) {
+    let workflow_b = get_workflow_dto_with_one_task("Workflow-B".to_string(), ReservationStateDto::Open, ReservationProceedingDto::Reserve);
+    let workflow_a = get_workflow_dto_with_one_task("Workflow-A".to_string(), ReservationStateDto::Open, ReservationProceedingDto::Reserve);
+
+    std::fs::write(dir.join("b.json"), serde_json::to_string(&workflow_b).unwrap()).unwrap();
+    std::fs::write(dir.join("a.yaml"), serde_yaml::to_string(&workflow_a).unwrap()).unwrap();
+    std::fs::write(dir.join("notes.txt"), "not a workflow").unwrap();
+}
+
+#[test]
+fn test_load_workflows_from_dir_reports_invalid_file() {
+    let dir = create_temp_dir("test_load_workflows_from_dir_invalid");
+    write_valid_workflows(&dir);
+    std::fs::write(dir.join("c.json"), "{ \"id\": ").unwrap();
+
+    let result = load_workflows_from_dir(dir.to_str().unwrap(), true);
+
+    match result {
+        Err(Error::BatchLoad { failures }) => {
+            assert_eq!(failures.len(), 1);
+            assert!(failures[0].0.ends_with("c.json"), "Unexpected failing file {}", failures[0].0);
+        }
+        Err(err) => panic!("Expected BatchLoad, got {:?}", err),
+        Ok(_) => panic!("Expected an error but got Ok"),
+    }
+
+    std::fs::remove_file(dir.join("c.json")).unwrap();
+    let workflows = load_workflows_from_dir(dir.to_str().unwrap(), true).expect("Loading of valid workflows failed.");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let workflow_ids: Vec<&str> = workflows.iter().map(|workflow| workflow.id.as_str()).collect();
+    assert_eq!(workflow_ids, vec!["Workflow-A", "Workflow-B"]);
+}
+
+/// Without `collect_errors` the loading stops at the first failing file.
+#[test]
+fn test_load_workflows_from_dir_stops_at_first_failure() {
+    let dir = create_temp_dir("test_load_workflows_from_dir_first_failure");
+    write_valid_workflows(&dir);
+    std::fs::write(dir.join("c.json"), "{ \"id\": ").unwrap();
+    std::fs::write(dir.join("d.yml"), "tasks: [unclosed").unwrap();
+
+    let collected = load_workflows_from_dir(dir.to_str().unwrap(), true);
+    let first_only = load_workflows_from_dir(dir.to_str().unwrap(), false);
+    let _ = std::fs::remove_dir_all(&dir);
+
+    assert!(matches!(collected, Err(Error::BatchLoad { ref failures }) if failures.len() == 2), "Got {:?}", collected.err());
+    assert!(matches!(first_only, Err(Error::BatchLoad { ref failures }) if failures.len() == 1), "Got {:?}", first_only.err());
+}
@@ -0,0 +1,30 @@
+use log::LevelFilter;
+use vrm_rust_workflow::logger::LogFilter;
+
+#[test]
+fn test_parse_log_filter_with_short_targets() {
+    let log_filter = LogFilter::parse("info,vrm::scheduler=debug,vrm::loader=warn", LevelFilter::Debug);
+
+    assert_eq!(log_filter.default_level, LevelFilter::Info);
+    assert_eq!(
+        log_filter.directives,
+        vec![
+            ("vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler".to_string(), LevelFilter::Debug),
+            ("vrm_rust_workflow::loader".to_string(), LevelFilter::Warn),
+        ]
+    );
+}
+
+/// Full module paths are used as given, invalid directives are skipped.
+#[test]
+fn test_parse_log_filter_with_module_paths_and_invalid_directives() {
+    let log_filter = LogFilter::parse(" actix=error , vrm_rust_workflow::domain=trace, vrm::adc=loud, verbose,", LevelFilter::Warn);
+
+    assert_eq!(log_filter.default_level, LevelFilter::Warn);
+    assert_eq!(log_filter.directives, vec![("actix".to_string(), LevelFilter::Error), ("vrm_rust_workflow::domain".to_string(), LevelFilter::Trace)]);
+}
+
+#[test]
+fn test_parse_empty_log_filter() {
+    assert_eq!(LogFilter::parse("", LevelFilter::Debug), LogFilter { default_level: LevelFilter::Debug, directives: vec![] });
+}
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::link_reservation::LinkReservation;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::resource::resource_store::{LinkResourceId, ResourceStore};
+use vrm_rust_workflow::domain::vrm_system_model::schedule::schedule_trait::Schedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::SlottedLinkSchedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::strategy::link::link_strategy::LinkStrategy;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::strategy::link::topology::{Link, NetworkTopology, Node};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AciId, ReservationName, ResourceName, RouterId, SlottedScheduleId};
+
+use crate::common::create_node_reservation;
+
+fn link(id: &str, source: &str, target: &str, capacity: i64) -> Link {
+    return Link { id: ResourceName::new(id), source: RouterId::new(source), target: RouterId::new(target), capacity };
+}
+
+fn node(name: &str) -> Node {
+    return Node { name: ResourceName::new(name), cpus: 8, connected_to_router: vec![] };
+}
+
+/// The only path N1 -> N3 traverses the links N1-R1 and R1-N3 with a bandwidth of 100 each.
+/// The network schedule and the link schedules consist of 10 slots of 60s.
+fn get_network_schedule(clock: Arc<GlobalClock>, store: ReservationStore) -> (SlottedLinkSchedule, ResourceStore, Vec<LinkResourceId>) {
+    let resource_store = ResourceStore::new();
+    let topology = NetworkTopology::new(
+        &vec![link("N1-R1", "N1", "R1", 100), link("R1-N3", "R1", "N3", 100)],
+        &vec![node("N1"), node("N3")],
+        60,
+        10,
+        clock.clone(),
+        AciId::new("AcI-001"),
+        store.clone(),
+        resource_store.clone(),
+    );
+    let link_ids: Vec<LinkResourceId> = topology.link_ids.iter().cloned().collect();
+    let capacity = topology.max_bandwidth_all_paths;
+
+    let schedule = SlottedLinkSchedule::new(
+        SlottedScheduleId::new("Test-Network-Schedule"),
+        10,
+        60,
+        capacity,
+        false,
+        LinkStrategy::new(topology, resource_store.clone()),
+        store,
+        clock,
+    );
+    return (schedule, resource_store, link_ids);
+}
+
+/// Transfer of 40 from N1 to N3 in [120, 240], i.e. in the slots 2 and 3.
+fn add_link_reservation(store: &ReservationStore, clock: Arc<GlobalClock>) -> ReservationId {
+    let Reservation::Node(node_reservation) = create_node_reservation(ReservationName::new("Transfer"), 40, 120, 240, ReservationState::Open, clock)
+    else {
+        panic!("Expected NodeReservation");
+    };
+
+    return store.add(Reservation::Link(LinkReservation {
+        base: node_reservation.base,
+        start_point: Some(RouterId::new("N1")),
+        end_point: Some(RouterId::new("N3")),
+    }));
+}
+
+fn get_link_loads(resource_store: &ResourceStore, link_ids: &[LinkResourceId], slot_index: i64) -> Vec<i64> {
+    return link_ids
+        .iter()
+        .map(|link_id| resource_store.with_mut_slotted_schedule_strategy(*link_id, |schedule| schedule.get_slot_load(slot_index)))
+        .collect();
+}
+
+#[test]
+fn test_delete_reservation_releases_link_bandwidth() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let (mut schedule, resource_store, link_ids) = get_network_schedule(clock.clone(), store.clone());
+    let res_id = add_link_reservation(&store, clock);
+
+    assert_eq!(get_link_loads(&resource_store, &link_ids, 2), vec![0, 0]);
+
+    assert_eq!(schedule.reserve(res_id), Some(res_id));
+    assert!(schedule.strategy.reserved_paths.contains_key(&res_id));
+    assert_eq!(get_link_loads(&resource_store, &link_ids, 2), vec![40, 40]);
+    assert_eq!(get_link_loads(&resource_store, &link_ids, 3), vec![40, 40]);
+    assert_eq!(get_link_loads(&resource_store, &link_ids, 4), vec![0, 0]);
+
+    schedule.delete_reservation(res_id);
+
+    assert!(!schedule.strategy.reserved_paths.contains_key(&res_id));
+    assert_eq!(get_link_loads(&resource_store, &link_ids, 2), vec![0, 0]);
+    assert_eq!(get_link_loads(&resource_store, &link_ids, 3), vec![0, 0]);
+    assert_eq!(schedule.get_slot_load(2), 0);
+}
+
+#[test]
+fn test_release_path_without_booked_path() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let (mut schedule, _, _) = get_network_schedule(clock.clone(), store.clone());
+    let res_id = add_link_reservation(&store, clock);
+
+    assert!(!schedule.release_path(res_id));
+}
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::resource::resource_store::ResourceStore;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::strategy::link::topology::{Link, NetworkTopology, Node, Path};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AciId, ResourceName, RouterId};
+
+fn link(id: &str, source: &str, target: &str, capacity: i64) -> Link {
+    return Link { id: ResourceName::new(id), source: RouterId::new(source), target: RouterId::new(target), capacity };
+}
+
+fn node(name: &str) -> Node {
+    return Node { name: ResourceName::new(name), cpus: 8, connected_to_router: vec![] };
+}
+
+fn get_topology(links: Vec<Link>) -> (NetworkTopology, ResourceStore) {
+    let resource_store = ResourceStore::new();
+    let nodes = vec![node("N1"), node("N2"), node("N3")];
+    let topology = NetworkTopology::new(
+        &links,
+        &nodes,
+        60,
+        10,
+        Arc::new(GlobalClock::new(true)),
+        AciId::new("AcI-001"),
+        ReservationStore::new(),
+        resource_store.clone(),
+    );
+    return (topology, resource_store);
+}
+
+fn get_link_names(path: &Path, resource_store: &ResourceStore) -> Vec<String> {
+    return path.network_links.iter().map(|link_id| resource_store.get_name(*link_id).id).collect();
+}
+
+/// The direct link N1 -> N3 has a low bandwidth, the route over R1 has two links with a high bandwidth.
+#[test]
+fn test_shortest_path_prefers_high_bandwidth_route() {
+    let (topology, resource_store) =
+        get_topology(vec![link("N1-N3", "N1", "N3", 10), link("N1-R1", "N1", "R1", 100), link("R1-N3", "R1", "N3", 100)]);
+
+    let path = topology.shortest_path(RouterId::new("N1"), RouterId::new("N3")).expect("No path found.");
+    assert_eq!(get_link_names(&path, &resource_store), vec!["N1-R1", "R1-N3"]);
+
+    // With a constant cost per link the number of hops is minimized
+    let path = topology.shortest_path_by(RouterId::new("N1"), RouterId::new("N3"), |_| 1.0).expect("No path found.");
+    assert_eq!(get_link_names(&path, &resource_store), vec!["N1-N3"]);
+}
+
+/// Both routes have a cost of 2 / 100, the route with fewer hops is preferred.
+#[test]
+fn test_shortest_path_prefers_fewer_hops_on_equal_cost() {
+    let (topology, resource_store) =
+        get_topology(vec![link("N1-N3", "N1", "N3", 50), link("N1-R1", "N1", "R1", 100), link("R1-N3", "R1", "N3", 100)]);
+
+    let path = topology.shortest_path(RouterId::new("N1"), RouterId::new("N3")).expect("No path found.");
+    assert_eq!(get_link_names(&path, &resource_store), vec!["N1-N3"]);
+}
+
+#[test]
+fn test_shortest_path_of_disconnected_routers() {
+    let (topology, _) = get_topology(vec![link("N1-N2", "N1", "N2", 100)]);
+
+    assert!(topology.shortest_path(RouterId::new("N1"), RouterId::new("N3")).is_none());
+    // Network links are directed
+    assert!(topology.shortest_path(RouterId::new("N2"), RouterId::new("N1")).is_none());
+    assert!(topology.shortest_path(RouterId::new("N1"), RouterId::new("N1")).expect("No path found.").network_links.is_empty());
+}
+
+fn is_loop_free(path: &Path, resource_store: &ResourceStore) -> bool {
+    let mut routers: Vec<RouterId> = path.network_links.iter().map(|link_id| resource_store.get_source(*link_id)).collect();
+    routers.push(resource_store.get_target(*path.network_links.last().unwrap()));
+
+    let number_of_routers = routers.len();
+    routers.sort();
+    routers.dedup();
+    return routers.len() == number_of_routers;
+}
+
+/// Five loop-free routes lead from N1 to N3:
+/// N1-R1-N3 (cost 0.02), N1-R1-R2-N3 and N1-R2-R1-N3 (0.031), N1-R2-N3 (0.04) and N1-N3 (0.1).
+#[test]
+fn test_k_shortest_paths_are_loop_free_and_sorted_by_cost() {
+    let (topology, resource_store) = get_topology(vec![
+        link("N1-N3", "N1", "N3", 10),
+        link("N1-R1", "N1", "R1", 100),
+        link("R1-N3", "R1", "N3", 100),
+        link("N1-R2", "N1", "R2", 50),
+        link("R2-N3", "R2", "N3", 50),
+        link("R1-R2", "R1", "R2", 1000),
+        link("R2-R1", "R2", "R1", 1000),
+    ]);
+
+    let paths = topology.k_shortest_paths(RouterId::new("N1"), RouterId::new("N3"), 10);
+    let path_names: Vec<Vec<String>> = paths.iter().map(|path| get_link_names(path, &resource_store)).collect();
+
+    assert_eq!(paths.len(), 5);
+    assert_eq!(path_names[0], vec!["N1-R1", "R1-N3"]);
+    assert!(path_names[1..3].contains(&vec!["N1-R1".to_string(), "R1-R2".to_string(), "R2-N3".to_string()]));
+    assert!(path_names[1..3].contains(&vec!["N1-R2".to_string(), "R2-R1".to_string(), "R1-N3".to_string()]));
+    assert_eq!(path_names[3], vec!["N1-R2", "R2-N3"]);
+    assert_eq!(path_names[4], vec!["N1-N3"]);
+
+    for path in &paths {
+        assert!(is_loop_free(path, &resource_store), "Path {:?} contains a loop.", path);
+    }
+
+    // Only the k cheapest paths are returned
+    let two_paths = topology.k_shortest_paths(RouterId::new("N1"), RouterId::new("N3"), 2);
+    assert_eq!(two_paths.iter().map(|path| get_link_names(path, &resource_store)).collect::<Vec<_>>(), path_names[..2].to_vec());
+
+    // The network schedule tries the cached paths in the same order
+    let cached_paths = topology.path_cache.get(&(RouterId::new("N1"), RouterId::new("N3"))).expect("No paths cached.");
+    assert_eq!(cached_paths.iter().map(|path| get_link_names(path, &resource_store)).collect::<Vec<_>>(), path_names);
+
+    assert!(topology.k_shortest_paths(RouterId::new("N3"), RouterId::new("N1"), 10).is_empty());
+}
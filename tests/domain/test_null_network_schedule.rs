@@ -0,0 +1,89 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::link_reservation::LinkReservation;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::probe_reservations::ProbeReservationComparator;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState, ReservationTrait};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::schedule::schedule_trait::Schedule;
+use vrm_rust_workflow::domain::vrm_system_model::scheduler_type::{ScheduleContext, SchedulerType};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ReservationName, RouterId, SlottedScheduleId};
+
+use crate::common::create_node_reservation;
+
+/// Adds a data dependency (LinkReservation) with 100 units of bandwidth, which may be transferred in [120, 600].
+fn add_data_dependency(store: &ReservationStore, clock: Arc<GlobalClock>) -> ReservationId {
+    let Reservation::Node(node_reservation) =
+        create_node_reservation(ReservationName::new("Data-Dependency"), 100, 120, 600, ReservationState::Open, clock)
+    else {
+        panic!("Expected NodeReservation");
+    };
+
+    return store.add(Reservation::Link(LinkReservation {
+        base: node_reservation.base,
+        start_point: Some(RouterId::new("R-Source")),
+        end_point: Some(RouterId::new("R-Target")),
+    }));
+}
+
+fn get_null_network_schedule(store: &ReservationStore, clock: Arc<GlobalClock>) -> Box<dyn Schedule> {
+    let schedule_context = ScheduleContext {
+        id: SlottedScheduleId::new("Null-Network"),
+        number_of_slots: 100,
+        slot_width: 60,
+        capacity: i64::MAX,
+        simulator: clock,
+        reservation_store: store.clone(),
+    };
+
+    return SchedulerType::NullNetworkSchedule.get_instance(schedule_context);
+}
+
+/// A data dependency is reserved instantly with zero duration and capacity and can be committed afterwards.
+#[test]
+fn test_null_network_schedule_commits_data_dependency_instantly() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let dependency_id = add_data_dependency(&store, clock.clone());
+    let mut schedule = get_null_network_schedule(&store, clock);
+
+    assert_eq!(schedule.reserve(dependency_id), Some(dependency_id));
+    assert_eq!(store.get_state(dependency_id), ReservationState::ReserveAnswer);
+
+    // The commit of the RMS only updates the state (see Rms::commit)
+    store.update_state(dependency_id, ReservationState::Committed);
+
+    assert_eq!(store.get_state(dependency_id), ReservationState::Committed);
+    assert_eq!(store.get_reserved_capacity(dependency_id), 0);
+    assert_eq!(store.get_assigned_start(dependency_id), 120);
+    assert_eq!(store.get_assigned_start(dependency_id), store.get_assigned_end(dependency_id));
+
+    // The network is never loaded
+    let load_metric = schedule.get_load_metric(0, 600);
+    assert_eq!(load_metric.avg_reserved_capacity, 0.0);
+    assert_eq!(load_metric.utilization, 0.0);
+}
+
+#[test]
+fn test_null_network_schedule_probe_returns_instant_candidate() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let dependency_id = add_data_dependency(&store, clock.clone());
+    let mut schedule = get_null_network_schedule(&store, clock);
+
+    let mut candidates = schedule.probe_best(dependency_id, ProbeReservationComparator::ESTReservationCompare);
+    assert_eq!(candidates.len(), 1);
+    assert_eq!(candidates.best_finish_time(), Some(120));
+    assert_eq!(store.get_state(dependency_id), ReservationState::ProbeAnswer);
+
+    let candidate = candidates.get_mut_reservations().pop().unwrap();
+    assert_eq!(candidate.get_reserved_capacity(), 0);
+    assert_eq!(candidate.get_task_duration(), 0);
+}
+
+#[test]
+fn test_slotted_schedule_null_network_is_parsed() {
+    let scheduler_type = SchedulerType::from_str("SlottedScheduleNullNetwork").expect("Unknown scheduler type.");
+    assert!(matches!(scheduler_type, SchedulerType::SlottedScheduleNullNetwork));
+}
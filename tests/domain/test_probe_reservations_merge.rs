@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::probe_reservations::{ProbeReservationComparator, ProbeReservations};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ComponentId, ReservationName};
+
+use crate::common::create_node_reservation;
+
+/// The ProbeReservations of both VrmComponents use the same local ids (`Job-1-0`, ...). After merging, every
+/// candidate must be kept and must still belong to the VrmComponent, which created it.
+#[test]
+fn test_merged_probe_reservations_keep_their_meta_data() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 400, ReservationState::Open, clock.clone()));
+
+    let mut probe_reservations = ProbeReservations::new(res_id, store.clone());
+
+    for (component_id, candidates) in [("AcI-001", vec![(240, 300)]), ("AcI-002", vec![(60, 120), (120, 180)])] {
+        let mut component_probe = ProbeReservations::new(res_id, store.clone());
+        for (start, end) in candidates {
+            let probe_res = create_node_reservation(ReservationName::new("Job-1"), 2, start, end, ReservationState::ProbeAnswer, clock.clone());
+            component_probe.add_reservation(probe_res).expect("Adding ProbeReservation failed.");
+        }
+        component_probe.add_probe_meta_data(ComponentId::new(component_id), None);
+        probe_reservations.add_probe_reservations(component_probe);
+    }
+
+    assert_eq!(probe_reservations.len(), 3);
+
+    let (component_id, shadow_schedule_id) =
+        probe_reservations.prompt_best(res_id, ProbeReservationComparator::EFTReservationCompare).expect("Promotion failed.");
+
+    assert_eq!(component_id, ComponentId::new("AcI-002"));
+    assert_eq!(shadow_schedule_id, None);
+    assert_eq!(store.get_assigned_end(res_id), 120);
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::probe_reservations::ProbeReservations;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ComponentId, ReservationName};
+
+use crate::common::create_node_reservation;
+
+/// Three candidates from two VrmComponents with the capacities 2, 4, 4 and the finish times 300, 120, 180.
+#[test]
+fn test_probe_reservations_summaries() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 400, ReservationState::Open, clock.clone()));
+
+    let mut probe_reservations = ProbeReservations::new(res_id, store.clone());
+    assert_eq!(probe_reservations.total_candidate_capacity(), 0);
+    assert_eq!(probe_reservations.best_finish_time(), None);
+
+    for (component_id, candidates) in [("AcI-001", vec![(2, 240, 300)]), ("AcI-002", vec![(4, 60, 120), (4, 120, 180)])] {
+        let mut component_probe = ProbeReservations::new(res_id, store.clone());
+        for (capacity, start, end) in candidates {
+            let probe_res =
+                create_node_reservation(ReservationName::new("Job-1"), capacity, start, end, ReservationState::ProbeAnswer, clock.clone());
+            component_probe.add_reservation(probe_res).expect("Adding ProbeReservation failed.");
+        }
+        component_probe.add_probe_meta_data(ComponentId::new(component_id), None);
+        probe_reservations.add_probe_reservations(component_probe);
+    }
+
+    assert_eq!(probe_reservations.len(), 3);
+    assert_eq!(probe_reservations.total_candidate_capacity(), 10);
+    assert_eq!(probe_reservations.best_finish_time(), Some(120));
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::probe_reservations::{ProbeReservationComparator, ProbeReservations};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ComponentId, ReservationName};
+
+use crate::common::create_node_reservation;
+
+/// The booking interval of the reservation is [0, 200]. "AcI-001" answers within the booking interval,
+/// "AcI-002" answers with an earlier finish, but starts before the booking interval.
+#[test]
+fn test_validate_against_booking_drops_out_of_window_probe() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 200, ReservationState::Open, clock.clone()));
+
+    let mut probe_reservations = ProbeReservations::new(res_id, store.clone());
+    for (component_id, start, end) in [("AcI-001", 100, 150), ("AcI-002", -50, 0)] {
+        let mut component_probe = ProbeReservations::new(res_id, store.clone());
+        let probe_res = create_node_reservation(ReservationName::new("Job-1"), 2, start, end, ReservationState::ProbeAnswer, clock.clone());
+        component_probe.add_reservation(probe_res).expect("Adding ProbeReservation failed.");
+        component_probe.add_probe_meta_data(ComponentId::new(component_id), None);
+        probe_reservations.add_probe_reservations(component_probe);
+    }
+    assert_eq!(probe_reservations.len(), 2);
+
+    assert_eq!(probe_reservations.validate_against_booking(&store), 1);
+    assert_eq!(probe_reservations.len(), 1);
+    assert_eq!(probe_reservations.best_finish_time(), Some(150));
+
+    let (component_id, _) = probe_reservations.prompt_best(res_id, ProbeReservationComparator::EFTReservationCompare).expect("Promotion failed.");
+    assert!(component_id.compare(&ComponentId::new("AcI-001")));
+
+    // A second validation does not drop the remaining valid probe
+    assert_eq!(probe_reservations.validate_against_booking(&store), 0);
+}
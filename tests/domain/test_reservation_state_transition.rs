@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ReservationName;
+use vrm_rust_workflow::error::Error;
+
+use crate::common::create_node_reservation;
+
+fn add_reservation(store: &ReservationStore, state: ReservationState) -> ReservationId {
+    let clock = Arc::new(GlobalClock::new(true));
+    return store.add(create_node_reservation(ReservationName::new("Test-Job"), 2, 0, 60, state, clock));
+}
+
+#[test]
+fn test_legal_lifecycle_transitions() {
+    let store = ReservationStore::new();
+    let res_id = add_reservation(&store, ReservationState::Open);
+
+    for new_state in [ReservationState::ProbeAnswer, ReservationState::ReserveAnswer, ReservationState::Committed, ReservationState::Finished] {
+        store.try_update_state(res_id, new_state).unwrap_or_else(|error| panic!("Transition to {:?} was rejected: {}", new_state, error));
+        assert_eq!(store.get_state(res_id), new_state);
+    }
+}
+
+#[test]
+fn test_legal_rejection_and_deletion() {
+    let store = ReservationStore::new();
+
+    let open_res_id = add_reservation(&store, ReservationState::Open);
+    assert!(store.try_update_state(open_res_id, ReservationState::Rejected).is_ok());
+
+    let reserved_res_id = add_reservation(&store, ReservationState::ReserveAnswer);
+    assert!(store.try_update_state(reserved_res_id, ReservationState::Deleted).is_ok());
+
+    let committed_res_id = add_reservation(&store, ReservationState::Committed);
+    assert!(store.try_update_state(committed_res_id, ReservationState::Deleted).is_ok());
+
+    // Staying in the same state is always allowed
+    assert!(store.try_update_state(committed_res_id, ReservationState::Deleted).is_ok());
+}
+
+/// Terminal states are never left, e.g. a rejected reservation can not be reopened.
+#[test]
+fn test_terminal_states_stay_terminal() {
+    let store = ReservationStore::new();
+
+    for terminal_state in [ReservationState::Rejected, ReservationState::Deleted, ReservationState::Finished] {
+        assert!(terminal_state.is_terminal());
+
+        let res_id = add_reservation(&store, terminal_state);
+        for new_state in [ReservationState::Open, ReservationState::ProbeAnswer, ReservationState::ReserveAnswer, ReservationState::Committed] {
+            assert!(store.try_update_state(res_id, new_state).is_err(), "{:?} -> {:?} must be illegal.", terminal_state, new_state);
+        }
+        assert_eq!(store.get_state(res_id), terminal_state);
+    }
+}
+
+/// The owner of a rejected reservation (e.g. a WorkflowScheduler retrying a CoAllocation) can explicitly reopen it.
+#[test]
+fn test_reopen_resets_terminal_reservation() {
+    let store = ReservationStore::new();
+    let res_id = add_reservation(&store, ReservationState::Rejected);
+
+    store.reopen(res_id);
+
+    assert_eq!(store.get_state(res_id), ReservationState::Open);
+    assert!(store.try_update_state(res_id, ReservationState::ReserveAnswer).is_ok());
+}
+
+/// Concurrent conflicting transitions of the same reservation: Only one of them may pass the transition check.
+#[test]
+fn test_concurrent_transitions_are_checked_atomically() {
+    for _ in 0..50 {
+        let store = ReservationStore::new();
+        let res_id = add_reservation(&store, ReservationState::ReserveAnswer);
+
+        let handles: Vec<_> = [ReservationState::Committed, ReservationState::Rejected]
+            .into_iter()
+            .map(|new_state| {
+                let store = store.clone();
+                std::thread::spawn(move || store.try_update_state(res_id, new_state).is_ok())
+            })
+            .collect();
+        let successful_transitions = handles.into_iter().map(|handle| handle.join().unwrap()).filter(|is_ok| *is_ok).count();
+
+        assert_eq!(successful_transitions, 1);
+    }
+}
+
+#[test]
+fn test_illegal_transitions_are_rejected() {
+    let store = ReservationStore::new();
+
+    for (from, to) in [
+        (ReservationState::Finished, ReservationState::Open),
+        (ReservationState::Rejected, ReservationState::Committed),
+        (ReservationState::Rejected, ReservationState::ReserveAnswer),
+        (ReservationState::Rejected, ReservationState::Open),
+        (ReservationState::Deleted, ReservationState::Open),
+        (ReservationState::Deleted, ReservationState::ReserveAnswer),
+        (ReservationState::Open, ReservationState::Committed),
+        (ReservationState::Committed, ReservationState::Open),
+    ] {
+        let res_id = add_reservation(&store, from);
+
+        match store.try_update_state(res_id, to) {
+            Err(Error::IllegalStateTransition { from: error_from, to: error_to }) => assert_eq!((error_from, error_to), (from, to)),
+            other => panic!("Expected IllegalStateTransition for {:?} -> {:?}, got {:?}", from, to, other),
+        }
+        assert_eq!(store.get_state(res_id), from, "State was changed by an illegal transition.");
+    }
+}
+
+#[test]
+fn test_update_state_forces_illegal_transition() {
+    let store = ReservationStore::new();
+    let res_id = add_reservation(&store, ReservationState::Finished);
+
+    store.update_state(res_id, ReservationState::Open);
+
+    assert_eq!(store.get_state(res_id), ReservationState::Open);
+}
@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::thread;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ReservationName;
+
+use crate::common::create_node_reservation;
+
+const NUMBER_OF_THREADS: usize = 16;
+const RESERVATIONS_PER_THREAD: usize = 50;
+
+/// Reservations added concurrently from several threads to one (shared) store receive unique ids.
+#[test]
+fn test_concurrent_add_allocates_unique_ids() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let handles: Vec<thread::JoinHandle<Vec<ReservationId>>> = (0..NUMBER_OF_THREADS)
+        .map(|thread_idx| {
+            let store = store.clone();
+            let clock = clock.clone();
+            thread::spawn(move || {
+                return (0..RESERVATIONS_PER_THREAD)
+                    .map(|res_idx| {
+                        let name = ReservationName::new(format!("Job-{}-{}", thread_idx, res_idx));
+                        store.add(create_node_reservation(name, 1, 0, 60, ReservationState::Open, clock.clone()))
+                    })
+                    .collect();
+            })
+        })
+        .collect();
+
+    let reservation_ids: Vec<ReservationId> = handles.into_iter().flat_map(|handle| handle.join().expect("Thread panicked.")).collect();
+    let unique_ids: HashSet<ReservationId> = reservation_ids.iter().cloned().collect();
+
+    assert_eq!(reservation_ids.len(), NUMBER_OF_THREADS * RESERVATIONS_PER_THREAD);
+    assert_eq!(unique_ids.len(), reservation_ids.len());
+    assert_eq!(store.count(), NUMBER_OF_THREADS * RESERVATIONS_PER_THREAD);
+
+    // Every id resolves to the reservation, which was added with it
+    for reservation_id in unique_ids {
+        assert!(store.get_name_for_key(reservation_id).is_some_and(|name| name.id.starts_with("Job-")));
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState, ReservationTrait};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ComponentId, ReservationName};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, create_node_reservation, get_direct_mapping_workflow_dto};
+
+fn get_workflow_subtask_ids(store: &ReservationStore, workflow_id: ReservationId) -> Vec<ReservationId> {
+    let workflow = store.get(workflow_id).expect("Workflow not found.");
+    match &*workflow.read().unwrap() {
+        Reservation::Workflow(workflow) => return workflow.get_all_reservation_ids(),
+        _ => panic!("Reservation is not a workflow."),
+    }
+}
+
+#[test]
+fn test_gc_terminal_removes_only_terminal_reservations() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let open_res_id = store.add(create_node_reservation(ReservationName::new("Job-Open"), 2, 0, 60, ReservationState::Open, clock.clone()));
+    let committed_res_id =
+        store.add(create_node_reservation(ReservationName::new("Job-Committed"), 2, 0, 60, ReservationState::Committed, clock.clone()));
+    let deleted_res_id = store.add(create_node_reservation(ReservationName::new("Job-Deleted"), 2, 0, 60, ReservationState::Open, clock.clone()));
+    let rejected_res_id = store.add(create_node_reservation(ReservationName::new("Job-Rejected"), 2, 0, 60, ReservationState::Open, clock.clone()));
+    let finished_res_id = store.add(create_node_reservation(ReservationName::new("Job-Finished"), 2, 0, 60, ReservationState::Committed, clock));
+
+    store.update_state(deleted_res_id, ReservationState::Deleted);
+    store.update_state(rejected_res_id, ReservationState::Rejected);
+    store.update_state(finished_res_id, ReservationState::Finished);
+    assert_eq!(store.count(), 5);
+
+    // A component still holding the reservation is not affected by the garbage collection
+    let deleted_handle = store.get(deleted_res_id).expect("Reservation not found.");
+
+    assert_eq!(store.gc_terminal(), 3);
+
+    assert_eq!(store.count(), 2);
+    let mut remaining_res_ids = store.iter_ids();
+    remaining_res_ids.sort();
+    let mut expected_res_ids = vec![open_res_id, committed_res_id];
+    expected_res_ids.sort();
+    assert_eq!(remaining_res_ids, expected_res_ids);
+
+    assert!(store.get_by_name(&ReservationName::new("Job-Deleted")).is_none());
+    assert_eq!(store.get_client_reservations(&ClientId::new("test_client")).len(), 2);
+    assert_eq!(deleted_handle.read().unwrap().get_name(), ReservationName::new("Job-Deleted"));
+
+    // Nothing is left to collect
+    assert_eq!(store.gc_terminal(), 0);
+    assert_eq!(store.count(), 2);
+}
+
+#[test]
+fn test_remove_returns_reservation() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::Open, clock));
+
+    let removed = store.remove(res_id).expect("Reservation must be removed.");
+
+    assert_eq!(removed.get_name(), ReservationName::new("Job-1"));
+    assert_eq!(store.count(), 0);
+    assert!(store.get_by_name(&ReservationName::new("Job-1")).is_none());
+    assert!(store.get_client_reservations(&ClientId::new("test_client")).is_empty());
+    assert!(store.remove(res_id).is_none());
+}
+
+#[test]
+fn test_gc_terminal_except_keeps_subtasks_of_live_workflows() {
+    let store = ReservationStore::new();
+    let workflow_id = Workflow::create_form_dto(
+        get_direct_mapping_workflow_dto("Test-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open),
+        ClientId::new("test_client"),
+        store.clone(),
+    )
+    .expect("Workflow construction failed.");
+
+    let number_of_reservations = store.count();
+    let finished_subtask_id = get_workflow_subtask_ids(&store, workflow_id)[0];
+    store.update_state(finished_subtask_id, ReservationState::Finished);
+
+    // The workflow is still running, hence its finished subtask must stay readable
+    assert_eq!(store.gc_terminal_except(&HashSet::new()), 0);
+    assert_eq!(store.get_state(finished_subtask_id), ReservationState::Finished);
+
+    // Once the whole workflow is terminal, its terminal subtasks are collected as well
+    store.update_state(workflow_id, ReservationState::Rejected);
+    assert_eq!(store.gc_terminal_except(&HashSet::new()), 2);
+    assert!(!store.contains(workflow_id));
+    assert!(!store.contains(finished_subtask_id));
+    assert_eq!(store.count(), number_of_reservations - 2);
+}
+
+#[test]
+fn test_gc_terminal_collects_subtasks_of_live_workflows() {
+    let store = ReservationStore::new();
+    let workflow_id = Workflow::create_form_dto(
+        get_direct_mapping_workflow_dto("Test-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open),
+        ClientId::new("test_client"),
+        store.clone(),
+    )
+    .expect("Workflow construction failed.");
+
+    let finished_subtask_id = get_workflow_subtask_ids(&store, workflow_id)[0];
+    store.update_state(finished_subtask_id, ReservationState::Finished);
+
+    assert_eq!(store.gc_terminal(), 1);
+    assert!(store.contains(workflow_id));
+    assert!(!store.contains(finished_subtask_id));
+}
+
+#[tokio::test]
+async fn test_gc_terminal_reservations_keeps_reservations_tracked_by_manager() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let adc = create_adc_with_dummy_aci(clock.clone(), store.clone()).await;
+
+    let tracked_res_id =
+        store.add(create_node_reservation(ReservationName::new("Job-Tracked"), 2, 0, 60, ReservationState::Committed, clock.clone()));
+    let untracked_res_id = store.add(create_node_reservation(ReservationName::new("Job-Untracked"), 2, 0, 60, ReservationState::Committed, clock));
+    store.update_state(tracked_res_id, ReservationState::Finished);
+    store.update_state(untracked_res_id, ReservationState::Finished);
+
+    let mut manager = adc.manager;
+    manager.register_allocation(tracked_res_id, ComponentId::new("AcI-Tracking"));
+
+    assert_eq!(manager.gc_terminal_reservations(), 1);
+    assert!(store.contains(tracked_res_id));
+    assert!(!store.contains(untracked_res_id));
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ReservationName};
+
+use crate::common::create_node_reservation;
+
+/// Two inserts with the same name must yield the same reservation, the second factory is never called.
+#[test]
+fn test_get_or_insert_by_name_reuses_existing_reservation() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let res_name = ReservationName::new("Fan-Out-Dependency");
+
+    let first_res_id =
+        store.get_or_insert_by_name(res_name.clone(), || create_node_reservation(res_name.clone(), 2, 0, 60, ReservationState::Open, clock.clone()));
+    let second_res_id = store.get_or_insert_by_name(res_name.clone(), || panic!("Factory must not be called for an existing name."));
+
+    assert_eq!(first_res_id, second_res_id);
+    assert_eq!(store.get_client_reservations(&ClientId::new("test_client")).len(), 1);
+    assert_eq!(store.get_reserved_capacity(second_res_id), 2);
+}
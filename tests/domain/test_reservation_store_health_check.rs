@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{HealthIssue, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_sync_gate::SyncRegistry;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ReservationName;
+
+use crate::common::create_node_reservation;
+
+/// A reservation left in `ReserveProbeReservation` by an aborted run must be reported, settled reservations not.
+#[test]
+fn test_health_check_reports_stuck_transient_state() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    store.add(create_node_reservation(ReservationName::new("Job-Open"), 2, 0, 60, ReservationState::Open, clock.clone()));
+    store.add(create_node_reservation(ReservationName::new("Job-Committed"), 2, 0, 60, ReservationState::Committed, clock.clone()));
+    assert!(store.health_check().is_empty());
+
+    let stuck_res_id =
+        store.add(create_node_reservation(ReservationName::new("Job-Stuck"), 2, 0, 60, ReservationState::ReserveProbeReservation, clock.clone()));
+
+    assert_eq!(
+        store.health_check(),
+        vec![HealthIssue::StuckTransientState {
+            reservation_id: stuck_res_id,
+            name: ReservationName::new("Job-Stuck"),
+            state: ReservationState::ReserveProbeReservation
+        }]
+    );
+
+    store.update_state(stuck_res_id, ReservationState::Rejected);
+    assert!(store.health_check().is_empty());
+}
+
+/// Gates are only valid, while their reservation waits in `ReserveProbeReservation` for the answer of an AcI.
+#[test]
+fn test_health_check_reports_orphaned_gate() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let sync_registry = SyncRegistry::new();
+
+    let waiting_res_id =
+        store.add(create_node_reservation(ReservationName::new("Job-Waiting"), 2, 0, 60, ReservationState::ReserveProbeReservation, clock.clone()));
+    let answered_res_id =
+        store.add(create_node_reservation(ReservationName::new("Job-Answered"), 2, 0, 60, ReservationState::ReserveAnswer, clock.clone()));
+    sync_registry.create_gate(waiting_res_id);
+    sync_registry.create_gate(answered_res_id);
+
+    assert_eq!(sync_registry.health_check(&store), vec![HealthIssue::OrphanedGate { reservation_id: answered_res_id }]);
+
+    sync_registry.remove_gate(answered_res_id);
+    assert!(sync_registry.health_check(&store).is_empty());
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ReservationName;
+
+use crate::common::create_node_reservation;
+
+#[test]
+fn test_iter_ids_and_count() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    assert_eq!(store.count(), 0);
+    assert!(store.iter_ids().is_empty());
+
+    let first_res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::Open, clock.clone()));
+    let second_res_id = store.add(create_node_reservation(ReservationName::new("Job-2"), 2, 0, 60, ReservationState::Open, clock));
+
+    assert_eq!(store.count(), 2);
+    let mut res_ids = store.iter_ids();
+    res_ids.sort();
+    let mut expected_res_ids = vec![first_res_id, second_res_id];
+    expected_res_ids.sort();
+    assert_eq!(res_ids, expected_res_ids);
+
+    store.remove(first_res_id);
+    assert_eq!(store.count(), 1);
+    assert_eq!(store.iter_ids(), vec![second_res_id]);
+}
+
+#[test]
+fn test_find_by_state() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let open_res_id = store.add(create_node_reservation(ReservationName::new("Job-Open"), 2, 0, 60, ReservationState::Open, clock.clone()));
+    let reserved_res_id =
+        store.add(create_node_reservation(ReservationName::new("Job-Reserved"), 2, 0, 60, ReservationState::ReserveAnswer, clock.clone()));
+    store.add(create_node_reservation(ReservationName::new("Job-Committed"), 2, 0, 60, ReservationState::Committed, clock));
+
+    assert_eq!(store.find_by_state(ReservationState::Open), vec![open_res_id]);
+    assert_eq!(store.find_by_state(ReservationState::ReserveAnswer), vec![reserved_res_id]);
+    assert!(store.find_by_state(ReservationState::Rejected).is_empty());
+
+    // After a roll back, no reservation remains in ReserveAnswer
+    store.update_state(reserved_res_id, ReservationState::Rejected);
+    assert!(store.find_by_state(ReservationState::ReserveAnswer).is_empty());
+    assert_eq!(store.find_by_state(ReservationState::Rejected), vec![reserved_res_id]);
+}
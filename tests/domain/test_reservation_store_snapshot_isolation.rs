@@ -0,0 +1,76 @@
+use std::sync::{Arc, RwLock};
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{ReservationState, ReservationTrait};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_notification_listener::ReservationNotificationListener;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ReservationName;
+
+use crate::common::create_node_reservation;
+
+#[derive(Debug, Default)]
+struct CountingListener {
+    notifications: usize,
+}
+
+impl ReservationNotificationListener for CountingListener {
+    fn on_reservation_change(&mut self, _: ReservationId, _: ReservationName, _: ReservationState, _: ReservationState) {
+        self.notifications += 1;
+    }
+}
+
+/// Mutations of a reservation in the snapshot must not be visible in the original store and vice versa.
+#[test]
+fn test_snapshot_mutations_do_not_leak_into_original() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::Open, clock));
+
+    let snapshot = store.snapshot();
+    snapshot.update_state(res_id, ReservationState::ReserveAnswer);
+    snapshot.with_reservation_mut(res_id, |reservation| reservation.set_reserved_capacity(8));
+
+    assert_eq!(snapshot.get_state(res_id), ReservationState::ReserveAnswer);
+    assert_eq!(snapshot.get_reserved_capacity(res_id), 8);
+    assert_eq!(store.get_state(res_id), ReservationState::Open);
+    assert_eq!(store.get_reserved_capacity(res_id), 2);
+
+    store.update_state(res_id, ReservationState::Rejected);
+
+    assert_eq!(snapshot.get_state(res_id), ReservationState::ReserveAnswer);
+}
+
+/// Reservations added to or removed from the snapshot do not change the original store.
+#[test]
+fn test_snapshot_structure_is_independent() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::Open, clock.clone()));
+
+    let snapshot = store.snapshot();
+    snapshot.remove(res_id);
+    let new_res_id = snapshot.add(create_node_reservation(ReservationName::new("Job-2"), 4, 60, 120, ReservationState::Open, clock));
+
+    assert!(snapshot.get(res_id).is_none());
+    assert!(store.get(res_id).is_some());
+    assert!(store.get(new_res_id).is_none());
+}
+
+/// State changes in the snapshot must not be reported to the listeners of the original store.
+#[test]
+fn test_snapshot_does_not_notify_listeners_of_original() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::Open, clock));
+
+    let listener = Arc::new(RwLock::new(CountingListener::default()));
+    store.add_listener(listener.clone());
+
+    let snapshot = store.snapshot();
+    snapshot.update_state(res_id, ReservationState::ReserveAnswer);
+    snapshot.update_state(res_id, ReservationState::Committed);
+    assert_eq!(listener.read().unwrap().notifications, 0);
+
+    store.update_state(res_id, ReservationState::ReserveAnswer);
+    assert_eq!(listener.read().unwrap().notifications, 1);
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::link_reservation::LinkReservation;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ReservationName, RouterId};
+
+use crate::common::create_node_reservation;
+
+/// Adds one NodeReservation and one LinkReservation (from router R-1 to R-2) to the store.
+fn add_node_and_link(store: &ReservationStore) -> (ReservationId, ReservationId) {
+    let clock = Arc::new(GlobalClock::new(true));
+    let node_res_id = store.add(create_node_reservation(ReservationName::new("Node-Job"), 2, 0, 60, ReservationState::Open, clock.clone()));
+
+    let Reservation::Node(node_reservation) = create_node_reservation(ReservationName::new("Link-Job"), 10, 0, 60, ReservationState::Open, clock)
+    else {
+        panic!("Expected NodeReservation");
+    };
+    let link_reservation =
+        LinkReservation { base: node_reservation.base, start_point: Some(RouterId::new("R-1")), end_point: Some(RouterId::new("R-2")) };
+    let link_res_id = store.add(Reservation::Link(link_reservation));
+
+    return (node_res_id, link_res_id);
+}
+
+#[test]
+fn test_typed_snapshots_return_owned_values() {
+    let mut store = ReservationStore::new();
+    let (node_res_id, link_res_id) = add_node_and_link(&store);
+
+    let node_reservation = store.get_node_reservation_snapshot(node_res_id).expect("NodeReservation expected.");
+    assert_eq!(node_reservation.base.name, ReservationName::new("Node-Job"));
+    assert_eq!(node_reservation.base.reserved_capacity, 2);
+
+    let link_reservation = store.get_link_reservation_snapshot(link_res_id).expect("LinkReservation expected.");
+    assert_eq!(link_reservation.base.name, ReservationName::new("Link-Job"));
+    assert_eq!(link_reservation.start_point, Some(RouterId::new("R-1")));
+    assert_eq!(link_reservation.end_point, Some(RouterId::new("R-2")));
+
+    // The snapshot is detached from the store
+    store.set_reserved_capacity(node_res_id, 8);
+    assert_eq!(node_reservation.base.reserved_capacity, 2);
+}
+
+#[test]
+fn test_typed_snapshots_return_none_on_type_mismatch() {
+    let store = ReservationStore::new();
+    let (node_res_id, link_res_id) = add_node_and_link(&store);
+
+    assert!(store.get_link_reservation_snapshot(node_res_id).is_none());
+    assert!(store.get_node_reservation_snapshot(link_res_id).is_none());
+}
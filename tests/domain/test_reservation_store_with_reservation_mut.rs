@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{ReservationState, ReservationTrait};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ReservationName;
+
+use crate::common::create_node_reservation;
+
+/// Several fields are modified within one closure, the result of the closure is returned.
+#[test]
+fn test_with_reservation_mut_applies_all_mutations() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::Open, clock));
+
+    let task_duration = store.with_reservation_mut(res_id, |reservation| {
+        reservation.set_booking_interval_start(100);
+        reservation.set_booking_interval_end(500);
+        reservation.set_reserved_capacity(4);
+        reservation.get_task_duration()
+    });
+
+    assert_eq!(task_duration, Some(store.get_task_duration(res_id)));
+    assert_eq!(store.get_booking_interval_start(res_id), 100);
+    assert_eq!(store.get_booking_interval_end(res_id), 500);
+    assert_eq!(store.get_reserved_capacity(res_id), 4);
+}
+
+#[test]
+fn test_with_reservation_mut_of_removed_reservation() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::Open, clock));
+    store.remove(res_id);
+
+    let mut is_called = false;
+    assert_eq!(store.with_reservation_mut(res_id, |_| is_called = true), None);
+    assert!(!is_called);
+}
@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::schedule::schedule_trait::Schedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::SlottedNodeSchedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::strategy::node::node_strategy::NodeStrategy;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ReservationName, SlottedScheduleId};
+
+use crate::common::create_node_reservation;
+
+const BOOKING_INTERVAL_END: i64 = 600;
+
+fn get_slotted_node_schedule(clock: Arc<GlobalClock>, store: ReservationStore) -> SlottedNodeSchedule {
+    // 10 slots of 60s with a capacity of 10
+    return SlottedNodeSchedule::new(SlottedScheduleId::new("Test-Schedule"), 10, 60, 10, false, NodeStrategy::default(), store, clock);
+}
+
+/// Adds an open reservation with the given duration, which can be placed anywhere in [0, BOOKING_INTERVAL_END].
+fn add_queued_reservation(store: &mut ReservationStore, clock: Arc<GlobalClock>, name: &str, capacity: i64, duration: i64) -> ReservationId {
+    let id = store.add(create_node_reservation(ReservationName::new(name), capacity, 0, duration, ReservationState::Open, clock));
+    store.set_booking_interval_end(id, BOOKING_INTERVAL_END);
+    return id;
+}
+
+/// A running reservation occupies 8 of 10 capacity in [0, 120), hence the large job (capacity 10) is blocked until 120.
+/// The small job (capacity 2, 60s) is backfilled into the idle capacity at 0 and the large job still starts at 120.
+#[test]
+fn test_small_job_backfilled_before_large_job_without_delay() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let mut store = ReservationStore::new();
+    let mut schedule = get_slotted_node_schedule(clock.clone(), store.clone());
+
+    let running = store.add(create_node_reservation(ReservationName::new("Running"), 8, 0, 120, ReservationState::Open, clock.clone()));
+    assert_eq!(schedule.reserve(running), Some(running));
+
+    let large_job = add_queued_reservation(&mut store, clock.clone(), "Large-Job", 10, 120);
+    let small_job = add_queued_reservation(&mut store, clock.clone(), "Small-Job", 2, 60);
+    let pivot_start = 120;
+
+    assert_eq!(schedule.find_backfill_slot(small_job, 0, pivot_start), Some(0));
+    assert_eq!(schedule.reserve_with_backfill(small_job, pivot_start), Some(small_job));
+    assert_eq!(store.get_assigned_start(small_job), 0);
+    assert_eq!(store.get_assigned_end(small_job), 60);
+    assert_eq!(store.get_state(small_job), ReservationState::ReserveAnswer);
+    assert_eq!(schedule.get_slot_load(0), 10);
+
+    assert_eq!(schedule.reserve(large_job), Some(large_job));
+    assert_eq!(store.get_assigned_start(large_job), pivot_start, "The backfilled job must not delay the large job.");
+}
+
+/// A job, which would still run at the planned start of the large job, is not backfilled.
+#[test]
+fn test_backfill_respects_reservation_floor() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let mut store = ReservationStore::new();
+    let mut schedule = get_slotted_node_schedule(clock.clone(), store.clone());
+
+    let running = store.add(create_node_reservation(ReservationName::new("Running"), 8, 0, 120, ReservationState::Open, clock.clone()));
+    assert_eq!(schedule.reserve(running), Some(running));
+
+    let long_job = add_queued_reservation(&mut store, clock.clone(), "Long-Small-Job", 2, 180);
+
+    assert_eq!(schedule.find_backfill_slot(long_job, 0, 120), None);
+    assert_eq!(schedule.reserve_with_backfill(long_job, 120), None);
+    assert_eq!(store.get_state(long_job), ReservationState::Open);
+    assert_eq!(schedule.get_slot_load(0), 8);
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::schedule::schedule_trait::Schedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::SlottedNodeSchedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::strategy::node::node_strategy::NodeStrategy;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ReservationName, SlottedScheduleId};
+
+use crate::common::create_node_reservation;
+
+fn get_slotted_node_schedule(clock: Arc<GlobalClock>, store: ReservationStore) -> SlottedNodeSchedule {
+    // 10 slots of 60s with a capacity of 10
+    return SlottedNodeSchedule::new(SlottedScheduleId::new("Test-Schedule"), 10, 60, 10, false, NodeStrategy::default(), store, clock);
+}
+
+/// Reserves a reservation in [start, end), which may be placed anywhere after `booking_interval_start`.
+#[allow(clippy::too_many_arguments)]
+fn reserve_at(
+    schedule: &mut SlottedNodeSchedule,
+    store: &mut ReservationStore,
+    clock: Arc<GlobalClock>,
+    name: &str,
+    capacity: i64,
+    (start, end): (i64, i64),
+    booking_interval_start: i64,
+    is_moveable: bool,
+) -> ReservationId {
+    let id = store.add(create_node_reservation(ReservationName::new(name), capacity, start, end, ReservationState::Open, clock));
+    store.set_booking_interval_start(id, booking_interval_start);
+    store.set_is_moveable(id, is_moveable);
+    schedule.reserve_without_check(id);
+    return id;
+}
+
+/// The moveable jobs in slot 2 and 4 leave only holes of one slot, so a job requiring 8 of 10 capacity for 3 slots
+/// does not fit before 360. After the compaction, both jobs are shifted earlier and the large job fits at 180.
+#[test]
+fn test_compaction_coalesces_free_capacity() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let mut store = ReservationStore::new();
+    let mut schedule = get_slotted_node_schedule(clock.clone(), store.clone());
+
+    reserve_at(&mut schedule, &mut store, clock.clone(), "Running", 10, (0, 60), 0, true);
+    let job_b = reserve_at(&mut schedule, &mut store, clock.clone(), "Job-B", 6, (120, 180), 0, true);
+    let job_c = reserve_at(&mut schedule, &mut store, clock.clone(), "Job-C", 6, (240, 300), 0, true);
+    let fixed_job = reserve_at(&mut schedule, &mut store, clock.clone(), "Fixed-Job", 4, (420, 480), 0, false);
+
+    let large_job = store.add(create_node_reservation(ReservationName::new("Large-Job"), 8, 0, 180, ReservationState::Open, clock.clone()));
+    store.set_booking_interval_end(large_job, 360);
+    assert!(schedule.calculate_schedule(large_job).is_empty(), "The large job must not fit into the fragmented schedule.");
+
+    let moved = schedule.compact();
+
+    assert_eq!(moved, vec![(job_b, 120, 60), (job_c, 240, 120)]);
+    assert_eq!(store.get_assigned_start(fixed_job), 420, "Not moveable reservations must keep their placement.");
+    assert_eq!(store.get_state(job_b), ReservationState::ReserveAnswer);
+    for slot in &schedule.slots {
+        assert!(slot.load <= slot.capacity);
+    }
+
+    assert_eq!(schedule.reserve(large_job), Some(large_job));
+    assert_eq!(store.get_assigned_start(large_job), 180);
+}
+
+/// A reservation is never moved before its booking interval start.
+#[test]
+fn test_compaction_respects_booking_interval_start() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let mut store = ReservationStore::new();
+    let mut schedule = get_slotted_node_schedule(clock.clone(), store.clone());
+
+    let job = reserve_at(&mut schedule, &mut store, clock.clone(), "Job", 5, (300, 360), 200, true);
+
+    assert_eq!(schedule.compact(), vec![(job, 300, 200)]);
+    assert_eq!(store.get_assigned_end(job), 260);
+    assert_eq!(store.get_booking_interval_start(job), 200);
+}
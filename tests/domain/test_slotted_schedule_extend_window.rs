@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::schedule_trait::Schedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::SlottedNodeSchedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::strategy::node::node_strategy::NodeStrategy;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ReservationName, SlottedScheduleId};
+
+use crate::common::create_node_reservation;
+
+fn get_slotted_node_schedule(clock: Arc<GlobalClock>, store: ReservationStore) -> SlottedNodeSchedule {
+    // 10 slots of 60s with a capacity of 10, the scheduling window ends at 599
+    return SlottedNodeSchedule::new(SlottedScheduleId::new("Test-Schedule"), 10, 60, 10, false, NodeStrategy::default(), store, clock);
+}
+
+/// A job starting after the original window end is rejected, after extending the window it is reserved.
+/// The reservation made before the extension keeps its slots.
+#[test]
+fn test_reserve_past_original_window_end_after_extension() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut schedule = get_slotted_node_schedule(clock.clone(), store.clone());
+
+    let early_job = store.add(create_node_reservation(ReservationName::new("Early-Job"), 4, 180, 300, ReservationState::Open, clock.clone()));
+    assert_eq!(schedule.reserve(early_job), Some(early_job));
+
+    let late_job = store.add(create_node_reservation(ReservationName::new("Late-Job"), 6, 600, 720, ReservationState::Open, clock.clone()));
+    assert_eq!(schedule.reserve(late_job), None, "The late job ends after the original scheduling window.");
+
+    schedule.extend_window(5);
+
+    assert_eq!(schedule.slots.len(), 15);
+    assert_eq!(schedule.end_slot_index, 14);
+    assert_eq!(schedule.scheduling_window_end_time, 899);
+    for slot_index in 3..=4 {
+        assert_eq!(schedule.get_slot_load(slot_index), 4, "Existing reservation must be preserved in slot {}.", slot_index);
+        assert!(schedule.get_slot(slot_index).unwrap().reservation_ids.contains(&early_job));
+    }
+
+    store.update_state(late_job, ReservationState::Open);
+    assert_eq!(schedule.reserve(late_job), Some(late_job));
+    assert_eq!(store.get_assigned_start(late_job), 600);
+    assert_eq!(schedule.get_slot_load(10), 6);
+    assert_eq!(schedule.get_slot_load(11), 6);
+    assert_eq!(schedule.get_slot_load(12), 0);
+}
+
+/// The window [0, 10] wraps around, as the virtual slot 10 maps onto the real slot 0. Extending the window must
+/// not copy the load of slot 0 into the new virtual slot 10.
+#[test]
+fn test_extension_does_not_duplicate_slot_zero() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut schedule = get_slotted_node_schedule(clock.clone(), store.clone());
+
+    let first_job = store.add(create_node_reservation(ReservationName::new("First-Job"), 4, 0, 120, ReservationState::Open, clock.clone()));
+    assert_eq!(schedule.reserve(first_job), Some(first_job));
+    assert_eq!(schedule.get_slot_load(0), 4);
+
+    schedule.end_slot_index = 10;
+    schedule.extend_window(5);
+
+    assert_eq!(schedule.get_slot_load(0), 4);
+    assert_eq!(schedule.get_slot_load(1), 4);
+    assert_eq!(schedule.get_slot_load(10), 0, "The load of slot 0 was copied into the new slot 10.");
+    assert!(!schedule.get_slot(10).unwrap().reservation_ids.contains(&first_job));
+
+    let full_job = store.add(create_node_reservation(ReservationName::new("Full-Job"), 10, 600, 660, ReservationState::Open, clock.clone()));
+    assert_eq!(schedule.reserve(full_job), Some(full_job));
+    assert_eq!(store.get_assigned_start(full_job), 600);
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::schedule_trait::Schedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::SlottedNodeSchedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::strategy::node::node_strategy::NodeStrategy;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ReservationName, SlottedScheduleId};
+
+use crate::common::create_node_reservation;
+
+fn get_slotted_node_schedule(clock: Arc<GlobalClock>, store: ReservationStore) -> SlottedNodeSchedule {
+    // 4 slots of 60s with a capacity of 1
+    return SlottedNodeSchedule::new(SlottedScheduleId::new("Test-Schedule"), 4, 60, 1, false, NodeStrategy::default(), store, clock);
+}
+
+fn assert_fragmentation(actual: &[(i64, f64)], expected: &[(i64, f64)]) {
+    assert_eq!(actual.len(), expected.len());
+    for ((actual_index, actual_frag), (expected_index, expected_frag)) in actual.iter().zip(expected) {
+        assert_eq!(actual_index, expected_index);
+        assert!((actual_frag - expected_frag).abs() < 1e-9, "Slot {}: expected {}, got {}", actual_index, expected_frag, actual_frag);
+    }
+}
+
+/// An empty schedule consists of one free block and is not fragmented.
+#[test]
+fn test_fragmentation_by_slot_empty_schedule() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let schedule = get_slotted_node_schedule(clock, store);
+
+    assert_fragmentation(&schedule.fragmentation_by_slot(), &[(0, 0.0), (1, 0.0), (2, 0.0), (3, 0.0)]);
+}
+
+/// A reservation in slot 1 splits the free capacity into the blocks [0] and [2, 3] (3 free slots in total).
+/// Slot 0 is in the small block (1 - 1/3), slots 2 and 3 in the large block (1 - 2/3), slot 1 is fully occupied.
+#[test]
+fn test_fragmentation_by_slot_locates_hotspot() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut schedule = get_slotted_node_schedule(clock.clone(), store.clone());
+
+    let reservation = store.add(create_node_reservation(ReservationName::new("Splitter"), 1, 60, 120, ReservationState::Open, clock));
+    assert_eq!(schedule.reserve(reservation), Some(reservation));
+
+    let system_fragmentation = schedule.get_system_fragmentation();
+    let is_frag_cache_up_to_date = schedule.is_frag_cache_up_to_date;
+
+    assert_fragmentation(&schedule.fragmentation_by_slot(), &[(0, 2.0 / 3.0), (1, 0.0), (2, 1.0 / 3.0), (3, 1.0 / 3.0)]);
+
+    assert_eq!(schedule.is_frag_cache_up_to_date, is_frag_cache_up_to_date);
+    assert_eq!(schedule.fragmentation_cache, system_fragmentation);
+}
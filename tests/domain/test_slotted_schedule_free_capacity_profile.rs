@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::schedule_trait::Schedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::SlottedNodeSchedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::strategy::node::node_strategy::NodeStrategy;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ReservationName, SlottedScheduleId};
+
+use crate::common::create_node_reservation;
+
+fn get_slotted_node_schedule(clock: Arc<GlobalClock>, store: ReservationStore) -> SlottedNodeSchedule {
+    // 10 slots of 60s with a capacity of 10
+    return SlottedNodeSchedule::new(SlottedScheduleId::new("Test-Schedule"), 10, 60, 10, false, NodeStrategy::default(), store, clock);
+}
+
+/// A job with capacity 4 in [120, 240] reduces the free capacity of the slots 2 and 3 only.
+#[test]
+fn test_profile_dips_during_reservation() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut schedule = get_slotted_node_schedule(clock.clone(), store.clone());
+
+    let job = store.add(create_node_reservation(ReservationName::new("Mid-Job"), 4, 120, 240, ReservationState::Open, clock));
+    assert_eq!(schedule.reserve(job), Some(job));
+
+    let profile = schedule.free_capacity_profile(0, 359);
+
+    assert_eq!(profile, vec![(0, 10), (1, 10), (2, 6), (3, 6), (4, 10), (5, 10)]);
+}
+
+#[test]
+fn test_profile_is_clamped_to_scheduling_window() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let schedule = get_slotted_node_schedule(clock, ReservationStore::new());
+
+    let profile = schedule.free_capacity_profile(540, 100_000);
+
+    assert_eq!(profile.first(), Some(&(9, 10)));
+    assert!(profile.iter().all(|(slot_index, _)| *slot_index <= schedule.end_slot_index));
+}
+
+#[test]
+fn test_profile_outside_of_scheduling_window_is_empty() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let schedule = get_slotted_node_schedule(clock, ReservationStore::new());
+
+    assert!(schedule.free_capacity_profile(100_000, 200_000).is_empty());
+    assert!(schedule.free_capacity_profile(300, 0).is_empty());
+}
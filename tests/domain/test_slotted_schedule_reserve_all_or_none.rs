@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::schedule::schedule_trait::Schedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::SlottedNodeSchedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::strategy::node::node_strategy::NodeStrategy;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ReservationName, SlottedScheduleId};
+
+use crate::common::create_node_reservation;
+
+fn get_slotted_node_schedule(clock: Arc<GlobalClock>, store: ReservationStore) -> SlottedNodeSchedule {
+    // 10 slots of 60s with a capacity of 10
+    return SlottedNodeSchedule::new(SlottedScheduleId::new("Test-Schedule"), 10, 60, 10, false, NodeStrategy::default(), store, clock);
+}
+
+fn add_open_reservation(store: &ReservationStore, clock: Arc<GlobalClock>, name: &str, capacity: i64, start: i64, end: i64) -> ReservationId {
+    return store.add(create_node_reservation(ReservationName::new(name), capacity, start, end, ReservationState::Open, clock));
+}
+
+/// Two members request 6 of 10 capacity in the same window, the group does not fit.
+/// The third member would fit on its own, but must not be placed either.
+#[test]
+fn test_reserve_all_or_none_group_does_not_fit() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut schedule = get_slotted_node_schedule(clock.clone(), store.clone());
+
+    let member_0 = add_open_reservation(&store, clock.clone(), "Member-0", 6, 0, 60);
+    let member_1 = add_open_reservation(&store, clock.clone(), "Member-1", 6, 0, 60);
+    let member_2 = add_open_reservation(&store, clock.clone(), "Member-2", 2, 120, 180);
+
+    let group = [(member_2, (120, 180)), (member_0, (0, 60)), (member_1, (0, 60))];
+
+    assert!(!schedule.can_reserve_all(&group));
+    assert!(!schedule.reserve_all_or_none(&group), "Group exceeds the slot capacity and must be rejected.");
+
+    for slot in &schedule.slots {
+        assert_eq!(slot.load, 0, "No partial placement may be left in the slots.");
+        assert!(slot.reservation_ids.is_empty());
+    }
+    assert!(schedule.active_reservations.is_empty());
+
+    for (member_id, _) in group {
+        assert_eq!(store.get_state(member_id), ReservationState::Open);
+    }
+}
+
+/// All members fit together and are reserved in their windows.
+#[test]
+fn test_reserve_all_or_none_group_fits() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut schedule = get_slotted_node_schedule(clock.clone(), store.clone());
+
+    let member_0 = add_open_reservation(&store, clock.clone(), "Member-0", 6, 0, 60);
+    let member_1 = add_open_reservation(&store, clock.clone(), "Member-1", 4, 0, 60);
+
+    assert!(schedule.reserve_all_or_none(&[(member_0, (0, 60)), (member_1, (0, 60))]));
+
+    assert_eq!(schedule.get_slot_load(0), 10);
+    assert_eq!(store.get_state(member_0), ReservationState::ReserveAnswer);
+    assert_eq!(store.get_state(member_1), ReservationState::ReserveAnswer);
+}
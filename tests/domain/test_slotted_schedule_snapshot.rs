@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::schedule_trait::Schedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::SlottedNodeSchedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::strategy::node::node_strategy::NodeStrategy;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ReservationName, SlottedScheduleId};
+
+use crate::common::create_node_reservation;
+
+fn get_slotted_node_schedule(clock: Arc<GlobalClock>, store: ReservationStore) -> SlottedNodeSchedule {
+    // 10 slots of 60s with a capacity of 10
+    return SlottedNodeSchedule::new(SlottedScheduleId::new("Test-Schedule"), 10, 60, 10, false, NodeStrategy::default(), store, clock);
+}
+
+/// A reservation placed after the snapshot is removed from the slots by the restore,
+/// the reservation placed before the snapshot is kept.
+#[test]
+fn test_restore_rolls_back_placement() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut schedule = get_slotted_node_schedule(clock.clone(), store.clone());
+
+    let first_job = store.add(create_node_reservation(ReservationName::new("First-Job"), 4, 0, 120, ReservationState::Open, clock.clone()));
+    assert_eq!(schedule.reserve(first_job), Some(first_job));
+
+    let snapshot = schedule.snapshot();
+
+    let second_job = store.add(create_node_reservation(ReservationName::new("Second-Job"), 6, 60, 180, ReservationState::Open, clock.clone()));
+    assert_eq!(schedule.reserve(second_job), Some(second_job));
+    assert_eq!(schedule.get_slot_load(1), 10);
+
+    schedule.restore(snapshot.clone());
+
+    assert_eq!(schedule.slots.len(), snapshot.slots.len());
+    for (slot, snapshot_slot) in schedule.slots.iter().zip(&snapshot.slots) {
+        assert_eq!(slot.load, snapshot_slot.load);
+        assert_eq!(slot.capacity, snapshot_slot.capacity);
+        assert_eq!(slot.reservation_ids, snapshot_slot.reservation_ids);
+    }
+    assert_eq!(schedule.get_slot_load(0), 4);
+    assert_eq!(schedule.get_slot_load(1), 4);
+    assert_eq!(schedule.get_slot_load(2), 0);
+    assert!(schedule.active_reservations.contains_key(&first_job));
+    assert!(!schedule.active_reservations.contains_key(&second_job));
+}
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::event_queue::SimEvent;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::schedule_trait::Schedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::SlottedNodeSchedule;
+use vrm_rust_workflow::domain::vrm_system_model::schedule::slotted_schedule::strategy::node::node_strategy::NodeStrategy;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ReservationName, SlottedScheduleId};
+
+use crate::common::create_node_reservation;
+
+fn get_slotted_node_schedule(clock: Arc<GlobalClock>) -> SlottedNodeSchedule {
+    return get_slotted_node_schedule_with_store(clock, ReservationStore::new());
+}
+
+fn get_slotted_node_schedule_with_store(clock: Arc<GlobalClock>, store: ReservationStore) -> SlottedNodeSchedule {
+    // 10 slots of 60s with a capacity of 10
+    return SlottedNodeSchedule::new(SlottedScheduleId::new("Test-Schedule"), 10, 60, 10, false, NodeStrategy::default(), store, clock);
+}
+
+#[test]
+fn test_time_to_slot_index_floors_to_slot_start() {
+    let schedule = get_slotted_node_schedule(Arc::new(GlobalClock::new(true)));
+
+    assert_eq!(schedule.time_to_slot_index(0), 0);
+    assert_eq!(schedule.time_to_slot_index(59), 0);
+    assert_eq!(schedule.time_to_slot_index(60), 1);
+    assert_eq!(schedule.time_to_slot_index(119), 1);
+    assert_eq!(schedule.time_to_slot_index(599), 9);
+}
+
+#[test]
+fn test_slot_index_to_time_returns_slot_start() {
+    let schedule = get_slotted_node_schedule(Arc::new(GlobalClock::new(true)));
+
+    assert_eq!(schedule.slot_index_to_time(0), 0);
+    assert_eq!(schedule.slot_index_to_time(1), 60);
+    assert_eq!(schedule.slot_index_to_time(9), 540);
+}
+
+/// An exclusive end time on a slot edge must not occupy the following slot.
+#[test]
+fn test_exclusive_end_time_on_slot_edge() {
+    let schedule = get_slotted_node_schedule(Arc::new(GlobalClock::new(true)));
+
+    assert_eq!(schedule.time_to_slot_index(120 - 1), 1);
+    assert_eq!(schedule.time_to_slot_index(121 - 1), 2);
+}
+
+#[test]
+fn test_round_trip_is_aligned_to_slot_start() {
+    let schedule = get_slotted_node_schedule(Arc::new(GlobalClock::new(true)));
+
+    for index in 0..10 {
+        assert_eq!(schedule.time_to_slot_index(schedule.slot_index_to_time(index)), index);
+    }
+    assert_eq!(schedule.slot_index_to_time(schedule.time_to_slot_index(150)), 120);
+}
+
+/// After the window moved to slot 2 (start time 120), earlier times map to indices before the window,
+/// which are not clamped and not resolvable by `get_slot`.
+#[test]
+fn test_times_before_window_start() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let mut schedule = get_slotted_node_schedule(clock.clone());
+
+    clock.schedule_event(150, SimEvent::ReservationEnd(Default::default()));
+    clock.advance_to_next_event();
+    schedule.update();
+
+    assert_eq!(schedule.start_slot_index, 2);
+    assert_eq!(schedule.scheduling_window_start_time, 120);
+
+    assert_eq!(schedule.time_to_slot_index(120), 2);
+    assert_eq!(schedule.time_to_slot_index(119), 1);
+    assert_eq!(schedule.time_to_slot_index(-1), -1);
+    assert_eq!(schedule.slot_index_to_time(1), 60);
+    assert!(schedule.get_slot(schedule.time_to_slot_index(119)).is_none());
+    assert!(schedule.get_slot(schedule.time_to_slot_index(120)).is_some());
+}
+
+/// A schedule created at time 150 starts its window at the beginning of slot 2 (120). Reserve and delete map the
+/// times of a job in [180, 300) to the slots 3 and 4 of this window.
+#[test]
+fn test_reserve_and_delete_in_window_created_after_time_zero() {
+    let clock = Arc::new(GlobalClock::new(true));
+    clock.schedule_event(150, SimEvent::ReservationEnd(Default::default()));
+    clock.advance_to_next_event();
+
+    let store = ReservationStore::new();
+    let mut schedule = get_slotted_node_schedule_with_store(clock.clone(), store.clone());
+
+    assert_eq!(schedule.start_slot_index, 2);
+    assert_eq!(schedule.end_slot_index, 11);
+    assert_eq!(schedule.scheduling_window_start_time, 120);
+    assert_eq!(schedule.scheduling_window_end_time, 719);
+
+    let job = store.add(create_node_reservation(ReservationName::new("Job"), 4, 180, 300, ReservationState::Open, clock));
+    assert_eq!(schedule.reserve(job), Some(job));
+    assert_eq!(schedule.free_capacity_profile(120, 359), vec![(2, 10), (3, 6), (4, 6), (5, 10)]);
+
+    schedule.delete_reservation(job);
+    assert_eq!(schedule.free_capacity_profile(120, 359), vec![(2, 10), (3, 10), (4, 10), (5, 10)]);
+}
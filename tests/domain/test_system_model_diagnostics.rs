@@ -0,0 +1,51 @@
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::error::Error;
+use vrm_rust_workflow::loader::diagnostic::DiagnosticSeverity;
+use vrm_rust_workflow::{generate_system_model, generate_system_model_explained};
+
+const JSON_FILE_PATH: &str = "src/data/test/test_workflow_with_simple_co_allocation_graph.json";
+
+/// Writes the test workflow file with an additional `data_in` of task A, which references an unknown output.
+fn write_workflow_with_dangling_data_in(file_name: &str) -> std::path::PathBuf {
+    let mut json_value: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(JSON_FILE_PATH).unwrap()).unwrap();
+    json_value["clients"][0]["workflows"][0]["tasks"][0]["nodeReservation"]["dataIn"]
+        .as_array_mut()
+        .expect("DataIn of task A expected.")
+        .push(serde_json::json!({ "sourceReservation": "Unknown", "sourcePort": "missing_port", "file": "missing.h5" }));
+
+    let file_path = std::env::temp_dir().join(format!("{}_{}.json", file_name, std::process::id()));
+    std::fs::write(&file_path, serde_json::to_string(&json_value).unwrap()).unwrap();
+    return file_path;
+}
+
+#[test]
+fn test_explained_reports_dangling_data_in() {
+    let file_path = write_workflow_with_dangling_data_in("test_system_model_diagnostics_dangling");
+    let result = generate_system_model_explained(file_path.to_str().unwrap(), ReservationStore::new());
+    let _ = std::fs::remove_file(&file_path);
+
+    let (system_model, diagnostics) = result.expect("Explained loading of system model failed.");
+
+    assert_eq!(system_model.unprocessed_reservations.len(), 1);
+    assert_eq!(diagnostics.len(), 1, "Expected exactly one diagnostic, got {:?}", diagnostics);
+    assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    assert_eq!(diagnostics[0].context, "Simulation-Run-0/A");
+    assert!(diagnostics[0].message.contains("Unknown/missing_port"));
+}
+
+/// `EXTERNAL` inputs are intentionally unresolved and must not be reported.
+#[test]
+fn test_explained_without_problems_has_no_diagnostics() {
+    let (_, diagnostics) = generate_system_model_explained(JSON_FILE_PATH, ReservationStore::new()).expect("Loading of system model failed.");
+
+    assert!(diagnostics.is_empty(), "Expected no diagnostics, got {:?}", diagnostics);
+}
+
+#[test]
+fn test_plain_generation_still_rejects_dangling_data_in() {
+    let file_path = write_workflow_with_dangling_data_in("test_system_model_diagnostics_strict");
+    let result = generate_system_model(file_path.to_str().unwrap(), ReservationStore::new());
+    let _ = std::fs::remove_file(&file_path);
+
+    assert!(matches!(result, Err(Error::Validation { .. })), "Expected Validation error, got {:?}", result.err());
+}
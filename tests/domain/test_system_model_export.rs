@@ -0,0 +1,54 @@
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::Reservation;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::{generate_and_dump, generate_system_model};
+
+const JSON_FILE_PATH: &str = "src/data/test/test_workflow_with_simple_co_allocation_graph.json";
+
+/// Returns the number of nodes of every unprocessed workflow, in the order of the SystemModel.
+fn get_node_counts(system_model_json: &serde_json::Value) -> Vec<usize> {
+    return system_model_json["workflows"]
+        .as_array()
+        .expect("Workflows section expected.")
+        .iter()
+        .map(|workflow| workflow["Workflow"]["nodes"].as_object().expect("Nodes of workflow expected.").len())
+        .collect();
+}
+
+#[test]
+fn test_system_model_json_round_trips_node_count() {
+    let store = ReservationStore::new();
+    let system_model = generate_system_model(JSON_FILE_PATH, store.clone()).expect("Loading of system model failed.");
+
+    let expected_node_counts: Vec<usize> = system_model
+        .unprocessed_reservations
+        .iter()
+        .map(|res_id| match store.get_reservation_snapshot(*res_id) {
+            Some(Reservation::Workflow(workflow)) => workflow.nodes.len(),
+            _ => panic!("Expected Workflow reservation"),
+        })
+        .collect();
+    assert!(!expected_node_counts.is_empty());
+
+    let json = system_model.to_json_pretty(&store).expect("Serialization of system model failed.");
+    let system_model_json: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(get_node_counts(&system_model_json), expected_node_counts);
+
+    // Every node reservation of the workflows is contained in the flattened reservations section
+    let number_of_nodes: usize = expected_node_counts.iter().sum();
+    let number_of_node_reservations =
+        system_model_json["reservations"].as_array().unwrap().iter().filter(|entry| entry["reservation"].get("Node").is_some()).count();
+    assert_eq!(number_of_node_reservations, number_of_nodes);
+}
+
+#[test]
+fn test_generate_and_dump_writes_system_model() {
+    let out_path = std::env::temp_dir().join(format!("test_system_model_export_{}.json", std::process::id()));
+    let store = ReservationStore::new();
+
+    let system_model = generate_and_dump(JSON_FILE_PATH, out_path.to_str().unwrap(), store.clone()).expect("Dump of system model failed.");
+    let dumped_json = std::fs::read_to_string(&out_path).unwrap();
+    let _ = std::fs::remove_file(&out_path);
+
+    assert_eq!(dumped_json, system_model.to_json_pretty(&store).unwrap());
+}
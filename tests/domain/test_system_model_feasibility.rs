@@ -0,0 +1,57 @@
+use vrm_rust_workflow::api::workflow_dto::client_dto::{ClientDto, ClientsDto};
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::vrm_system_model::client::client::Clients;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::RejectionReason;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ReservationName, WorkflowNodeId};
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+const TOTAL_NODE_CAPACITY: i64 = 1024;
+const AVG_NET_SPEED: i64 = 10;
+
+fn create_system_model(workflows: Vec<WorkflowDto>, store: &ReservationStore) -> Clients {
+    let dto = ClientsDto { clients: vec![ClientDto { id: "Test-Client-001".to_string(), workflows }] };
+    return Clients::from_dto(dto, store.clone()).expect("SystemModel construction failed.");
+}
+
+fn get_workflow_dto(workflow_id: &str) -> WorkflowDto {
+    return get_direct_mapping_workflow_dto(workflow_id.to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+}
+
+/// The diamond c0 -> (c1, c2) -> c3 needs at least three task durations (150s), which exceeds the booking interval 10..100.
+#[test]
+fn test_only_over_deadline_workflow_is_reported() {
+    let store = ReservationStore::new();
+    let mut over_deadline_dto = get_workflow_dto("Over-Deadline-Workflow");
+    over_deadline_dto.booking_interval_end = 100;
+
+    let system_model = create_system_model(vec![get_workflow_dto("Feasible-Workflow"), over_deadline_dto], &store);
+    let infeasible_workflows = system_model.check_feasibility(&store, TOTAL_NODE_CAPACITY, AVG_NET_SPEED);
+
+    assert_eq!(infeasible_workflows.len(), 1, "Expected exactly one infeasible workflow, got {:?}", infeasible_workflows);
+    assert_eq!(infeasible_workflows[0].0, ReservationName::new("Over-Deadline-Workflow"));
+    match &infeasible_workflows[0].1 {
+        RejectionReason::DeadlineMiss { node, projected_finish, deadline } => {
+            assert_eq!(*node, WorkflowNodeId::new("c3"));
+            assert!(*projected_finish >= 10 + 150, "Projected finish {} is below the sum of the task durations.", projected_finish);
+            assert_eq!(*deadline, 100);
+        }
+        reason => panic!("Expected DeadlineMiss, got {:?}", reason),
+    }
+}
+
+#[test]
+fn test_over_capacity_workflow_is_reported() {
+    let store = ReservationStore::new();
+    let mut over_capacity_dto = get_workflow_dto("Over-Capacity-Workflow");
+    over_capacity_dto.tasks[1].node_reservation.cpus = TOTAL_NODE_CAPACITY + 1;
+
+    let system_model = create_system_model(vec![over_capacity_dto], &store);
+    let infeasible_workflows = system_model.check_feasibility(&store, TOTAL_NODE_CAPACITY, AVG_NET_SPEED);
+
+    assert_eq!(infeasible_workflows.len(), 1, "Expected exactly one infeasible workflow, got {:?}", infeasible_workflows);
+    assert_eq!(infeasible_workflows[0].0, ReservationName::new("Over-Capacity-Workflow"));
+    assert_eq!(infeasible_workflows[0].1, RejectionReason::NoCapacity { node: WorkflowNodeId::new("c1") });
+}
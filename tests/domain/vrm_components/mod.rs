@@ -2,3 +2,24 @@ pub mod test_aci_commit;
 pub mod test_aci_delete;
 pub mod test_aci_probe;
 pub mod test_aci_reserve;
+pub mod test_vrm_component_manager_blacklist;
+pub mod test_vrm_component_manager_can_fit_workflow;
+pub mod test_vrm_component_manager_capacity_breakdown;
+pub mod test_vrm_component_manager_commit_workflow;
+pub mod test_vrm_component_manager_component_not_found;
+pub mod test_vrm_component_manager_custom_order;
+pub mod test_vrm_component_manager_delete_component;
+pub mod test_vrm_component_manager_dummy_component;
+pub mod test_vrm_component_manager_fairness;
+pub mod test_vrm_component_manager_free_capacity;
+pub mod test_vrm_component_manager_gantt;
+pub mod test_vrm_component_manager_load_delta;
+pub mod test_vrm_component_manager_parallel_probe;
+pub mod test_vrm_component_manager_probe_first_n;
+pub mod test_vrm_component_manager_reconcile;
+pub mod test_vrm_component_manager_reserve_backoff;
+pub mod test_vrm_component_manager_satisfaction_weight;
+pub mod test_vrm_component_manager_seeded_order;
+pub mod test_vrm_component_manager_shadow_conflict;
+pub mod test_vrm_component_manager_total_capacity_cache;
+pub mod test_vrm_component_manager_transfer_duration;
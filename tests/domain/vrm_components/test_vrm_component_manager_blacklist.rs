@@ -0,0 +1,165 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::reserve_config::ReserveConfig;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_order::VrmComponentOrder;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::probe_reservations::ProbeReservationComparator;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ComponentId, ReservationName};
+
+use crate::common::{RejectingReserveComponent, create_dummy_aci, create_node_reservation, get_aci_dto};
+
+async fn get_manager() -> VrmComponentManager {
+    return get_manager_with_store(Arc::new(GlobalClock::new(true)), ReservationStore::new()).await;
+}
+
+async fn get_manager_with_store(clock: Arc<GlobalClock>, store: ReservationStore) -> VrmComponentManager {
+    let registry = RegistryClient::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60);
+
+    for aci_id in ["AcI-001", "AcI-002", "AcI-003"] {
+        let mut aci_dto = get_aci_dto("ADC-001".to_string());
+        aci_dto.id = aci_id.to_string();
+        let aci = AcI::from_dto(aci_dto, clock.clone(), store.clone()).await.expect("Error in the AcI Mock process happened.");
+        assert!(manager.add_vrm_component(registry.spawn_component(Box::new(aci)), clock.clone(), store.clone(), 60, 60));
+    }
+    return manager;
+}
+
+/// A VrmComponent is skipped by both VrmComponent orders once it reached `max_failures` and is used again after a reset.
+#[tokio::test]
+async fn test_component_over_failure_threshold_is_skipped() {
+    let mut manager = get_manager().await;
+    manager.max_failures = 2;
+    let flaky_id = ComponentId::new("AcI-002");
+
+    manager.increment_failure(flaky_id.clone());
+    assert!(!manager.is_blacklisted(&flaky_id));
+    assert!(manager.blacklisted_components().is_empty());
+
+    manager.increment_failure(flaky_id.clone());
+    assert!(manager.is_blacklisted(&flaky_id));
+    assert_eq!(manager.blacklisted_components(), vec![flaky_id.clone()]);
+
+    assert_eq!(
+        manager.get_ordered_vrm_components(VrmComponentOrder::OrderStartFirst),
+        vec![ComponentId::new("AcI-001"), ComponentId::new("AcI-003")]
+    );
+    let mut random_order = manager.get_random_ordered_vrm_components();
+    random_order.sort();
+    assert_eq!(random_order, vec![ComponentId::new("AcI-001"), ComponentId::new("AcI-003")]);
+
+    manager.reset_failures(flaky_id.clone());
+    assert!(manager.blacklisted_components().is_empty());
+    assert_eq!(manager.get_ordered_vrm_components(VrmComponentOrder::OrderStartFirst).len(), 3);
+}
+
+/// A VrmComponent, which rejects reserves (e.g. because it is full), is no faulty VrmComponent and must not be blacklisted.
+#[tokio::test]
+async fn test_rejected_reserves_do_not_blacklist_component() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let registry = RegistryClient::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60);
+    manager.max_failures = 2;
+    manager.reserve_config.probe_retries = 0;
+
+    let aci = create_dummy_aci(clock.clone(), store.clone()).await;
+    let rejecting_component = RejectingReserveComponent { aci, reservation_store: store.clone(), answer_state: ReservationState::Rejected };
+    assert!(manager.add_vrm_component(registry.spawn_component(Box::new(rejecting_component)), clock.clone(), store.clone(), 60, 60));
+
+    for index in 0..3 {
+        let res_id =
+            store.add(create_node_reservation(ReservationName::new(format!("Job-{}", index)), 2, 0, 60, ReservationState::Open, clock.clone()));
+
+        let result = manager.reserve_task_at_best_vrm_component(
+            res_id,
+            None,
+            &mut HashMap::new(),
+            ProbeReservationComparator::EFTReservationCompare,
+            |_, _| Ordering::Equal,
+        );
+        assert!(result.is_none());
+    }
+
+    assert!(manager.blacklisted_components().is_empty());
+    assert_eq!(manager.get_random_ordered_vrm_components(), vec![ComponentId::new("AcI-001")]);
+}
+
+/// A VrmComponent, which does not answer the reserves within the gate_timeout, is faulty and blacklisted.
+#[tokio::test]
+async fn test_unanswered_reserves_blacklist_component() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let registry = RegistryClient::new();
+    let reserve_config = ReserveConfig { probe_retries: 0, gate_timeout: Duration::from_millis(10), ..ReserveConfig::default() };
+    let mut manager = VrmComponentManager::new_with_config(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60, reserve_config);
+    manager.max_failures = 2;
+
+    let aci = create_dummy_aci(clock.clone(), store.clone()).await;
+    let silent_component =
+        RejectingReserveComponent { aci, reservation_store: store.clone(), answer_state: ReservationState::ReserveProbeReservation };
+    assert!(manager.add_vrm_component(registry.spawn_component(Box::new(silent_component)), clock.clone(), store.clone(), 60, 60));
+
+    for index in 0..2 {
+        assert!(manager.blacklisted_components().is_empty());
+        let res_id =
+            store.add(create_node_reservation(ReservationName::new(format!("Job-{}", index)), 2, 0, 60, ReservationState::Open, clock.clone()));
+
+        let result = manager.reserve_task_at_best_vrm_component(
+            res_id,
+            None,
+            &mut HashMap::new(),
+            ProbeReservationComparator::EFTReservationCompare,
+            |_, _| Ordering::Equal,
+        );
+        assert!(result.is_none());
+    }
+
+    assert_eq!(manager.blacklisted_components(), vec![ComponentId::new("AcI-001")]);
+    assert!(manager.get_random_ordered_vrm_components().is_empty());
+}
+
+/// Failed commits are counted, while a successful commit resets the failures of the VrmComponent.
+#[tokio::test]
+async fn test_failed_commits_are_counted_and_reset_by_successful_commit() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = get_manager_with_store(clock.clone(), store.clone()).await;
+    manager.max_failures = 2;
+
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::Open, clock.clone()));
+    let mut grid_component_res_database = HashMap::new();
+    let result = manager.reserve_task_at_best_vrm_component(
+        res_id,
+        None,
+        &mut grid_component_res_database,
+        ProbeReservationComparator::EFTReservationCompare,
+        |_, _| Ordering::Equal,
+    );
+    assert!(result.is_some());
+    let component_id = grid_component_res_database.get(&res_id).cloned().expect("Reservation must be reserved at a VrmComponent.");
+
+    // No AcI can host 10000 CPUs, hence the instant allocation at the commit fails
+    for name in ["Oversized-1", "Oversized-2"] {
+        let oversized_res_id = store.add(create_node_reservation(ReservationName::new(name), 10000, 0, 60, ReservationState::Open, clock.clone()));
+        assert!(!manager.commit_at_component(oversized_res_id, component_id.clone()));
+        assert!(!manager.is_blacklisted(&component_id));
+
+        // The successful commit resets the failure, so the second failure does not reach `max_failures`
+        if name == "Oversized-1" {
+            assert!(manager.commit_at_component(res_id, component_id.clone()));
+        }
+    }
+
+    let oversized_res_id = store.add(create_node_reservation(ReservationName::new("Oversized-3"), 10000, 0, 60, ReservationState::Open, clock));
+    assert!(!manager.commit_at_component(oversized_res_id, component_id.clone()));
+    assert!(manager.is_blacklisted(&component_id));
+}
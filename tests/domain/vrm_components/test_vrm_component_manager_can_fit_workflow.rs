@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::rms_config_dto::rms_dto::RmsSystemWrapper;
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{DataInDto, ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ClientId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{get_aci_dto, get_direct_mapping_workflow_dto};
+
+/// The AcI consists of 4 nodes with 256 CPUs each and of links with a total bandwidth of 30000.
+async fn get_manager(clock: Arc<GlobalClock>, store: ReservationStore) -> VrmComponentManager {
+    let registry = RegistryClient::new();
+
+    let mut aci_dto = get_aci_dto("ADC-001".to_string());
+    if let RmsSystemWrapper::DummyRms(ref mut dummy_rms_dto) = aci_dto.rms_system {
+        dummy_rms_dto.typ = "RmsSimulator".to_string();
+    }
+    let aci = AcI::from_dto(aci_dto, clock.clone(), store.clone()).await.expect("Error in the AcI Mock process happened.");
+    let aci_proxy = registry.spawn_component(Box::new(aci));
+    return VrmComponentManager::new(AdcId::new("ADC-001"), vec![aci_proxy], clock, store, 60, 60);
+}
+
+fn get_workflow_dto() -> WorkflowDto {
+    return get_direct_mapping_workflow_dto("Test-Can-Fit-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+}
+
+fn can_fit(manager: &VrmComponentManager, store: &ReservationStore, workflow_dto: WorkflowDto) -> bool {
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+    return store.with_workflow_mut(workflow_res_id, |workflow| manager.can_fit_workflow(workflow)).expect("Expected Workflow reservation");
+}
+
+#[tokio::test]
+async fn test_can_fit_workflow_within_capacity() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let manager = get_manager(clock, store.clone()).await;
+
+    assert!(can_fit(&manager, &store, get_workflow_dto()));
+}
+
+/// Task c1 requests 2000 CPUs, but the AcI only has 1024 CPUs in total.
+#[tokio::test]
+async fn test_can_not_fit_over_capacity_co_allocation() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let manager = get_manager(clock, store.clone()).await;
+
+    let mut workflow_dto = get_workflow_dto();
+    workflow_dto.tasks[1].node_reservation.cpus = 2000;
+
+    assert!(!can_fit(&manager, &store, workflow_dto));
+}
+
+/// The output of c0 can not be transferred to c1 within the booking interval, even if all links are used.
+#[tokio::test]
+async fn test_can_not_fit_over_capacity_transfer() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let manager = get_manager(clock, store.clone()).await;
+
+    let mut workflow_dto = get_workflow_dto();
+    let booking_interval_length = workflow_dto.booking_interval_end - workflow_dto.booking_interval_start;
+    workflow_dto.tasks[0].node_reservation.data_out[0].size = Some(manager.get_total_link_capacity() * booking_interval_length + 1);
+    workflow_dto.tasks[1].node_reservation.data_in.push(DataInDto {
+        source_reservation: "c0".to_string(),
+        source_port: "preprocessed_data".to_string(),
+        file: Some("preprocessed.h5".to_string()),
+    });
+
+    assert!(!can_fit(&manager, &store, workflow_dto));
+}
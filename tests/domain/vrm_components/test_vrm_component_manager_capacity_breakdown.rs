@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::rms_config_dto::rms_dto::RmsSystemWrapper;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::metrics::CapacityBreakdown;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::AdcId;
+
+use crate::common::get_aci_dto;
+
+/// The AcI consists of 4 nodes with 256 CPUs each and of 4 links with 10000, 10000, 5000 and 5000 bandwidth.
+/// In contrast to the RmsNodeSimulator, the RmsSimulator also registers the links as resources.
+#[tokio::test]
+async fn test_capacity_breakdown_separates_nodes_and_links() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let registry = RegistryClient::new();
+
+    let mut aci_dto = get_aci_dto("ADC-001".to_string());
+    if let RmsSystemWrapper::DummyRms(ref mut dummy_rms_dto) = aci_dto.rms_system {
+        dummy_rms_dto.typ = "RmsSimulator".to_string();
+    }
+    let aci = AcI::from_dto(aci_dto, clock.clone(), store.clone()).await.expect("Error in the AcI Mock process happened.");
+    let aci_proxy = registry.spawn_component(Box::new(aci));
+    let manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![aci_proxy], clock, store, 60, 60);
+
+    let breakdown = manager.capacity_breakdown();
+
+    assert_eq!(breakdown, CapacityBreakdown { node: 4 * 256, link: 30000, total: 4 * 256 + 30000 });
+    assert_eq!(breakdown.node, manager.get_total_node_capacity());
+    assert_eq!(breakdown.link, manager.get_total_link_capacity());
+    assert_eq!(breakdown.total, manager.get_total_capacity());
+}
+
+#[test]
+fn test_capacity_breakdown_without_components() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock, ReservationStore::new(), 60, 60);
+
+    assert_eq!(manager.capacity_breakdown(), CapacityBreakdown { node: 0, link: 0, total: 0 });
+}
@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ClientId, ComponentId, DUMMY_COMPONENT_ID, ReservationName, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+use vrm_rust_workflow::error::Error;
+
+use crate::common::{create_dummy_aci, create_node_reservation, get_direct_mapping_workflow_dto};
+
+/// Creates the workflow c0 -> (c1, c2) -> c3 and registers its reserved nodes as subtasks (in the order c0, ..., c3)
+/// at the manager. The node c3 is mapped to `last_component_id`, all other nodes to the `DUMMY_COMPONENT_ID`.
+fn setup(last_component_id: ComponentId) -> (VrmComponentManager, ReservationStore, ReservationId, Vec<ReservationId>) {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock, store.clone(), 60, 60);
+
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Commit-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let Some(Reservation::Workflow(workflow)) = store.get_reservation_snapshot(workflow_id) else {
+        panic!("Expected Workflow reservation");
+    };
+    let subtask_ids: Vec<ReservationId> =
+        ["c0", "c1", "c2", "c3"].iter().map(|node_id| workflow.nodes[&WorkflowNodeId::new(*node_id)].reservation_id).collect();
+
+    for (index, subtask_id) in subtask_ids.iter().enumerate() {
+        let component_id = if index == subtask_ids.len() - 1 { last_component_id.clone() } else { DUMMY_COMPONENT_ID.clone() };
+
+        store.update_state(*subtask_id, ReservationState::ReserveAnswer);
+        manager.res_to_vrm_component.insert(*subtask_id, component_id.clone());
+        manager.not_committed_reservations.insert(*subtask_id, component_id);
+        manager.reverse_workflow_subtasks.insert(*subtask_id, workflow_id);
+    }
+    manager.workflow_subtasks.insert(workflow_id, subtask_ids.clone());
+
+    return (manager, store, workflow_id, subtask_ids);
+}
+
+#[test]
+fn test_commit_workflow_commits_all_subtasks() {
+    let (mut manager, store, workflow_id, subtask_ids) = setup(DUMMY_COMPONENT_ID.clone());
+
+    assert!(manager.commit_workflow(workflow_id).is_ok());
+    assert!(subtask_ids.iter().all(|subtask_id| store.get_state(*subtask_id) == ReservationState::Committed));
+}
+
+/// The commit of c3 fails, because its VrmComponent is not registered. The already committed subtasks c0, c1 and c2
+/// must be rolled back, so that no subtask of the workflow stays committed.
+#[test]
+fn test_commit_workflow_rolls_back_on_failing_commit() {
+    let (mut manager, store, workflow_id, subtask_ids) = setup(ComponentId::new("AcI-Unreachable"));
+
+    let result = manager.commit_workflow(workflow_id);
+
+    match result {
+        Err(Error::WorkflowCommitFailed { workflow, subtask }) => {
+            assert_eq!(workflow, workflow_id);
+            assert_eq!(subtask, subtask_ids[3]);
+        }
+        other => panic!("Expected WorkflowCommitFailed, got {:?}", other),
+    }
+
+    assert!(subtask_ids.iter().all(|subtask_id| store.get_state(*subtask_id) != ReservationState::Committed));
+    assert_eq!(store.get_state(subtask_ids[3]), ReservationState::Rejected);
+    assert_eq!(store.get_state(workflow_id), ReservationState::Rejected);
+}
+
+#[test]
+fn test_commit_untracked_workflow() {
+    let (mut manager, _, workflow_id, _) = setup(DUMMY_COMPONENT_ID.clone());
+    manager.workflow_subtasks.clear();
+
+    assert!(matches!(manager.commit_workflow(workflow_id), Err(Error::WorkflowNotTracked { .. })));
+}
+
+/// The subtasks Job-0, ..., Job-3 fill all nodes of a real AcI and are committed, before the commit of the last subtask
+/// fails. The rollback must delete the committed subtasks at the AcI, so that the AcI is empty again.
+#[tokio::test]
+async fn test_commit_workflow_rolls_back_committed_subtasks_at_aci() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let registry = RegistryClient::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60);
+    let aci = create_dummy_aci(clock.clone(), store.clone()).await;
+    assert!(manager.add_vrm_component(registry.spawn_component(Box::new(aci)), clock.clone(), store.clone(), 60, 60));
+
+    let component_id = ComponentId::new("AcI-001");
+    let mut subtask_ids = Vec::new();
+    for index in 0..4 {
+        let res_id =
+            store.add(create_node_reservation(ReservationName::new(format!("Job-{}", index)), 256, 0, 60, ReservationState::Open, clock.clone()));
+        manager.reserve(component_id.clone(), res_id, None);
+        assert_eq!(store.get_state(res_id), ReservationState::ReserveAnswer);
+        manager.register_allocation(res_id, component_id.clone());
+        subtask_ids.push(res_id);
+    }
+    // The VrmComponent of the last subtask is not registered, hence its commit fails
+    let failing_id =
+        store.add(create_node_reservation(ReservationName::new("Job-Failing"), 2, 0, 60, ReservationState::ReserveAnswer, clock.clone()));
+    manager.res_to_vrm_component.insert(failing_id, ComponentId::new("AcI-Unreachable"));
+    manager.not_committed_reservations.insert(failing_id, ComponentId::new("AcI-Unreachable"));
+    subtask_ids.push(failing_id);
+
+    let workflow_id = store.add(create_node_reservation(ReservationName::new("Workflow"), 0, 0, 60, ReservationState::ReserveAnswer, clock.clone()));
+    manager.workflow_subtasks.insert(workflow_id, subtask_ids.clone());
+
+    match manager.commit_workflow(workflow_id) {
+        Err(Error::WorkflowCommitFailed { workflow, subtask }) => {
+            assert_eq!(workflow, workflow_id);
+            assert_eq!(subtask, failing_id);
+        }
+        other => panic!("Expected WorkflowCommitFailed, got {:?}", other),
+    }
+    assert!(subtask_ids.iter().all(|subtask_id| store.get_state(*subtask_id) == ReservationState::Rejected));
+
+    // All nodes of the AcI are free again
+    for index in 0..4 {
+        let res_id = store.add(create_node_reservation(
+            ReservationName::new(format!("Job-After-{}", index)),
+            256,
+            0,
+            60,
+            ReservationState::Open,
+            clock.clone(),
+        ));
+        assert!(manager.commit_at_component(res_id, component_id.clone()));
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ComponentId, ReservationName};
+use vrm_rust_workflow::error::Error;
+
+use crate::common::create_node_reservation;
+
+fn get_manager(store: ReservationStore) -> VrmComponentManager {
+    let clock = Arc::new(GlobalClock::new(true));
+    return VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock, store, 60, 60);
+}
+
+#[test]
+fn test_try_get_vrm_component_container_of_unknown_component() {
+    let mut manager = get_manager(ReservationStore::new());
+    let stale_component_id = ComponentId::new("AcI-Stale");
+
+    match manager.try_get_vrm_component_container(&stale_component_id) {
+        Err(Error::ComponentNotFound { adc, component }) => {
+            assert_eq!(adc, AdcId::new("ADC-001"));
+            assert_eq!(component, stale_component_id);
+        }
+        other => panic!("Expected ComponentNotFound, got {:?}", other.map(|_| ())),
+    }
+    assert!(matches!(manager.try_get_vrm_component_container_mut(&stale_component_id), Err(Error::ComponentNotFound { .. })));
+}
+
+/// A stale ComponentId must fail the commit and the deletion cleanly instead of crashing the process.
+#[test]
+fn test_commit_and_delete_at_unknown_component_fail_cleanly() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = get_manager(store.clone());
+    let stale_component_id = ComponentId::new("AcI-Stale");
+
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::ReserveAnswer, clock));
+
+    assert!(!manager.commit_at_component(res_id, stale_component_id.clone()));
+    assert_eq!(store.get_state(res_id), ReservationState::Rejected);
+
+    manager.res_to_vrm_component.insert(res_id, stale_component_id);
+    assert!(!manager.delete_task_at_component(res_id, None));
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_order::VrmComponentOrder;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ComponentId};
+
+use crate::common::get_aci_dto;
+
+const ACI_IDS: [&str; 4] = ["AcI-001", "AcI-002", "AcI-003", "AcI-004"];
+
+async fn get_manager() -> VrmComponentManager {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let registry = RegistryClient::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60);
+
+    for aci_id in ACI_IDS {
+        let mut aci_dto = get_aci_dto("ADC-001".to_string());
+        aci_dto.id = aci_id.to_string();
+        let aci = AcI::from_dto(aci_dto, clock.clone(), store.clone()).await.expect("Error in the AcI Mock process happened.");
+        assert!(manager.add_vrm_component(registry.spawn_component(Box::new(aci)), clock.clone(), store.clone(), 60, 60));
+    }
+    return manager;
+}
+
+/// Ordering by `registration_index` descending returns the VrmComponents in reverse order of registration.
+#[tokio::test]
+async fn test_custom_order_by_registration_index_descending() {
+    let manager = get_manager().await;
+
+    let registration_order = manager.get_ordered_vrm_components(VrmComponentOrder::OrderStartFirst);
+    let expected_registration_order: Vec<ComponentId> = ACI_IDS.iter().map(|aci_id| ComponentId::new(*aci_id)).collect();
+    assert_eq!(registration_order, expected_registration_order);
+
+    let reverse_order = manager.get_components_ordered_by(|a, b| b.registration_index.cmp(&a.registration_index));
+    let expected_reverse_order: Vec<ComponentId> = expected_registration_order.into_iter().rev().collect();
+    assert_eq!(reverse_order, expected_reverse_order);
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ComponentId, ReservationName};
+
+use crate::common::{create_node_reservation, get_aci_dto};
+
+async fn get_manager_with_two_components(clock: Arc<GlobalClock>, store: ReservationStore) -> VrmComponentManager {
+    let registry = RegistryClient::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60);
+
+    for aci_id in ["AcI-001", "AcI-002"] {
+        let mut aci_dto = get_aci_dto("ADC-001".to_string());
+        aci_dto.id = aci_id.to_string();
+        let aci = AcI::from_dto(aci_dto, clock.clone(), store.clone()).await.expect("Error in the AcI Mock process happened.");
+        assert!(manager.add_vrm_component(registry.spawn_component(Box::new(aci)), clock.clone(), store.clone(), 60, 60));
+    }
+    return manager;
+}
+
+/// Deleting a VrmComponent must release all reservations, which are tracked for this VrmComponent.
+#[tokio::test]
+async fn test_delete_vrm_component_releases_its_reservations() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = get_manager_with_two_components(clock.clone(), store.clone()).await;
+    let deleted_component = ComponentId::new("AcI-001");
+    let remaining_component = ComponentId::new("AcI-002");
+
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 120, ReservationState::Open, clock.clone()));
+    manager.reserve(deleted_component.clone(), res_id, None);
+    manager.register_allocation(res_id, deleted_component.clone());
+    assert_eq!(store.get_state(res_id), ReservationState::ReserveAnswer);
+    assert_eq!(manager.not_committed_reservations.get(&res_id), Some(&deleted_component));
+
+    let remaining_res_id = store.add(create_node_reservation(ReservationName::new("Job-2"), 2, 0, 120, ReservationState::Committed, clock));
+    manager.committed_reservations.insert(remaining_res_id, remaining_component.clone());
+
+    assert_eq!(manager.delete_vrm_component(deleted_component.clone()), Some(vec![res_id]));
+
+    assert_eq!(store.get_state(res_id), ReservationState::Rejected);
+    assert!(!manager.res_to_vrm_component.contains_key(&res_id));
+    assert!(!manager.committed_reservations.contains_key(&res_id));
+    assert!(!manager.not_committed_reservations.contains_key(&res_id));
+    assert_eq!(manager.get_component_slot_width(&deleted_component), None);
+
+    // Reservations of other VrmComponents are untouched
+    assert_eq!(store.get_state(remaining_res_id), ReservationState::Committed);
+    assert_eq!(manager.committed_reservations.get(&remaining_res_id), Some(&remaining_component));
+    assert_eq!(manager.get_component_slot_width(&remaining_component), Some(60));
+}
+
+#[tokio::test]
+async fn test_delete_unknown_vrm_component() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = get_manager_with_two_components(clock, store).await;
+
+    assert_eq!(manager.delete_vrm_component(ComponentId::new("AcI-Stale")), None);
+    assert_eq!(manager.delete_vrm_component(ComponentId::new("AcI-001")), Some(vec![]));
+}
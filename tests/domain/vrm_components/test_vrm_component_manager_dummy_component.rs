@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ComponentId, DUMMY_COMPONENT_ID, ReservationName};
+
+use crate::common::create_node_reservation;
+
+/// The manager has no VrmComponent, hence any access to a real VrmComponent would panic.
+/// A reservation on the `DUMMY_COMPONENT_ID` must be committed and deleted locally.
+#[test]
+fn test_dummy_component_reservation_is_handled_locally() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60);
+
+    let res_id =
+        store.add(create_node_reservation(ReservationName::new("Dummy-Dependency"), 0, 0, 60, ReservationState::ReserveAnswer, clock.clone()));
+    manager.res_to_vrm_component.insert(res_id, DUMMY_COMPONENT_ID.clone());
+
+    assert!(manager.commit_at_component(res_id, DUMMY_COMPONENT_ID.clone()));
+    assert_eq!(store.get_state(res_id), ReservationState::Committed);
+    assert!(manager.committed_reservations.is_empty());
+
+    assert!(manager.delete_task_at_component(res_id, None));
+    assert_eq!(store.get_state(res_id), ReservationState::Deleted);
+}
+
+#[test]
+fn test_dummy_component_detection() {
+    assert!(DUMMY_COMPONENT_ID.is_dummy());
+    assert!(ComponentId::new("ADC INTERNAL JOB").is_dummy());
+    assert!(!ComponentId::new("AcI-001").is_dummy());
+}
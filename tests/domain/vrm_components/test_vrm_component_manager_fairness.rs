@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::link_reservation::LinkReservation;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ClientId, ComponentId, ReservationName};
+
+use crate::common::create_node_reservation;
+
+fn add_committed_reservation(
+    manager: &mut VrmComponentManager,
+    store: &ReservationStore,
+    clock: Arc<GlobalClock>,
+    client_id: &ClientId,
+    res_name: &str,
+    capacity: i64,
+    duration: i64,
+) {
+    let mut reservation = create_node_reservation(ReservationName::new(res_name), capacity, 0, duration, ReservationState::Committed, clock);
+    if let Reservation::Node(ref mut node_reservation) = reservation {
+        node_reservation.base.client_id = client_id.clone();
+    }
+    let res_id = store.add(reservation);
+    manager.committed_reservations.insert(res_id, ComponentId::new("AcI-001"));
+}
+
+/// Client A commits 10 * 100 = 1000 capacity-seconds, client B only 1 * 100 = 100.
+/// Jain's index: (1000 + 100)^2 / (2 * (1000^2 + 100^2)) = 1210000 / 2020000 < 1.
+#[test]
+fn test_fairness_report_with_unequal_usage() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60);
+
+    let client_a = ClientId::new("Client-A");
+    let client_b = ClientId::new("Client-B");
+
+    add_committed_reservation(&mut manager, &store, clock.clone(), &client_a, "Client-A-Job-1", 5, 100);
+    add_committed_reservation(&mut manager, &store, clock.clone(), &client_a, "Client-A-Job-2", 5, 100);
+    add_committed_reservation(&mut manager, &store, clock.clone(), &client_b, "Client-B-Job-1", 1, 100);
+
+    let report = manager.fairness_report();
+
+    assert_eq!(report.per_client_work.get(&client_a), Some(&1000));
+    assert_eq!(report.per_client_work.get(&client_b), Some(&100));
+    assert!(report.jains_index < 1.0, "Unequal usage must result in a Jain's index below 1, got {}", report.jains_index);
+    assert!((report.jains_index - 1210000.0 / 2020000.0).abs() < 1e-9);
+}
+
+/// Client B is known to the manager by a not yet committed reservation, but has no committed work.
+/// Jain's index: 1000^2 / (2 * 1000^2) = 0.5.
+#[test]
+fn test_fairness_report_includes_clients_without_committed_work() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60);
+
+    let client_a = ClientId::new("Client-A");
+    let client_b = ClientId::new("Client-B");
+
+    add_committed_reservation(&mut manager, &store, clock.clone(), &client_a, "Client-A-Job-1", 10, 100);
+
+    let mut reservation = create_node_reservation(ReservationName::new("Client-B-Job-1"), 10, 0, 100, ReservationState::ReserveAnswer, clock);
+    if let Reservation::Node(ref mut node_reservation) = reservation {
+        node_reservation.base.client_id = client_b.clone();
+    }
+    let res_id = store.add(reservation);
+    manager.not_committed_reservations.insert(res_id, ComponentId::new("AcI-001"));
+
+    let report = manager.fairness_report();
+
+    assert_eq!(report.per_client_work.get(&client_a), Some(&1000));
+    assert_eq!(report.per_client_work.get(&client_b), Some(&0));
+    assert!((report.jains_index - 0.5).abs() < 1e-9);
+}
+
+/// Committed link reservations do not count as work, their capacity is a bandwidth and not a number of CPUs.
+#[test]
+fn test_fairness_report_counts_node_reservations_only() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60);
+
+    let client_a = ClientId::new("Client-A");
+    let client_b = ClientId::new("Client-B");
+
+    add_committed_reservation(&mut manager, &store, clock.clone(), &client_a, "Client-A-Job-1", 5, 100);
+    add_committed_reservation(&mut manager, &store, clock.clone(), &client_b, "Client-B-Job-1", 5, 100);
+
+    let Reservation::Node(node_reservation) =
+        create_node_reservation(ReservationName::new("Client-B-Link-1"), 1000, 0, 100, ReservationState::Committed, clock)
+    else {
+        panic!("Expected node reservation");
+    };
+    let mut link_base = node_reservation.base;
+    link_base.client_id = client_b.clone();
+    let link_id = store.add(Reservation::Link(LinkReservation { base: link_base, start_point: None, end_point: None }));
+    manager.committed_reservations.insert(link_id, ComponentId::new("AcI-001"));
+
+    let report = manager.fairness_report();
+
+    assert_eq!(report.per_client_work.get(&client_a), Some(&500));
+    assert_eq!(report.per_client_work.get(&client_b), Some(&500));
+    assert!((report.jains_index - 1.0).abs() < 1e-9);
+}
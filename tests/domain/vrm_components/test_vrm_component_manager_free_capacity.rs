@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::rms::rms::RmsLoadMetric;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ShadowScheduleId};
+use vrm_rust_workflow::domain::vrm_system_model::utils::load_buffer::LoadMetric;
+
+use crate::common::{HookedComponent, VrmComponentHooks, create_dummy_aci_with_id};
+
+/// Wraps the AcI `aci_id` into a VrmComponent, whose schedule is loaded with `avg_reserved_capacity` out of `capacity`
+/// in every time window.
+async fn create_partially_loaded_component(
+    aci_id: &str,
+    capacity: i64,
+    avg_reserved_capacity: f64,
+    clock: Arc<GlobalClock>,
+    store: ReservationStore,
+) -> HookedComponent {
+    let aci = create_dummy_aci_with_id(aci_id, clock, store).await;
+    let load_metric_hook = move |_: &AcI, start: i64, end: i64, _: Option<ShadowScheduleId>| {
+        let utilization = avg_reserved_capacity / capacity as f64;
+        let node_load_metric = LoadMetric::new(start, end, avg_reserved_capacity, capacity as f64, utilization);
+        RmsLoadMetric { node_load_metric: Some(node_load_metric), link_load_metric: None }
+    };
+    return HookedComponent { aci, hooks: VrmComponentHooks { get_load_metric: Some(Box::new(load_metric_hook)), ..Default::default() } };
+}
+
+/// Three partially loaded components with 1024/256 (free 768), 512/500.5 (free 11) and 128/0 (free 128) CPUs.
+#[tokio::test]
+async fn test_free_capacity_sums_over_all_components() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let registry = RegistryClient::new();
+
+    let mut component_proxies = Vec::new();
+    for (aci_id, capacity, avg_reserved_capacity) in [("AcI-001", 1024, 256.0), ("AcI-002", 512, 500.5), ("AcI-003", 128, 0.0)] {
+        let component = create_partially_loaded_component(aci_id, capacity, avg_reserved_capacity, clock.clone(), store.clone()).await;
+        component_proxies.push(registry.spawn_component(Box::new(component)));
+    }
+    let manager = VrmComponentManager::new(AdcId::new("ADC-001"), component_proxies, clock, store, 60, 60);
+
+    assert_eq!(manager.free_capacity(0, 600, None), 768 + 11 + 128);
+}
+
+/// Without any VrmComponent there is no free capacity.
+#[test]
+fn test_free_capacity_without_components() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock, ReservationStore::new(), 60, 60);
+
+    assert_eq!(manager.free_capacity(0, 600, None), 0);
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::gantt::GanttBar;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ComponentId, ReservationName};
+
+use crate::common::create_node_reservation;
+
+/// Two reservations on the same AcI must both appear as bars with their assigned times.
+#[test]
+fn test_gantt_data_contains_all_reservations_of_component() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60);
+    let component_id = ComponentId::new("AcI-001");
+
+    let committed_res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 4, 0, 120, ReservationState::Committed, clock.clone()));
+    let reserved_res_id =
+        store.add(create_node_reservation(ReservationName::new("Job-2"), 2, 60, 300, ReservationState::ReserveAnswer, clock.clone()));
+    manager.committed_reservations.insert(committed_res_id, component_id.clone());
+    manager.not_committed_reservations.insert(reserved_res_id, component_id.clone());
+
+    let gantt_data = manager.gantt_data(0, 600);
+    let bars = gantt_data.get_bars(&component_id).expect("Component is missing in the Gantt data.");
+
+    assert_eq!(
+        bars,
+        &vec![
+            GanttBar { reservation_name: "Job-1".to_string(), client: "test_client".to_string(), start: 0, end: 120, capacity: 4 },
+            GanttBar { reservation_name: "Job-2".to_string(), client: "test_client".to_string(), start: 60, end: 300, capacity: 2 },
+        ]
+    );
+
+    let json = gantt_data.to_json().expect("Serialization of the Gantt data failed.");
+    assert!(json.contains("\"reservation_name\": \"Job-1\""));
+    assert!(json.contains("\"reservation_name\": \"Job-2\""));
+
+    // Only Job-2 overlaps with the time window [200, 600)
+    let gantt_data = manager.gantt_data(200, 600);
+    let bars = gantt_data.get_bars(&component_id).expect("Component is missing in the Gantt data.");
+    assert_eq!(bars.len(), 1);
+    assert_eq!(bars[0].reservation_name, "Job-2");
+}
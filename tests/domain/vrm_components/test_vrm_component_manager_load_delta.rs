@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ShadowScheduleId};
+use vrm_rust_workflow::domain::vrm_system_model::utils::load_buffer::LoadMetric;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, create_heft_sync_scheduler, get_workflow_dto_with_one_task};
+
+/// A task, which is only reserved on the shadow schedule, adds load compared to the master schedule.
+#[tokio::test]
+async fn test_load_delta_of_shadow_only_reservation_is_positive() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let shadow_schedule_id = ShadowScheduleId::new("Test-Shadow-Schedule");
+    assert!(adc.manager.create_shadow_schedule(shadow_schedule_id.clone()), "Creation of the shadow schedule failed.");
+
+    // Without any reservation the shadow schedule equals the master schedule
+    let empty_delta = adc.manager.load_delta(0, 600, shadow_schedule_id.clone());
+    assert_eq!(empty_delta.avg_reserved_capacity, 0.0);
+    assert_eq!(empty_delta.utilization, 0.0);
+
+    let workflow_dto =
+        get_workflow_dto_with_one_task("Test-Load-Delta-Workflow".to_string(), ReservationStateDto::Open, ReservationProceedingDto::Reserve);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    assert!(scheduler.reserve_on_shadow(workflow_res_id, &mut adc, shadow_schedule_id.clone()), "Reserve on shadow schedule failed.");
+
+    let load_delta = adc.manager.load_delta(0, 600, shadow_schedule_id);
+    assert!(load_delta.avg_reserved_capacity > 0.0, "Expected a positive load delta, got {:?}", load_delta);
+    assert!(load_delta.utilization > 0.0, "Expected a positive utilization delta, got {:?}", load_delta);
+    assert_eq!(load_delta.possible_capacity, 0.0, "The shadow schedule must not change the possible capacity.");
+}
+
+#[test]
+fn test_load_metric_subtraction_is_element_wise() {
+    let shadow_load_metric = LoadMetric::new(0, 600, 12.0, 64.0, 0.75);
+    let master_load_metric = LoadMetric::new(60, 540, 4.0, 16.0, 0.25);
+
+    let load_delta = shadow_load_metric - master_load_metric;
+
+    assert_eq!((load_delta.start_time, load_delta.end_time), (0, 600));
+    assert_eq!(load_delta.avg_reserved_capacity, 8.0);
+    assert_eq!(load_delta.possible_capacity, 48.0);
+    assert_eq!(load_delta.utilization, 0.5);
+}
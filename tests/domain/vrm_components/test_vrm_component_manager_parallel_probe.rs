@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::vrm_component_proxy::VrmComponentProxy;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::probe_reservations::ProbeReservations;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{ReservationState, ReservationTrait};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ReservationName};
+
+use crate::common::{create_node_reservation, get_aci_dto};
+
+async fn get_aci_proxies(clock: Arc<GlobalClock>, store: ReservationStore) -> Vec<VrmComponentProxy> {
+    let registry = RegistryClient::new();
+    let mut aci_proxies = Vec::new();
+
+    for aci_id in ["AcI-001", "AcI-002", "AcI-003", "AcI-004", "AcI-005"] {
+        let mut aci_dto = get_aci_dto("ADC-001".to_string());
+        aci_dto.id = aci_id.to_string();
+        let aci = AcI::from_dto(aci_dto, clock.clone(), store.clone()).await.expect("Error in the AcI Mock process happened.");
+        aci_proxies.push(registry.spawn_component(Box::new(aci)));
+    }
+    return aci_proxies;
+}
+
+/// Returns the time windows and capacities of all candidates in a canonical order.
+fn get_candidates(probe_reservations: &ProbeReservations) -> Vec<(i64, i64, i64)> {
+    let mut candidates: Vec<(i64, i64, i64)> = probe_reservations
+        .local_reservation_store
+        .values()
+        .map(|candidate| (candidate.get_assigned_start(), candidate.get_assigned_end(), candidate.get_reserved_capacity()))
+        .collect();
+    candidates.sort();
+    return candidates;
+}
+
+/// Managers with different seeds merge the concurrently collected probes in different VrmComponent orders,
+/// nevertheless the aggregated set of candidates must be the same for every probe round.
+#[tokio::test]
+async fn test_aggregated_probes_independent_of_probe_order() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let aci_proxies = get_aci_proxies(clock.clone(), store.clone()).await;
+
+    let mut first_manager = VrmComponentManager::new_seeded(AdcId::new("ADC-001"), aci_proxies.clone(), clock.clone(), store.clone(), 60, 60, 1);
+    let mut second_manager = VrmComponentManager::new_seeded(AdcId::new("ADC-001"), aci_proxies, clock.clone(), store.clone(), 60, 60, 2);
+
+    let mut store_handle = store.clone();
+    let reservation_id = store.add(create_node_reservation(ReservationName::new("Probe-Job"), 4, 0, 120, ReservationState::Open, clock.clone()));
+    store_handle.set_booking_interval_end(reservation_id, 600);
+
+    let expected_candidates = get_candidates(&first_manager.probe_all_vrm_components(reservation_id, None));
+    assert!(!expected_candidates.is_empty());
+    assert_eq!(expected_candidates.len() % 5, 0, "Every AcI must contribute the same candidates.");
+
+    for _ in 0..5 {
+        assert_eq!(get_candidates(&first_manager.probe_all_vrm_components(reservation_id, None)), expected_candidates);
+        assert_eq!(get_candidates(&second_manager.probe_all_vrm_components(reservation_id, None)), expected_candidates);
+    }
+}
+
+/// Probing inline or in chunks smaller than the number of VrmComponents yields the same candidates as probing
+/// all VrmComponents at once.
+#[tokio::test]
+async fn test_chunked_probes_match_unbounded_probes() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let aci_proxies = get_aci_proxies(clock.clone(), store.clone()).await;
+
+    let mut manager = VrmComponentManager::new_seeded(AdcId::new("ADC-001"), aci_proxies, clock.clone(), store.clone(), 60, 60, 1);
+
+    let mut store_handle = store.clone();
+    let reservation_id = store.add(create_node_reservation(ReservationName::new("Probe-Job"), 4, 0, 120, ReservationState::Open, clock.clone()));
+    store_handle.set_booking_interval_end(reservation_id, 600);
+
+    manager.max_probe_threads = 5;
+    let expected_candidates = get_candidates(&manager.probe_all_vrm_components(reservation_id, None));
+    assert_eq!(expected_candidates.len() % 5, 0, "Every AcI must contribute the same candidates.");
+
+    for max_probe_threads in [0, 1, 2] {
+        manager.max_probe_threads = max_probe_threads;
+        assert_eq!(get_candidates(&manager.probe_all_vrm_components(reservation_id, None)), expected_candidates);
+    }
+}
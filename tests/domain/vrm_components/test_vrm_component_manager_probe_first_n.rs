@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_order::VrmComponentOrder;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ComponentId, ReservationName};
+
+use crate::common::{create_node_reservation, get_aci_dto};
+
+async fn get_manager_with_five_components(clock: Arc<GlobalClock>, store: ReservationStore) -> VrmComponentManager {
+    let registry = RegistryClient::new();
+    let mut aci_proxies = Vec::new();
+
+    for aci_id in ["AcI-001", "AcI-002", "AcI-003", "AcI-004", "AcI-005"] {
+        let mut aci_dto = get_aci_dto("ADC-001".to_string());
+        aci_dto.id = aci_id.to_string();
+        let aci = AcI::from_dto(aci_dto, clock.clone(), store.clone()).await.expect("Error in the AcI Mock process happened.");
+        aci_proxies.push(registry.spawn_component(Box::new(aci)));
+    }
+    return VrmComponentManager::new(AdcId::new("ADC-001"), aci_proxies, clock, store, 60, 60);
+}
+
+fn add_probe_job(store: &ReservationStore, clock: Arc<GlobalClock>) -> ReservationId {
+    let mut store_handle = store.clone();
+    let reservation_id = store.add(create_node_reservation(ReservationName::new("Probe-Job"), 4, 0, 120, ReservationState::Open, clock));
+    store_handle.set_booking_interval_end(reservation_id, 600);
+    return reservation_id;
+}
+
+#[tokio::test]
+async fn test_probing_stops_after_n_components() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = get_manager_with_five_components(clock.clone(), store.clone()).await;
+    let reservation_id = add_probe_job(&store, clock);
+
+    let all_probes = manager.probe_all_components(reservation_id);
+    assert_eq!(all_probes.get_component_ids().len(), 5);
+
+    let first_probes = manager.probe_first_n_components(reservation_id, 2, VrmComponentOrder::OrderStartFirst);
+
+    assert!(!first_probes.is_empty());
+    assert!(first_probes.get_component_ids().len() <= 2, "Probed VrmComponents: {:?}", first_probes.get_component_ids());
+    assert!(first_probes.len() < all_probes.len());
+    assert_ne!(store.get_state(reservation_id), ReservationState::Rejected);
+}
+
+/// The VrmComponents are probed in the configured order, i.e. with `OrderStartFirst` in the order of registration
+/// and with `OrderNext(3)` starting at the fourth registered VrmComponent.
+#[tokio::test]
+async fn test_probing_follows_configured_order() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = get_manager_with_five_components(clock.clone(), store.clone()).await;
+    let reservation_id = add_probe_job(&store, clock);
+
+    for (vrm_component_order, expected_component_ids) in
+        [(VrmComponentOrder::OrderStartFirst, ["AcI-001", "AcI-002"]), (VrmComponentOrder::OrderNext(3), ["AcI-004", "AcI-005"])]
+    {
+        let expected_component_ids: Vec<ComponentId> = expected_component_ids.map(ComponentId::new).to_vec();
+
+        assert_eq!(manager.get_ordered_vrm_components(vrm_component_order)[..2], expected_component_ids[..]);
+        assert_eq!(manager.probe_first_n_components(reservation_id, 2, vrm_component_order).get_component_ids(), expected_component_ids);
+    }
+}
+
+/// If `n` exceeds the number of VrmComponents, all VrmComponents are probed.
+#[tokio::test]
+async fn test_large_n_probes_all_components() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut manager = get_manager_with_five_components(clock.clone(), store.clone()).await;
+    let reservation_id = add_probe_job(&store, clock);
+
+    let probes = manager.probe_first_n_components(reservation_id, 10, VrmComponentOrder::OrderStartFirst);
+
+    assert_eq!(probes.get_component_ids().len(), 5);
+    assert_eq!(probes.len(), manager.probe_all_components(reservation_id).len());
+}
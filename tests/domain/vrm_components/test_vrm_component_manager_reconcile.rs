@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::reconcile::ReconcileReport;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ClientId, DUMMY_COMPONENT_ID, ReservationName, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_node_reservation, get_direct_mapping_workflow_dto};
+
+/// Creates the workflow c0 -> (c1, c2) -> c3 and marks its nodes as reserved on the `DUMMY_COMPONENT_ID`.
+/// Only the nodes are tracked as not committed, the workflow itself is not registered at the manager.
+fn setup() -> (VrmComponentManager, ReservationStore, ReservationId, Vec<ReservationId>) {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock, store.clone(), 60, 60);
+
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Reconcile-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let Some(Reservation::Workflow(workflow)) = store.get_reservation_snapshot(workflow_id) else {
+        panic!("Expected Workflow reservation");
+    };
+    let subtask_ids: Vec<ReservationId> =
+        ["c0", "c1", "c2", "c3"].iter().map(|node_id| workflow.nodes[&WorkflowNodeId::new(*node_id)].reservation_id).collect();
+
+    for subtask_id in subtask_ids.iter() {
+        store.update_state(*subtask_id, ReservationState::ReserveAnswer);
+    }
+
+    return (manager, store, workflow_id, subtask_ids);
+}
+
+#[test]
+fn test_reconcile_removes_orphaned_workflow_subtask() {
+    let (mut manager, store, workflow_id, subtask_ids) = setup();
+
+    // c0 and c1 belong to the registered workflow, c2 is an orphan of an interrupted reserve
+    for subtask_id in &subtask_ids[..2] {
+        manager.res_to_vrm_component.insert(*subtask_id, DUMMY_COMPONENT_ID.clone());
+        manager.not_committed_reservations.insert(*subtask_id, DUMMY_COMPONENT_ID.clone());
+        manager.reverse_workflow_subtasks.insert(*subtask_id, workflow_id);
+    }
+    let orphan_id = subtask_ids[2];
+    manager.not_committed_reservations.insert(orphan_id, DUMMY_COMPONENT_ID.clone());
+    manager.reserve_times.insert(orphan_id, 0);
+
+    let report = manager.reconcile();
+
+    assert_eq!(report.removed, vec![(orphan_id, DUMMY_COMPONENT_ID.clone())]);
+    assert!(report.failed.is_empty());
+    assert_eq!(store.get_state(orphan_id), ReservationState::Deleted);
+    assert!(!manager.not_committed_reservations.contains_key(&orphan_id));
+    assert!(!manager.reserve_times.contains_key(&orphan_id));
+    assert!(!manager.res_to_vrm_component.contains_key(&orphan_id));
+
+    // Subtasks of the registered workflow stay untouched
+    for subtask_id in &subtask_ids[..2] {
+        assert!(manager.not_committed_reservations.contains_key(subtask_id));
+        assert_eq!(store.get_state(*subtask_id), ReservationState::ReserveAnswer);
+    }
+
+    // A second reconcile finds nothing
+    assert!(manager.reconcile().is_empty());
+}
+
+#[test]
+fn test_reconcile_ignores_atomic_reservations() {
+    let (mut manager, store, _, _) = setup();
+    let clock = Arc::new(GlobalClock::new(true));
+
+    let task_id = store.add(create_node_reservation(ReservationName::new("Task-Atomic"), 4, 0, 600, ReservationState::ReserveAnswer, clock));
+    manager.res_to_vrm_component.insert(task_id, DUMMY_COMPONENT_ID.clone());
+    manager.not_committed_reservations.insert(task_id, DUMMY_COMPONENT_ID.clone());
+
+    assert_eq!(manager.reconcile(), ReconcileReport::default());
+    assert!(manager.not_committed_reservations.contains_key(&task_id));
+    assert_eq!(store.get_state(task_id), ReservationState::ReserveAnswer);
+}
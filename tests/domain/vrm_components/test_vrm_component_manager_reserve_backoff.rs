@@ -0,0 +1,160 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::reserve_config::{
+    BackoffClock, ReserveConfig,
+};
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::probe_reservations::ProbeReservationComparator;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_sync_gate::ReservationSyncGate;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ReservationName};
+
+use crate::common::{RejectingReserveComponent, create_dummy_aci, create_node_reservation};
+
+/// Controllable clock, which does not block but records all requested waiting intervals.
+#[derive(Debug, Default)]
+struct RecordingClock {
+    waited: Mutex<Vec<Duration>>,
+}
+
+impl BackoffClock for RecordingClock {
+    fn sleep(&self, duration: Duration) {
+        self.waited.lock().unwrap().push(duration);
+    }
+}
+
+/// The only VrmComponent answers the probes, but rejects every reserve, so all retries are performed.
+/// The intervals must follow 1s, 2s, 4s and are capped by the max backoff of 5s.
+#[tokio::test]
+async fn test_reserve_retries_follow_exponential_backoff() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let registry = RegistryClient::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60);
+
+    let aci = create_dummy_aci(clock.clone(), store.clone()).await;
+    let rejecting_component = RejectingReserveComponent { aci, reservation_store: store.clone(), answer_state: ReservationState::Rejected };
+    assert!(manager.add_vrm_component(registry.spawn_component(Box::new(rejecting_component)), clock.clone(), store.clone(), 60, 60));
+
+    let recording_clock = Arc::new(RecordingClock::default());
+    manager.reserve_config = ReserveConfig {
+        probe_retries: 4,
+        initial_backoff: Duration::from_secs(1),
+        backoff_multiplier: 2,
+        max_backoff: Duration::from_secs(5),
+        gate_timeout: Duration::from_secs(15),
+        clock: recording_clock.clone(),
+    };
+
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::Open, clock));
+    let mut grid_component_res_database = HashMap::new();
+
+    let result = manager.reserve_task_at_best_vrm_component(
+        res_id,
+        None,
+        &mut grid_component_res_database,
+        ProbeReservationComparator::EFTReservationCompare,
+        |_, _| Ordering::Equal,
+    );
+
+    let expected_backoff = vec![Duration::from_secs(1), Duration::from_secs(2), Duration::from_secs(4), Duration::from_secs(5)];
+
+    assert!(result.is_none());
+    assert!(grid_component_res_database.is_empty());
+    assert_eq!(manager.reserve_config.get_backoff_schedule(), expected_backoff);
+    assert_eq!(*recording_clock.waited.lock().unwrap(), expected_backoff);
+}
+
+/// Without retries the manager gives up after the first failed probe round, without any backoff.
+#[test]
+fn test_reserve_without_retries_gives_up_immediately() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let recording_clock = Arc::new(RecordingClock::default());
+    let reserve_config = ReserveConfig { probe_retries: 0, clock: recording_clock.clone(), ..ReserveConfig::default() };
+    let mut manager = VrmComponentManager::new_with_config(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60, reserve_config);
+
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::Open, clock));
+    let mut grid_component_res_database = HashMap::new();
+
+    let result = manager.reserve_task_at_best_vrm_component(
+        res_id,
+        None,
+        &mut grid_component_res_database,
+        ProbeReservationComparator::EFTReservationCompare,
+        |_, _| Ordering::Equal,
+    );
+
+    assert!(result.is_none());
+    assert!(manager.reserve_config.get_backoff_schedule().is_empty());
+    assert!(recording_clock.waited.lock().unwrap().is_empty());
+}
+
+/// Without any VrmComponent no probe round finds a candidate, hence the reserve is given up without backoff.
+#[test]
+fn test_reserve_without_candidates_skips_backoff() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let recording_clock = Arc::new(RecordingClock::default());
+    let reserve_config = ReserveConfig { probe_retries: 4, clock: recording_clock.clone(), ..ReserveConfig::default() };
+    let mut manager = VrmComponentManager::new_with_config(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60, reserve_config);
+
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::Open, clock));
+
+    let result =
+        manager.reserve_task_at_best_vrm_component(res_id, None, &mut HashMap::new(), ProbeReservationComparator::EFTReservationCompare, |_, _| {
+            Ordering::Equal
+        });
+
+    assert!(result.is_none());
+    assert!(recording_clock.waited.lock().unwrap().is_empty());
+}
+
+/// The reserve path waits for the answer of the AcI at most the configured `gate_timeout` instead of the default.
+#[tokio::test]
+async fn test_reserve_of_unanswered_gate_is_limited_by_gate_timeout() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let registry = RegistryClient::new();
+    let reserve_config = ReserveConfig { probe_retries: 0, gate_timeout: Duration::from_millis(10), ..ReserveConfig::default() };
+    let mut manager = VrmComponentManager::new_with_config(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60, reserve_config);
+
+    let aci = create_dummy_aci(clock.clone(), store.clone()).await;
+    let silent_component =
+        RejectingReserveComponent { aci, reservation_store: store.clone(), answer_state: ReservationState::ReserveProbeReservation };
+    assert!(manager.add_vrm_component(registry.spawn_component(Box::new(silent_component)), clock.clone(), store.clone(), 60, 60));
+
+    let res_id = store.add(create_node_reservation(ReservationName::new("Job-1"), 2, 0, 60, ReservationState::Open, clock));
+    let mut grid_component_res_database = HashMap::new();
+
+    let started = std::time::Instant::now();
+    let result = manager.reserve_task_at_best_vrm_component(
+        res_id,
+        None,
+        &mut grid_component_res_database,
+        ProbeReservationComparator::EFTReservationCompare,
+        |_, _| Ordering::Equal,
+    );
+
+    assert!(result.is_none());
+    assert!(grid_component_res_database.is_empty());
+    assert!(started.elapsed() < Duration::from_secs(5), "The reserve waited {:?}, instead of the gate_timeout.", started.elapsed());
+}
+
+/// A gate, which is never answered by an AcI, is treated as rejected after the configured `gate_timeout`.
+#[test]
+fn test_gate_wait_is_limited_by_gate_timeout() {
+    let reserve_config = ReserveConfig { gate_timeout: Duration::from_millis(10), ..ReserveConfig::default() };
+    let gate = ReservationSyncGate::new(ReservationState::ReserveProbeReservation);
+
+    let result = reserve_config.wait_at_gate(&gate);
+
+    assert_eq!(result.state, ReservationState::Rejected);
+    assert!(result.aci_id.is_none());
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::metrics::SatisfactionWeight;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_order::VrmComponentOrder;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ComponentId, ShadowScheduleId};
+
+use crate::common::{HookedComponent, VrmComponentHooks, create_dummy_aci_with_id};
+
+/// Wraps the AcI `aci_id` into a VrmComponent with a fixed node capacity and a fixed satisfaction.
+async fn create_fixed_satisfaction_component(
+    aci_id: &str,
+    capacity: i64,
+    satisfaction: f64,
+    clock: Arc<GlobalClock>,
+    store: ReservationStore,
+) -> HookedComponent {
+    let aci = create_dummy_aci_with_id(aci_id, clock, store).await;
+    let hooks = VrmComponentHooks {
+        get_total_capacity: Some(Box::new(move |_: &AcI| capacity)),
+        get_total_node_capacity: Some(Box::new(move |_: &AcI| capacity)),
+        get_satisfaction: Some(Box::new(move |_: &mut AcI, _: i64, _: i64, _: Option<ShadowScheduleId>| satisfaction)),
+        get_system_satisfaction: Some(Box::new(move |_: &mut AcI, _: Option<ShadowScheduleId>| satisfaction)),
+        ..Default::default()
+    };
+    return HookedComponent { aci, hooks };
+}
+
+/// Creates a two-level hierarchy: The top manager has a direct AcI (capacity 100, satisfaction 1.0) and a child ADC,
+/// which wraps a single AcI (capacity 100, satisfaction 0.0).
+async fn get_two_level_manager() -> VrmComponentManager {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let registry = RegistryClient::new();
+
+    let leaf = create_fixed_satisfaction_component("AcI-001", 100, 1.0, clock.clone(), store.clone()).await;
+    let child_leaf = create_fixed_satisfaction_component("AcI-002", 100, 0.0, clock.clone(), store.clone()).await;
+    let leaf_proxy = registry.spawn_component(Box::new(leaf));
+    let child_leaf_proxy = registry.spawn_component(Box::new(child_leaf));
+
+    let child_adc = ADC::new(
+        AdcId::new("ADC-Child"),
+        vec![child_leaf_proxy],
+        registry.clone(),
+        store.clone(),
+        None,
+        VrmComponentOrder::OrderStartFirst,
+        60,
+        clock.clone(),
+        60,
+        60,
+    );
+    let child_adc_proxy = registry.spawn_component(Box::new(child_adc));
+
+    return VrmComponentManager::new(AdcId::new("ADC-Top"), vec![leaf_proxy, child_adc_proxy], clock, store, 60, 60);
+}
+
+#[tokio::test]
+async fn test_hierarchy_depth_of_two_level_manager() {
+    let manager = get_two_level_manager().await;
+
+    assert_eq!(manager.vrm_components.get(&ComponentId::new("AcI-001")).unwrap().vrm_component.get_hierarchy_depth(), 0);
+    assert_eq!(manager.vrm_components.get(&ComponentId::new("ADC-Child")).unwrap().vrm_component.get_hierarchy_depth(), 1);
+    assert_eq!(manager.get_hierarchy_depth(), 2);
+}
+
+/// With the default `Capacity` weighting both children have the weight 100, hence the average is 0.5.
+/// With a depth decay of 0.5, the child ADC (depth 1) only has the weight 50, hence the average is 100 / 150.
+#[tokio::test]
+async fn test_depth_decay_damps_satisfaction_of_child_adc() {
+    let mut manager = get_two_level_manager().await;
+    assert_eq!(manager.satisfaction_weight_mode, SatisfactionWeight::Capacity);
+
+    let plain_satisfaction = manager.get_satisfaction(0, 600, None);
+    let plain_system_satisfaction = manager.get_system_satisfaction(None);
+    assert!((plain_satisfaction - 0.5).abs() < 1e-9);
+    assert!((plain_system_satisfaction - 0.5).abs() < 1e-9);
+
+    manager.satisfaction_weight_mode = SatisfactionWeight::CapacityWithDepthDecay { factor: 0.5 };
+    let decayed_satisfaction = manager.get_satisfaction(0, 600, None);
+    let decayed_system_satisfaction = manager.get_system_satisfaction(None);
+    assert!((decayed_satisfaction - 100.0 / 150.0).abs() < 1e-9);
+    assert!((decayed_system_satisfaction - 100.0 / 150.0).abs() < 1e-9);
+    assert!(decayed_satisfaction > plain_satisfaction);
+}
+
+/// A decay factor of 1.0 is equivalent to the plain capacity weighting.
+#[tokio::test]
+async fn test_depth_decay_with_factor_one_matches_capacity() {
+    let mut manager = get_two_level_manager().await;
+    let plain_satisfaction = manager.get_satisfaction(0, 600, None);
+
+    manager.satisfaction_weight_mode = SatisfactionWeight::CapacityWithDepthDecay { factor: 1.0 };
+    assert!((manager.get_satisfaction(0, 600, None) - plain_satisfaction).abs() < 1e-9);
+}
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ComponentId};
+
+use crate::common::get_aci_dto;
+
+async fn get_seeded_manager(seed: u64) -> VrmComponentManager {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let registry = RegistryClient::new();
+    let mut manager = VrmComponentManager::new_seeded(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60, seed);
+
+    for aci_id in ["AcI-001", "AcI-002", "AcI-003", "AcI-004", "AcI-005"] {
+        let mut aci_dto = get_aci_dto("ADC-001".to_string());
+        aci_dto.id = aci_id.to_string();
+        let aci = AcI::from_dto(aci_dto, clock.clone(), store.clone()).await.expect("Error in the AcI Mock process happened.");
+        assert!(manager.add_vrm_component(registry.spawn_component(Box::new(aci)), clock.clone(), store.clone(), 60, 60));
+    }
+    return manager;
+}
+
+/// Two managers with the same seed must produce the same sequence of random VrmComponent orders.
+#[tokio::test]
+async fn test_same_seed_results_in_same_order() {
+    let first_manager = get_seeded_manager(7).await;
+    let second_manager = get_seeded_manager(7).await;
+
+    for _ in 0..10 {
+        let first_order = first_manager.get_random_ordered_vrm_components();
+        let second_order = second_manager.get_random_ordered_vrm_components();
+
+        assert_eq!(first_order, second_order);
+
+        let mut sorted_order = first_order.clone();
+        sorted_order.sort();
+        assert_eq!(
+            sorted_order,
+            vec![
+                ComponentId::new("AcI-001"),
+                ComponentId::new("AcI-002"),
+                ComponentId::new("AcI-003"),
+                ComponentId::new("AcI-004"),
+                ComponentId::new("AcI-005")
+            ]
+        );
+    }
+}
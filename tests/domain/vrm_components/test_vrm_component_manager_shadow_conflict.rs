@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_notification_listener::ReservationNotificationListener;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ReservationName, ShadowScheduleId};
+
+use crate::common::{create_adc_with_dummy_aci, create_node_reservation};
+
+#[derive(Debug, Default)]
+struct CountingListener {
+    notifications: usize,
+}
+
+impl ReservationNotificationListener for CountingListener {
+    fn on_reservation_change(&mut self, _: ReservationId, _: ReservationName, _: ReservationState, _: ReservationState) {
+        self.notifications += 1;
+    }
+}
+
+/// Creates an ADC with one AcI and the two shadow schedules A and B. Both reserve `capacity` of the AcI in the window
+/// [0, 600] resp. [300, 900], additionally B reserves the AcI in the non-overlapping window [1200, 1800].
+async fn get_adc_with_two_shadow_schedules(capacity: impl Fn(i64) -> i64) -> (ADC, ShadowScheduleId, ShadowScheduleId, [ReservationId; 3]) {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock.clone(), store.clone()).await;
+    let component_id = adc.manager.vrm_components.keys().next().cloned().unwrap();
+    let capacity = capacity(adc.manager.vrm_components[&component_id].vrm_component.get_total_capacity());
+
+    let add_task = |name: &str, start: i64, end: i64| {
+        store.add(create_node_reservation(ReservationName::new(name), capacity, start, end, ReservationState::ReserveAnswer, clock.clone()))
+    };
+    let task_a = add_task("Task-A", 0, 600);
+    let task_b = add_task("Task-B", 300, 900);
+    let task_c = add_task("Task-C", 1200, 1800);
+
+    let shadow_a = ShadowScheduleId::new("Test-Shadow-A");
+    let shadow_b = ShadowScheduleId::new("Test-Shadow-B");
+    assert!(adc.manager.create_shadow_schedule(shadow_a.clone()), "Creation of the shadow schedule A failed.");
+    assert!(adc.manager.create_shadow_schedule(shadow_b.clone()), "Creation of the shadow schedule B failed.");
+
+    assert!(adc.manager.register_shadow_workflow_subtasks(shadow_a.clone(), &HashMap::from([(task_a, component_id.clone())])));
+    assert!(
+        adc.manager.register_shadow_workflow_subtasks(shadow_b.clone(), &HashMap::from([(task_b, component_id.clone()), (task_c, component_id)]))
+    );
+
+    return (adc, shadow_a, shadow_b, [task_a, task_b, task_c]);
+}
+
+/// Open shadow schedules do not block each other, only the commit of B after A is checked against A.
+#[tokio::test]
+async fn test_commit_overcommitting_shadow_schedule_is_refused() {
+    let (mut adc, shadow_a, shadow_b, [task_a, task_b, _]) = get_adc_with_two_shadow_schedules(|total_capacity| total_capacity / 2 + 1).await;
+
+    assert!(adc.manager.shadow_schedule_conflicts(shadow_a.clone()).is_empty());
+    assert!(adc.manager.shadow_schedule_conflicts(shadow_b.clone()).is_empty());
+    assert!(adc.manager.commit_shadow_schedule(shadow_a), "Without commits in between the commit of A must succeed.");
+
+    let mut expected = vec![task_a, task_b];
+    expected.sort();
+    assert_eq!(adc.manager.shadow_schedule_conflicts(shadow_b.clone()), expected);
+    assert!(!adc.manager.commit_shadow_schedule(shadow_b.clone()), "The commit of B must be refused, as A was committed in between.");
+    assert!(adc.manager.shadow_schedule_reservations.contains_key(&shadow_b), "A refused commit must keep the shadow schedule.");
+
+    // A shadow schedule created after the commit of A already contains A
+    let shadow_c = ShadowScheduleId::new("Test-Shadow-C");
+    assert!(adc.manager.create_shadow_schedule(shadow_c.clone()));
+    assert!(adc.manager.shadow_schedule_conflicts(shadow_c.clone()).is_empty());
+    assert!(adc.manager.commit_shadow_schedule(shadow_c));
+}
+
+/// Overlapping reservations, which together fit into the capacity of the VrmComponent, are no conflict.
+#[tokio::test]
+async fn test_commit_of_overlapping_shadow_schedules_within_capacity() {
+    let (mut adc, shadow_a, shadow_b, _) = get_adc_with_two_shadow_schedules(|_| 4).await;
+
+    assert!(adc.manager.commit_shadow_schedule(shadow_a));
+    assert!(adc.manager.shadow_schedule_conflicts(shadow_b.clone()).is_empty());
+    assert!(adc.manager.commit_shadow_schedule(shadow_b));
+
+    // Unknown shadow schedules are ignored
+    assert!(adc.manager.shadow_schedule_conflicts(ShadowScheduleId::new("Test-Shadow-Unknown")).is_empty());
+}
+
+/// The listeners of the master store are only notified about changes of the shadow schedule after its commit.
+#[tokio::test]
+async fn test_commit_moves_listeners_to_shadow_store() {
+    let (mut adc, shadow_a, _, [task_a, _, _]) = get_adc_with_two_shadow_schedules(|_| 4).await;
+
+    let listener = Arc::new(RwLock::new(CountingListener::default()));
+    adc.manager.reservation_store.add_listener(listener.clone());
+
+    let shadow_store = adc.manager.shadow_schedule_reservations[&shadow_a].1.clone();
+    shadow_store.update_state(task_a, ReservationState::Committed);
+    assert_eq!(listener.read().unwrap().notifications, 0, "A change in the shadow store was notified to the master listeners.");
+
+    assert!(adc.manager.commit_shadow_schedule(shadow_a));
+    adc.manager.reservation_store.update_state(task_a, ReservationState::Finished);
+    assert_eq!(listener.read().unwrap().notifications, 1, "The listeners were not moved to the committed shadow store.");
+}
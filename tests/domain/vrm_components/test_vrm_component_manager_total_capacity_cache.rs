@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::rms_config_dto::rms_dto::RmsSystemWrapper;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::vrm_component_proxy::VrmComponentProxy;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_trait::VrmComponent;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ComponentId};
+
+use crate::common::get_aci_dto;
+
+/// Creates an AcI with 4 nodes with 256 CPUs each. If `with_links` is set, the links are registered as resources as well.
+async fn spawn_aci(registry: &RegistryClient, aci_id: &str, with_links: bool, clock: Arc<GlobalClock>, store: ReservationStore) -> VrmComponentProxy {
+    let mut aci_dto = get_aci_dto("ADC-001".to_string());
+    aci_dto.id = aci_id.to_string();
+    if with_links {
+        if let RmsSystemWrapper::DummyRms(ref mut dummy_rms_dto) = aci_dto.rms_system {
+            dummy_rms_dto.typ = "RmsSimulator".to_string();
+        }
+    }
+
+    let aci = AcI::from_dto(aci_dto, clock, store).await.expect("Error in the AcI Mock process happened.");
+    return registry.spawn_component(Box::new(aci));
+}
+
+/// Asserts, that the cached totals equal a full recomputation over all VrmComponents.
+fn assert_totals_match_recomputation(manager: &VrmComponentManager) {
+    let containers = manager.vrm_components.values();
+    let total_capacity: i64 = containers.clone().map(|container| container.vrm_component.get_total_capacity()).sum();
+    let total_node_capacity: i64 = containers.clone().map(|container| container.vrm_component.get_total_node_capacity()).sum();
+    let total_link_capacity: i64 = containers.clone().map(|container| container.vrm_component.get_total_link_capacity()).sum();
+    let link_resource_count: usize = containers.map(|container| container.vrm_component.get_link_resource_count()).sum();
+
+    assert_eq!(manager.get_total_capacity(), total_capacity);
+    assert_eq!(manager.get_total_node_capacity(), total_node_capacity);
+    assert_eq!(manager.get_total_link_capacity(), total_link_capacity);
+    assert_eq!(manager.get_link_resource_count(), link_resource_count);
+}
+
+#[tokio::test]
+async fn test_cached_totals_follow_adds_and_deletes() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let registry = RegistryClient::new();
+
+    let initial_aci = spawn_aci(&registry, "AcI-001", true, clock.clone(), store.clone()).await;
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![initial_aci], clock.clone(), store.clone(), 60, 60);
+    assert_totals_match_recomputation(&manager);
+    assert_eq!(manager.get_total_node_capacity(), 4 * 256);
+
+    for (aci_id, with_links) in [("AcI-002", false), ("AcI-003", true)] {
+        let aci = spawn_aci(&registry, aci_id, with_links, clock.clone(), store.clone()).await;
+        assert!(manager.add_vrm_component(aci, clock.clone(), store.clone(), 60, 60));
+        assert_totals_match_recomputation(&manager);
+    }
+    assert_eq!(manager.get_total_node_capacity(), 3 * 4 * 256);
+
+    assert!(manager.delete_vrm_component(ComponentId::new("AcI-001")).is_some());
+    assert_totals_match_recomputation(&manager);
+
+    // Neither a duplicate add nor the deletion of an unknown VrmComponent changes the totals
+    let duplicate_aci = spawn_aci(&registry, "AcI-002", true, clock.clone(), store.clone()).await;
+    assert!(!manager.add_vrm_component(duplicate_aci, clock.clone(), store.clone(), 60, 60));
+    assert!(manager.delete_vrm_component(ComponentId::new("AcI-Unknown")).is_none());
+    assert_totals_match_recomputation(&manager);
+    assert_eq!(manager.get_total_node_capacity(), 2 * 4 * 256);
+
+    let (total_capacity, total_link_capacity) = (manager.get_total_capacity(), manager.get_total_link_capacity());
+    manager.recompute_totals();
+    assert_eq!(manager.get_total_capacity(), total_capacity);
+    assert_eq!(manager.get_total_link_capacity(), total_link_capacity);
+}
+
+#[test]
+fn test_totals_without_components() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock, ReservationStore::new(), 60, 60);
+
+    manager.recompute_totals();
+
+    assert_eq!(manager.get_total_capacity(), 0);
+    assert_eq!(manager.get_total_node_capacity(), 0);
+    assert_eq!(manager.get_total_link_capacity(), 0);
+    assert_eq!(manager.get_link_resource_count(), 0);
+}
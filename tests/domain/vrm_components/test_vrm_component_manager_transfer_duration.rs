@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_registry::registry_client::RegistryClient;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ComponentId};
+
+use crate::common::get_aci_dto;
+
+/// "AcI-Fine" utilizes slots of 60 s, "AcI-Coarse" slots of 120 s.
+async fn get_manager_with_different_slot_widths() -> VrmComponentManager {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let registry = RegistryClient::new();
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock.clone(), store.clone(), 60, 60);
+
+    for (aci_id, num_of_slots, slot_width) in [("AcI-Fine", 60, 60), ("AcI-Coarse", 30, 120)] {
+        let mut aci_dto = get_aci_dto("ADC-001".to_string());
+        aci_dto.id = aci_id.to_string();
+        let aci = AcI::from_dto(aci_dto, clock.clone(), store.clone()).await.expect("Error in the AcI Mock process happened.");
+        assert!(manager.add_vrm_component(registry.spawn_component(Box::new(aci)), clock.clone(), store.clone(), num_of_slots, slot_width));
+    }
+    return manager;
+}
+
+#[tokio::test]
+async fn test_transfer_duration_is_rounded_to_whole_slots() {
+    let manager = get_manager_with_different_slot_widths().await;
+    let fine = ComponentId::new("AcI-Fine");
+    let coarse = ComponentId::new("AcI-Coarse");
+
+    assert_eq!(manager.get_component_slot_width(&fine), Some(60));
+    assert_eq!(manager.get_component_slot_width(&coarse), Some(120));
+
+    // 1500 / 10 = 150 s, which are 3 slots of 60 s
+    assert_eq!(manager.get_transfer_duration(1500, 10, &fine, &fine), 180);
+
+    // Between both VrmComponents the coarser schedule is relevant: 2 slots of 120 s
+    assert_eq!(manager.get_transfer_duration(1500, 10, &fine, &coarse), 240);
+    assert_eq!(manager.get_transfer_duration(1500, 10, &coarse, &fine), 240);
+
+    // A transfer, which fits exactly into one slot, is not extended
+    assert_eq!(manager.get_transfer_duration(1200, 10, &fine, &coarse), 120);
+}
+
+#[tokio::test]
+async fn test_transfer_duration_without_known_slot_width() {
+    let manager = get_manager_with_different_slot_widths().await;
+    let unknown = ComponentId::new("AcI-Unknown");
+
+    // Unregistered VrmComponents do not contribute a slot width
+    assert_eq!(manager.get_transfer_duration(1500, 10, &unknown, &unknown), 150);
+    assert_eq!(manager.get_transfer_duration(1500, 10, &unknown, &ComponentId::new("AcI-Fine")), 180);
+
+    // Without known link speed the transfer occupies a single slot
+    assert_eq!(manager.get_transfer_duration(1500, 0, &unknown, &unknown), 1);
+    assert_eq!(manager.get_transfer_duration(1500, 0, &ComponentId::new("AcI-Coarse"), &unknown), 120);
+}
@@ -1 +1,47 @@
-pub mod test_workflow_co_allocation;
\ No newline at end of file
+pub mod test_admission_controller;
+pub mod test_cpop_scheduler;
+pub mod test_data_locality_zones;
+pub mod test_dependency_latency;
+pub mod test_heft_co_allocation_window;
+pub mod test_heft_link_validation;
+pub mod test_heft_moldable_reshaping;
+pub mod test_heft_placement_snapshot;
+pub mod test_heft_priority_ordering;
+pub mod test_heft_probe;
+pub mod test_heft_rejection_diagnostics;
+pub mod test_heft_rejection_reason;
+pub mod test_heft_reserve_cancellation;
+pub mod test_heft_reserve_deadline;
+pub mod test_heft_shadow_reserve;
+pub mod test_min_min_scheduler;
+pub mod test_placement_failure_policy;
+pub mod test_soft_sync_dependency;
+pub mod test_workflow_assigned_window;
+pub mod test_workflow_bidirectional_co_allocation;
+pub mod test_workflow_builder;
+pub mod test_workflow_co_allocation;
+pub mod test_workflow_critical_path;
+pub mod test_workflow_cycle_detection;
+pub mod test_workflow_dot_export;
+pub mod test_workflow_downward_rank;
+pub mod test_workflow_dto_validation;
+pub mod test_workflow_explicit_earliest_start;
+pub mod test_workflow_implicit_dependency_ids;
+pub mod test_workflow_incremental_rank;
+pub mod test_workflow_lower_bound_makespan;
+pub mod test_workflow_merge;
+pub mod test_workflow_parse_error;
+pub mod test_workflow_pretty_print;
+pub mod test_workflow_rank_duration_cache;
+pub mod test_workflow_rank_network_speed;
+pub mod test_workflow_ready_nodes;
+pub mod test_workflow_reschedule;
+pub mod test_workflow_reservation_lookup;
+pub mod test_workflow_slack;
+pub mod test_workflow_split_oversized_nodes;
+pub mod test_workflow_stable_ordering;
+pub mod test_workflow_structural_equality;
+pub mod test_workflow_summary;
+pub mod test_workflow_to_dto;
+pub mod test_workflow_topological_order;
+pub mod test_workflow_yaml_loading;
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::admission_controller::{
+    AdmissionController, AdmissionPolicy,
+};
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::heft_sync_workflow_scheduler::HEFTSyncWorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ClientId;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, get_workflow_dto_with_one_task};
+
+/// Single task workflow, which requests `cpus` for `duration` inside the booking interval 10 to 100.
+fn get_single_task_workflow_dto(workflow_id: &str, cpus: i64, duration: i64) -> WorkflowDto {
+    let mut workflow_dto = get_workflow_dto_with_one_task(workflow_id.to_string(), ReservationStateDto::Open, ReservationProceedingDto::Reserve);
+    workflow_dto.tasks[0].id = format!("{}-c0", workflow_id);
+    workflow_dto.tasks[0].link_reservation = vec![];
+    workflow_dto.tasks[0].node_reservation.cpus = cpus;
+    workflow_dto.tasks[0].node_reservation.duration = duration;
+    return workflow_dto;
+}
+
+/// Adds one large workflow followed by four small workflows (all in the same arrival order) and admits them.
+/// Every task requests 250 cpus and all tasks overlap in time, so at most four workflows fit on the four
+/// nodes (256 cpus each) of the dummy AcI.
+async fn admit_large_and_small_workflows(admission_policy: AdmissionPolicy) -> (Vec<ReservationId>, Vec<ReservationId>) {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+    adc.workflow_scheduler = Some(HEFTSyncWorkflowScheduler::new(store.clone()));
+
+    let client_id = ClientId::new("Test-Client-001");
+    let mut admission_controller = AdmissionController::new(admission_policy, store.clone());
+
+    let large_workflow = Workflow::create_form_dto(get_single_task_workflow_dto("Large-Workflow", 250, 80), client_id.clone(), store.clone())
+        .expect("Workflow construction failed.");
+    admission_controller.add_workflow(large_workflow);
+
+    let mut small_workflows = Vec::new();
+    for i in 0..4 {
+        let small_workflow =
+            Workflow::create_form_dto(get_single_task_workflow_dto(&format!("Small-Workflow-{}", i), 250, 50), client_id.clone(), store.clone())
+                .expect("Workflow construction failed.");
+        admission_controller.add_workflow(small_workflow);
+        small_workflows.push(small_workflow);
+    }
+
+    let admitted = admission_controller.admit(&mut adc);
+    return (admitted, small_workflows);
+}
+
+#[tokio::test]
+async fn test_shortest_job_first_admits_more_small_workflows_than_fifo() {
+    let (sjf_admitted, sjf_small_workflows) = admit_large_and_small_workflows(AdmissionPolicy::ShortestJobFirst).await;
+    let (fifo_admitted, fifo_small_workflows) = admit_large_and_small_workflows(AdmissionPolicy::ArrivalOrder).await;
+
+    let sjf_small_admitted = sjf_admitted.iter().filter(|res_id| sjf_small_workflows.contains(res_id)).count();
+    let fifo_small_admitted = fifo_admitted.iter().filter(|res_id| fifo_small_workflows.contains(res_id)).count();
+
+    assert!(
+        sjf_small_admitted > fifo_small_admitted,
+        "SJF admitted {} small workflows, FIFO admitted {} small workflows.",
+        sjf_small_admitted,
+        fifo_small_admitted
+    );
+}
+
+#[test]
+fn test_admission_order_of_policies() {
+    let store = ReservationStore::new();
+    let client_id = ClientId::new("Test-Client-001");
+
+    let mut long_deadline_dto = get_single_task_workflow_dto("Large-Workflow", 100, 50);
+    long_deadline_dto.booking_interval_end = 200;
+    let large_workflow = Workflow::create_form_dto(long_deadline_dto, client_id.clone(), store.clone()).expect("Workflow construction failed.");
+    let small_workflow = Workflow::create_form_dto(get_single_task_workflow_dto("Small-Workflow", 10, 50), client_id, store.clone())
+        .expect("Workflow construction failed.");
+
+    for (admission_policy, expected_order) in [
+        (AdmissionPolicy::ArrivalOrder, vec![large_workflow, small_workflow]),
+        (AdmissionPolicy::ShortestJobFirst, vec![small_workflow, large_workflow]),
+        (AdmissionPolicy::EarliestDeadlineFirst, vec![small_workflow, large_workflow]),
+    ] {
+        let mut admission_controller = AdmissionController::new(admission_policy, store.clone());
+        admission_controller.add_workflow(large_workflow);
+        admission_controller.add_workflow(small_workflow);
+
+        assert_eq!(admission_controller.get_admission_order(), expected_order, "Wrong admission order for {:?}", admission_policy);
+    }
+}
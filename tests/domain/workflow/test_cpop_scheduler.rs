@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::cpop_workflow_scheduler::CpopWorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::{
+    RejectionReason, WorkflowScheduler,
+};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ComponentId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc, create_adc_with_dummy_aci, create_dummy_aci_with_id, create_heft_sync_scheduler, get_direct_mapping_workflow_dto};
+
+/// Creates an ADC with a single AcI and adds the workflow to the reservation store.
+async fn setup(workflow_dto: WorkflowDto) -> (ADC, ReservationStore, ReservationId) {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    return (adc, store, workflow_res_id);
+}
+
+/// Returns the fork-join workflow c0 -> (c1, c2) -> c3, where c2 has a duration of 80, hence c0 -> c2 -> c3 is the critical path.
+fn get_workflow_dto(name: &str) -> WorkflowDto {
+    let mut workflow_dto = get_direct_mapping_workflow_dto(name.to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[2].node_reservation.duration = 80;
+    return workflow_dto;
+}
+
+fn get_makespan(store: &ReservationStore, workflow_res_id: ReservationId) -> i64 {
+    return store.get_assigned_end(workflow_res_id) - store.get_assigned_start(workflow_res_id);
+}
+
+#[tokio::test]
+async fn test_cpop_identifies_critical_path() {
+    let (_adc, store, workflow_res_id) = setup(get_workflow_dto("Test-CPOP-Critical-Path-Workflow")).await;
+    let scheduler = CpopWorkflowScheduler { heft_sync: create_heft_sync_scheduler(store.clone()) };
+
+    let (critical_co_allocations, co_allocation_keys) = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            workflow.calculate_downward_rank(10, &store).unwrap();
+            workflow.calculate_upward_rank(10, &store).unwrap();
+
+            let co_allocation_keys: Vec<_> = ["c0", "c1", "c2", "c3"]
+                .iter()
+                .map(|node_id| workflow.nodes.get(&WorkflowNodeId::new(*node_id)).unwrap().co_allocation_key.clone().unwrap())
+                .collect();
+            (scheduler.get_critical_co_allocations(workflow), co_allocation_keys)
+        })
+        .expect("Expected Workflow reservation");
+
+    assert_eq!(critical_co_allocations.len(), 3);
+    assert!(critical_co_allocations.contains(&co_allocation_keys[0]));
+    assert!(!critical_co_allocations.contains(&co_allocation_keys[1]));
+    assert!(critical_co_allocations.contains(&co_allocation_keys[2]));
+    assert!(critical_co_allocations.contains(&co_allocation_keys[3]));
+}
+
+/// On a single AcI with sufficient capacity the critical path is pinned onto the AcI, hence CPOP reaches the same
+/// makespan as HEFTSync (the length of the critical path).
+#[tokio::test]
+async fn test_cpop_makespan_matches_heft_sync_on_fork_join_workflow() {
+    let (mut heft_adc, heft_store, heft_workflow_res_id) = setup(get_workflow_dto("Test-HEFTSync-Workflow")).await;
+    let mut heft_scheduler = create_heft_sync_scheduler(heft_store.clone());
+    assert!(heft_scheduler.reserve(heft_workflow_res_id, &mut heft_adc).is_ok(), "HEFTSync reservation failed.");
+
+    let (mut cpop_adc, cpop_store, cpop_workflow_res_id) = setup(get_workflow_dto("Test-CPOP-Workflow")).await;
+    let mut cpop_scheduler = CpopWorkflowScheduler::new(cpop_store.clone());
+    assert_eq!(cpop_scheduler.name(), "CpopWorkflowScheduler");
+    assert!(cpop_scheduler.reserve(cpop_workflow_res_id, &mut cpop_adc).is_ok(), "CPOP reservation failed.");
+
+    assert_eq!(cpop_store.get_state(cpop_workflow_res_id), ReservationState::ReserveAnswer);
+    let task_res_ids = cpop_store
+        .with_workflow_mut(cpop_workflow_res_id, |workflow| workflow.nodes.values().map(|node| node.reservation_id).collect::<Vec<_>>())
+        .expect("Expected Workflow reservation");
+    for task_res_id in task_res_ids {
+        assert!(cpop_adc.manager.get_handler_id(task_res_id).is_some(), "Task was not placed by CPOP.");
+    }
+
+    assert_eq!(get_makespan(&cpop_store, cpop_workflow_res_id), 180);
+    assert_eq!(get_makespan(&cpop_store, cpop_workflow_res_id), get_makespan(&heft_store, heft_workflow_res_id));
+}
+
+#[tokio::test]
+async fn test_cpop_rolls_back_on_deadline_miss() {
+    let mut workflow_dto = get_workflow_dto("Test-CPOP-Deadline-Workflow");
+    workflow_dto.booking_interval_end = 100;
+    let (mut adc, store, workflow_res_id) = setup(workflow_dto).await;
+
+    let mut scheduler = CpopWorkflowScheduler { heft_sync: create_heft_sync_scheduler(store.clone()) };
+    assert!(scheduler.reserve(workflow_res_id, &mut adc).is_err());
+
+    assert_eq!(store.get_state(workflow_res_id), ReservationState::Rejected);
+    assert!(adc.manager.not_committed_reservations.is_empty(), "Placement was not rolled back.");
+    let diagnostics = scheduler.heft_sync.last_rejection_diagnostics().expect("No rejection diagnostics recorded.");
+    assert!(matches!(diagnostics.reason, RejectionReason::DeadlineMiss { .. }), "Unexpected rejection reason {:?}", diagnostics.reason);
+}
+
+/// With several AcIs the critical path c0 -> c2 -> c3 is pinned onto one AcI, which must not be blacklisted.
+#[tokio::test]
+async fn test_cpop_pins_critical_path_onto_non_blacklisted_aci() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let mut acis = Vec::new();
+    for aci_id in ["AcI-001", "AcI-002", "AcI-003"] {
+        acis.push(Box::new(create_dummy_aci_with_id(aci_id, clock.clone(), store.clone()).await) as _);
+    }
+    let mut adc = create_adc(acis, clock, store.clone());
+
+    let blacklisted_id = ComponentId::new("AcI-001");
+    for _ in 0..adc.manager.max_failures {
+        adc.manager.increment_failure(blacklisted_id.clone());
+    }
+    assert!(adc.manager.is_blacklisted(&blacklisted_id));
+
+    let workflow_res_id =
+        Workflow::create_form_dto(get_workflow_dto("Test-CPOP-Blacklist-Workflow"), ClientId::new("Test-Client-001"), store.clone())
+            .expect("Workflow construction failed.");
+    let mut scheduler = CpopWorkflowScheduler { heft_sync: create_heft_sync_scheduler(store.clone()) };
+    assert!(scheduler.reserve(workflow_res_id, &mut adc).is_ok(), "CPOP reservation failed.");
+
+    let critical_res_ids = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            ["c0", "c2", "c3"].iter().map(|node_id| workflow.nodes[&WorkflowNodeId::new(*node_id)].reservation_id).collect::<Vec<_>>()
+        })
+        .expect("Expected Workflow reservation");
+    let critical_path_component = adc.manager.get_handler_id(critical_res_ids[0]).expect("Critical task was not placed by CPOP.");
+
+    assert_ne!(critical_path_component, blacklisted_id);
+    for critical_res_id in &critical_res_ids {
+        assert_eq!(adc.manager.get_handler_id(*critical_res_id), Some(critical_path_component.clone()));
+    }
+}
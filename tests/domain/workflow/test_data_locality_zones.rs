@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::VrmComponentManager;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_manager::zones::ZoneCommunicationCost;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::probe_reservations::{ProbeReservationComparator, ProbeReservations};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::config::INTRA_ZONE_LINK_SPEED_FACTOR;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{AdcId, ClientId, ComponentId, ReservationName, WorkflowNodeId, ZoneId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::dependency::DataDependency;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_node_reservation, get_direct_mapping_workflow_dto};
+
+const AVERAGE_LINK_SPEED: i64 = 10;
+
+fn get_data_dependency(size: i64, latency: i64) -> DataDependency {
+    let store = ReservationStore::new();
+    let clock = Arc::new(GlobalClock::new(true));
+    let res_id = store.add(create_node_reservation(ReservationName::new("Data-c0-c1"), 1, 0, 0, ReservationState::Open, clock));
+
+    DataDependency {
+        reservation_id: res_id,
+        source_node: Some(WorkflowNodeId::new("c0")),
+        target_node: Some(WorkflowNodeId::new("c1")),
+        port_name: "port".to_string(),
+        size,
+        latency,
+    }
+}
+
+/// "Zone-A-Component-1" and "Zone-A-Component-2" are part of zone A, "Zone-B-Component" of zone B.
+fn get_zone_communication_cost() -> ZoneCommunicationCost {
+    let component_zones = HashMap::from([
+        (ComponentId::new("Zone-A-Component-1"), ZoneId::new("Zone-A")),
+        (ComponentId::new("Zone-A-Component-2"), ZoneId::new("Zone-A")),
+        (ComponentId::new("Zone-B-Component"), ZoneId::new("Zone-B")),
+    ]);
+    return ZoneCommunicationCost::new(component_zones, AVERAGE_LINK_SPEED);
+}
+
+#[test]
+fn test_intra_zone_communication_is_cheaper_than_cross_zone_communication() {
+    let cost = get_zone_communication_cost();
+    let data_dependency = get_data_dependency(1000, 2);
+    let source = ComponentId::new("Zone-A-Component-1");
+
+    assert_eq!(cost.get_communication_time(&data_dependency, &source, &source), 0);
+    assert_eq!(
+        cost.get_communication_time(&data_dependency, &source, &ComponentId::new("Zone-A-Component-2")),
+        1000 / (AVERAGE_LINK_SPEED * INTRA_ZONE_LINK_SPEED_FACTOR) + 2
+    );
+    assert_eq!(cost.get_communication_time(&data_dependency, &source, &ComponentId::new("Zone-B-Component")), 1000 / AVERAGE_LINK_SPEED + 2);
+
+    // VrmComponents without zone are treated like a different zone
+    assert_eq!(cost.get_communication_time(&data_dependency, &source, &ComponentId::new("No-Zone-Component")), 1000 / AVERAGE_LINK_SPEED + 2);
+}
+
+/// Before the placement, the communication time is the mean over all pairs of VrmComponents: Only the pair of the
+/// two zone A components communicates within a zone (1000 / 100 = 10), the two other pairs across zones (1000 / 10 = 100).
+#[test]
+fn test_average_communication_time_considers_zones() {
+    let data_dependency = get_data_dependency(1000, 0);
+
+    assert_eq!(get_zone_communication_cost().get_average_communication_time(&data_dependency), (10 + 2 * 100) / 3);
+
+    // Without zones the average link speed is utilized
+    let cost_without_zones = ZoneCommunicationCost::new(HashMap::new(), AVERAGE_LINK_SPEED);
+    assert_eq!(cost_without_zones.get_average_communication_time(&data_dependency), 100);
+
+    // If all VrmComponents share one zone, every transfer is an intra-zone transfer
+    let single_zone_cost = ZoneCommunicationCost::new(
+        HashMap::from([
+            (ComponentId::new("Zone-A-Component-1"), ZoneId::new("Zone-A")),
+            (ComponentId::new("Zone-A-Component-2"), ZoneId::new("Zone-A")),
+        ]),
+        AVERAGE_LINK_SPEED,
+    );
+    assert_eq!(single_zone_cost.get_average_communication_time(&data_dependency), 10);
+}
+
+/// Workflow c0 -> (c1, c2) -> c3 with durations of 50, where only c0 -> c2 transfers data (size 1000). The zone-aware
+/// rank of c0 uses the average communication time of 70 instead of 100 and the critical path matches this rank.
+#[test]
+fn test_upward_rank_with_zones_uses_zone_aware_communication_time() {
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Zone-Rank-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+    let cost = get_zone_communication_cost();
+
+    let (rank_without_zones, rank_with_zones, (length, path)) = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            workflow
+                .data_dependencies
+                .values_mut()
+                .find(|dep| dep.source_node == Some(WorkflowNodeId::new("c0")) && dep.target_node == Some(WorkflowNodeId::new("c2")))
+                .expect("Expected data dependency c0 -> c2")
+                .size = 1000;
+            let entry_rank = |workflow: &Workflow| workflow.co_allocations.values().map(|co_allocation| co_allocation.rank_upward).max().unwrap();
+
+            workflow.calculate_upward_rank(AVERAGE_LINK_SPEED, &store).unwrap();
+            let rank_without_zones = entry_rank(workflow);
+
+            workflow.calculate_upward_rank_with_zones(&cost, &store).unwrap();
+            (rank_without_zones, entry_rank(workflow), workflow.critical_path_with_zones(&cost, &store))
+        })
+        .expect("Expected Workflow reservation");
+
+    assert_eq!(rank_without_zones, 50 + 100 + 100);
+    assert_eq!(rank_with_zones, 50 + 70 + 100);
+    assert_eq!(length, rank_with_zones);
+    assert_eq!(path, vec![WorkflowNodeId::new("c0"), WorkflowNodeId::new("c2"), WorkflowNodeId::new("c3")]);
+}
+
+/// The predecessor c0 is placed on "Zone-A-Component-1". "Zone-B-Component" finishes c1 slightly earlier than
+/// "Zone-A-Component-2", but the cross zone transfer of the data outweighs the earlier finish time.
+#[test]
+fn test_scheduler_prefers_data_dependent_pair_within_one_zone() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let cost = get_zone_communication_cost();
+    let data_dependency = get_data_dependency(1000, 0);
+    let source = ComponentId::new("Zone-A-Component-1");
+
+    let res_id = store.add(create_node_reservation(ReservationName::new("c1"), 2, 0, 200, ReservationState::Open, clock.clone()));
+    let mut probe_reservations = ProbeReservations::new(res_id, store.clone());
+
+    for (component_id, end) in [("Zone-A-Component-2", 100), ("Zone-B-Component", 90)] {
+        let mut component_probe = ProbeReservations::new(res_id, store.clone());
+        let probe_res = create_node_reservation(ReservationName::new("c1"), 2, end - 50, end, ReservationState::ProbeAnswer, clock.clone());
+        component_probe.add_reservation(probe_res).expect("Adding ProbeReservation failed.");
+        component_probe.add_probe_meta_data(ComponentId::new(component_id), None);
+        probe_reservations.add_probe_reservations(component_probe);
+    }
+
+    let (component_id, _) = probe_reservations
+        .prompt_best_with_penalty(res_id, ProbeReservationComparator::EFTReservationCompare, |component_id| {
+            cost.get_communication_time(&data_dependency, &source, component_id)
+        })
+        .expect("Promotion failed.");
+
+    assert!(component_id.compare(&ComponentId::new("Zone-A-Component-2")), "Data dependent tasks should be kept within one zone.");
+    assert_eq!(store.get_assigned_end(res_id), 100);
+}
+
+#[test]
+fn test_manager_zone_assignment() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let mut manager = VrmComponentManager::new(AdcId::new("ADC-001"), vec![], clock, ReservationStore::new(), 60, 60);
+    assert!(!manager.get_zone_communication_cost().has_zones());
+
+    manager.set_component_zone(ComponentId::new("AcI-001"), ZoneId::new("Zone-A"));
+    manager.set_component_zone(ComponentId::new("AcI-002"), ZoneId::new("Zone-A"));
+
+    assert_eq!(manager.get_component_zone(&ComponentId::new("AcI-001")), Some(&ZoneId::new("Zone-A")));
+    assert_eq!(manager.get_component_zone(&ComponentId::new("AcI-003")), None);
+
+    let cost = manager.get_zone_communication_cost();
+    assert!(cost.has_zones());
+    assert!(cost.is_same_zone(&ComponentId::new("AcI-001"), &ComponentId::new("AcI-002")));
+    assert!(!cost.is_same_zone(&ComponentId::new("AcI-001"), &ComponentId::new("AcI-003")));
+}
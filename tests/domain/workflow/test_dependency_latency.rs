@@ -0,0 +1,26 @@
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationId;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::WorkflowNodeId;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::dependency::DataDependency;
+
+/// A short transfer (size 10 with a speed of 10 -> 1s) over a high latency link (100s)
+/// must be estimated slower than the size alone implies.
+#[test]
+fn test_high_latency_short_transfer_communication_time() {
+    let avg_net_speed = 10;
+
+    let data_dependency = DataDependency {
+        reservation_id: ReservationId::default(),
+        source_node: Some(WorkflowNodeId::new("A")),
+        target_node: Some(WorkflowNodeId::new("B")),
+        port_name: "port".to_string(),
+        size: 10,
+        latency: 100,
+    };
+
+    let size_only_estimate = data_dependency.size / avg_net_speed;
+    let communication_time = data_dependency.get_communication_time(avg_net_speed);
+
+    assert_eq!(size_only_estimate, 1);
+    assert_eq!(communication_time, 101);
+    assert!(communication_time > size_only_estimate);
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ComponentId, ReservationName};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc, create_dummy_aci_with_id, create_heft_sync_scheduler, create_node_reservation, get_direct_mapping_workflow_dto};
+
+/// Creates two non-moldable members of a CoAllocation with the uneven durations 50 s and 120 s.
+fn create_uneven_members(store: &ReservationStore) -> Vec<ReservationId> {
+    let clock = Arc::new(GlobalClock::new(true));
+    return [("Short-Member", 50), ("Long-Member", 120)]
+        .into_iter()
+        .map(|(name, duration)| store.add(create_node_reservation(ReservationName::new(name), 2, 0, duration, ReservationState::Open, clock.clone())))
+        .collect();
+}
+
+/// The first task is placed in [100, 150], hence the window of the CoAllocation is widened for the longer member.
+#[test]
+fn test_co_allocation_window_is_widened_to_longest_member() {
+    let store = ReservationStore::new();
+    let member_ids = create_uneven_members(&store);
+    let scheduler = create_heft_sync_scheduler(store.clone());
+
+    let co_allocation_members = scheduler.apply_co_allocation_window(&member_ids, 100, 150, 2, 1000).expect("CoAllocation window should fit.");
+
+    assert_eq!(co_allocation_members, vec![(member_ids[0], (100, 150)), (member_ids[1], (100, 220))]);
+    for member_id in member_ids {
+        assert_eq!(store.get_booking_interval_start(member_id), 100);
+        assert_eq!(store.get_booking_interval_end(member_id), 220);
+    }
+}
+
+/// The widened window [100, 220] exceeds the booking interval end of the workflow, so no window is applied.
+#[test]
+fn test_co_allocation_window_fails_beyond_booking_interval_end() {
+    let store = ReservationStore::new();
+    let member_ids = create_uneven_members(&store);
+    let scheduler = create_heft_sync_scheduler(store.clone());
+
+    assert!(scheduler.apply_co_allocation_window(&member_ids, 100, 150, 2, 200).is_none());
+    for member_id in member_ids {
+        assert_eq!(store.get_booking_interval_start(member_id), 0);
+    }
+}
+
+/// Workflow c0 -> (c1, c2) -> c3, where c1 is synchronized with the seven tasks c1-1, ..., c1-7, which all
+/// need 250 CPUs for 250 s.
+/// A single AcI has 4 nodes with 256 CPUs, hence the members of the CoAllocation must be split onto both AcIs.
+#[tokio::test]
+async fn test_co_allocation_members_are_placed_on_different_components() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let aci_1 = create_dummy_aci_with_id("AcI-001", clock.clone(), store.clone()).await;
+    let aci_2 = create_dummy_aci_with_id("AcI-002", clock.clone(), store.clone()).await;
+    let mut adc = create_adc(vec![Box::new(aci_1), Box::new(aci_2)], clock, store.clone());
+
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Multi-Component-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[1].node_reservation.cpus = 250;
+    workflow_dto.tasks[1].node_reservation.duration = 250;
+    workflow_dto.tasks[1].node_reservation.is_moldable = false;
+    for index in 1..=7 {
+        let mut member = workflow_dto.tasks[1].clone();
+        member.id = format!("c1-{}", index);
+        member.link_reservation = Vec::new();
+        member.node_reservation.data_in = Vec::new();
+        member.node_reservation.data_out = Vec::new();
+        member.node_reservation.dependencies.data = Vec::new();
+        member.node_reservation.dependencies.sync = vec!["c1".to_string()];
+        workflow_dto.tasks.push(member);
+    }
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+    let member_res_ids = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            workflow.nodes.iter().filter(|(node_id, _)| node_id.id.starts_with("c1")).map(|(_, node)| node.reservation_id).collect::<Vec<_>>()
+        })
+        .unwrap();
+    assert_eq!(member_res_ids.len(), 8);
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    assert!(scheduler.reserve(workflow_res_id, &mut adc).is_ok());
+
+    let mut member_component_ids: Vec<ComponentId> = member_res_ids
+        .iter()
+        .map(|member_res_id| adc.manager.not_committed_reservations.get(member_res_id).cloned().expect("All members must be reserved."))
+        .collect();
+    member_component_ids.sort();
+    member_component_ids.dedup();
+    assert_eq!(member_component_ids, vec![ComponentId::new("AcI-001"), ComponentId::new("AcI-002")]);
+
+    let start = store.get_assigned_start(member_res_ids[0]);
+    assert!(member_res_ids.iter().all(|member_res_id| store.get_assigned_start(*member_res_id) == start));
+}
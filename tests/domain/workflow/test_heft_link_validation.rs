@@ -0,0 +1,152 @@
+use std::sync::{Arc, Mutex};
+
+use vrm_rust_workflow::api::rms_config_dto::rms_dto::RmsSystemWrapper;
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_trait::VrmComponent;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::link_reservation::LinkReservation;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{Reservation, ReservationState, ReservationTrait};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ComponentId, ReservationName, RouterId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{
+    HookedComponent, VrmComponentHooks, create_adc, create_dummy_aci_with_id, create_heft_sync_scheduler, create_node_reservation, get_aci_dto,
+    get_direct_mapping_workflow_dto,
+};
+
+/// Creates an ADC with the two AcIs "AcI-Source" and "AcI-Target", both with the routers Router-001 to Router-003.
+async fn create_source_target_adc(clock: Arc<GlobalClock>, store: ReservationStore) -> ADC {
+    let source_aci = create_dummy_aci_with_id("AcI-Source", clock.clone(), store.clone()).await;
+    let target_aci = create_dummy_aci_with_id("AcI-Target", clock.clone(), store.clone()).await;
+    return create_adc(vec![Box::new(source_aci), Box::new(target_aci)], clock, store);
+}
+
+/// Creates an AcI with the single node Node-001 (256 CPUs), which is connected to Router-001.
+async fn create_single_node_aci(aci_id: &str, clock: Arc<GlobalClock>, store: ReservationStore) -> AcI {
+    let mut aci_dto = get_aci_dto("ADC-001".to_string());
+    aci_dto.id = aci_id.to_string();
+    if let RmsSystemWrapper::DummyRms(ref mut dummy_rms_dto) = aci_dto.rms_system {
+        dummy_rms_dto.grid_nodes.truncate(1);
+    }
+    return AcI::from_dto(aci_dto, clock, store).await.expect("Error in the AcI Mock process happened.");
+}
+
+/// Wraps the AcI into a VrmComponent, whose router list (e.g. due to a faulty topology export) names the router
+/// Router-Foreign, which is not part of the network topology of the AcI. All other requests, including `contains_router`,
+/// are answered by the AcI. LinkReservations, which are offered from Router-Foreign, are recorded in `misrouted_links`.
+fn create_misrouted_component(aci: AcI, misrouted_links: Arc<Mutex<Vec<ReservationName>>>) -> HookedComponent {
+    let can_handel_hook = move |aci: &AcI, res: Reservation| {
+        if let Reservation::Link(link) = &res {
+            if link.start_point == Some(RouterId::new("Router-Foreign")) {
+                misrouted_links.lock().unwrap().push(res.get_name());
+            }
+        }
+        aci.can_handel(res)
+    };
+    let hooks = VrmComponentHooks {
+        get_router_list: Some(Box::new(|_: &AcI| vec![RouterId::new("Router-Foreign")])),
+        can_handel: Some(Box::new(can_handel_hook)),
+        ..Default::default()
+    };
+    return HookedComponent { aci, hooks };
+}
+
+/// Adds a LinkReservation, which was assigned from router `start_point` to `end_point`.
+fn add_assigned_link_reservation(store: &ReservationStore, start_point: &str, end_point: &str) -> ReservationId {
+    let clock = Arc::new(GlobalClock::new(true));
+    let Reservation::Node(node_reservation) = create_node_reservation(ReservationName::new("Transfer"), 10, 0, 60, ReservationState::Open, clock)
+    else {
+        panic!("Expected NodeReservation");
+    };
+
+    return store.add(Reservation::Link(LinkReservation {
+        base: node_reservation.base,
+        start_point: Some(RouterId::new(start_point)),
+        end_point: Some(RouterId::new(end_point)),
+    }));
+}
+
+#[tokio::test]
+async fn test_end_points_of_component_topologies_are_accepted() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let adc = create_source_target_adc(clock, store.clone()).await;
+    let link_res_id = add_assigned_link_reservation(&store, "Router-001", "Router-002");
+    let scheduler = create_heft_sync_scheduler(store.clone());
+
+    assert!(scheduler.validate_link_end_points(link_res_id, &ComponentId::new("AcI-Source"), &ComponentId::new("AcI-Target"), &adc));
+    assert_eq!(store.get_state(link_res_id), ReservationState::Open);
+}
+
+/// A start router, which is not part of the topology of the source VrmComponent, must reject the LinkReservation.
+#[tokio::test]
+async fn test_unknown_start_router_rejects_link() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let adc = create_source_target_adc(clock, store.clone()).await;
+    let link_res_id = add_assigned_link_reservation(&store, "Router-Unrelated", "Router-002");
+    let scheduler = create_heft_sync_scheduler(store.clone());
+
+    assert!(!scheduler.validate_link_end_points(link_res_id, &ComponentId::new("AcI-Source"), &ComponentId::new("AcI-Target"), &adc));
+    assert_eq!(store.get_state(link_res_id), ReservationState::Rejected);
+}
+
+/// The end router is checked against the target VrmComponent, an unknown VrmComponent has no routers at all.
+#[tokio::test]
+async fn test_end_router_of_unknown_component_rejects_link() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let adc = create_source_target_adc(clock, store.clone()).await;
+    let link_res_id = add_assigned_link_reservation(&store, "Router-001", "Router-002");
+    let scheduler = create_heft_sync_scheduler(store.clone());
+
+    assert!(!scheduler.validate_link_end_points(link_res_id, &ComponentId::new("AcI-Source"), &ComponentId::new("AcI-Unknown"), &adc));
+    assert_eq!(store.get_state(link_res_id), ReservationState::Rejected);
+}
+
+/// Both AcIs announce a router, which is not part of their topology. The parallel tasks c1 and c2 need the single node
+/// of an AcI each, hence the workflow contains a data transfer between both AcIs. This transfer is assigned to the
+/// announced router and must be rejected by the validation, before it is offered to any VrmComponent.
+#[tokio::test]
+async fn test_reserve_with_faulty_router_list_rejects_data_transfer() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let mut store = ReservationStore::new();
+    let misrouted_links = Arc::new(Mutex::new(Vec::new()));
+
+    let source_aci = create_single_node_aci("AcI-Source", clock.clone(), store.clone()).await;
+    let target_aci = create_single_node_aci("AcI-Target", clock.clone(), store.clone()).await;
+    let mut adc = create_adc(
+        vec![
+            Box::new(create_misrouted_component(source_aci, misrouted_links.clone())),
+            Box::new(create_misrouted_component(target_aci, misrouted_links.clone())),
+        ],
+        clock,
+        store.clone(),
+    );
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    scheduler.base.validate_links = true;
+    adc.workflow_scheduler = Some(Box::new(scheduler));
+
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Misrouted-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[1].node_reservation.cpus = 256;
+    workflow_dto.tasks[2].node_reservation.cpus = 256;
+    workflow_dto.booking_interval_end = 250;
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    // Data transfers without reserved capacity are dummy dependencies, which are never assigned to routers
+    for dependency in ["data.c0.c1", "data.c0.c2", "data.c1.c3", "data.c2.c3"] {
+        let dependency_res_id = store.get_key_for_name(ReservationName::new(format!("Test-Misrouted-Workflow.{}", dependency)));
+        store.set_reserved_capacity(dependency_res_id, 10);
+    }
+
+    adc.reserve(workflow_res_id, None);
+
+    assert_ne!(store.get_state(workflow_res_id), ReservationState::ReserveAnswer);
+    assert!(misrouted_links.lock().unwrap().is_empty(), "Misrouted transfers were offered: {:?}", misrouted_links.lock().unwrap());
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ReservationName};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{
+    RejectingReserveComponent, create_adc, create_adc_with_dummy_aci, create_dummy_aci, create_heft_sync_scheduler, get_direct_mapping_workflow_dto,
+};
+
+/// Diamond workflow, whose entry task c0 requests 2 cpus for 400 s (moldable work of 800).
+fn get_workflow_dto(name: &str, is_moldable: bool) -> WorkflowDto {
+    let mut workflow_dto = get_direct_mapping_workflow_dto(name.to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[0].node_reservation.duration = 400;
+    workflow_dto.tasks[0].node_reservation.is_moldable = is_moldable;
+    return workflow_dto;
+}
+
+/// Reserves the workflow with the HEFTSync scheduler and returns the store together with the (reserved capacity, assigned start, assigned end) of c0.
+async fn reserve_and_get_c0_shape(workflow_dto: WorkflowDto) -> (ReservationStore, (i64, i64, i64)) {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    assert!(scheduler.reserve(workflow_res_id, &mut adc).is_ok(), "Workflow reservation failed.");
+
+    let c0_res_id = store.get_key_for_name(ReservationName::new("c0"));
+    let shape = (store.get_reserved_capacity(c0_res_id), store.get_assigned_start(c0_res_id), store.get_assigned_end(c0_res_id));
+    return (store, shape);
+}
+
+#[tokio::test]
+async fn test_moldable_task_finishes_earlier_by_widening() {
+    let (_, (rigid_capacity, _, rigid_end)) = reserve_and_get_c0_shape(get_workflow_dto("Test-Rigid-Workflow", false)).await;
+    let (store, (moldable_capacity, moldable_start, moldable_end)) = reserve_and_get_c0_shape(get_workflow_dto("Test-Moldable-Workflow", true)).await;
+
+    assert_eq!(rigid_capacity, 2);
+    assert!(moldable_capacity > rigid_capacity, "Moldable task was not widened (capacity {}).", moldable_capacity);
+    assert!(moldable_end < rigid_end, "Moldable task finishes at {}, not before the rigid task at {}.", moldable_end, rigid_end);
+
+    // The widened shape still covers the whole moldable work
+    let c0_res_id = store.get_key_for_name(ReservationName::new("c0"));
+    assert!(moldable_capacity * (moldable_end - moldable_start) >= 800);
+    assert_eq!(store.get_task_duration(c0_res_id), moldable_end - moldable_start);
+}
+
+/// The only VrmComponent answers the probes of the widened shapes, but rejects every reserve. The failed placement
+/// must not leave the widened shape in the store, i.e. c0 requests again 2 cpus for 400 s.
+#[tokio::test]
+async fn test_failed_placement_restores_original_shape() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let aci = create_dummy_aci(clock.clone(), store.clone()).await;
+    let rejecting_component = RejectingReserveComponent { aci, reservation_store: store.clone(), answer_state: ReservationState::Rejected };
+    let mut adc = create_adc(vec![Box::new(rejecting_component)], clock, store.clone());
+    adc.manager.reserve_config.probe_retries = 0;
+
+    let workflow_res_id =
+        Workflow::create_form_dto(get_workflow_dto("Test-Moldable-Workflow", true), ClientId::new("Test-Client-001"), store.clone())
+            .expect("Workflow construction failed.");
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    let _ = scheduler.reserve(workflow_res_id, &mut adc);
+
+    let c0_res_id = store.get_key_for_name(ReservationName::new("c0"));
+    assert_eq!(store.get_reserved_capacity(c0_res_id), 2);
+    assert_eq!(store.get_task_duration(c0_res_id), 400);
+}
@@ -0,0 +1,67 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ClientId;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, create_heft_sync_scheduler, get_direct_mapping_workflow_dto};
+
+const SNAPSHOT_DIR: &str = "tests/snapshots";
+
+/// Compares `actual` with the golden snapshot `tests/snapshots/<name>.snap`.
+/// A missing golden snapshot is created, setting `UPDATE_SNAPSHOTS` overwrites an existing one.
+fn assert_snapshot(name: &str, actual: &str) {
+    let path = Path::new(SNAPSHOT_DIR).join(format!("{}.snap", name));
+
+    if !path.exists() || std::env::var("UPDATE_SNAPSHOTS").is_ok() {
+        fs::create_dir_all(SNAPSHOT_DIR).expect("Creating snapshot directory failed.");
+        fs::write(&path, actual).expect("Writing snapshot failed.");
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).expect("Reading snapshot failed.");
+    assert_eq!(actual, expected, "Placement differs from golden snapshot {:?}. Rerun with UPDATE_SNAPSHOTS=1, if the change is intended.", path);
+}
+
+/// Reserves the workflow c0 -> (c1, c2) -> c3 on a freshly created ADC with a single AcI and returns its placement snapshot.
+async fn reserve_direct_mapping_workflow() -> String {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Snapshot-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    assert!(scheduler.reserve(workflow_res_id, &mut adc).is_ok(), "Workflow reservation failed.");
+
+    return scheduler.placement_snapshot(workflow_res_id, &adc).expect("Workflow not found.");
+}
+
+#[tokio::test]
+async fn test_heft_placement_snapshot() {
+    let snapshot = reserve_direct_mapping_workflow().await;
+
+    // The placement of equal inputs is deterministic
+    assert_eq!(snapshot, reserve_direct_mapping_workflow().await);
+
+    let lines: Vec<&str> = snapshot.lines().collect();
+    assert!(lines[0].starts_with("workflow Test-Snapshot-Workflow state=ReserveAnswer"));
+
+    let node_lines: Vec<&str> = lines.iter().filter(|line| line.starts_with("node ")).cloned().collect();
+    assert_eq!(node_lines.len(), 4);
+    for (node_line, node_id) in node_lines.iter().zip(["c0", "c1", "c2", "c3"]) {
+        assert!(node_line.starts_with(&format!("node {} ", node_id)));
+        assert!(!node_line.contains("component=unplaced"), "Node {} was not placed.", node_id);
+    }
+
+    assert_snapshot("heft_placement_direct_mapping_workflow", &snapshot);
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ReservationName};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, create_heft_sync_scheduler, get_direct_mapping_workflow_dto};
+
+/// Diamond workflow, whose tasks c1 and c2 have an equal upward rank, but both request all 1024 cpus of the AcI,
+/// so that they can not run in parallel. Task c2 has a priority of 5.
+fn get_workflow_dto(name: &str) -> WorkflowDto {
+    let mut workflow_dto = get_direct_mapping_workflow_dto(name.to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[1].node_reservation.cpus = 1024;
+    workflow_dto.tasks[2].node_reservation.cpus = 1024;
+    workflow_dto.tasks[2].priority = Some(5);
+    return workflow_dto;
+}
+
+/// Reserves the workflow with the HEFTSync scheduler and returns the assigned start of c1 and c2.
+async fn reserve_and_get_starts(is_priority_ordering: bool) -> (i64, i64) {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let workflow_res_id = Workflow::create_form_dto(get_workflow_dto("Test-Priority-Workflow"), ClientId::new("Test-Client-001"), store.clone())
+        .expect("Workflow construction failed.");
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    scheduler.base.is_priority_ordering = is_priority_ordering;
+    assert!(scheduler.reserve(workflow_res_id, &mut adc).is_ok(), "Workflow reservation failed.");
+
+    let c1_res_id = store.get_key_for_name(ReservationName::new("c1"));
+    let c2_res_id = store.get_key_for_name(ReservationName::new("c2"));
+    return (store.get_assigned_start(c1_res_id), store.get_assigned_start(c2_res_id));
+}
+
+#[test]
+fn test_task_priority_overrides_workflow_priority() {
+    let store = ReservationStore::new();
+    let mut workflow_dto = get_workflow_dto("Test-Priority-Dto-Workflow");
+    workflow_dto.priority = Some(2);
+
+    Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    assert_eq!(store.get_priority(store.get_key_for_name(ReservationName::new("Test-Priority-Dto-Workflow"))), 2);
+    assert_eq!(store.get_priority(store.get_key_for_name(ReservationName::new("c1"))), 2);
+    assert_eq!(store.get_priority(store.get_key_for_name(ReservationName::new("c2"))), 5);
+}
+
+#[tokio::test]
+async fn test_equal_ranks_are_ordered_by_id_without_priority_ordering() {
+    let (c1_start, c2_start) = reserve_and_get_starts(false).await;
+
+    assert!(c1_start < c2_start, "c1 starts at {}, not before c2 at {}.", c1_start, c2_start);
+}
+
+#[tokio::test]
+async fn test_high_priority_node_gets_earlier_slot() {
+    let (c1_start, c2_start) = reserve_and_get_starts(true).await;
+
+    assert!(c2_start < c1_start, "High priority c2 starts at {}, not before c1 at {}.", c2_start, c1_start);
+}
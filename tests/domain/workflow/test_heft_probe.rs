@@ -0,0 +1,116 @@
+use std::sync::{Arc, RwLock};
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_notification_listener::ReservationNotificationListener;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ReservationName};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, create_heft_sync_scheduler, get_direct_mapping_workflow_dto};
+
+/// Counts all state change notifications of the reservation store.
+#[derive(Debug, Default)]
+struct CountingListener {
+    notifications: usize,
+}
+
+impl ReservationNotificationListener for CountingListener {
+    fn on_reservation_change(&mut self, _: ReservationId, _: ReservationName, _: ReservationState, _: ReservationState) {
+        self.notifications += 1;
+    }
+}
+
+/// Creates an ADC with a single AcI and adds the workflow to the reservation store.
+async fn setup(workflow_dto: WorkflowDto) -> (ADC, ReservationStore, ReservationId) {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    return (adc, store, workflow_res_id);
+}
+
+#[tokio::test]
+async fn test_probe_returns_tentative_placement_without_reserving() {
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Probe-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let (mut adc, store, workflow_res_id) = setup(workflow_dto).await;
+    let task_res_ids = store
+        .with_workflow_mut(workflow_res_id, |workflow| workflow.nodes.values().map(|node| node.reservation_id).collect::<Vec<_>>())
+        .expect("Expected Workflow reservation");
+
+    let master_load_before = adc.manager.get_load_metric(0, 600, None).node_load_metric.map(|metric| metric.utilization);
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    let probe_reservations = scheduler.probe(workflow_res_id, &mut adc);
+
+    assert_eq!(probe_reservations.len(), 4);
+    for probe_res_id in probe_reservations.iter() {
+        assert_eq!(store.get_state(*probe_res_id), ReservationState::ProbeReservation);
+        assert!(store.get_assigned_start(*probe_res_id) >= 10);
+        assert!(store.get_assigned_end(*probe_res_id) <= 1000000);
+        assert!(store.get_assigned_end(*probe_res_id) > store.get_assigned_start(*probe_res_id));
+    }
+
+    // Nothing was reserved at the VrmComponents
+    assert!(adc.manager.shadow_schedule_reservations.is_empty());
+    assert!(adc.manager.not_committed_reservations.is_empty());
+    assert!(adc.manager.workflow_subtasks.is_empty());
+    for task_res_id in &task_res_ids {
+        assert!(adc.manager.get_handler_id(*task_res_id).is_none(), "Task was registered in the master schedule.");
+        assert_eq!(store.get_state(*task_res_id), ReservationState::Open);
+    }
+    assert_eq!(store.get_state(workflow_res_id), ReservationState::Open);
+
+    let master_load_after = adc.manager.get_load_metric(0, 600, None).node_load_metric.map(|metric| metric.utilization);
+    assert_eq!(master_load_before, master_load_after, "Master schedule was modified by the probe.");
+
+    // The probed workflow can still be reserved
+    assert!(scheduler.reserve(workflow_res_id, &mut adc).is_ok(), "Workflow reservation after probe failed.");
+}
+
+#[tokio::test]
+async fn test_probe_returns_empty_set_if_deadline_is_missed() {
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Probe-Deadline-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.booking_interval_end = 100;
+    let (mut adc, store, workflow_res_id) = setup(workflow_dto).await;
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    let probe_reservations = scheduler.probe(workflow_res_id, &mut adc);
+
+    assert!(probe_reservations.is_empty());
+    assert!(adc.manager.shadow_schedule_reservations.is_empty());
+    assert_eq!(store.get_state(workflow_res_id), ReservationState::Open);
+}
+
+/// The dry-run must neither notify the listeners of the store nor leave other reservations than the returned
+/// probe reservations in the store. Afterwards the listeners are attached again.
+#[tokio::test]
+async fn test_probe_is_not_notified_and_leaves_only_returned_probe_reservations() {
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Probe-Listener-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let (mut adc, store, workflow_res_id) = setup(workflow_dto).await;
+
+    let listener = Arc::new(RwLock::new(CountingListener::default()));
+    store.add_listener(listener.clone());
+    let count_before = store.count();
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    let probe_reservations = scheduler.probe(workflow_res_id, &mut adc);
+
+    assert_eq!(probe_reservations.len(), 4);
+    assert_eq!(listener.read().unwrap().notifications, 0, "The dry-run was notified to the listeners.");
+    assert_eq!(store.count(), count_before + probe_reservations.len());
+
+    store.update_state(workflow_res_id, ReservationState::Rejected);
+    assert_eq!(listener.read().unwrap().notifications, 1, "The listener was not attached again after the dry-run.");
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::heft_sync_workflow_scheduler::HEFTSyncWorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::{
+    RejectionReason, WorkflowScheduler,
+};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ReservationName, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, create_heft_sync_scheduler, get_direct_mapping_workflow_dto};
+
+/// Reserves the workflow on a freshly created ADC with a single AcI and returns the scheduler and the result of `reserve`.
+async fn reserve_workflow(workflow_dto: WorkflowDto) -> (HEFTSyncWorkflowScheduler, bool) {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    let reserved = scheduler.reserve(workflow_res_id, &mut adc).is_ok();
+    return (scheduler, reserved);
+}
+
+#[tokio::test]
+async fn test_rejection_diagnostics_deadline() {
+    // c0 fits into [10, 100], but c1 and c2 can not start before c0 is finished at 60
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Deadline-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.booking_interval_end = 100;
+
+    let (scheduler, reserved) = reserve_workflow(workflow_dto).await;
+    assert!(!reserved);
+
+    let diagnostics = scheduler.last_rejection_diagnostics().expect("No rejection diagnostics recorded.");
+    assert_eq!(diagnostics.workflow, ReservationName::new("Test-Deadline-Workflow"));
+    assert!(matches!(diagnostics.reason, RejectionReason::DeadlineMiss { .. }), "Unexpected rejection reason {:?}", diagnostics.reason);
+    assert!(
+        [Some(ReservationName::new("c1")), Some(ReservationName::new("c2"))].contains(&diagnostics.failing_node),
+        "Unexpected failing node {:?}",
+        diagnostics.failing_node
+    );
+    assert!(diagnostics.co_allocation.is_some());
+    assert!(diagnostics.rolled_back.iter().any(|(name, _)| *name == ReservationName::new("c0")), "Placement of c0 was not rolled back.");
+}
+
+#[tokio::test]
+async fn test_rejection_diagnostics_no_compute() {
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-No-Compute-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[1].node_reservation.cpus = 1_000_000;
+
+    let (scheduler, reserved) = reserve_workflow(workflow_dto).await;
+    assert!(!reserved);
+
+    let diagnostics = scheduler.last_rejection_diagnostics().expect("No rejection diagnostics recorded.");
+    assert_eq!(diagnostics.reason, RejectionReason::NoCapacity { node: WorkflowNodeId::new("c1") });
+    assert_eq!(diagnostics.failing_node, Some(ReservationName::new("c1")));
+    assert!(diagnostics.free_capacity < 1_000_000);
+    assert!(diagnostics.rolled_back.iter().any(|(name, _)| *name == ReservationName::new("c0")), "Placement of c0 was not rolled back.");
+}
+
+#[tokio::test]
+async fn test_rejection_diagnostics_cleared_on_success() {
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Success-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+
+    let (scheduler, reserved) = reserve_workflow(workflow_dto).await;
+    assert!(reserved);
+    assert!(scheduler.last_rejection_diagnostics().is_none());
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::{
+    RejectionReason, WorkflowScheduler,
+};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, create_heft_sync_scheduler, get_direct_mapping_workflow_dto};
+
+/// Reserves the workflow with the HEFTSync scheduler on a freshly created ADC with a single AcI and returns the result of `reserve`.
+async fn reserve_workflow(workflow_dto: WorkflowDto) -> Result<(), RejectionReason> {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    let result = scheduler.reserve(workflow_res_id, &mut adc);
+
+    if result.is_err() {
+        assert_eq!(store.get_state(workflow_res_id), ReservationState::Rejected);
+    }
+    return result;
+}
+
+#[tokio::test]
+async fn test_reserve_returns_deadline_miss() {
+    // c0 fits into [10, 100], but c1 and c2 can not finish before the booking interval end
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Deadline-Miss-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.booking_interval_end = 100;
+
+    let result = reserve_workflow(workflow_dto).await;
+
+    match result {
+        Err(RejectionReason::DeadlineMiss { node, projected_finish, deadline }) => {
+            assert!([WorkflowNodeId::new("c1"), WorkflowNodeId::new("c2")].contains(&node), "Unexpected failing node {:?}", node);
+            assert_eq!(deadline, 100);
+            assert!(projected_finish > deadline, "Projected finish {} does not exceed the deadline {}.", projected_finish, deadline);
+        }
+        other => panic!("Expected a DeadlineMiss, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_reserve_returns_no_capacity() {
+    // The booking interval is large enough, but no AcI can provide the cpus of c1
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-No-Capacity-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[1].node_reservation.cpus = 1_000_000;
+
+    let result = reserve_workflow(workflow_dto).await;
+
+    assert_eq!(result, Err(RejectionReason::NoCapacity { node: WorkflowNodeId::new("c1") }));
+}
+
+#[tokio::test]
+async fn test_reserve_returns_ok_on_success() {
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Reserved-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+
+    let result = reserve_workflow(workflow_dto).await;
+
+    assert_eq!(result, Ok(()));
+}
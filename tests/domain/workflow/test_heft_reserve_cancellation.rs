@@ -0,0 +1,64 @@
+use std::sync::{Arc, RwLock};
+
+use tokio_util::sync::CancellationToken;
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowReservationOutcome;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_notification_listener::ReservationNotificationListener;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ReservationName, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, create_heft_sync_scheduler, get_direct_mapping_workflow_dto};
+
+/// Cancels the token as soon as the first task is reserved, i.e. in the middle of the workflow placement.
+#[derive(Debug)]
+struct CancelOnFirstReserve {
+    cancellation_token: CancellationToken,
+}
+
+impl ReservationNotificationListener for CancelOnFirstReserve {
+    fn on_reservation_change(&mut self, _: ReservationId, _: ReservationName, _: ReservationState, new_state: ReservationState) {
+        if new_state == ReservationState::ReserveAnswer {
+            self.cancellation_token.cancel();
+        }
+    }
+}
+
+/// Workflow c0 -> (c1, c2) -> c3 on a single AcI. The placement is cancelled after c0 is reserved,
+/// so the scheduler must stop before c1 and roll back the reservation of c0.
+#[tokio::test]
+async fn test_reserve_with_cancellation_rolls_back() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Cancellation-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+    let node_res_ids = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            ["c0", "c1", "c2", "c3"].map(|node_id| workflow.nodes.get(&WorkflowNodeId::new(node_id)).unwrap().reservation_id)
+        })
+        .expect("Workflow not found.");
+
+    let cancellation_token = CancellationToken::new();
+    store.add_listener(Arc::new(RwLock::new(CancelOnFirstReserve { cancellation_token: cancellation_token.clone() })));
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    let outcome = scheduler.reserve_with_cancellation(workflow_res_id, &mut adc, &cancellation_token);
+
+    assert_eq!(outcome, WorkflowReservationOutcome::Cancelled);
+    assert!(cancellation_token.is_cancelled());
+    assert_eq!(store.get_state(workflow_res_id), ReservationState::Rejected);
+    assert_eq!(store.get_state(node_res_ids[0]), ReservationState::Deleted, "The reserved task c0 must be rolled back.");
+    assert!(adc.manager.not_committed_reservations.is_empty(), "All done sub-reservations must be rolled back.");
+
+    // The placement stopped at the next node boundary
+    for node_res_id in &node_res_ids[1..] {
+        assert_eq!(store.get_state(*node_res_id), ReservationState::Open);
+    }
+}
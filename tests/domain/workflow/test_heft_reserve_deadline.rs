@@ -0,0 +1,55 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowReservationOutcome;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_trait::VrmComponent;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ShadowScheduleId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{HookedComponent, VrmComponentHooks, create_adc, create_dummy_aci, create_heft_sync_scheduler, get_direct_mapping_workflow_dto};
+
+/// Workflow c0 -> (c1, c2) -> c3 on a single AcI, where each reserve takes 100 s of simulation time.
+/// The deadline of 150 s expires during the placement, so the workflow must be rolled back.
+#[tokio::test]
+async fn test_reserve_with_deadline_rolls_back_on_timeout() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let reserve_calls = Arc::new(AtomicUsize::new(0));
+
+    let aci = create_dummy_aci(clock.clone(), store.clone()).await;
+    // Each reserve request delays the VrmComponent by advancing the simulated clock by 100 s
+    let (hook_clock, hook_reserve_calls) = (clock.clone(), reserve_calls.clone());
+    let reserve_hook = move |aci: &mut AcI, reservation_id: ReservationId, shadow_schedule_id: Option<ShadowScheduleId>| {
+        hook_reserve_calls.fetch_add(1, Ordering::SeqCst);
+        let reserved_id = aci.reserve(reservation_id, shadow_schedule_id);
+        hook_clock.reference_start_time.fetch_add(100, Ordering::SeqCst);
+        reserved_id
+    };
+    let delayed_component = HookedComponent { aci, hooks: VrmComponentHooks { reserve: Some(Box::new(reserve_hook)), ..Default::default() } };
+    let mut adc = create_adc(vec![Box::new(delayed_component)], clock.clone(), store.clone());
+
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Deadline-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+    let node_res_ids =
+        store.with_workflow_mut(workflow_res_id, |workflow| workflow.nodes.values().map(|node| node.reservation_id).collect::<Vec<_>>()).unwrap();
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    let deadline = clock.get_system_time_s() + 150;
+    let outcome = scheduler.reserve_with_deadline(workflow_res_id, &mut adc, deadline);
+
+    assert_eq!(outcome, WorkflowReservationOutcome::Timeout);
+    assert!(reserve_calls.load(Ordering::SeqCst) < node_res_ids.len(), "The placement must stop before all tasks are reserved.");
+    assert_eq!(store.get_state(workflow_res_id), ReservationState::Rejected);
+    assert!(adc.manager.not_committed_reservations.is_empty(), "All done sub-reservations must be rolled back.");
+
+    for node_res_id in node_res_ids {
+        assert_ne!(store.get_state(node_res_id), ReservationState::ReserveAnswer);
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ShadowScheduleId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, create_heft_sync_scheduler, get_workflow_dto_with_one_task};
+
+/// Schedules a workflow with a single task on a shadow schedule.
+/// The placement must only be visible on the shadow schedule, the master schedule and the
+/// master tracking of the VrmComponentManager must stay untouched.
+#[tokio::test]
+async fn test_reserve_on_shadow_does_not_touch_master_schedule() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let workflow_dto =
+        get_workflow_dto_with_one_task("Test-Shadow-Workflow".to_string(), ReservationStateDto::Open, ReservationProceedingDto::Reserve);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+    let task_res_ids = store
+        .with_workflow_mut(workflow_res_id, |workflow| workflow.nodes.values().map(|node| node.reservation_id).collect::<Vec<_>>())
+        .expect("Expected Workflow reservation");
+
+    let master_load_before = adc.manager.get_load_metric(0, 600, None).node_load_metric.map(|metric| metric.utilization);
+
+    let shadow_schedule_id = ShadowScheduleId::new("Test-Shadow-Schedule");
+    assert!(adc.manager.create_shadow_schedule(shadow_schedule_id.clone()), "Creation of the shadow schedule failed.");
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    assert!(scheduler.reserve_on_shadow(workflow_res_id, &mut adc, shadow_schedule_id.clone()), "Reserve on shadow schedule failed.");
+
+    let (shadow_map, _) = adc.manager.shadow_schedule_reservations.get(&shadow_schedule_id).expect("Shadow schedule was not found.");
+    for task_res_id in &task_res_ids {
+        assert!(shadow_map.contains_key(task_res_id), "Task was not registered in the shadow schedule.");
+        assert!(adc.manager.get_handler_id(*task_res_id).is_none(), "Task was registered in the master schedule.");
+    }
+
+    assert!(adc.manager.not_committed_reservations.is_empty());
+    assert!(adc.manager.workflow_subtasks.is_empty());
+
+    let master_load_after = adc.manager.get_load_metric(0, 600, None).node_load_metric.map(|metric| metric.utilization);
+    assert_eq!(master_load_before, master_load_after, "Master schedule was modified by the shadow reserve.");
+}
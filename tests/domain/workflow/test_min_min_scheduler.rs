@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::adc::ADC;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::min_min_workflow_scheduler::MinMinWorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::{
+    RejectionReason, WorkflowScheduler,
+};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ClientId;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, create_heft_sync_scheduler, get_direct_mapping_workflow_dto};
+
+/// Creates an ADC with a single AcI and adds the workflow to the reservation store.
+async fn setup(workflow_dto: WorkflowDto) -> (ADC, ReservationStore, ReservationId) {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    return (adc, store, workflow_res_id);
+}
+
+fn get_makespan(store: &ReservationStore, workflow_res_id: ReservationId) -> i64 {
+    return store.get_assigned_end(workflow_res_id) - store.get_assigned_start(workflow_res_id);
+}
+
+/// Schedules the fork-join workflow c0 -> (c1, c2) -> c3 with Min-Min and HEFTSync.
+/// On a single AcI with sufficient capacity c1 and c2 run in parallel, so both heuristics reach the same makespan.
+#[tokio::test]
+async fn test_min_min_makespan_matches_heft_sync_on_fork_join_workflow() {
+    let (mut heft_adc, heft_store, heft_workflow_res_id) =
+        setup(get_direct_mapping_workflow_dto("Test-HEFTSync-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open))
+            .await;
+    let mut heft_scheduler = create_heft_sync_scheduler(heft_store.clone());
+    assert!(heft_scheduler.reserve(heft_workflow_res_id, &mut heft_adc).is_ok(), "HEFTSync reservation failed.");
+
+    let (mut min_min_adc, min_min_store, min_min_workflow_res_id) =
+        setup(get_direct_mapping_workflow_dto("Test-MinMin-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open))
+            .await;
+    let mut min_min_scheduler = MinMinWorkflowScheduler::new(min_min_store.clone());
+    assert_eq!(min_min_scheduler.name(), "MinMinWorkflowScheduler");
+    assert!(min_min_scheduler.reserve(min_min_workflow_res_id, &mut min_min_adc).is_ok(), "Min-Min reservation failed.");
+
+    assert_eq!(min_min_store.get_state(min_min_workflow_res_id), ReservationState::ReserveAnswer);
+    let task_res_ids = min_min_store
+        .with_workflow_mut(min_min_workflow_res_id, |workflow| workflow.nodes.values().map(|node| node.reservation_id).collect::<Vec<_>>())
+        .expect("Expected Workflow reservation");
+    for task_res_id in task_res_ids {
+        assert!(min_min_adc.manager.get_handler_id(task_res_id).is_some(), "Task was not placed by Min-Min.");
+    }
+
+    assert_eq!(get_makespan(&min_min_store, min_min_workflow_res_id), get_makespan(&heft_store, heft_workflow_res_id));
+}
+
+#[tokio::test]
+async fn test_min_min_rolls_back_on_deadline_miss() {
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-MinMin-Deadline-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.booking_interval_end = 100;
+    let (mut adc, store, workflow_res_id) = setup(workflow_dto).await;
+
+    let mut scheduler = MinMinWorkflowScheduler { heft_sync: create_heft_sync_scheduler(store.clone()) };
+    assert!(scheduler.reserve(workflow_res_id, &mut adc).is_err());
+
+    assert_eq!(store.get_state(workflow_res_id), ReservationState::Rejected);
+    assert!(adc.manager.not_committed_reservations.is_empty(), "Placement was not rolled back.");
+    let diagnostics = scheduler.heft_sync.last_rejection_diagnostics().expect("No rejection diagnostics recorded.");
+    assert!(matches!(diagnostics.reason, RejectionReason::DeadlineMiss { .. }), "Unexpected rejection reason {:?}", diagnostics.reason);
+}
@@ -0,0 +1,113 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::{
+    PlacementFailurePolicy, WorkflowScheduler,
+};
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_trait::VrmComponent;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ShadowScheduleId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{HookedComponent, VrmComponentHooks, create_adc, create_dummy_aci, create_heft_sync_scheduler, get_direct_mapping_workflow_dto};
+
+/// Result of one placement of the workflow c0 -> (c1, c2) -> c3 on a single flaky VrmComponent.
+struct PlacementRun {
+    is_reserved: bool,
+    failed_reserve_calls: usize,
+    store: ReservationStore,
+    workflow_res_id: ReservationId,
+}
+
+/// Reserves the workflow c0 -> (c1, c2) -> c3 with the given `placement_failure_policy`, where the
+/// first `failures` reserve requests of the node `failing_node` are rejected by the VrmComponent.
+async fn run_placement(failing_node: &str, failures: usize, placement_failure_policy: PlacementFailurePolicy) -> PlacementRun {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let failing_reservation = Arc::new(Mutex::new(None));
+    let failed_reserve_calls = Arc::new(AtomicUsize::new(0));
+
+    // The VrmComponent rejects the reserve requests of `failing_reservation`, as long as failures are remaining
+    let aci = create_dummy_aci(clock.clone(), store.clone()).await;
+    let (hook_store, hook_failing_reservation, hook_failed_reserve_calls) =
+        (store.clone(), failing_reservation.clone(), failed_reserve_calls.clone());
+    let remaining_failures = AtomicUsize::new(failures);
+    let reserve_hook = move |aci: &mut AcI, reservation_id: ReservationId, shadow_schedule_id: Option<ShadowScheduleId>| {
+        let is_failing_reservation = *hook_failing_reservation.lock().unwrap() == Some(reservation_id);
+
+        if is_failing_reservation && remaining_failures.load(Ordering::SeqCst) > 0 {
+            remaining_failures.fetch_sub(1, Ordering::SeqCst);
+            hook_failed_reserve_calls.fetch_add(1, Ordering::SeqCst);
+            hook_store.update_state(reservation_id, ReservationState::Rejected);
+            return reservation_id;
+        }
+        aci.reserve(reservation_id, shadow_schedule_id)
+    };
+    let flaky_component = HookedComponent { aci, hooks: VrmComponentHooks { reserve: Some(Box::new(reserve_hook)), ..Default::default() } };
+    let mut adc = create_adc(vec![Box::new(flaky_component)], clock, store.clone());
+
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Placement-Failure-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+    let failing_res_id =
+        store.with_workflow_mut(workflow_res_id, |workflow| workflow.nodes.get(&WorkflowNodeId::new(failing_node)).unwrap().reservation_id).unwrap();
+    *failing_reservation.lock().unwrap() = Some(failing_res_id);
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    scheduler.base.placement_failure_policy = placement_failure_policy;
+    let is_reserved = scheduler.reserve(workflow_res_id, &mut adc).is_ok();
+
+    if !is_reserved {
+        assert!(adc.manager.not_committed_reservations.is_empty(), "All done sub-reservations must be rolled back.");
+    }
+
+    PlacementRun { is_reserved, failed_reserve_calls: failed_reserve_calls.load(Ordering::SeqCst), store, workflow_res_id }
+}
+
+/// The intermediate node c1 can never be reserved. The `Abort` policy rejects the workflow after
+/// the first failed placement, while `Retry(2)` places the CoAllocation of c1 two more times.
+#[tokio::test]
+async fn test_intermediate_placement_failure_is_retried() {
+    let abort_run = run_placement("c1", usize::MAX, PlacementFailurePolicy::Abort).await;
+    let retry_run = run_placement("c1", usize::MAX, PlacementFailurePolicy::Retry(2)).await;
+
+    assert!(!abort_run.is_reserved);
+    assert!(!retry_run.is_reserved);
+    assert!(abort_run.failed_reserve_calls > 0);
+    assert_eq!(retry_run.failed_reserve_calls, 3 * abort_run.failed_reserve_calls);
+    assert_eq!(abort_run.store.get_state(abort_run.workflow_res_id), ReservationState::Rejected);
+    assert_eq!(retry_run.store.get_state(retry_run.workflow_res_id), ReservationState::Rejected);
+}
+
+/// The reserve requests of the intermediate node c1 fail during the whole first placement only.
+/// The retry places the workflow, while the `Abort` policy rejects it.
+#[tokio::test]
+async fn test_transient_intermediate_placement_failure_is_recovered_by_retry() {
+    let failures_of_one_placement = run_placement("c1", usize::MAX, PlacementFailurePolicy::Abort).await.failed_reserve_calls;
+
+    let abort_run = run_placement("c1", failures_of_one_placement, PlacementFailurePolicy::Abort).await;
+    let retry_run = run_placement("c1", failures_of_one_placement, PlacementFailurePolicy::Retry(1)).await;
+
+    assert!(!abort_run.is_reserved);
+    assert!(retry_run.is_reserved);
+    assert_eq!(retry_run.store.get_state(retry_run.workflow_res_id), ReservationState::ReserveAnswer);
+}
+
+/// The exit node c3 can never be reserved. The failure of an exit CoAllocation is always fatal,
+/// so the `Retry` policy must not place it again.
+#[tokio::test]
+async fn test_exit_placement_failure_is_always_fatal() {
+    let abort_run = run_placement("c3", usize::MAX, PlacementFailurePolicy::Abort).await;
+    let retry_run = run_placement("c3", usize::MAX, PlacementFailurePolicy::Retry(3)).await;
+
+    assert!(!abort_run.is_reserved);
+    assert!(!retry_run.is_reserved);
+    assert!(abort_run.failed_reserve_calls > 0);
+    assert_eq!(retry_run.failed_reserve_calls, abort_run.failed_reserve_calls);
+    assert_eq!(retry_run.store.get_state(retry_run.workflow_res_id), ReservationState::Rejected);
+}
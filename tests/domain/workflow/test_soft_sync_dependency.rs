@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::probe_reservations::{ProbeReservationComparator, ProbeReservations};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::config::SOFT_SYNC_SEPARATION_PENALTY;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ComponentId, ReservationName, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_node_reservation, get_direct_mapping_workflow_dto};
+
+/// Creates the ProbeReservations of the soft synchronized task, one candidate on the VrmComponent of the partner
+/// (finish time `same_component_end`) and one candidate on another VrmComponent (finish time `other_component_end`).
+fn get_probe_reservations(
+    store: &ReservationStore,
+    clock: Arc<GlobalClock>,
+    same_component_end: i64,
+    other_component_end: i64,
+) -> (ReservationId, ProbeReservations) {
+    let res_id = store.add(create_node_reservation(ReservationName::new("Soft-Sync-Task"), 2, 0, 200, ReservationState::Open, clock.clone()));
+    let mut probe_reservations = ProbeReservations::new(res_id, store.clone());
+
+    for (component_id, end) in [("Partner-Component", same_component_end), ("Other-Component", other_component_end)] {
+        let mut component_probe = ProbeReservations::new(res_id, store.clone());
+        let probe_res =
+            create_node_reservation(ReservationName::new("Soft-Sync-Task"), 2, end - 50, end, ReservationState::ProbeAnswer, clock.clone());
+        component_probe.add_reservation(probe_res).expect("Adding ProbeReservation failed.");
+        component_probe.add_probe_meta_data(ComponentId::new(component_id), None);
+        probe_reservations.add_probe_reservations(component_probe);
+    }
+    return (res_id, probe_reservations);
+}
+
+/// The partner task is placed on "Partner-Component", all other VrmComponents are penalized.
+fn separation_penalty(component_id: &ComponentId) -> i64 {
+    if component_id.compare(&ComponentId::new("Partner-Component")) { 0 } else { SOFT_SYNC_SEPARATION_PENALTY }
+}
+
+#[test]
+fn test_soft_sync_is_parsed_without_merging_co_allocations() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Soft-Sync-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[2].node_reservation.dependencies.soft_sync = vec!["c1".to_string()];
+
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let (num_of_co_allocations, num_of_sync_deps, c1_partners, c2_partners) = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            (
+                workflow.co_allocations.len(),
+                workflow.sync_dependencies.len(),
+                workflow.get_soft_sync_partners(&WorkflowNodeId::new("c1")),
+                workflow.get_soft_sync_partners(&WorkflowNodeId::new("c2")),
+            )
+        })
+        .expect("Workflow not found.");
+
+    assert_eq!(num_of_co_allocations, 4, "Soft synced nodes must not be merged into one CoAllocation.");
+    assert_eq!(num_of_sync_deps, 0);
+    assert_eq!(c1_partners, vec![(WorkflowNodeId::new("c2"), SOFT_SYNC_SEPARATION_PENALTY)]);
+    assert_eq!(c2_partners, vec![(WorkflowNodeId::new("c1"), SOFT_SYNC_SEPARATION_PENALTY)]);
+}
+
+/// The other VrmComponent finishes slightly earlier, but not enough to compensate the separation penalty.
+#[test]
+fn test_soft_sync_prefers_component_of_partner() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let (res_id, mut probe_reservations) = get_probe_reservations(&store, clock, 100, 100 - SOFT_SYNC_SEPARATION_PENALTY / 2);
+
+    let (component_id, _) = probe_reservations
+        .prompt_best_with_penalty(res_id, ProbeReservationComparator::EFTReservationCompare, separation_penalty)
+        .expect("Promotion failed.");
+
+    assert!(component_id.compare(&ComponentId::new("Partner-Component")), "Soft synced task should be placed with its partner.");
+    assert_eq!(store.get_assigned_end(res_id), 100);
+}
+
+/// The other VrmComponent finishes much earlier, so the soft synced tasks are split.
+#[test]
+fn test_soft_sync_can_split_if_separation_is_worth_it() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let (res_id, mut probe_reservations) = get_probe_reservations(&store, clock, 200, 200 - 2 * SOFT_SYNC_SEPARATION_PENALTY);
+
+    let (component_id, _) = probe_reservations
+        .prompt_best_with_penalty(res_id, ProbeReservationComparator::EFTReservationCompare, separation_penalty)
+        .expect("Promotion failed.");
+
+    assert!(component_id.compare(&ComponentId::new("Other-Component")), "Soft synced task should be split from its partner.");
+    assert_eq!(store.get_assigned_end(res_id), 200 - 2 * SOFT_SYNC_SEPARATION_PENALTY);
+}
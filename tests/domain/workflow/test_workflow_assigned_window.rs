@@ -0,0 +1,49 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{ASSIGNED_TIME_UNSET, ReservationTrait};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Workflow c0 -> (c1, c2) -> c3, where only c0 [20, 70] and c1 [70, 120] are scheduled.
+/// The unscheduled subtasks c2 and c3 must not pull the workflow window towards 0.
+#[test]
+fn test_only_scheduled_subtasks_affect_workflow_window() {
+    let mut store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Assigned-Window-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let node_res_ids = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            ["c0", "c1", "c2", "c3"].map(|node_id| workflow.nodes.get(&WorkflowNodeId::new(node_id)).unwrap().reservation_id)
+        })
+        .expect("Workflow not found.");
+
+    // All reservations are unscheduled after construction
+    assert_eq!(store.get_assigned_start(workflow_res_id), ASSIGNED_TIME_UNSET);
+    assert_eq!(store.get_assigned_end(workflow_res_id), ASSIGNED_TIME_UNSET);
+    for node_res_id in node_res_ids {
+        assert_eq!(store.get_assigned_start(node_res_id), ASSIGNED_TIME_UNSET);
+        assert_eq!(store.get_assigned_end(node_res_id), ASSIGNED_TIME_UNSET);
+    }
+
+    store.set_assigned_start(node_res_ids[0], 20);
+    store.set_assigned_end(node_res_ids[0], 70);
+    store.set_assigned_start(node_res_ids[1], 70);
+    store.set_assigned_end(node_res_ids[1], 120);
+
+    let (assigned_start, assigned_end) = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            for node_res_id in node_res_ids {
+                workflow.update_reservation(store.clone(), node_res_id);
+            }
+            (workflow.get_assigned_start(), workflow.get_assigned_end())
+        })
+        .expect("Workflow not found.");
+
+    assert_eq!(assigned_start, 20);
+    assert_eq!(assigned_end, 120);
+}
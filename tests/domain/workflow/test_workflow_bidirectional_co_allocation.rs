@@ -0,0 +1,35 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+use vrm_rust_workflow::error::Error;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Workflow c0 -> (c1, c2) -> c3, where c0 and c3 are synchronized into the same CoAllocation.
+/// Hence, the CoAllocation {c0, c3} depends on c1 (c1 -> c3) and c1 depends on {c0, c3} (c0 -> c1), which is
+/// already rejected as a cycle before the CoAllocation graph is built.
+#[test]
+fn test_bidirectional_co_allocation_dependency_is_rejected() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Bidirectional-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[3].node_reservation.dependencies.sync = vec!["c0".to_string()];
+
+    let result = Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store);
+
+    match result {
+        Err(Error::CyclicWorkflow { cycle }) => assert!(cycle.contains(&WorkflowNodeId::new("c0")) && cycle.contains(&WorkflowNodeId::new("c3"))),
+        other => panic!("Expected a CyclicWorkflow error, got {:?}", other),
+    }
+}
+
+/// The same workflow without the synchronization must still be accepted.
+#[test]
+fn test_unidirectional_co_allocation_dependencies_are_accepted() {
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Unidirectional-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+
+    assert!(Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store).is_ok());
+}
@@ -0,0 +1,70 @@
+use std::collections::BTreeSet;
+
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::WorkflowNodeId;
+use vrm_rust_workflow::test_support::workflow_builder::WorkflowBuilder;
+
+fn node_ids(ids: &[&str]) -> BTreeSet<WorkflowNodeId> {
+    return ids.iter().map(|id| WorkflowNodeId::new(*id)).collect();
+}
+
+fn edge(source: &str, target: &str) -> (WorkflowNodeId, WorkflowNodeId) {
+    return (WorkflowNodeId::new(source), WorkflowNodeId::new(target));
+}
+
+fn sized_edge(source: &str, target: &str, size: i64) -> (WorkflowNodeId, WorkflowNodeId, i64) {
+    return (WorkflowNodeId::new(source), WorkflowNodeId::new(target), size);
+}
+
+/// Builds the diamond c0 -> (c1, c2) -> c3 only with data edges.
+#[test]
+fn test_workflow_builder_diamond() {
+    let store = ReservationStore::new();
+    let workflow = WorkflowBuilder::new("Test-Builder-Diamond")
+        .node("c0", 50, 2)
+        .node("c1", 60, 2)
+        .node("c2", 70, 4)
+        .node("c3", 50, 2)
+        .data_edge("c0", "c1", 50)
+        .data_edge("c0", "c2", 50)
+        .data_edge("c1", "c3", 20)
+        .data_edge("c2", "c3", 30)
+        .build(store.clone());
+
+    assert_eq!(workflow.nodes.len(), 4);
+    assert_eq!(workflow.nodes.keys().cloned().collect::<BTreeSet<_>>(), node_ids(&["c0", "c1", "c2", "c3"]));
+
+    let data_edges: BTreeSet<(WorkflowNodeId, WorkflowNodeId, i64)> = workflow
+        .data_dependencies
+        .values()
+        .map(|data_dependency| (data_dependency.source_node.clone().unwrap(), data_dependency.target_node.clone().unwrap(), data_dependency.size))
+        .collect();
+    let expected_edges =
+        BTreeSet::from([sized_edge("c0", "c1", 50), sized_edge("c0", "c2", 50), sized_edge("c1", "c3", 20), sized_edge("c2", "c3", 30)]);
+    assert_eq!(data_edges, expected_edges);
+    assert!(workflow.sync_dependencies.is_empty());
+
+    // Without sync edges every node is its own CoAllocation
+    assert_eq!(workflow.co_allocations.len(), 4);
+    assert_eq!(workflow.entry_nodes.iter().cloned().collect::<BTreeSet<_>>(), node_ids(&["c0"]));
+    assert_eq!(workflow.exit_nodes.iter().cloned().collect::<BTreeSet<_>>(), node_ids(&["c3"]));
+
+    let c2_res_id = workflow.nodes[&WorkflowNodeId::new("c2")].reservation_id;
+    assert_eq!(store.get_task_duration(c2_res_id), 70);
+    assert_eq!(store.get_reserved_capacity(c2_res_id), 4);
+}
+
+/// A sync edge creates a SyncDependency and merges both nodes into one CoAllocation.
+#[test]
+fn test_workflow_builder_sync_edge() {
+    let store = ReservationStore::new();
+    let workflow = WorkflowBuilder::new("Test-Builder-Sync").node("a", 50, 2).node("b", 50, 2).sync_edge("a", "b", 100).build(store);
+
+    assert!(workflow.data_dependencies.is_empty());
+    assert_eq!(workflow.sync_dependencies.len(), 1);
+
+    let sync_dependency = workflow.sync_dependencies.values().next().unwrap();
+    assert_eq!((sync_dependency.source_node.clone().unwrap(), sync_dependency.target_node.clone().unwrap()), edge("a", "b"));
+    assert_eq!(sync_dependency.bandwidth, 100);
+    assert_eq!(workflow.co_allocations.len(), 1);
+}
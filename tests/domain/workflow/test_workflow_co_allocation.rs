@@ -228,6 +228,7 @@ fn create_dummy_workflow_dto() -> (WorkflowDto, ClientId) {
         tasks: vec![],
         state: ReservationStateDto::Open,
         request_proceeding: ReservationProceedingDto::Commit,
+        priority: None,
     };
 
     // Common dummy link reservation (not used for node logic, but required by DTO)
@@ -240,19 +241,21 @@ fn create_dummy_workflow_dto() -> (WorkflowDto, ClientId) {
         id: "A".to_string(),
         reservation_state: ReservationStateDto::Open,
         request_proceeding: ReservationProceedingDto::Commit,
+        priority: None,
         link_reservation: vec![dummy_link_res.clone()],
         node_reservation: NodeReservationDto {
             duration: 10,
             cpus: 1,
             is_moldable: false,
+            earliest_start: None,
             task_path: "/bin/task_a".to_string(),
             output_path: Some("/out/task_a.log".to_string()),
             error_path: Some("/err/task_a.log".to_string()),
             current_working_directory: Some("/err/task_a.log".to_string()),
             environment: Some(vec!["/err/task_a.log".to_string()]),
-            data_out: vec![DataOutDto { name: "port1".to_string(), size: Some(100), bandwidth: None, file: Some("output.dat".to_string()) }],
+            data_out: vec![DataOutDto { name: "port1".to_string(), size: Some(100), bandwidth: None, latency: None, file: Some("output.dat".to_string()) }],
             data_in: vec![], // A is Entry
-            dependencies: DependencyDto { data: vec![], sync: vec![] },
+            dependencies: DependencyDto { data: vec![], sync: vec![], soft_sync: vec![] },
         },
     };
 
@@ -260,11 +263,13 @@ fn create_dummy_workflow_dto() -> (WorkflowDto, ClientId) {
         id: "B".to_string(),
         reservation_state: ReservationStateDto::Open,
         request_proceeding: ReservationProceedingDto::Commit,
+        priority: None,
         link_reservation: vec![dummy_link_res.clone()],
         node_reservation: NodeReservationDto {
             duration: 15,
             cpus: 2,
             is_moldable: true,
+            earliest_start: None,
             task_path: "/bin/task_a".to_string(),
             output_path: None,
             error_path: None,
@@ -272,7 +277,7 @@ fn create_dummy_workflow_dto() -> (WorkflowDto, ClientId) {
             environment: Some(vec!["/err/task_a.log".to_string()]),
             data_out: vec![],
             data_in: vec![DataInDto { source_reservation: "A".to_string(), source_port: "port1".to_string(), file: Some("output.dat".to_string()) }],
-            dependencies: DependencyDto { data: vec![], sync: vec![] },
+            dependencies: DependencyDto { data: vec![], sync: vec![], soft_sync: vec![] },
         },
     };
 
@@ -282,6 +287,7 @@ fn create_dummy_workflow_dto() -> (WorkflowDto, ClientId) {
         name: "sync_port".to_string(),
         size: None,
         bandwidth: Some(50), // Indicates Sync
+        latency: None,
         file: None,
     });
 
@@ -289,11 +295,13 @@ fn create_dummy_workflow_dto() -> (WorkflowDto, ClientId) {
         id: "C".to_string(),
         reservation_state: ReservationStateDto::Committed,
         request_proceeding: ReservationProceedingDto::Reserve,
+        priority: None,
         link_reservation: vec![dummy_link_res.clone()],
         node_reservation: NodeReservationDto {
             duration: 20,
             cpus: 4,
             is_moldable: false,
+            earliest_start: None,
             task_path: "/bin/task_c".to_string(),
             output_path: None,
             error_path: None,
@@ -301,7 +309,7 @@ fn create_dummy_workflow_dto() -> (WorkflowDto, ClientId) {
             environment: Some(vec!["/err/task_a.log".to_string()]),
             data_out: vec![],
             data_in: vec![DataInDto { source_reservation: "B".to_string(), source_port: "sync_port".to_string(), file: None }],
-            dependencies: DependencyDto { data: vec![], sync: vec![] },
+            dependencies: DependencyDto { data: vec![], sync: vec![], soft_sync: vec![] },
         },
     };
 
@@ -356,7 +364,7 @@ fn test_stage_1_generate_workflow_nodes() {
 fn test_stage_2_build_dependencies() {
     let (dto, client_id) = create_dummy_workflow_dto();
     let store = ReservationStore::new();
-    let (data_deps, sync_deps) = Workflow::build_all_dependencies(&dto, client_id, store.clone()).expect("Should build deps");
+    let (data_deps, sync_deps) = Workflow::build_all_dependencies(&dto, client_id, store.clone(), &mut Vec::new()).expect("Should build deps");
 
     // A->B is Data, B->C is Sync
     assert_eq!(data_deps.len(), 1);
@@ -383,9 +391,9 @@ fn test_stage_3_populate_adjacency() {
     let store = ReservationStore::new();
 
     let mut nodes = Workflow::generate_workflow_nodes(&dto, client_id.clone(), store.clone());
-    let (data_deps, sync_deps) = Workflow::build_all_dependencies(&dto, client_id, store.clone()).unwrap();
+    let (data_deps, sync_deps) = Workflow::build_all_dependencies(&dto, client_id, store.clone(), &mut Vec::new()).unwrap();
 
-    Workflow::populate_node_adjacency_lists(&mut nodes, &data_deps, &sync_deps);
+    Workflow::populate_node_adjacency_lists(&mut nodes, &data_deps, &sync_deps, &mut Vec::new());
 
     let node_a = nodes.get(&WorkflowNodeId::new("A")).unwrap();
     let node_b = nodes.get(&WorkflowNodeId::new("B")).unwrap();
@@ -405,10 +413,10 @@ fn test_stage_4_co_allocations() {
     let (dto, client_id) = create_dummy_workflow_dto();
     let store = ReservationStore::new();
     let mut nodes = Workflow::generate_workflow_nodes(&dto, client_id.clone(), store.clone());
-    let (data_deps, sync_deps) = Workflow::build_all_dependencies(&dto, client_id, store.clone()).unwrap();
+    let (data_deps, sync_deps) = Workflow::build_all_dependencies(&dto, client_id, store.clone(), &mut Vec::new()).unwrap();
 
     // We must populate adjacency first or CoAllocation building might miss context (though it relies mostly on sync_deps map)
-    Workflow::populate_node_adjacency_lists(&mut nodes, &data_deps, &sync_deps);
+    Workflow::populate_node_adjacency_lists(&mut nodes, &data_deps, &sync_deps, &mut Vec::new());
 
     let (co_allocs, node_map) = Workflow::build_co_allocations(&nodes, &sync_deps).expect("CoAlloc build failed");
 
@@ -434,11 +442,11 @@ fn test_stage_5_co_allocation_dependencies() {
     let (dto, client_id) = create_dummy_workflow_dto();
     let store = ReservationStore::new();
     let mut nodes = Workflow::generate_workflow_nodes(&dto, client_id.clone(), store.clone());
-    let (data_deps, sync_deps) = Workflow::build_all_dependencies(&dto, client_id, store.clone()).unwrap();
-    Workflow::populate_node_adjacency_lists(&mut nodes, &data_deps, &sync_deps);
+    let (data_deps, sync_deps) = Workflow::build_all_dependencies(&dto, client_id, store.clone(), &mut Vec::new()).unwrap();
+    Workflow::populate_node_adjacency_lists(&mut nodes, &data_deps, &sync_deps, &mut Vec::new());
     let (mut co_allocs, node_map) = Workflow::build_co_allocations(&nodes, &sync_deps).unwrap();
 
-    let ca_deps = Workflow::build_co_allocation_dependencies(&data_deps, &node_map, &mut co_allocs).unwrap();
+    let ca_deps = Workflow::build_co_allocation_dependencies(&data_deps, &node_map, &mut co_allocs, &mut Vec::new()).unwrap();
 
     // A -> B is a Data link.
     // A is Group 1, B is Group 2 (with C).
@@ -459,10 +467,10 @@ fn test_stage_6_entry_exit_points() {
     let (dto, client_id) = create_dummy_workflow_dto();
     let store = ReservationStore::new();
     let mut nodes = Workflow::generate_workflow_nodes(&dto, client_id.clone(), store.clone());
-    let (data_deps, sync_deps) = Workflow::build_all_dependencies(&dto, client_id, store.clone()).unwrap();
-    Workflow::populate_node_adjacency_lists(&mut nodes, &data_deps, &sync_deps);
+    let (data_deps, sync_deps) = Workflow::build_all_dependencies(&dto, client_id, store.clone(), &mut Vec::new()).unwrap();
+    Workflow::populate_node_adjacency_lists(&mut nodes, &data_deps, &sync_deps, &mut Vec::new());
     let (mut co_allocs, node_map) = Workflow::build_co_allocations(&nodes, &sync_deps).unwrap();
-    let _ = Workflow::build_co_allocation_dependencies(&data_deps, &node_map, &mut co_allocs).unwrap();
+    let _ = Workflow::build_co_allocation_dependencies(&data_deps, &node_map, &mut co_allocs, &mut Vec::new()).unwrap();
 
     let (entry_nodes, exit_nodes, entry_groups, exit_groups) = Workflow::find_entry_exit_points(&nodes, &co_allocs);
 
@@ -0,0 +1,74 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Workflow c0 -> (c1, c2) -> c3, where c2 has a duration of 80 and all other tasks a duration of 50.
+/// The critical path is c0 -> c2 -> c3 and its length equals the lower bound of the makespan (180).
+#[test]
+fn test_critical_path_follows_longest_branch() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Critical-Path-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[2].node_reservation.duration = 80;
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let (lower_bound, (length, path)) = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            let lower_bound = workflow.lower_bound_makespan(10, &store).unwrap();
+            (lower_bound, workflow.critical_path(10, &store))
+        })
+        .expect("Expected Workflow reservation");
+
+    assert_eq!(length, 180);
+    assert_eq!(length, lower_bound);
+    assert_eq!(path, vec![WorkflowNodeId::new("c0"), WorkflowNodeId::new("c2"), WorkflowNodeId::new("c3")]);
+}
+
+/// Workflow c0 -> (c1, c2) -> c3 with equal durations of 50, where only the dependency c0 -> c2 transfers data
+/// (size 300, i.e. a communication time of 30 at a network speed of 10). Both branches have the same rank, but
+/// only c2 together with the communication time determines the rank of c0, so the critical path is c0 -> c2 -> c3.
+#[test]
+fn test_critical_path_considers_communication_time() {
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Critical-Path-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let (length, path) = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            let data_dependency = workflow
+                .data_dependencies
+                .values_mut()
+                .find(|dep| dep.source_node == Some(WorkflowNodeId::new("c0")) && dep.target_node == Some(WorkflowNodeId::new("c2")))
+                .expect("Expected data dependency c0 -> c2");
+            data_dependency.size = 300;
+
+            workflow.lower_bound_makespan(10, &store).unwrap();
+            workflow.critical_path(10, &store)
+        })
+        .expect("Expected Workflow reservation");
+
+    assert_eq!(length, 180);
+    assert_eq!(path, vec![WorkflowNodeId::new("c0"), WorkflowNodeId::new("c2"), WorkflowNodeId::new("c3")]);
+}
+
+/// Without computed ranks, only the members of the entry `CoAllocation` are returned.
+#[test]
+fn test_critical_path_without_ranks_returns_entry() {
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Critical-Path-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let (length, path) =
+        store.with_workflow_mut(workflow_res_id, |workflow| workflow.critical_path(10, &store)).expect("Expected Workflow reservation");
+
+    assert_eq!(length, 0);
+    assert_eq!(path, vec![WorkflowNodeId::new("c0")]);
+}
@@ -0,0 +1,90 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+use vrm_rust_workflow::error::Error;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Workflow c0 -> (c1, c2) -> c3, where c0 additionally depends on c3, i.e. c0 -> c1 -> c3 -> c0.
+#[test]
+fn test_cyclic_workflow_is_rejected() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Cyclic-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[0].node_reservation.dependencies.data.push("c3".to_string());
+
+    match Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store) {
+        Err(Error::CyclicWorkflow { cycle }) => {
+            assert_eq!(cycle, vec![WorkflowNodeId::new("c1"), WorkflowNodeId::new("c3"), WorkflowNodeId::new("c0")]);
+        }
+        other => panic!("Expected a CyclicWorkflow error, got {:?}.", other),
+    }
+}
+
+/// Sync declared on both nodes only demands a simultaneous start and is no cycle.
+#[test]
+fn test_mutual_sync_is_not_a_cycle() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Mutual-Sync-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[1].node_reservation.dependencies.sync.push("c2".to_string());
+    workflow_dto.tasks[2].node_reservation.dependencies.sync.push("c1".to_string());
+
+    assert!(Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store).is_ok());
+}
+
+/// Workflow c0 -> c1, c2 -> c3 -> c0, where c2 is synchronized with c1, i.e. the cycle passes through the
+/// CoAllocation (c1, c2).
+#[test]
+fn test_cycle_through_sync_group_is_rejected() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Sync-Cycle-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[0].node_reservation.dependencies.data.push("c3".to_string());
+    workflow_dto.tasks[2].node_reservation.dependencies.data.clear();
+    workflow_dto.tasks[2].node_reservation.dependencies.sync.push("c1".to_string());
+    workflow_dto.tasks[3].node_reservation.dependencies.data = vec!["c2".to_string()];
+
+    match Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store) {
+        Err(Error::CyclicWorkflow { cycle }) => {
+            assert_eq!(cycle, vec![WorkflowNodeId::new("c2"), WorkflowNodeId::new("c3"), WorkflowNodeId::new("c0"), WorkflowNodeId::new("c1")]);
+        }
+        other => panic!("Expected a CyclicWorkflow error, got {:?}.", other),
+    }
+}
+
+/// A data dependency between synchronized nodes (c0 -> c1, c1 sync c0) can never be fulfilled.
+#[test]
+fn test_data_dependency_within_sync_group_is_rejected() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Sync-Data-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[1].node_reservation.dependencies.sync.push("c0".to_string());
+
+    match Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store) {
+        Err(Error::CyclicWorkflow { cycle }) => {
+            assert_eq!(cycle, vec![WorkflowNodeId::new("c0"), WorkflowNodeId::new("c1")]);
+        }
+        other => panic!("Expected a CyclicWorkflow error, got {:?}.", other),
+    }
+}
+
+/// Workflow c0 -> c1 -> c0, i.e. two nodes depending on each other.
+#[test]
+fn test_two_node_cycle_is_rejected() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Two-Node-Cycle-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks.truncate(2);
+    workflow_dto.tasks[0].node_reservation.dependencies.data.push("c1".to_string());
+
+    match Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store) {
+        Err(Error::CyclicWorkflow { cycle }) => {
+            assert_eq!(cycle.len(), 2);
+            assert!(cycle.contains(&WorkflowNodeId::new("c0")));
+            assert!(cycle.contains(&WorkflowNodeId::new("c1")));
+        }
+        other => panic!("Expected a CyclicWorkflow error, got {:?}.", other),
+    }
+}
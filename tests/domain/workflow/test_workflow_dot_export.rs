@@ -0,0 +1,46 @@
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::Reservation;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ClientId;
+use vrm_rust_workflow::generate_system_model;
+
+/// Workflow "Simulation-Run-0": Data-Preprocessing-1 and -2 -> Data-Preprocessing-3 -> Data-Preprocessing-4,
+/// where each edge is both a DataDependency and a SyncDependency.
+#[test]
+fn test_workflow_dot_export() {
+    let file_path: &str = "src/data/test/test_workflow_loading_01.json";
+    let store = ReservationStore::new();
+    generate_system_model(file_path, store.clone()).expect("Loading of system model failed.");
+
+    let client_id = ClientId::new("7209cffb-259f-404b-ac91-4795b4ad39e7");
+    let workflow_rid = store
+        .get_client_reservations(&client_id)
+        .into_iter()
+        .find(|rid| store.get_name_for_key(*rid).is_some_and(|name| name.to_string() == "Simulation-Run-0"))
+        .expect("Workflow 'Simulation-Run-0' not found in client reservations");
+
+    let workflow_lock = store.get(workflow_rid).expect("Workflow not found in store");
+    let workflow_guard = workflow_lock.read().unwrap();
+    let workflow = match &*workflow_guard {
+        Reservation::Workflow(w) => w,
+        _ => panic!("Expected Workflow reservation"),
+    };
+
+    let dot = workflow.to_dot(&store);
+
+    assert!(dot.starts_with("digraph"));
+    assert!(dot.trim_end().ends_with('}'));
+
+    let data_edges = dot.lines().filter(|line| line.contains(" -> ") && line.contains("style=solid")).count();
+    let sync_edges = dot.lines().filter(|line| line.contains(" -> ") && line.contains("style=dashed")).count();
+    assert_eq!(data_edges, 3);
+    assert_eq!(sync_edges, 3);
+    assert_eq!(dot.lines().filter(|line| line.contains(" -> ")).count(), 6);
+
+    // Every CoAllocation is a cluster, entry and exit nodes are highlighted
+    assert_eq!(dot.matches("subgraph cluster_").count(), workflow.co_allocations.len());
+    for entry_node in &workflow.entry_nodes {
+        assert!(dot.contains(&format!("\"{}\" [label=", entry_node)));
+    }
+    assert_eq!(dot.matches("fillcolor=green").count(), workflow.entry_nodes.len());
+    assert_eq!(dot.matches("fillcolor=red").count(), workflow.exit_nodes.iter().filter(|node| !workflow.entry_nodes.contains(node)).count());
+}
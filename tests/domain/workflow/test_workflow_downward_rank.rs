@@ -0,0 +1,42 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Diamond workflow c0 -> (c1, c2) -> c3, where every task has a duration of 50 and all dependencies have a size of 0.
+/// Both ranks can be computed on the same workflow: c0 has the largest upward rank and c3 the largest downward rank.
+#[test]
+fn test_upward_and_downward_rank_on_diamond_workflow() {
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Downward-Rank-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            let upward_ranked_nodes = workflow.calculate_upward_rank(10, &store).unwrap();
+            let downward_ranked_nodes = workflow.calculate_downward_rank(10, &store).unwrap();
+
+            assert_eq!(upward_ranked_nodes.len(), 4);
+            assert_eq!(downward_ranked_nodes.len(), 4);
+
+            let c0 = workflow.nodes.get(&WorkflowNodeId::new("c0")).unwrap();
+            let c3 = workflow.nodes.get(&WorkflowNodeId::new("c3")).unwrap();
+            assert_eq!(upward_ranked_nodes[0].reservation_id, c0.reservation_id);
+            assert_eq!(downward_ranked_nodes[0].reservation_id, c3.reservation_id);
+
+            let c0_co_allocation = workflow.co_allocations.get(c0.co_allocation_key.as_ref().unwrap()).unwrap();
+            let c3_co_allocation = workflow.co_allocations.get(c3.co_allocation_key.as_ref().unwrap()).unwrap();
+            assert_eq!(c0_co_allocation.rank_upward, 150);
+            assert_eq!(c0_co_allocation.rank_downward, 50);
+            assert_eq!(c3_co_allocation.rank_upward, 50);
+            assert_eq!(c3_co_allocation.rank_downward, 150);
+
+            let max_downward_rank = workflow.co_allocations.values().map(|co_allocation| co_allocation.rank_downward).max().unwrap();
+            assert_eq!(c3_co_allocation.rank_downward, max_downward_rank);
+        })
+        .expect("Workflow not found.");
+}
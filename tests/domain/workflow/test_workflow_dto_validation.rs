@@ -0,0 +1,109 @@
+use vrm_rust_workflow::api::workflow_dto::client_dto::{ClientDto, ClientsDto};
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{DataInDto, ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::validation::ValidationError;
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::error::Error;
+use vrm_rust_workflow::generate_system_model;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+const WORKFLOW_ID: &str = "Test-Validation-Workflow";
+
+fn get_workflow_dto() -> WorkflowDto {
+    return get_direct_mapping_workflow_dto(WORKFLOW_ID.to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+}
+
+fn get_clients_dto(workflow_dto: WorkflowDto) -> ClientsDto {
+    return ClientsDto { clients: vec![ClientDto { id: "Test-Client-001".to_string(), workflows: vec![workflow_dto] }] };
+}
+
+#[test]
+fn test_valid_workflow() {
+    assert_eq!(get_clients_dto(get_workflow_dto()).validate(), Ok(()));
+}
+
+#[test]
+fn test_duplicate_task_id() {
+    let mut workflow_dto = get_workflow_dto();
+    workflow_dto.tasks[2].id = workflow_dto.tasks[1].id.clone();
+
+    assert_eq!(
+        get_clients_dto(workflow_dto).validate(),
+        Err(vec![ValidationError::DuplicateTaskId { workflow: WORKFLOW_ID.to_string(), task: "c1".to_string() }])
+    );
+}
+
+#[test]
+fn test_dangling_data_in() {
+    let mut workflow_dto = get_workflow_dto();
+    workflow_dto.tasks[3].node_reservation.data_in.push(DataInDto {
+        source_reservation: "c0".to_string(),
+        source_port: "unknown_port".to_string(),
+        file: None,
+    });
+    // Existing output of another task is a valid reference
+    workflow_dto.tasks[3].node_reservation.data_in.push(DataInDto {
+        source_reservation: "c0".to_string(),
+        source_port: "preprocessed_data".to_string(),
+        file: None,
+    });
+
+    assert_eq!(
+        get_clients_dto(workflow_dto).validate(),
+        Err(vec![ValidationError::DanglingDataIn {
+            workflow: WORKFLOW_ID.to_string(),
+            task: "c3".to_string(),
+            source_reservation: "c0".to_string(),
+            source_port: "unknown_port".to_string(),
+        }])
+    );
+}
+
+#[test]
+fn test_negative_values() {
+    let mut workflow_dto = get_workflow_dto();
+    workflow_dto.tasks[0].node_reservation.duration = -1;
+    workflow_dto.tasks[1].node_reservation.cpus = -2;
+    workflow_dto.tasks[2].node_reservation.data_out[0].size = Some(-3);
+
+    assert_eq!(
+        get_clients_dto(workflow_dto).validate(),
+        Err(vec![
+            ValidationError::NegativeValue { workflow: WORKFLOW_ID.to_string(), task: "c0".to_string(), field: "duration".to_string(), value: -1 },
+            ValidationError::NegativeValue { workflow: WORKFLOW_ID.to_string(), task: "c1".to_string(), field: "cpus".to_string(), value: -2 },
+            ValidationError::NegativeValue { workflow: WORKFLOW_ID.to_string(), task: "c2".to_string(), field: "size".to_string(), value: -3 },
+        ])
+    );
+}
+
+#[test]
+fn test_invalid_booking_interval() {
+    let mut workflow_dto = get_workflow_dto();
+    workflow_dto.booking_interval_end = workflow_dto.booking_interval_start;
+
+    assert_eq!(
+        get_clients_dto(workflow_dto).validate(),
+        Err(vec![ValidationError::InvalidBookingInterval { workflow: WORKFLOW_ID.to_string(), start: 10, end: 10 }])
+    );
+}
+
+/// All problems are reported at once and `generate_system_model` rejects the file before the model construction.
+#[test]
+fn test_generate_system_model_reports_all_problems() {
+    let mut workflow_dto = get_workflow_dto();
+    workflow_dto.booking_interval_end = 0;
+    workflow_dto.tasks[0].node_reservation.cpus = -1;
+
+    let file_path = std::env::temp_dir().join(format!("test_workflow_dto_validation_{}.json", std::process::id()));
+    std::fs::write(&file_path, serde_json::to_string(&get_clients_dto(workflow_dto)).unwrap()).unwrap();
+
+    let result = generate_system_model(file_path.to_str().unwrap(), ReservationStore::new());
+    let _ = std::fs::remove_file(&file_path);
+
+    match result {
+        Err(Error::Validation { errors }) => assert_eq!(errors.len(), 2, "Unexpected validation errors: {:?}", errors),
+        Err(err) => panic!("Expected Validation error, got {:?}", err),
+        Ok(_) => panic!("Expected an error but got Ok"),
+    }
+}
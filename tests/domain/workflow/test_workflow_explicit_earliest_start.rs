@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, create_heft_sync_scheduler, get_direct_mapping_workflow_dto};
+
+const C1_EARLIEST_START: i64 = 300;
+
+/// Workflow c0 -> (c1, c2) -> c3 on a single AcI, where the input data of c1 is only available at 300.
+/// c0 finishes much earlier, but c1 must be pushed to its explicit earliest start, while c2 is not affected.
+#[tokio::test]
+async fn test_node_is_pushed_to_explicit_earliest_start() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Earliest-Start-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[1].node_reservation.earliest_start = Some(C1_EARLIEST_START);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let node_res_ids = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            assert_eq!(workflow.nodes.get(&WorkflowNodeId::new("c1")).unwrap().earliest_start, Some(C1_EARLIEST_START));
+            ["c0", "c1", "c2", "c3"].map(|node_id| workflow.nodes.get(&WorkflowNodeId::new(node_id)).unwrap().reservation_id)
+        })
+        .expect("Workflow not found.");
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    assert!(scheduler.reserve(workflow_res_id, &mut adc).is_ok(), "Reserve of the workflow failed.");
+
+    assert!(store.get_assigned_end(node_res_ids[0]) < C1_EARLIEST_START, "Predecessor c0 should finish before the explicit earliest start.");
+    assert!(store.get_assigned_start(node_res_ids[1]) >= C1_EARLIEST_START);
+    assert!(store.get_assigned_start(node_res_ids[2]) < C1_EARLIEST_START, "c2 has no explicit earliest start.");
+    assert!(store.get_assigned_start(node_res_ids[3]) >= store.get_assigned_end(node_res_ids[1]));
+}
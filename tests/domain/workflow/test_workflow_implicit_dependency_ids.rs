@@ -0,0 +1,154 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{DataInDto, ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ClientId;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Returns all DataDependencies from `source` to `target` as (id, port, size), sorted by id.
+fn get_data_dependencies(workflow_dto: WorkflowDto, source: &str, target: &str) -> Vec<(String, String, i64)> {
+    let store = ReservationStore::new();
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    return store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            let mut data_dependencies: Vec<(String, String, i64)> = workflow
+                .data_dependencies
+                .iter()
+                .filter(|(_, dep)| {
+                    dep.source_node.as_ref().is_some_and(|node| node.id == source) && dep.target_node.as_ref().is_some_and(|node| node.id == target)
+                })
+                .map(|(dep_id, dep)| (dep_id.id.clone(), dep.port_name.clone(), dep.size))
+                .collect();
+            data_dependencies.sort();
+            data_dependencies
+        })
+        .expect("Workflow not found.");
+}
+
+/// Node c1 receives the explicit port "data" of c0 and additionally lists c0 in `dependencies.data`.
+#[test]
+fn test_explicit_data_port_and_implicit_dependency_coexist() {
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Port-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[0].node_reservation.data_out[0].name = "data".to_string();
+    workflow_dto.tasks[1].node_reservation.data_in.push(DataInDto {
+        source_reservation: "c0".to_string(),
+        source_port: "data".to_string(),
+        file: None,
+    });
+    assert_eq!(workflow_dto.tasks[1].node_reservation.dependencies.data, vec!["c0".to_string()]);
+
+    let data_dependencies = get_data_dependencies(workflow_dto, "c0", "c1");
+
+    assert_eq!(
+        data_dependencies,
+        vec![
+            ("Test-Port-Workflow.c0.data".to_string(), "data".to_string(), 50),
+            ("Test-Port-Workflow.data.c0.c1".to_string(), "data".to_string(), 0),
+        ]
+    );
+}
+
+/// The explicit port "c1" of node "data.c0" gets the id `Test-Collision-Workflow.data.c0.c1`, which equals the id of the
+/// implicit dependency c0 -> c1. The implicit dependency must be disambiguated instead of overwriting the explicit one.
+#[test]
+fn test_colliding_implicit_dependency_id_is_disambiguated() {
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Collision-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+
+    let mut dotted_task = workflow_dto.tasks[0].clone();
+    dotted_task.id = "data.c0".to_string();
+    dotted_task.node_reservation.data_out[0].name = "c1".to_string();
+    dotted_task.node_reservation.data_out[0].size = Some(30);
+    workflow_dto.tasks.push(dotted_task);
+    workflow_dto.tasks[1].node_reservation.data_in.push(DataInDto {
+        source_reservation: "data.c0".to_string(),
+        source_port: "c1".to_string(),
+        file: None,
+    });
+
+    assert_eq!(
+        get_data_dependencies(workflow_dto.clone(), "data.c0", "c1"),
+        vec![("Test-Collision-Workflow.data.c0.c1".to_string(), "c1".to_string(), 30)]
+    );
+    assert_eq!(get_data_dependencies(workflow_dto, "c0", "c1"), vec![("Test-Collision-Workflow.data.c0.c1.1".to_string(), "data".to_string(), 0)]);
+}
+
+/// Returns the reservation ids of all DataDependencies of the Workflow from `source` to `target`.
+fn get_data_dependency_reservation_ids(store: &ReservationStore, workflow_res_id: ReservationId, source: &str, target: &str) -> Vec<ReservationId> {
+    return store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            workflow
+                .data_dependencies
+                .values()
+                .filter(|dep| {
+                    dep.source_node.as_ref().is_some_and(|node| node.id == source) && dep.target_node.as_ref().is_some_and(|node| node.id == target)
+                })
+                .map(|dep| dep.reservation_id)
+                .collect()
+        })
+        .expect("Workflow not found.");
+}
+
+/// c1 lists c0 twice in `dependencies.data`, both references share one dependency reservation. Parsing the same
+/// Workflow a second time into the same store must not reuse the dependency reservation of the first Workflow.
+#[test]
+fn test_implicit_dependency_reservation_is_reused_within_one_workflow_only() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Fan-Out-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[1].node_reservation.dependencies.data.push("c0".to_string());
+
+    let first_workflow_res_id =
+        Workflow::create_form_dto(workflow_dto.clone(), ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+    let second_workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let first_reservation_ids = get_data_dependency_reservation_ids(&store, first_workflow_res_id, "c0", "c1");
+    let second_reservation_ids = get_data_dependency_reservation_ids(&store, second_workflow_res_id, "c0", "c1");
+
+    assert_eq!(first_reservation_ids.len(), 1);
+    assert_eq!(second_reservation_ids.len(), 1);
+    assert_ne!(first_reservation_ids[0], second_reservation_ids[0]);
+}
+
+/// The output port "data" of c0 is consumed by c1 and c2 (fan-out). Both DataDependencies share one reservation.
+#[test]
+fn test_output_port_consumers_share_one_reservation() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Port-Fan-Out-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[0].node_reservation.data_out[0].name = "data".to_string();
+    for consumer in [1, 2] {
+        workflow_dto.tasks[consumer].node_reservation.data_in.push(DataInDto {
+            source_reservation: "c0".to_string(),
+            source_port: "data".to_string(),
+            file: None,
+        });
+    }
+
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+    let port_dependencies: Vec<(String, ReservationId)> = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            let mut port_dependencies: Vec<(String, ReservationId)> = workflow
+                .data_dependencies
+                .iter()
+                .filter(|(_, dep)| dep.port_name == "data" && dep.size == 50)
+                .map(|(dep_id, dep)| (dep_id.id.clone(), dep.reservation_id))
+                .collect();
+            port_dependencies.sort();
+            port_dependencies
+        })
+        .expect("Workflow not found.");
+
+    assert_eq!(
+        port_dependencies.iter().map(|(dep_id, _)| dep_id.as_str()).collect::<Vec<_>>(),
+        vec!["Test-Port-Fan-Out-Workflow.c0.data.c1", "Test-Port-Fan-Out-Workflow.c0.data.c2"]
+    );
+    assert_eq!(port_dependencies[0].1, port_dependencies[1].1);
+    assert_eq!(store.get_name_for_key(port_dependencies[0].1).unwrap().id, "Test-Port-Fan-Out-Workflow.c0.data");
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, CoAllocationId, ReservationName, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+fn get_ranks(workflow: &Workflow) -> HashMap<CoAllocationId, (i64, i64)> {
+    return workflow
+        .co_allocations
+        .iter()
+        .map(|(key, co_allocation)| (key.clone(), (co_allocation.rank_upward, co_allocation.number_of_nodes_critical_path_upwards)))
+        .collect();
+}
+
+/// Modifies the duration of c1 of the workflow c0 -> (c1, c2) -> c3, only c1 and its ancestor c0 are recomputed
+/// and the result must match a full recompute.
+#[test]
+fn test_update_rank_from_matches_full_recompute() {
+    let mut store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Incremental-Rank-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let ranks_before = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            workflow.calculate_upward_rank(10, &store).unwrap();
+            get_ranks(workflow)
+        })
+        .expect("Expected Workflow reservation");
+
+    let c1_res_id = store.get_key_for_name(ReservationName::new("c1"));
+    store.set_task_duration(c1_res_id, 200);
+
+    let (incremental_ranks, full_ranks, c1_key, c2_key) = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            let co_allocation_key = |node_id: &str| workflow.nodes.get(&WorkflowNodeId::new(node_id)).unwrap().co_allocation_key.clone().unwrap();
+            let (c1_key, c2_key) = (co_allocation_key("c1"), co_allocation_key("c2"));
+
+            workflow.update_rank_from(c1_key.clone(), 10, &store).unwrap();
+            let incremental_ranks = get_ranks(workflow);
+
+            workflow.calculate_upward_rank(10, &store).unwrap();
+            (incremental_ranks, get_ranks(workflow), c1_key, c2_key)
+        })
+        .expect("Expected Workflow reservation");
+
+    assert_eq!(incremental_ranks, full_ranks);
+    assert_eq!(incremental_ranks[&c1_key].0, ranks_before[&c1_key].0 + 150);
+    assert_eq!(incremental_ranks[&c2_key], ranks_before[&c2_key]);
+}
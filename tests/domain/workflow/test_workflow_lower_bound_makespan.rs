@@ -0,0 +1,22 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ClientId;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Workflow c0 -> (c1, c2) -> c3, where every task has a duration of 50 and all dependencies have a size of 0.
+/// The critical path is c0 -> c1 -> c3 (or c0 -> c2 -> c3) with a length of 3 * 50 = 150.
+#[test]
+fn test_lower_bound_makespan_equals_critical_path() {
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Lower-Bound-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let lower_bound =
+        store.with_workflow_mut(workflow_res_id, |workflow| workflow.lower_bound_makespan(10, &store)).expect("Expected Workflow reservation");
+
+    assert_eq!(lower_bound.unwrap(), 150);
+}
@@ -0,0 +1,72 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::Reservation;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+use vrm_rust_workflow::error::Error;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Builds the diamond workflow c0 -> (c1, c2) -> c3 and returns a copy of it.
+fn create_diamond_workflow(workflow_name: &str, store: &ReservationStore) -> Workflow {
+    let workflow_dto = get_direct_mapping_workflow_dto(workflow_name.to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    return match store.get_reservation_snapshot(workflow_res_id) {
+        Some(Reservation::Workflow(workflow)) => workflow,
+        _ => panic!("Expected Workflow reservation"),
+    };
+}
+
+#[test]
+fn test_merge_two_diamonds() {
+    let store = ReservationStore::new();
+    let diamond_a = create_diamond_workflow("Diamond-A", &store);
+    let diamond_b = create_diamond_workflow("Diamond-B", &store);
+    let number_of_data_dependencies = diamond_a.data_dependencies.len() + diamond_b.data_dependencies.len();
+
+    let merged = diamond_a.merge(diamond_b, &store).expect("Merging of the workflows failed.");
+
+    assert_eq!(merged.base.name.id, "Diamond-A+Diamond-B");
+    assert_eq!(merged.nodes.len(), 8);
+    assert_eq!(merged.data_dependencies.len(), number_of_data_dependencies);
+    assert_eq!(merged.co_allocations.len(), 8);
+
+    assert_eq!(merged.entry_nodes.len(), 2);
+    assert_eq!(merged.exit_nodes.len(), 2);
+    assert_eq!(merged.entry_co_allocation.len(), 2);
+    assert_eq!(merged.exit_co_allocation.len(), 2);
+
+    for workflow_name in ["Diamond-A", "Diamond-B"] {
+        assert!(merged.entry_nodes.contains(&WorkflowNodeId::new(format!("{}.c0", workflow_name))));
+        assert!(merged.exit_nodes.contains(&WorkflowNodeId::new(format!("{}.c3", workflow_name))));
+    }
+
+    // All references of the dependencies and nodes point into the merged graph
+    for data_dependency in merged.data_dependencies.values() {
+        let source_node = data_dependency.source_node.as_ref().unwrap();
+        let target_node = data_dependency.target_node.as_ref().unwrap();
+        assert!(merged.nodes.contains_key(source_node));
+        assert!(merged.nodes.contains_key(target_node));
+        assert_eq!(source_node.id.split('.').next(), target_node.id.split('.').next());
+    }
+
+    for node in merged.nodes.values() {
+        assert!(merged.co_allocations.contains_key(node.co_allocation_key.as_ref().unwrap()));
+        for dep_id in node.incoming_data.iter().chain(node.outgoing_data.iter()) {
+            assert!(merged.data_dependencies.contains_key(dep_id));
+        }
+    }
+}
+
+#[test]
+fn test_merge_rejects_id_collision() {
+    let store = ReservationStore::new();
+    let diamond_a = create_diamond_workflow("Diamond", &store);
+    let diamond_b = create_diamond_workflow("Diamond", &store);
+
+    let result = diamond_a.merge(diamond_b, &store);
+
+    assert!(matches!(result, Err(Error::IdCollision { .. })));
+}
@@ -0,0 +1,27 @@
+use vrm_rust_workflow::error::Error;
+use vrm_rust_workflow::loader::parser::parse_json_file;
+
+/// The trailing comma in line 4 must be reported together with the file path.
+#[test]
+fn test_parse_error_reports_file_and_line() {
+    let file_path = std::env::temp_dir().join(format!("test_workflow_parse_error_{}.json", std::process::id()));
+    let file_path = file_path.to_str().unwrap();
+    std::fs::write(file_path, "{\n  \"clients\": [\n    { \"id\": \"Client-001\" },\n  ]\n}\n").unwrap();
+
+    let result = parse_json_file::<serde_json::Value>(file_path);
+    let _ = std::fs::remove_file(file_path);
+
+    match result {
+        Err(Error::Parse { path, line, column, message }) => {
+            assert_eq!(path, file_path);
+            assert_eq!(line, 4);
+            assert!(column > 0);
+            assert!(message.contains("trailing comma"), "Unexpected message: {}", message);
+
+            let display = Error::Parse { path, line, column, message: message.clone() }.to_string();
+            assert_eq!(display, format!("failed to parse {} at line 4 col {}: {}", file_path, column, message));
+        }
+        Err(err) => panic!("Expected Parse error, got {:?}", err),
+        Ok(_) => panic!("Expected an error but got Ok"),
+    }
+}
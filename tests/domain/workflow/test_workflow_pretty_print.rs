@@ -0,0 +1,36 @@
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::test_support::workflow_builder::WorkflowBuilder;
+
+/// Every WorkflowNode is listed exactly once as member of its CoAllocation, the edges are listed below their source.
+#[test]
+fn test_pretty_print_lists_all_nodes_once() {
+    let store = ReservationStore::new();
+    let mut workflow = WorkflowBuilder::new("Test-Pretty-Print")
+        .node("c0", 50, 2)
+        .node("c1", 60, 2)
+        .node("c2", 70, 4)
+        .node("c3", 50, 2)
+        .data_edge("c0", "c1", 50)
+        .data_edge("c0", "c2", 50)
+        .data_edge("c1", "c3", 20)
+        .data_edge("c2", "c3", 30)
+        .sync_edge("c1", "c2", 100)
+        .build(store.clone());
+    workflow.calculate_upward_rank(10, &store).unwrap();
+
+    let tree = workflow.pretty_print(&store);
+    let lines: Vec<&str> = tree.lines().collect();
+
+    for node_id in ["c0", "c1", "c2", "c3"] {
+        let member_line = format!("- {} (", node_id);
+        assert_eq!(lines.iter().filter(|line| line.trim_start().starts_with(&member_line)).count(), 1, "Node {} not listed once:\n{}", node_id, tree);
+    }
+    assert!(tree.contains("- c2 (duration: 70, capacity: 4)"), "{}", tree);
+    assert!(tree.contains("=> c2 (sync, bandwidth: 100)"), "{}", tree);
+    assert!(tree.contains("-> c3 (data, size: 30)"), "{}", tree);
+
+    // c1 and c2 are co-allocated, the entry CoAllocation has the highest rank and is listed first
+    assert_eq!(lines.iter().filter(|line| line.trim_start().starts_with("CoAllocation")).count(), 3);
+    let first_co_allocation = lines.iter().position(|line| line.trim_start().starts_with("CoAllocation")).unwrap();
+    assert!(lines[first_co_allocation + 1].trim_start().starts_with("- c0 ("), "{}", tree);
+}
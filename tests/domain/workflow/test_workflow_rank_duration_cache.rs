@@ -0,0 +1,35 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ReservationName};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// The CoAllocation durations are cached per rank pass. If the duration of c1 of the workflow c0 -> (c1, c2) -> c3
+/// is modified between two passes, the next pass must pick up the new critical path c0 -> c1 -> c3 (50 + 200 + 50).
+#[test]
+fn test_rank_passes_pick_up_modified_task_duration() {
+    let mut store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Rank-Cache-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let get_max_ranks = |store: &ReservationStore| {
+        store
+            .with_workflow_mut(workflow_res_id, |workflow| {
+                let max_rank_upward = workflow.lower_bound_makespan(10, store).unwrap();
+                workflow.calculate_downward_rank(10, store).unwrap();
+                let max_rank_downward = workflow.co_allocations.values().map(|co_allocation| co_allocation.rank_downward).max().unwrap();
+                (max_rank_upward, max_rank_downward)
+            })
+            .expect("Expected Workflow reservation")
+    };
+
+    assert_eq!(get_max_ranks(&store), (150, 150));
+
+    let c1_res_id = store.get_key_for_name(ReservationName::new("c1"));
+    store.set_task_duration(c1_res_id, 200);
+
+    assert_eq!(get_max_ranks(&store), (300, 300));
+}
@@ -0,0 +1,58 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ClientId;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+use vrm_rust_workflow::error::Error;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Workflow c0 -> (c1, c2) -> c3, where every data dependency transfers `size`.
+fn create_workflow(store: &ReservationStore, size: i64) -> ReservationId {
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Network-Speed-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            for data_dependency in workflow.data_dependencies.values_mut() {
+                data_dependency.size = size;
+            }
+        })
+        .expect("Expected Workflow reservation");
+    return workflow_res_id;
+}
+
+#[test]
+fn test_rank_with_zero_speed_and_transfers_fails() {
+    let store = ReservationStore::new();
+    let workflow_res_id = create_workflow(&store, 50);
+
+    let (upward_result, downward_result) = store
+        .with_workflow_mut(workflow_res_id, |workflow| (workflow.calculate_upward_rank(0, &store), workflow.calculate_downward_rank(0, &store)))
+        .expect("Expected Workflow reservation");
+
+    assert!(matches!(upward_result, Err(Error::NoNetworkCapacity)));
+    assert!(matches!(downward_result, Err(Error::NoNetworkCapacity)));
+}
+
+/// Nothing has to be transferred, hence the ranks only consist of the task durations (3 * 50).
+#[test]
+fn test_rank_with_zero_speed_without_transfers_succeeds() {
+    let store = ReservationStore::new();
+    let workflow_res_id = create_workflow(&store, 0);
+
+    let (upward_ranked_nodes, downward_ranked_nodes, lower_bound) = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            (
+                workflow.calculate_upward_rank(0, &store).expect("Upward rank calculation failed."),
+                workflow.calculate_downward_rank(0, &store).expect("Downward rank calculation failed."),
+                workflow.lower_bound_makespan(0, &store).expect("Lower bound calculation failed."),
+            )
+        })
+        .expect("Expected Workflow reservation");
+
+    assert_eq!(upward_ranked_nodes.len(), 4);
+    assert_eq!(downward_ranked_nodes.len(), 4);
+    assert_eq!(lower_bound, 150);
+}
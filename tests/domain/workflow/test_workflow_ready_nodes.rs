@@ -0,0 +1,139 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ReservationName, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+fn get_ready_nodes(store: &ReservationStore, workflow_res_id: ReservationId) -> Vec<WorkflowNodeId> {
+    return store.with_workflow_mut(workflow_res_id, |workflow| workflow.ready_nodes(store)).expect("Workflow not found.");
+}
+
+fn finish(store: &ReservationStore, node_name: &str) {
+    store.update_state(store.get_key_for_name(ReservationName::new(node_name)), ReservationState::Finished);
+}
+
+fn node_ids(ids: &[&str]) -> Vec<WorkflowNodeId> {
+    return ids.iter().map(|id| WorkflowNodeId::new(*id)).collect();
+}
+
+/// Workflow c0 -> (c1, c2) -> c3 is executed by pulling the ready frontier and finishing its nodes.
+#[test]
+fn test_ready_frontier_follows_finished_nodes() {
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Ready-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c0"]));
+
+    finish(&store, "c0");
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c1", "c2"]));
+
+    // c3 waits for both of its predecessors
+    finish(&store, "c1");
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c2"]));
+
+    finish(&store, "c2");
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c3"]));
+
+    finish(&store, "c3");
+    assert!(get_ready_nodes(&store, workflow_res_id).is_empty());
+}
+
+/// A rejected node is not executed anymore and does not release its successors.
+#[test]
+fn test_rejected_node_blocks_successors() {
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Ready-Rejected-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    finish(&store, "c0");
+    store.update_state(store.get_key_for_name(ReservationName::new("c1")), ReservationState::Rejected);
+    finish(&store, "c2");
+
+    assert!(get_ready_nodes(&store, workflow_res_id).is_empty());
+}
+
+/// Builds the Workflow c0 -> (c1, c2) -> c3, whose tasks c1 and c2 are co-allocated by the provided SyncDependencies.
+fn create_synced_workflow(store: &ReservationStore, workflow_id: &str, c1_syncs_with_c2: bool, c2_syncs_with_c1: bool) -> ReservationId {
+    let mut workflow_dto = get_direct_mapping_workflow_dto(workflow_id.to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    if c1_syncs_with_c2 {
+        workflow_dto.tasks[1].node_reservation.dependencies.sync = vec!["c2".to_string()];
+    }
+    if c2_syncs_with_c1 {
+        workflow_dto.tasks[2].node_reservation.dependencies.sync = vec!["c1".to_string()];
+    }
+    return Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+}
+
+/// A SyncDependency declared on both nodes does not block the co-allocated nodes mutually.
+#[test]
+fn test_sync_pair_declared_both_ways_becomes_ready() {
+    let store = ReservationStore::new();
+    let workflow_res_id = create_synced_workflow(&store, "Test-Ready-Mutual-Sync-Workflow", true, true);
+
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c0"]));
+
+    finish(&store, "c0");
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c1", "c2"]));
+}
+
+/// The target of a one-way SyncDependency is released together with its source and not after it.
+#[test]
+fn test_one_way_sync_partners_become_ready_together() {
+    let store = ReservationStore::new();
+    let workflow_res_id = create_synced_workflow(&store, "Test-Ready-One-Way-Sync-Workflow", false, true);
+
+    finish(&store, "c0");
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c1", "c2"]));
+
+    // The remaining partner stays ready, until it is finished as well
+    finish(&store, "c1");
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c2"]));
+
+    finish(&store, "c2");
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c3"]));
+}
+
+/// Only `Finished` data predecessors release their successors, reserved ones do not.
+#[test]
+fn test_reserved_predecessor_does_not_release_successors() {
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Ready-Reserved-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    store.update_state(store.get_key_for_name(ReservationName::new("c0")), ReservationState::ReserveAnswer);
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c0"]));
+
+    store.update_state(store.get_key_for_name(ReservationName::new("c0")), ReservationState::Committed);
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c0"]));
+
+    finish(&store, "c0");
+    store.update_state(store.get_key_for_name(ReservationName::new("c1")), ReservationState::Committed);
+    store.update_state(store.get_key_for_name(ReservationName::new("c2")), ReservationState::ReserveAnswer);
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c1", "c2"]));
+}
+
+/// Committed sync partners are released together, since the `incoming_sync` of a node is not awaited.
+#[test]
+fn test_committed_sync_partners_do_not_await_each_other() {
+    let store = ReservationStore::new();
+    let workflow_res_id = create_synced_workflow(&store, "Test-Ready-Committed-Sync-Workflow", true, false);
+
+    for node_name in ["c0", "c1", "c2", "c3"] {
+        store.update_state(store.get_key_for_name(ReservationName::new(node_name)), ReservationState::Committed);
+    }
+    finish(&store, "c0");
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c1", "c2"]));
+
+    // c3 waits for both partners to be finished and not only for their joint start
+    finish(&store, "c2");
+    assert_eq!(get_ready_nodes(&store, workflow_res_id), node_ids(&["c1"]));
+}
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc_with_dummy_aci, create_heft_sync_scheduler, get_direct_mapping_workflow_dto};
+
+/// Workflow c0 -> (c1, c2) -> c3 on a single AcI. After the placement, the CoAllocation of c0 is fixed (e.g. already
+/// running) and the workflow is rescheduled with a later booking interval start. Hence, c0 must keep its window,
+/// while c1, c2 and c3 are moved behind the new booking interval start.
+#[tokio::test]
+async fn test_reschedule_keeps_fixed_co_allocation_and_moves_others() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Reschedule-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    assert!(scheduler.reserve(workflow_res_id, &mut adc).is_ok(), "Initial reserve of the workflow failed.");
+
+    let (node_res_ids, fixed_co_allocation_key) = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            let node_res_ids = ["c0", "c1", "c2", "c3"].map(|node_id| workflow.nodes.get(&WorkflowNodeId::new(node_id)).unwrap().reservation_id);
+            (node_res_ids, workflow.nodes.get(&WorkflowNodeId::new("c0")).unwrap().co_allocation_key.clone().unwrap())
+        })
+        .expect("Workflow not found.");
+
+    let windows_before: Vec<(i64, i64)> =
+        node_res_ids.iter().map(|res_id| (store.get_assigned_start(*res_id), store.get_assigned_end(*res_id))).collect();
+    let new_booking_interval_start = windows_before[0].1 + 100;
+    let fixed_windows = HashMap::from([(fixed_co_allocation_key.clone(), windows_before[0])]);
+
+    let is_rescheduled = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            workflow.base.booking_interval_start = new_booking_interval_start;
+            let is_rescheduled = workflow.reschedule(&fixed_windows, &mut adc);
+
+            assert!(!workflow.co_allocations.get(&fixed_co_allocation_key).unwrap().is_moveable);
+            is_rescheduled
+        })
+        .expect("Workflow not found.");
+    assert!(is_rescheduled, "Reschedule of the workflow failed.");
+
+    // Fixed CoAllocation keeps its window
+    assert_eq!((store.get_assigned_start(node_res_ids[0]), store.get_assigned_end(node_res_ids[0])), windows_before[0]);
+
+    // Moveable CoAllocations are moved behind the new booking interval start
+    for (index, res_id) in node_res_ids.iter().enumerate().skip(1) {
+        assert_eq!(store.get_state(*res_id), ReservationState::ReserveAnswer);
+        assert!(store.get_assigned_start(*res_id) >= new_booking_interval_start);
+        assert_ne!(store.get_assigned_start(*res_id), windows_before[index].0);
+    }
+    assert!(store.get_assigned_start(node_res_ids[3]) >= store.get_assigned_end(node_res_ids[1]));
+    assert!(store.get_assigned_start(node_res_ids[3]) >= store.get_assigned_end(node_res_ids[2]));
+}
+
+/// The CoAllocation of c0 is fixed, but the new booking interval is shorter than the duration of c1 and c2. The
+/// aborted reschedule must restore the released dependencies c0 -> c1 and c0 -> c2 of the fixed CoAllocation.
+#[tokio::test]
+async fn test_aborted_reschedule_restores_dependencies_of_fixed_co_allocation() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut adc = create_adc_with_dummy_aci(clock, store.clone()).await;
+
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Reschedule-Abort-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    assert!(scheduler.reserve(workflow_res_id, &mut adc).is_ok(), "Initial reserve of the workflow failed.");
+
+    let (c0_res_id, fixed_co_allocation_key, dependency_res_ids) = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            let c0 = workflow.nodes.get(&WorkflowNodeId::new("c0")).unwrap();
+            let co_allocation_key = c0.co_allocation_key.clone().unwrap();
+            let dependency_res_ids: Vec<_> =
+                workflow.co_allocations[&co_allocation_key].outgoing_data_dependencies.iter().map(|data_dep| data_dep.reservation_id).collect();
+            (c0.reservation_id, co_allocation_key, dependency_res_ids)
+        })
+        .expect("Workflow not found.");
+    assert_eq!(dependency_res_ids.len(), 2);
+
+    let dependencies_before: Vec<(ReservationState, i64, i64)> = dependency_res_ids
+        .iter()
+        .map(|res_id| (store.get_state(*res_id), store.get_assigned_start(*res_id), store.get_assigned_end(*res_id)))
+        .collect();
+    let c0_window = (store.get_assigned_start(c0_res_id), store.get_assigned_end(c0_res_id));
+    let fixed_windows = HashMap::from([(fixed_co_allocation_key, c0_window)]);
+
+    let is_rescheduled = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            workflow.base.booking_interval_start = c0_window.1 + 100;
+            workflow.base.booking_interval_end = c0_window.1 + 130;
+            workflow.reschedule(&fixed_windows, &mut adc)
+        })
+        .expect("Workflow not found.");
+    assert!(!is_rescheduled, "The moveable CoAllocations must not fit into the new booking interval.");
+    assert_eq!(store.get_state(workflow_res_id), ReservationState::Rejected);
+
+    // The fixed CoAllocation and its dependencies are kept
+    assert_eq!((store.get_assigned_start(c0_res_id), store.get_assigned_end(c0_res_id)), c0_window);
+    for (res_id, dependency_before) in dependency_res_ids.iter().zip(dependencies_before) {
+        assert_eq!((store.get_state(*res_id), store.get_assigned_start(*res_id), store.get_assigned_end(*res_id)), dependency_before);
+    }
+}
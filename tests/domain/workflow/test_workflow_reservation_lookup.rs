@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::ReservationState;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ReservationName, SyncDependencyId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::dependency::{DependencyRef, SyncDependency};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_node_reservation, get_direct_mapping_workflow_dto};
+
+/// Every node and every data dependency of the workflow c0 -> (c1, c2) -> c3 is found by its reservation id.
+#[test]
+fn test_lookup_of_nodes_and_data_dependencies() {
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Lookup-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            for node_id in ["c0", "c1", "c2", "c3"] {
+                let node = workflow.nodes.get(&WorkflowNodeId::new(node_id)).unwrap();
+                let found_node = workflow.node_for_reservation(node.reservation_id).expect("Node was not found.");
+                assert_eq!(found_node.reservation_id, node.reservation_id);
+                assert!(workflow.dependency_for_reservation(node.reservation_id).is_none());
+            }
+
+            assert!(!workflow.data_dependencies.is_empty());
+            for data_dependency in workflow.data_dependencies.values() {
+                let Some(DependencyRef::Data(found_dependency)) = workflow.dependency_for_reservation(data_dependency.reservation_id) else {
+                    panic!("DataDependency was not found.");
+                };
+                assert_eq!(found_dependency.reservation_id, data_dependency.reservation_id);
+                assert_eq!(found_dependency.source_node, data_dependency.source_node);
+                assert_eq!(found_dependency.target_node, data_dependency.target_node);
+                assert!(workflow.node_for_reservation(data_dependency.reservation_id).is_none());
+            }
+
+            // The reservation of the workflow itself is neither a node nor a dependency
+            assert!(workflow.node_for_reservation(workflow_res_id).is_none());
+            assert!(workflow.dependency_for_reservation(workflow_res_id).is_none());
+        })
+        .expect("Expected Workflow reservation");
+}
+
+#[test]
+fn test_lookup_of_sync_dependency() {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Lookup-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+    let sync_res_id = store.add(create_node_reservation(ReservationName::new("Test-Sync"), 1, 0, 50, ReservationState::Open, clock));
+
+    store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            workflow.sync_dependencies.insert(
+                SyncDependencyId::new("Test-Sync"),
+                SyncDependency {
+                    reservation_id: sync_res_id,
+                    source_node: Some(WorkflowNodeId::new("c1")),
+                    target_node: Some(WorkflowNodeId::new("c2")),
+                    port_name: "port".to_string(),
+                    bandwidth: 10,
+                },
+            );
+
+            let dependency = workflow.dependency_for_reservation(sync_res_id).expect("SyncDependency was not found.");
+            assert!(matches!(dependency, DependencyRef::Sync(_)));
+            assert_eq!(dependency.source_node(), Some(&WorkflowNodeId::new("c1")));
+            assert_eq!(dependency.target_node(), Some(&WorkflowNodeId::new("c2")));
+        })
+        .expect("Expected Workflow reservation");
+}
@@ -0,0 +1,34 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Workflow c0 -> (c1, c2) -> c3, where c2 has a duration of 80 and all other tasks a duration of 50.
+/// The critical path c0 -> c2 -> c3 has no slack, while c1 can be shifted by the difference of both branches (30).
+#[test]
+fn test_compute_slack_marks_critical_path() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Slack-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[2].node_reservation.duration = 80;
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            workflow.calculate_upward_rank(10, &store).unwrap();
+            workflow.calculate_downward_rank(10, &store).unwrap();
+            workflow.compute_slack(&store);
+
+            for (node_id, expected_slack) in [("c0", 0), ("c1", 30), ("c2", 0), ("c3", 0)] {
+                let node = workflow.nodes.get(&WorkflowNodeId::new(node_id)).unwrap();
+                let co_allocation = workflow.co_allocations.get(node.co_allocation_key.as_ref().unwrap()).unwrap();
+
+                assert_eq!(co_allocation.spare_time, expected_slack, "Unexpected slack of {}", node_id);
+                assert_eq!(co_allocation.is_moveable, expected_slack > 0, "Unexpected is_moveable of {}", node_id);
+            }
+        })
+        .expect("Workflow not found.");
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use vrm_rust_workflow::api::rms_config_dto::rms_dto::{GridNodeDto, RmsSystemWrapper};
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::simulator::simulator::GlobalClock;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::aci::AcI;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::scheduler::workflow_scheduler::WorkflowScheduler;
+use vrm_rust_workflow::domain::vrm_system_model::grid_resource_management_system::vrm_component_trait::VrmComponent;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::{ReservationState, ReservationTrait};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::{ReservationId, ReservationStore};
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, ReservationName, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::{create_adc, create_heft_sync_scheduler, get_aci_dto, get_workflow_dto_with_one_task};
+
+/// A moldable task with 16 CPUs must be split into two co-allocated sub-nodes, which fit on VrmComponents with 8 CPUs.
+#[test]
+fn test_split_oversized_moldable_node() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_workflow_dto_with_one_task("Test-Split-Workflow".to_string(), ReservationStateDto::Open, ReservationProceedingDto::Reserve);
+    workflow_dto.tasks[0].node_reservation.cpus = 16;
+    workflow_dto.tasks[0].node_reservation.is_moldable = true;
+
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let (split_node_ids, node_res_ids, co_allocation_keys, number_of_co_allocations, number_of_sync_dependencies) = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            let split_node_ids = workflow.split_oversized_nodes(8, store.clone()).expect("Splitting of the workflow nodes failed.");
+            let nodes: Vec<_> =
+                [WorkflowNodeId::new("c0"), WorkflowNodeId::new("c0.split.1")].iter().map(|id| workflow.nodes.get(id).unwrap().clone()).collect();
+            (
+                split_node_ids,
+                nodes.iter().map(|node| node.reservation_id).collect::<Vec<_>>(),
+                nodes.iter().map(|node| node.co_allocation_key.clone()).collect::<Vec<_>>(),
+                workflow.co_allocations.len(),
+                workflow.sync_dependencies.len(),
+            )
+        })
+        .expect("Workflow not found.");
+
+    assert_eq!(split_node_ids, vec![WorkflowNodeId::new("c0.split.1")]);
+    assert_eq!(number_of_co_allocations, 1);
+    assert_eq!(number_of_sync_dependencies, 1);
+    assert!(co_allocation_keys[0].is_some());
+    assert_eq!(co_allocation_keys[0], co_allocation_keys[1]);
+
+    let mut total_moldable_work = 0;
+    for node_res_id in node_res_ids {
+        let reservation = store.get_reservation_snapshot(node_res_id).expect("Node reservation not found.");
+        assert_eq!(reservation.get_reserved_capacity(), 8);
+        assert!(reservation.is_moldable());
+        total_moldable_work += reservation.get_moldable_work();
+    }
+    assert_eq!(total_moldable_work, 16 * 50);
+}
+
+/// Reserves a Workflow with one moldable task of 16 CPUs on two AcIs with a single node of 8 CPUs each.
+///
+/// # Returns
+/// The result of the reservation, the store and the reservation id of the Workflow.
+async fn reserve_oversized_task(split_oversized_nodes: bool) -> (bool, ReservationStore, ReservationId) {
+    let clock = Arc::new(GlobalClock::new(true));
+    let store = ReservationStore::new();
+
+    let mut vrm_components: Vec<Box<dyn VrmComponent + Send>> = Vec::new();
+    for aci_id in ["AcI-001", "AcI-002"] {
+        let mut aci_dto = get_aci_dto("ADC-001".to_string());
+        aci_dto.id = aci_id.to_string();
+        if let RmsSystemWrapper::DummyRms(ref mut dummy_rms_dto) = aci_dto.rms_system {
+            dummy_rms_dto.grid_nodes = vec![GridNodeDto { id: "Node-001".to_string(), cpus: 8, connected_to_router: vec!["Router-001".to_string()] }];
+        }
+        let aci = AcI::from_dto(aci_dto, clock.clone(), store.clone()).await.expect("Error in the AcI Mock process happened.");
+        vrm_components.push(Box::new(aci));
+    }
+    let mut adc = create_adc(vrm_components, clock, store.clone());
+
+    let mut workflow_dto =
+        get_workflow_dto_with_one_task("Test-Split-Workflow".to_string(), ReservationStateDto::Open, ReservationProceedingDto::Reserve);
+    workflow_dto.tasks[0].node_reservation.cpus = 16;
+    workflow_dto.tasks[0].node_reservation.is_moldable = true;
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let mut scheduler = create_heft_sync_scheduler(store.clone());
+    scheduler.base.split_oversized_nodes = split_oversized_nodes;
+    let is_reserved = scheduler.reserve(workflow_res_id, &mut adc).is_ok();
+    return (is_reserved, store, workflow_res_id);
+}
+
+/// Without splitting, the task of 16 CPUs fits on no AcI. With splitting enabled, the HEFTSync scheduler splits it
+/// into two co-allocated parts of 8 CPUs, which are reserved at the same time on different AcIs.
+#[tokio::test]
+async fn test_heft_sync_reserves_split_oversized_node() {
+    let (is_reserved, _, _) = reserve_oversized_task(false).await;
+    assert!(!is_reserved, "The oversized task was reserved without splitting.");
+
+    let (is_reserved, store, workflow_res_id) = reserve_oversized_task(true).await;
+    assert!(is_reserved, "The split task was not reserved.");
+
+    let number_of_nodes = store.with_workflow_mut(workflow_res_id, |workflow| workflow.nodes.len()).expect("Workflow not found.");
+    assert_eq!(number_of_nodes, 2);
+
+    // Both parts are placed as one CoAllocation and together cover the moldable work of the original task
+    let part_res_ids: Vec<ReservationId> = ["c0", "c0.split.1"].iter().map(|name| store.get_key_for_name(ReservationName::new(*name))).collect();
+    let mut total_work = 0;
+    for part_res_id in &part_res_ids {
+        assert_eq!(store.get_state(*part_res_id), ReservationState::ReserveAnswer);
+        total_work += store.get_reserved_capacity(*part_res_id) * store.get_task_duration(*part_res_id);
+    }
+    assert_eq!(store.get_assigned_start(part_res_ids[0]), store.get_assigned_start(part_res_ids[1]));
+    assert!(total_work >= 16 * 50);
+}
@@ -0,0 +1,74 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::Reservation;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, CoAllocationId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Builds the two merged diamonds "Diamond-A" and "Diamond-B" (each c0 -> (c1, c2) -> c3) in a new store.
+fn build_workflow() -> (Workflow, ReservationStore) {
+    let store = ReservationStore::new();
+    let mut diamonds: Vec<Workflow> = ["Diamond-A", "Diamond-B"]
+        .into_iter()
+        .map(|workflow_name| {
+            let workflow_dto =
+                get_direct_mapping_workflow_dto(workflow_name.to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+            let workflow_res_id =
+                Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+            match store.get_reservation_snapshot(workflow_res_id) {
+                Some(Reservation::Workflow(workflow)) => workflow,
+                _ => panic!("Expected Workflow reservation"),
+            }
+        })
+        .collect();
+
+    let diamond_b = diamonds.pop().unwrap();
+    let diamond_a = diamonds.pop().unwrap();
+    let workflow = diamond_a.merge(diamond_b, &store).expect("Merging of the workflows failed.");
+    return (workflow, store);
+}
+
+#[test]
+fn test_entry_and_exit_points_are_stable() {
+    let (first_workflow, _) = build_workflow();
+    let (second_workflow, _) = build_workflow();
+
+    assert_eq!(first_workflow.entry_nodes, second_workflow.entry_nodes);
+    assert_eq!(first_workflow.exit_nodes, second_workflow.exit_nodes);
+    assert_eq!(first_workflow.entry_co_allocation, second_workflow.entry_co_allocation);
+    assert_eq!(first_workflow.exit_co_allocation, second_workflow.exit_co_allocation);
+
+    assert_eq!(first_workflow.entry_nodes, vec![WorkflowNodeId::new("Diamond-A.c0"), WorkflowNodeId::new("Diamond-B.c0")]);
+    assert_eq!(first_workflow.exit_nodes, vec![WorkflowNodeId::new("Diamond-A.c3"), WorkflowNodeId::new("Diamond-B.c3")]);
+    assert_eq!(first_workflow.entry_co_allocation, vec![CoAllocationId::new("Diamond-A.c0"), CoAllocationId::new("Diamond-B.c0")]);
+    assert_eq!(first_workflow.exit_co_allocation, vec![CoAllocationId::new("Diamond-A.c3"), CoAllocationId::new("Diamond-B.c3")]);
+}
+
+/// Both diamonds (and c1, c2 within each diamond) have equal upward ranks, the ties are ordered by the CoAllocation id.
+#[test]
+fn test_equal_upward_ranks_are_ordered_by_id() {
+    let (mut first_workflow, first_store) = build_workflow();
+    let (mut second_workflow, second_store) = build_workflow();
+
+    let first_order: Vec<CoAllocationId> = first_workflow
+        .calculate_upward_rank(10, &first_store)
+        .unwrap()
+        .into_iter()
+        .map(|workflow_node| workflow_node.co_allocation_key.unwrap())
+        .collect();
+    let second_order: Vec<CoAllocationId> = second_workflow
+        .calculate_upward_rank(10, &second_store)
+        .unwrap()
+        .into_iter()
+        .map(|workflow_node| workflow_node.co_allocation_key.unwrap())
+        .collect();
+
+    assert_eq!(first_order, second_order);
+    assert_eq!(first_order.len(), 8);
+    for ranked_pair in first_order.windows(2) {
+        let a_rank = first_workflow.co_allocations.get(&ranked_pair[0]).unwrap().rank_upward;
+        let b_rank = first_workflow.co_allocations.get(&ranked_pair[1]).unwrap().rank_upward;
+        assert!(a_rank > b_rank || (a_rank == b_rank && ranked_pair[0].id < ranked_pair[1].id));
+    }
+}
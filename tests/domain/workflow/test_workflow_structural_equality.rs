@@ -0,0 +1,77 @@
+use vrm_rust_workflow::api::workflow_dto::client_dto::ClientsDto;
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation::Reservation;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow_structural_diff::StructuralElement;
+use vrm_rust_workflow::loader::parser::parse_json_file;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+const JSON_FILE_PATH: &str = "src/data/test/test_workflow_with_simple_co_allocation_graph.json";
+
+/// Builds the Workflow in a fresh ReservationStore, so that all reservation ids are generated independently.
+fn build_workflow(workflow_dto: WorkflowDto) -> Workflow {
+    let store = ReservationStore::new();
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let Some(Reservation::Workflow(workflow)) = store.get_reservation_snapshot(workflow_res_id) else {
+        panic!("Workflow not found.");
+    };
+    return workflow;
+}
+
+fn parse_workflow_file() -> Workflow {
+    let clients_dto: ClientsDto = parse_json_file(JSON_FILE_PATH).expect("Parsing failed.");
+    return build_workflow(clients_dto.clients[0].workflows[0].clone());
+}
+
+#[test]
+fn test_reparsed_file_is_structurally_equal() {
+    let first_workflow = parse_workflow_file();
+    let second_workflow = parse_workflow_file();
+
+    let diff = first_workflow.structural_diff(&second_workflow);
+    assert!(diff.is_empty(), "Re-parsed workflows differ: {:?}", diff);
+    assert!(first_workflow.structurally_equal(&second_workflow));
+}
+
+#[test]
+fn test_task_order_does_not_change_structure() {
+    let clients_dto: ClientsDto = parse_json_file(JSON_FILE_PATH).expect("Parsing failed.");
+    let mut reversed_workflow_dto = clients_dto.clients[0].workflows[0].clone();
+    reversed_workflow_dto.tasks.reverse();
+
+    let workflow = build_workflow(clients_dto.clients[0].workflows[0].clone());
+    let reversed_workflow = build_workflow(reversed_workflow_dto);
+
+    assert!(workflow.structurally_equal(&reversed_workflow), "{:?}", workflow.structural_diff(&reversed_workflow));
+}
+
+/// Removing the dependency c2 -> c3 of the diamond is reported as the only difference.
+#[test]
+fn test_structural_diff_reports_missing_dependency() {
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Diff-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let mut reduced_workflow_dto = workflow_dto.clone();
+    reduced_workflow_dto.tasks[3].node_reservation.dependencies.data = vec!["c1".to_string()];
+
+    let workflow = build_workflow(workflow_dto);
+    let reduced_workflow = build_workflow(reduced_workflow_dto);
+
+    assert!(!workflow.structurally_equal(&reduced_workflow));
+
+    let diff = workflow.structural_diff(&reduced_workflow);
+    assert_eq!(
+        diff.only_in_self,
+        vec![StructuralElement::DataDependency {
+            source: Some(WorkflowNodeId::new("c2")),
+            target: Some(WorkflowNodeId::new("c3")),
+            port: "data".to_string(),
+        }]
+    );
+    assert!(diff.only_in_other.is_empty());
+}
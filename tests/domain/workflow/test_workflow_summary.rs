@@ -0,0 +1,62 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{DataInDto, ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ClientId;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow_summary::WorkflowSummary;
+use vrm_rust_workflow::generate_system_model;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Workflow "Simulation-Run-0": Data-Preprocessing-1 and -2 -> Data-Preprocessing-3 -> Data-Preprocessing-4 with 8 cpus each
+/// and durations of 2, 2, 1800 and 2. All edges are also SyncDependencies, so that all nodes form one CoAllocation.
+/// The DataDependencies are implicit and transfer no data.
+#[test]
+fn test_summary_of_loaded_workflow() {
+    let file_path: &str = "src/data/test/test_workflow_loading_01.json";
+    let store = ReservationStore::new();
+    generate_system_model(file_path, store.clone()).expect("Loading of system model failed.");
+
+    let client_id = ClientId::new("7209cffb-259f-404b-ac91-4795b4ad39e7");
+    let workflow_rid = store
+        .get_client_reservations(&client_id)
+        .into_iter()
+        .find(|rid| store.get_name_for_key(*rid).is_some_and(|name| name.to_string() == "Simulation-Run-0"))
+        .expect("Workflow 'Simulation-Run-0' not found in client reservations");
+
+    let summary = store
+        .with_workflow_mut(workflow_rid, |workflow| {
+            workflow.calculate_upward_rank(10, &store).unwrap();
+            workflow.summary(&store)
+        })
+        .expect("Workflow not found.");
+
+    assert_eq!(
+        summary,
+        WorkflowSummary { node_count: 4, co_allocation_count: 1, total_compute_work: (2 + 2 + 1800 + 2) * 8, total_data_volume: 0, depth: 1 }
+    );
+}
+
+/// Workflow c0 -> (c1, c2) -> c3 with 2 cpus and a duration of 50 each, where c1 additionally receives the 50 units of
+/// preprocessed data of c0.
+#[test]
+fn test_summary_of_diamond_workflow() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Summary-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[1].node_reservation.data_in.push(DataInDto {
+        source_reservation: "c0".to_string(),
+        source_port: "preprocessed_data".to_string(),
+        file: Some("preprocessed.h5".to_string()),
+    });
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    let summary = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            workflow.calculate_upward_rank(10, &store).unwrap();
+            workflow.summary(&store)
+        })
+        .expect("Workflow not found.");
+
+    assert_eq!(summary, WorkflowSummary { node_count: 4, co_allocation_count: 4, total_compute_work: 4 * 50 * 2, total_data_volume: 50, depth: 3 });
+}
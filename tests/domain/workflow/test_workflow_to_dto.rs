@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use vrm_rust_workflow::api::workflow_dto::client_dto::ClientsDto;
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{DataInDto, ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::api::workflow_dto::workflow_dto::WorkflowDto;
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ClientId;
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+use vrm_rust_workflow::loader::parser::parse_json_file;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+/// Node (id, duration, cpus) and dependencies (source, target, port, size/bandwidth) of a workflow, independent of their order.
+#[derive(Debug, PartialEq, Eq)]
+struct WorkflowGraph {
+    nodes: HashSet<(String, i64, i64)>,
+    data_dependencies: HashSet<(String, String, String, i64, i64)>,
+    sync_dependencies: HashSet<(String, String, String, i64)>,
+}
+
+/// Parses the DTO into a Workflow and returns its graph together with the DTO reconstructed by `to_dto`.
+fn parse_workflow(workflow_dto: WorkflowDto) -> (WorkflowGraph, WorkflowDto) {
+    let store = ReservationStore::new();
+    let workflow_res_id =
+        Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).expect("Workflow construction failed.");
+
+    return store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            let graph = WorkflowGraph {
+                nodes: workflow
+                    .nodes
+                    .iter()
+                    .map(|(node_id, node)| {
+                        (node_id.id.clone(), store.get_task_duration(node.reservation_id), store.get_reserved_capacity(node.reservation_id))
+                    })
+                    .collect(),
+                data_dependencies: workflow
+                    .data_dependencies
+                    .values()
+                    .map(|dep| {
+                        (dep.source_node.clone().unwrap().id, dep.target_node.clone().unwrap().id, dep.port_name.clone(), dep.size, dep.latency)
+                    })
+                    .collect(),
+                sync_dependencies: workflow
+                    .sync_dependencies
+                    .values()
+                    .map(|dep| (dep.source_node.clone().unwrap().id, dep.target_node.clone().unwrap().id, dep.port_name.clone(), dep.bandwidth))
+                    .collect(),
+            };
+            (graph, workflow.to_dto(&store))
+        })
+        .expect("Workflow not found.");
+}
+
+/// Round trip through JSON, like writing the workflow back into a file and loading it again.
+fn round_trip_json(workflow_dto: &WorkflowDto) -> WorkflowDto {
+    let json = serde_json::to_string(workflow_dto).expect("Serialization failed.");
+    return serde_json::from_str(&json).expect("Deserialization failed.");
+}
+
+#[test]
+fn test_round_trip_of_loaded_workflow() {
+    let clients_dto: ClientsDto = parse_json_file("src/data/test/test_workflow_with_simple_co_allocation_graph.json").expect("Parsing failed.");
+    let workflow_dto = clients_dto.clients[0].workflows[0].clone();
+
+    let (graph, reconstructed_dto) = parse_workflow(workflow_dto);
+    let (round_trip_graph, _) = parse_workflow(round_trip_json(&reconstructed_dto));
+
+    assert_eq!(round_trip_graph, graph);
+    assert_eq!(reconstructed_dto.id, "Simulation-Run-0");
+    assert_eq!(reconstructed_dto.booking_interval_start, 10);
+    assert_eq!(reconstructed_dto.booking_interval_end, 1000000);
+}
+
+/// Workflow c0 -> (c1, c2) -> c3 with implicit data dependencies, plus a port based data dependency c1 -> c3.
+#[test]
+fn test_round_trip_keeps_implicit_and_port_dependencies_apart() {
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-To-Dto-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[3].node_reservation.data_in =
+        vec![DataInDto { source_reservation: "c1".to_string(), source_port: "preprocessed_data".to_string(), file: None }];
+
+    let (graph, reconstructed_dto) = parse_workflow(workflow_dto);
+    assert_eq!(graph.data_dependencies.len(), 5);
+
+    let (round_trip_graph, _) = parse_workflow(round_trip_json(&reconstructed_dto));
+    assert_eq!(round_trip_graph, graph);
+
+    // Implicit dependencies (size 0) are emitted into `dependencies`, not as `data_out` ports
+    let task = |id: &str| reconstructed_dto.tasks.iter().find(|task| task.id == id).expect("Task not found.");
+    assert_eq!(task("c1").node_reservation.dependencies.data, vec!["c0".to_string()]);
+    assert_eq!(task("c3").node_reservation.dependencies.data, vec!["c1".to_string(), "c2".to_string()]);
+    assert!(task("c0").node_reservation.data_out.is_empty());
+
+    // The port based dependency is emitted as `data_out` of c1 and `data_in` of c3
+    let c1_data_out = &task("c1").node_reservation.data_out;
+    assert_eq!(c1_data_out.len(), 1);
+    assert_eq!(c1_data_out[0].name, "preprocessed_data");
+    assert_eq!(c1_data_out[0].size, Some(50));
+
+    let c3_data_in = &task("c3").node_reservation.data_in;
+    assert_eq!(c3_data_in.len(), 1);
+    assert_eq!(c3_data_in[0].source_reservation, "c1");
+    assert_eq!(c3_data_in[0].source_port, "preprocessed_data");
+}
@@ -0,0 +1,72 @@
+use vrm_rust_workflow::api::workflow_dto::reservation_dto::{ReservationProceedingDto, ReservationStateDto};
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::{ClientId, DataDependencyId, WorkflowNodeId};
+use vrm_rust_workflow::domain::vrm_system_model::workflow::workflow::Workflow;
+use vrm_rust_workflow::error::Error;
+
+use crate::common::get_direct_mapping_workflow_dto;
+
+fn get_position(order: &[WorkflowNodeId], node_id: &str) -> usize {
+    return order.iter().position(|ordered_node_id| *ordered_node_id == WorkflowNodeId::new(node_id)).expect("Node is missing in the order.");
+}
+
+/// Diamond workflow c0 -> (c1, c2) -> c3.
+#[test]
+fn test_topological_order_of_diamond() {
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Topological-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id = Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).unwrap();
+
+    let order = store.with_workflow_mut(workflow_res_id, |workflow| workflow.topological_order()).unwrap().expect("Workflow is acyclic.");
+
+    assert_eq!(order.len(), 4);
+    assert_eq!(order.first(), Some(&WorkflowNodeId::new("c0")));
+    assert_eq!(order.last(), Some(&WorkflowNodeId::new("c3")));
+    assert!((1..=2).contains(&get_position(&order, "c1")));
+    assert!((1..=2).contains(&get_position(&order, "c2")));
+}
+
+/// Sync declared on both nodes only demands a simultaneous start and does not prevent an order.
+#[test]
+fn test_topological_order_with_mutual_sync() {
+    let store = ReservationStore::new();
+    let mut workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Topological-Sync-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    workflow_dto.tasks[1].node_reservation.dependencies.sync.push("c2".to_string());
+    workflow_dto.tasks[2].node_reservation.dependencies.sync.push("c1".to_string());
+    let workflow_res_id = Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).unwrap();
+
+    let order = store.with_workflow_mut(workflow_res_id, |workflow| workflow.topological_order()).unwrap().expect("Workflow is acyclic.");
+
+    assert_eq!(order, vec![WorkflowNodeId::new("c0"), WorkflowNodeId::new("c1"), WorkflowNodeId::new("c2"), WorkflowNodeId::new("c3")]);
+}
+
+/// A DataDependency c3 -> c0, which is added after the construction, closes the cycle c0 -> c1 -> c3 -> c0.
+#[test]
+fn test_topological_order_of_cyclic_workflow() {
+    let store = ReservationStore::new();
+    let workflow_dto =
+        get_direct_mapping_workflow_dto("Test-Topological-Cyclic-Workflow".to_string(), ReservationProceedingDto::Reserve, ReservationStateDto::Open);
+    let workflow_res_id = Workflow::create_form_dto(workflow_dto, ClientId::new("Test-Client-001"), store.clone()).unwrap();
+
+    let result = store
+        .with_workflow_mut(workflow_res_id, |workflow| {
+            let mut back_edge = workflow.data_dependencies.values().next().unwrap().clone();
+            back_edge.source_node = Some(WorkflowNodeId::new("c3"));
+            back_edge.target_node = Some(WorkflowNodeId::new("c0"));
+            workflow.data_dependencies.insert(DataDependencyId::new("c3-c0"), back_edge);
+            workflow.nodes.get_mut(&WorkflowNodeId::new("c0")).unwrap().incoming_data.push(DataDependencyId::new("c3-c0"));
+
+            workflow.topological_order()
+        })
+        .unwrap();
+
+    match result {
+        Err(Error::CyclicWorkflow { cycle }) => {
+            assert!(cycle.contains(&WorkflowNodeId::new("c0")), "Unexpected cycle {:?}", cycle);
+            assert!(cycle.contains(&WorkflowNodeId::new("c3")), "Unexpected cycle {:?}", cycle);
+        }
+        other => panic!("Expected a CyclicWorkflow error, got {:?}.", other),
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+
+use vrm_rust_workflow::domain::vrm_system_model::reservation::reservation_store::ReservationStore;
+use vrm_rust_workflow::domain::vrm_system_model::utils::id::ReservationName;
+use vrm_rust_workflow::error::Error;
+use vrm_rust_workflow::generate_system_model;
+use vrm_rust_workflow::loader::parser::parse_workflow_file;
+
+const JSON_FILE_PATH: &str = "src/data/test/test_workflow_with_simple_co_allocation_graph.json";
+
+/// Loads the system model and returns the names of all unprocessed reservations.
+fn load_reservation_names(file_path: &str) -> HashSet<ReservationName> {
+    let store = ReservationStore::new();
+    let system_model = generate_system_model(file_path, store.clone()).expect("Loading of system model failed.");
+
+    return system_model.unprocessed_reservations.iter().map(|res_id| store.get_name_for_key(*res_id).unwrap()).collect();
+}
+
+/// The YAML representation of the JSON test file must result in the same system model.
+#[test]
+fn test_yaml_and_json_result_in_same_system_model() {
+    let json_value: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(JSON_FILE_PATH).unwrap()).unwrap();
+    let yaml = serde_yaml::to_string(&json_value).unwrap();
+
+    let expected_names = load_reservation_names(JSON_FILE_PATH);
+    assert!(!expected_names.is_empty());
+
+    for ext in ["yaml", "yml"] {
+        let yaml_file_path = std::env::temp_dir().join(format!("test_workflow_yaml_loading_{}.{}", std::process::id(), ext));
+        std::fs::write(&yaml_file_path, &yaml).unwrap();
+
+        let names = load_reservation_names(yaml_file_path.to_str().unwrap());
+        let _ = std::fs::remove_file(&yaml_file_path);
+
+        assert_eq!(names, expected_names, "System model loaded from .{} differs from the JSON system model.", ext);
+    }
+}
+
+/// Files with an unknown extension are parsed as JSON.
+#[test]
+fn test_unknown_file_format_falls_back_to_json() {
+    let file_path = std::env::temp_dir().join(format!("test_workflow_yaml_loading_fallback_{}.toml", std::process::id()));
+
+    std::fs::write(&file_path, r#"{"clients": [], "id": 7}"#).unwrap();
+    let parsed: Result<serde_json::Value, Error> = parse_workflow_file(file_path.to_str().unwrap());
+    assert_eq!(parsed.unwrap(), serde_json::json!({"clients": [], "id": 7}));
+
+    // YAML content is not accepted, as the file is parsed as JSON
+    std::fs::write(&file_path, "clients: []").unwrap();
+    let parsed: Result<serde_json::Value, Error> = parse_workflow_file(file_path.to_str().unwrap());
+    let _ = std::fs::remove_file(&file_path);
+
+    assert!(matches!(parsed, Err(Error::Parse { .. })), "Expected a JSON parse error, got {:?}", parsed);
+}
+
+#[test]
+fn test_malformed_yaml() {
+    let yaml_file_path = std::env::temp_dir().join(format!("test_workflow_yaml_loading_malformed_{}.yaml", std::process::id()));
+    std::fs::write(&yaml_file_path, "clients: [unclosed").unwrap();
+
+    let result = generate_system_model(yaml_file_path.to_str().unwrap(), ReservationStore::new());
+    let _ = std::fs::remove_file(&yaml_file_path);
+
+    assert!(matches!(result, Err(Error::YamlDeserializationError(_))), "Expected YamlDeserializationError, got {:?}", result.err());
+}
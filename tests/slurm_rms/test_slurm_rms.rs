@@ -294,6 +294,8 @@ fn create_node_reservation(res_name: ReservationName, reservation_state: Reserva
         is_moldable: false,
         moldable_work: duration,
         frag_delta: 0.0,
+        is_moveable: false,
+        priority: 0,
     };
 
     let node_res = NodeReservation {
@@ -331,6 +333,8 @@ fn create_link_reservation(res_name: ReservationName, reservation_state: Reserva
         is_moldable: false,
         moldable_work: duration,
         frag_delta: 0.0,
+        is_moveable: false,
+        priority: 0,
     };
 
     let link_res = LinkReservation { base, end_point: None, start_point: None };